@@ -1,6 +1,7 @@
 use super::truetype;
 
 use pinot::avar::{Avar, AVAR};
+use pinot::cmap::{Charmap, CharmapProxy, Cmap, CMAP};
 use pinot::colr::{Colr, COLR};
 use pinot::cpal::{Cpal, CPAL};
 use pinot::fvar::{Fvar, FVAR};
@@ -14,6 +15,7 @@ pub struct FontInfo {
     pub axis_count: u16,
     pub loca_fmt: u8,
     pub hmetric_count: u16,
+    pub charmap: CharmapProxy,
 }
 
 impl FontInfo {
@@ -35,12 +37,17 @@ impl FontInfo {
             .hhea()
             .map(|hhea| hhea.num_long_metrics())
             .unwrap_or_default();
+        let charmap = provider
+            .cmap()
+            .map(|cmap| CharmapProxy::new(&cmap))
+            .unwrap_or_default();
         Self {
             upem,
             glyph_count,
             loca_fmt,
             axis_count,
             hmetric_count,
+            charmap,
         }
     }
 }
@@ -50,6 +57,7 @@ pub struct Cached {
     pub simple: SimpleCached,
     pub color: Option<ColorCached>,
     pub var: Option<VarCached>,
+    pub cmap: u32,
     pub info: FontInfo,
 }
 
@@ -75,11 +83,13 @@ impl Cached {
         } else {
             None
         };
+        let cmap = font.find_record(CMAP).map(|r| r.offset).unwrap_or(0);
         let info = FontInfo::new(font);
         Self {
             simple,
             color,
             var,
+            cmap,
             info,
         }
     }
@@ -108,6 +118,7 @@ pub struct Data<'a> {
     pub simple: SimpleData<'a>,
     pub color: Option<ColorData<'a>>,
     pub var: Option<VarData<'a>>,
+    pub charmap: Charmap<'a>,
     pub info: FontInfo,
 }
 
@@ -131,10 +142,18 @@ impl<'a> Data<'a> {
                 None
             },
         });
+        let charmap = match cached.cmap {
+            0 => Charmap::default(),
+            offset => cached
+                .info
+                .charmap
+                .materialize(&Cmap::new(font.data.get(offset as usize..)?)),
+        };
         Some(Self {
             simple,
             color,
             var,
+            charmap,
             info: cached.info,
         })
     }
@@ -158,10 +177,15 @@ impl<'a> Data<'a> {
         } else {
             None
         };
+        let charmap = provider
+            .cmap()
+            .map(|cmap| info.charmap.materialize(&cmap))
+            .unwrap_or_default();
         Some(Self {
             simple,
             color,
             var,
+            charmap,
             info,
         })
     }
@@ -173,6 +197,7 @@ impl Default for Data<'_> {
             simple: SimpleData::None,
             color: None,
             var: None,
+            charmap: Charmap::default(),
             info: FontInfo {
                 upem: 1,
                 ..Default::default()
@@ -1,6 +1,7 @@
 use super::geometry::{Bounds, Point, Transform};
 use super::glyph::{Glyph, PathBuilder};
 use super::{cache, data, truetype};
+use pinot::cmap::Charmap;
 use pinot::colr::Paint;
 use pinot::types::{Fixed, Tag};
 use pinot::{FontRef, TableProvider};
@@ -9,6 +10,8 @@ use std::collections::{HashMap, HashSet};
 pub struct Context {
     cache: super::cache::Cache,
     state: State,
+    /// Normalized coordinates before the avar 2.0 cross axis mapping.
+    base_coords: Vec<i16>,
     coords: Vec<i16>,
 }
 
@@ -33,12 +36,14 @@ impl Context {
         Self {
             cache: cache::Cache::new(8),
             state: State::new(),
+            base_coords: vec![],
             coords: vec![],
         }
     }
 
     pub fn new_scaler<'a>(&'a mut self, provider: &impl TableProvider<'a>) -> Builder<'a> {
         let data = data::Data::from_table_provider(provider).unwrap_or_default();
+        self.base_coords.clear();
         self.coords.clear();
         Builder {
             ctx: self,
@@ -52,6 +57,7 @@ impl Context {
     pub fn new_scaler_with_id<'a>(&'a mut self, font: &FontRef<'a>, font_id: u64) -> Builder<'a> {
         let data =
             data::Data::from_cached(font, &self.cache.get(font, font_id)).unwrap_or_default();
+        self.base_coords.clear();
         self.coords.clear();
         Builder {
             ctx: self,
@@ -88,7 +94,8 @@ impl<'a> Builder<'a> {
         I::Item: Into<(Tag, f32)>,
     {
         if let Some(var) = self.font.var {
-            self.ctx.coords.resize(var.fvar.num_axes() as usize, 0);
+            let num_axes = var.fvar.num_axes() as usize;
+            self.ctx.base_coords.resize(num_axes, 0);
             for setting in settings {
                 let (tag, value) = setting.into();
                 for axis in var.fvar.axes() {
@@ -100,12 +107,17 @@ impl<'a> Builder<'a> {
                                 .map(|map| map.apply(coord))
                                 .unwrap_or(coord);
                         }
-                        if let Some(c) = self.ctx.coords.get_mut(axis.index as usize) {
+                        if let Some(c) = self.ctx.base_coords.get_mut(axis.index as usize) {
                             *c = coord.to_f2dot14();
                         }
                     }
                 }
             }
+            self.ctx.coords.resize(num_axes, 0);
+            match var.avar {
+                Some(avar) => avar.apply_deltas(&self.ctx.base_coords, &mut self.ctx.coords),
+                None => self.ctx.coords.copy_from_slice(&self.ctx.base_coords),
+            }
         }
         self
     }
@@ -146,6 +158,10 @@ impl<'a> Scaler<'a> {
         self.coords
     }
 
+    pub fn charmap(&self) -> Charmap<'a> {
+        self.font.charmap
+    }
+
     pub fn glyph(&mut self, gid: u16) -> Option<Glyph> {
         let mut glyph = Glyph::default();
         if load_glyph(self, gid, &mut glyph) {
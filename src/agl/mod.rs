@@ -0,0 +1,217 @@
+//! Adobe Glyph List name synthesis and parsing.
+//!
+//! This module maps glyph names to Unicode sequences following the
+//! [Adobe Glyph List Specification](https://github.com/adobe-type-tools/agl-specification)
+//! and synthesizes names for codepoints that round trip through the same
+//! algorithm.
+
+mod data;
+
+use crate::cmap::ReverseCharmap;
+use crate::parse_prelude::GlyphId;
+use crate::post::GlyphNames;
+use crate::TableProvider;
+use data::{NAMES, PREFERRED};
+use std::{borrow::Cow, collections::HashSet, format, string::String, vec::Vec};
+
+/// Returns the codepoint for the specified name in the Adobe Glyph List.
+pub fn lookup(name: &str) -> Option<u32> {
+    let index = NAMES.binary_search_by(|entry| entry.0.cmp(name)).ok()?;
+    Some(NAMES[index].1 as u32)
+}
+
+/// Returns the Unicode sequence for the specified glyph name. Any suffix
+/// following the first period is ignored and the remainder is split into
+/// components separated by underscores. Each component is either a name in
+/// the Adobe Glyph List, `uni` followed by one or more groups of four
+/// uppercase hexadecimal digits or `u` followed by four to six uppercase
+/// hexadecimal digits. Components that do not match are skipped so the
+/// sequence is empty if the name carries no Unicode information.
+pub fn to_unicode(name: &str) -> Vec<u32> {
+    let mut codepoints = Vec::new();
+    let base = name.split('.').next().unwrap_or_default();
+    for component in base.split('_') {
+        if let Some(codepoint) = lookup(component) {
+            codepoints.push(codepoint);
+        } else if let Some(digits) = component.strip_prefix("uni") {
+            if digits.is_empty() || digits.len() % 4 != 0 || !digits.is_ascii() {
+                continue;
+            }
+            let start = codepoints.len();
+            for i in (0..digits.len()).step_by(4) {
+                match parse_hex(&digits[i..i + 4]) {
+                    Some(codepoint) => codepoints.push(codepoint),
+                    None => {
+                        codepoints.truncate(start);
+                        break;
+                    }
+                }
+            }
+        } else if let Some(digits) = component.strip_prefix('u') {
+            if (4..=6).contains(&digits.len()) {
+                codepoints.extend(parse_hex(digits));
+            }
+        }
+    }
+    codepoints
+}
+
+/// Returns the codepoint for the specified glyph name if it maps to a
+/// single codepoint.
+pub fn to_codepoint(name: &str) -> Option<u32> {
+    match to_unicode(name).as_slice() {
+        [codepoint] => Some(*codepoint),
+        _ => None,
+    }
+}
+
+/// Returns the preferred glyph name for the specified codepoint. This is the
+/// name in the Adobe Glyph List when one is conventionally used for the
+/// character and `uniXXXX` or `uXXXXX` otherwise.
+pub fn glyph_name(codepoint: u32) -> Cow<'static, str> {
+    if let Ok(index) = PREFERRED.binary_search_by(|entry| (entry.0 as u32).cmp(&codepoint)) {
+        return Cow::Borrowed(NAMES[PREFERRED[index].1 as usize].0);
+    }
+    if codepoint <= 0xFFFF {
+        Cow::Owned(format!("uni{:04X}", codepoint))
+    } else {
+        Cow::Owned(format!("u{:X}", codepoint))
+    }
+}
+
+/// Returns the glyph name for a ligature of the specified codepoints formed
+/// by joining the names of the components with underscores.
+pub fn ligature_name(codepoints: &[u32]) -> String {
+    let mut name = String::new();
+    for (i, codepoint) in codepoints.iter().enumerate() {
+        if i != 0 {
+            name.push('_');
+        }
+        name.push_str(&glyph_name(*codepoint));
+    }
+    name
+}
+
+/// Returns names for every glyph in the specified font. Names are taken from
+/// the PostScript or compact font format tables when available. Remaining
+/// glyphs are named after the lowest codepoint that maps to them in the
+/// character map or `glyphN` for unmapped glyphs. Synthesized names that
+/// collide with an existing name receive a numeric suffix.
+pub fn glyph_names<'a>(font: &impl TableProvider<'a>) -> GlyphNames<'a> {
+    let mut names = GlyphNames::new(font).into_names();
+    let num_glyphs = font.maxp().map(|maxp| maxp.num_glyphs()).unwrap_or(0) as usize;
+    if names.len() < num_glyphs {
+        names.resize(num_glyphs, None);
+    }
+    if names.iter().all(|name| name.is_some()) {
+        return GlyphNames::from_names(names);
+    }
+    let reverse = font.charmap().map(|charmap| ReverseCharmap::new(&charmap));
+    let mut used = names
+        .iter()
+        .flatten()
+        .map(|name| String::from(name.as_ref()))
+        .collect::<HashSet<_>>();
+    for (glyph_id, name) in names.iter_mut().enumerate() {
+        if name.is_some() {
+            continue;
+        }
+        let glyph_id = glyph_id as GlyphId;
+        let base = if glyph_id == 0 {
+            Cow::Borrowed(".notdef")
+        } else {
+            match reverse
+                .as_ref()
+                .and_then(|reverse| reverse.codepoints(glyph_id).next())
+            {
+                Some(codepoint) => glyph_name(codepoint),
+                None => Cow::Owned(format!("glyph{}", glyph_id)),
+            }
+        };
+        let mut unique = base.clone();
+        let mut suffix = 1;
+        while used.contains(unique.as_ref()) {
+            unique = Cow::Owned(format!("{}.{}", base, suffix));
+            suffix += 1;
+        }
+        used.insert(String::from(unique.as_ref()));
+        *name = Some(unique);
+    }
+    GlyphNames::from_names(names)
+}
+
+/// Parses four to six uppercase hexadecimal digits as a Unicode scalar value.
+fn parse_hex(digits: &str) -> Option<u32> {
+    if !digits
+        .bytes()
+        .all(|b| b.is_ascii_digit() || (b'A'..=b'F').contains(&b))
+    {
+        return None;
+    }
+    let codepoint = u32::from_str_radix(digits, 16).ok()?;
+    match codepoint {
+        0xD800..=0xDFFF => None,
+        _ if codepoint > 0x10FFFF => None,
+        _ => Some(codepoint),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{TestFont, Writer};
+    use crate::FontRef;
+
+    #[test]
+    fn parse_names() {
+        assert_eq!(lookup("Aacute"), Some(0xC1));
+        assert_eq!(to_unicode("f_f_i.liga"), [0x66, 0x66, 0x69]);
+        assert_eq!(to_unicode("uni00410042_u1F600"), [0x41, 0x42, 0x1F600]);
+        assert_eq!(to_codepoint("uni0041.sc"), Some(0x41));
+        // Lowercase digits, surrogates and unknown components carry no
+        // Unicode information.
+        assert_eq!(to_unicode("uni004a"), []);
+        assert_eq!(to_unicode("uniD800"), []);
+        assert_eq!(to_unicode("foo_a"), [0x61]);
+        assert_eq!(to_codepoint("f_i"), None);
+    }
+
+    #[test]
+    fn synthesize_names() {
+        assert_eq!(glyph_name(0xE9), "eacute");
+        assert_eq!(glyph_name(0x4E00), "uni4E00");
+        assert_eq!(glyph_name(0x1F600), "u1F600");
+        assert_eq!(ligature_name(&[0x66, 0x4E00]), "f_uni4E00");
+        for codepoint in [0x41, 0xE9, 0x4E00, 0x1F600] {
+            assert_eq!(to_codepoint(&glyph_name(codepoint)), Some(codepoint));
+        }
+    }
+
+    #[test]
+    fn font_names() {
+        // The PostScript table names the first two glyphs only.
+        let mut post = Writer::new();
+        post.u32(0x00020000);
+        for _ in 0..7 {
+            post.u32(0);
+        }
+        post.u16(2).u16(0).u16(258).u8(7).bytes(b"uni4E00");
+        let data = TestFont::new(6)
+            .chars("Aé一")
+            .table(b"post", post.finish())
+            .build();
+        let names = glyph_names(&FontRef::from_index(&data, 0).unwrap());
+        assert_eq!(
+            names.iter().collect::<Vec<_>>(),
+            [
+                Some(".notdef"),
+                Some("uni4E00"),
+                Some("eacute"),
+                Some("uni4E00.1"),
+                Some("glyph4"),
+                Some("glyph5")
+            ]
+        );
+        assert_eq!(names.glyph_id("uni4E00.1"), Some(3));
+    }
+}
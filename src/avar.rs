@@ -1,6 +1,7 @@
 //! Axis variations table.
 
 use super::parse_prelude::*;
+use super::var::item::{DeltaSetIndexMap, Index, ItemVariationStore};
 
 /// Tag for the `avar` table.
 pub const AVAR: Tag = Tag::new(b"avar");
@@ -45,6 +46,66 @@ impl<'a> Avar<'a> {
             values: c.read_slice16()?,
         })
     }
+
+    /// Returns the mapping from axis indices to delta set indices for
+    /// version 2 tables.
+    pub fn axis_index_map(&self) -> Option<DeltaSetIndexMap<'a>> {
+        let offset = self.0.read_u32(self.v2_offset()?)?;
+        DeltaSetIndexMap::new(self.0, offset)
+    }
+
+    /// Returns the item variation store for version 2 tables.
+    pub fn var_store(&self) -> Option<ItemVariationStore<'a>> {
+        let offset = self.0.read_u32(self.v2_offset()? + 4)?;
+        if offset == 0 {
+            return None;
+        }
+        ItemVariationStore::new(self.0, offset)
+    }
+
+    /// Applies the cross axis mapping of version 2 tables to normalized
+    /// coordinates that have already been modified by the segment maps and
+    /// writes the result to `out`. The coordinates are copied unchanged for
+    /// version 1 tables.
+    pub fn apply_deltas(&self, coords: &[NormalizedCoord], out: &mut [NormalizedCoord]) {
+        let store = self.var_store();
+        let index_map = self.axis_index_map();
+        for (i, value) in out.iter_mut().enumerate() {
+            let coord = coords.get(i).copied().unwrap_or(0);
+            let store = match store {
+                Some(store) => store,
+                None => {
+                    *value = coord;
+                    continue;
+                }
+            };
+            // Without an index map, the axis index is used directly as the
+            // delta set index.
+            let index = match index_map {
+                Some(map) => map.get(i as u32),
+                None => Some(Index::new(0, i as u16)),
+            };
+            let delta = index
+                .map(|index| store.delta(index, coords).round().to_i32())
+                .unwrap_or(0);
+            *value = (coord as i32 + delta).clamp(-0x4000, 0x4000) as NormalizedCoord;
+        }
+    }
+
+    /// Returns the offset of the version 2 fields that follow the segment
+    /// maps.
+    fn v2_offset(&self) -> Option<usize> {
+        if self.major_version() < 2 {
+            return None;
+        }
+        let mut c = Cursor::new(self.0.data());
+        c.skip(8)?;
+        for _ in 0..self.num_axes() {
+            let count = c.read_u16()? as usize;
+            c.skip(count * 4)?;
+        }
+        Some(c.offset())
+    }
 }
 
 /// Collection of value maps for a single axis.
@@ -57,19 +118,36 @@ pub struct SegmentMap<'a> {
 impl<'a> SegmentMap<'a> {
     /// Returns a modified copy of the coordinate according to the value maps.
     pub fn apply(&self, coord: Fixed) -> Fixed {
+        self.map(coord, false)
+    }
+
+    /// Returns the coordinate that maps to the specified modified coordinate
+    /// according to the value maps.
+    pub fn apply_inverse(&self, coord: Fixed) -> Fixed {
+        self.map(coord, true)
+    }
+
+    fn map(&self, coord: Fixed, inverse: bool) -> Fixed {
+        let endpoints = |value_map: ValueMap| {
+            let from = Fixed::from_f2dot14(value_map.from_coord);
+            let to = Fixed::from_f2dot14(value_map.to_coord);
+            if inverse {
+                (to, from)
+            } else {
+                (from, to)
+            }
+        };
         let mut prev = ValueMap::default();
         for (i, value_map) in self.values.iter().enumerate() {
             use core::cmp::Ordering::*;
-            let from = Fixed::from_f2dot14(value_map.from_coord);
+            let (from, to) = endpoints(value_map);
             match from.cmp(&coord) {
-                Equal => return Fixed::from_f2dot14(value_map.to_coord),
+                Equal => return to,
                 Greater => {
                     if i == 0 {
                         return coord;
                     }
-                    let to = Fixed::from_f2dot14(value_map.to_coord);
-                    let prev_from = Fixed::from_f2dot14(prev.from_coord);
-                    let prev_to = Fixed::from_f2dot14(prev.to_coord);
+                    let (prev_from, prev_to) = endpoints(prev);
                     return prev_to + ((to - prev_to) * (coord - prev_from) / (from - prev_from));
                 }
                 _ => {}
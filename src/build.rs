@@ -0,0 +1,454 @@
+//! Font file construction.
+
+use crate::checksum::CHECKSUM_MAGIC;
+use crate::font::FontRef;
+use crate::head::HEAD;
+use crate::parse_prelude::*;
+use core::fmt;
+use std::borrow::Cow;
+use std::collections::{BTreeMap, HashMap};
+use std::vec::Vec;
+
+/// Error that occurred while building a font file.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum BuildError {
+    /// The table with the specified tag is too short to contain a field that
+    /// is updated by the builder.
+    TruncatedTable(Tag),
+}
+
+impl fmt::Display for BuildError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::TruncatedTable(tag) => write!(f, "truncated '{}' table", tag),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for BuildError {}
+
+/// Builder for a font file from a set of tables.
+///
+/// Tables are provided as raw byte blobs keyed by tag. The builder emits the
+/// table directory in tag order with the appropriate binary search fields,
+/// pads each table to a four byte boundary, computes the table checksums and
+/// updates the checksum adjustment in the font header table.
+#[derive(Clone, Default, Debug)]
+pub struct FontBuilder<'a> {
+    sfnt_version: u32,
+    tables: BTreeMap<Tag, Cow<'a, [u8]>>,
+}
+
+impl<'a> FontBuilder<'a> {
+    /// Creates a new empty builder for a font with the specified version tag.
+    /// This is typically 0x00010000 for fonts with TrueType outlines and
+    /// `OTTO` for fonts with PostScript outlines.
+    pub fn new(sfnt_version: u32) -> Self {
+        Self {
+            sfnt_version,
+            tables: BTreeMap::new(),
+        }
+    }
+
+    /// Creates a new builder that contains all tables in the specified font.
+    pub fn from_font(font: &FontRef<'a>) -> Option<Self> {
+        let sfnt_version = Buffer::new(font.data).read_u32(font.offset as usize)?;
+        let mut builder = Self::new(sfnt_version);
+        for table in font.tables() {
+            builder.insert(table.record.tag, table.data);
+        }
+        Some(builder)
+    }
+
+    /// Returns the version tag of the font.
+    pub fn sfnt_version(&self) -> u32 {
+        self.sfnt_version
+    }
+
+    /// Sets the version tag of the font.
+    pub fn set_sfnt_version(&mut self, sfnt_version: u32) {
+        self.sfnt_version = sfnt_version;
+    }
+
+    /// Returns the number of tables in the builder.
+    pub fn len(&self) -> usize {
+        self.tables.len()
+    }
+
+    /// Returns true if the builder does not contain any tables.
+    pub fn is_empty(&self) -> bool {
+        self.tables.is_empty()
+    }
+
+    /// Returns an iterator over the tags of the tables in the builder in
+    /// sorted order.
+    pub fn tags(&self) -> impl Iterator<Item = Tag> + '_ {
+        self.tables.keys().copied()
+    }
+
+    /// Returns the data for the table with the specified tag.
+    pub fn get(&self, tag: Tag) -> Option<&[u8]> {
+        self.tables.get(&tag).map(|data| data.as_ref())
+    }
+
+    /// Adds a table to the builder, returning the data of the table that it
+    /// replaces.
+    pub fn insert(&mut self, tag: Tag, data: impl Into<Cow<'a, [u8]>>) -> Option<Cow<'a, [u8]>> {
+        self.tables.insert(tag, data.into())
+    }
+
+    /// Removes the table with the specified tag, returning its data.
+    pub fn remove(&mut self, tag: Tag) -> Option<Cow<'a, [u8]>> {
+        self.tables.remove(&tag)
+    }
+
+    /// Builds the font file. Returns an error if the font header table is
+    /// too short to contain the checksum adjustment.
+    pub fn build(&self) -> Result<Vec<u8>, BuildError> {
+        let tables = self.tables()?;
+        let mut font = Vec::new();
+        let mut offset = directory_len(tables.len()) as u32;
+        let mut records = Vec::with_capacity(tables.len());
+        for (tag, data) in &tables {
+            records.push(TableRecord {
+                tag: *tag,
+                checksum: checksum(data),
+                offset,
+                len: data.len() as u32,
+            });
+            offset += padded_len(data.len()) as u32;
+        }
+        write_directory(&mut font, self.sfnt_version, &records);
+        for (_, data) in &tables {
+            write_table(&mut font, data);
+        }
+        if let Some(record) = records.iter().find(|record| record.tag == HEAD) {
+            let adjustment = CHECKSUM_MAGIC.wrapping_sub(checksum(&font));
+            let offset = record.offset as usize + 8;
+            font[offset..offset + 4].copy_from_slice(&adjustment.to_be_bytes());
+        }
+        Ok(font)
+    }
+
+    /// Returns the tables in tag order with the checksum adjustment in the
+    /// font header table cleared.
+    pub(crate) fn tables(&self) -> Result<Tables<'_>, BuildError> {
+        self.tables
+            .iter()
+            .map(|(tag, data)| {
+                let data = if *tag == HEAD {
+                    if data.len() < 12 {
+                        return Err(BuildError::TruncatedTable(HEAD));
+                    }
+                    let mut head = data.to_vec();
+                    head[8..12].copy_from_slice(&[0; 4]);
+                    Cow::Owned(head)
+                } else {
+                    Cow::Borrowed(data.as_ref())
+                };
+                Ok((*tag, data))
+            })
+            .collect()
+    }
+}
+
+/// Tables of a font in tag order.
+pub(crate) type Tables<'a> = Vec<(Tag, Cow<'a, [u8]>)>;
+
+/// Returns the size of a table directory with the specified number of
+/// tables.
+pub(crate) fn directory_len(num_tables: usize) -> usize {
+    12 + num_tables * 16
+}
+
+/// Returns the size of a table padded to a four byte boundary.
+pub(crate) fn padded_len(len: usize) -> usize {
+    (len + 3) & !3
+}
+
+/// Writes a table directory with the specified version tag and records. The
+/// records must be sorted by tag.
+pub(crate) fn write_directory(buf: &mut Vec<u8>, sfnt_version: u32, records: &[TableRecord]) {
+    let num_tables = records.len() as u16;
+    // An empty directory has no binary search parameters.
+    let (search_range, entry_selector, range_shift) = if num_tables == 0 {
+        (0, 0, 0)
+    } else {
+        let entry_selector = 15 - num_tables.leading_zeros() as u16;
+        let search_range = (1u16 << entry_selector).wrapping_mul(16);
+        let range_shift = num_tables.wrapping_mul(16).wrapping_sub(search_range);
+        (search_range, entry_selector, range_shift)
+    };
+    buf.extend_from_slice(&sfnt_version.to_be_bytes());
+    buf.extend_from_slice(&num_tables.to_be_bytes());
+    buf.extend_from_slice(&search_range.to_be_bytes());
+    buf.extend_from_slice(&entry_selector.to_be_bytes());
+    buf.extend_from_slice(&range_shift.to_be_bytes());
+    for record in records {
+        buf.extend_from_slice(&record.tag.0.to_be_bytes());
+        buf.extend_from_slice(&record.checksum.to_be_bytes());
+        buf.extend_from_slice(&record.offset.to_be_bytes());
+        buf.extend_from_slice(&record.len.to_be_bytes());
+    }
+}
+
+/// Writes the table data followed by padding to a four byte boundary.
+pub(crate) fn write_table(buf: &mut Vec<u8>, data: &[u8]) {
+    buf.extend_from_slice(data);
+    buf.resize(padded_len(buf.len()), 0);
+}
+
+/// Builder for a font collection file.
+///
+/// Tables with identical data are stored once and shared between the fonts
+/// in the collection. Font header tables are never shared since the checksum
+/// adjustment depends on the other tables in each font.
+#[derive(Clone, Default, Debug)]
+pub struct CollectionBuilder<'a> {
+    fonts: Vec<FontBuilder<'a>>,
+    major_version: u16,
+    signature: Option<Cow<'a, [u8]>>,
+}
+
+impl<'a> CollectionBuilder<'a> {
+    /// Creates a new empty builder for a version 1.0 collection.
+    pub fn new() -> Self {
+        Self {
+            fonts: Vec::new(),
+            major_version: 1,
+            signature: None,
+        }
+    }
+
+    /// Returns the major version of the collection header.
+    pub fn major_version(&self) -> u16 {
+        self.major_version
+    }
+
+    /// Sets the major version of the collection header. Only versions 1 and
+    /// 2 are supported; other values are clamped to that range.
+    pub fn set_major_version(&mut self, major_version: u16) {
+        self.major_version = major_version.clamp(1, 2);
+    }
+
+    /// Sets the digital signature table for the collection. This requires a
+    /// version 2.0 header, so the version is updated accordingly.
+    pub fn set_signature(&mut self, data: impl Into<Cow<'a, [u8]>>) {
+        self.major_version = 2;
+        self.signature = Some(data.into());
+    }
+
+    /// Returns the number of fonts in the builder.
+    pub fn len(&self) -> usize {
+        self.fonts.len()
+    }
+
+    /// Returns true if the builder does not contain any fonts.
+    pub fn is_empty(&self) -> bool {
+        self.fonts.is_empty()
+    }
+
+    /// Returns the font at the specified index.
+    pub fn get(&self, index: usize) -> Option<&FontBuilder<'a>> {
+        self.fonts.get(index)
+    }
+
+    /// Returns the font at the specified index for modification.
+    pub fn get_mut(&mut self, index: usize) -> Option<&mut FontBuilder<'a>> {
+        self.fonts.get_mut(index)
+    }
+
+    /// Adds a font to the collection.
+    pub fn push(&mut self, font: FontBuilder<'a>) {
+        self.fonts.push(font);
+    }
+
+    /// Builds the collection file. Returns an error if the font header table
+    /// of any font is too short to contain the checksum adjustment.
+    pub fn build(&self) -> Result<Vec<u8>, BuildError> {
+        let fonts = self
+            .fonts
+            .iter()
+            .map(|font| font.tables())
+            .collect::<Result<Vec<_>, _>>()?;
+        let header_len = 12 + fonts.len() * 4 + if self.major_version >= 2 { 12 } else { 0 };
+        let mut offset = header_len;
+        let mut font_offsets = Vec::with_capacity(fonts.len());
+        for tables in &fonts {
+            font_offsets.push(offset as u32);
+            offset += directory_len(tables.len());
+        }
+        let mut shared: HashMap<&[u8], u32> = HashMap::new();
+        let mut blobs: Vec<&[u8]> = Vec::new();
+        let mut records = Vec::with_capacity(fonts.len());
+        for tables in &fonts {
+            let mut font_records = Vec::with_capacity(tables.len());
+            for (tag, data) in tables {
+                let data: &[u8] = data;
+                let table_offset = match shared.get(data) {
+                    Some(table_offset) if *tag != HEAD => *table_offset,
+                    _ => {
+                        let table_offset = offset as u32;
+                        if *tag != HEAD {
+                            shared.insert(data, table_offset);
+                        }
+                        blobs.push(data);
+                        offset += padded_len(data.len());
+                        table_offset
+                    }
+                };
+                font_records.push(TableRecord {
+                    tag: *tag,
+                    checksum: checksum(data),
+                    offset: table_offset,
+                    len: data.len() as u32,
+                });
+            }
+            records.push(font_records);
+        }
+        let mut file = Vec::with_capacity(offset);
+        file.extend_from_slice(b"ttcf");
+        file.extend_from_slice(&self.major_version.to_be_bytes());
+        file.extend_from_slice(&0u16.to_be_bytes());
+        file.extend_from_slice(&(fonts.len() as u32).to_be_bytes());
+        for font_offset in &font_offsets {
+            file.extend_from_slice(&font_offset.to_be_bytes());
+        }
+        if self.major_version >= 2 {
+            match &self.signature {
+                Some(signature) => {
+                    file.extend_from_slice(b"DSIG");
+                    file.extend_from_slice(&(signature.len() as u32).to_be_bytes());
+                    file.extend_from_slice(&(offset as u32).to_be_bytes());
+                }
+                None => file.extend_from_slice(&[0; 12]),
+            }
+        }
+        for (font, font_records) in self.fonts.iter().zip(&records) {
+            write_directory(&mut file, font.sfnt_version, font_records);
+        }
+        for blob in blobs {
+            write_table(&mut file, blob);
+        }
+        if let (true, Some(signature)) = (self.major_version >= 2, &self.signature) {
+            write_table(&mut file, signature);
+        }
+        for (font_offset, font_records) in font_offsets.iter().zip(&records) {
+            let head = match font_records.iter().find(|record| record.tag == HEAD) {
+                Some(head) => head,
+                None => continue,
+            };
+            let start = *font_offset as usize;
+            let directory = &file[start..start + directory_len(font_records.len())];
+            let sum = font_records
+                .iter()
+                .fold(checksum(directory), |sum, record| {
+                    sum.wrapping_add(record.checksum)
+                });
+            let adjustment = CHECKSUM_MAGIC.wrapping_sub(sum);
+            let offset = head.offset as usize + 8;
+            file[offset..offset + 4].copy_from_slice(&adjustment.to_be_bytes());
+        }
+        Ok(file)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::checksum;
+    use crate::font::{FontDataRef, TableProvider};
+
+    fn builder(name: &[u8]) -> FontBuilder<'static> {
+        let mut builder = FontBuilder::new(0x00010000);
+        builder.insert(HEAD, std::vec![1; 54]);
+        builder.insert(Tag::new(b"name"), name.to_vec());
+        builder.insert(Tag::new(b"post"), &b"post"[..]);
+        builder
+    }
+
+    #[test]
+    fn font() {
+        let data = builder(b"font").build().unwrap();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        assert_eq!(font.len(), 3);
+        assert_eq!(font.table_data(Tag::new(b"name")), Some(&b"font"[..]));
+        assert_eq!(checksum::verify(&font).count(), 0);
+    }
+
+    #[test]
+    fn collection() {
+        let mut collection = CollectionBuilder::new();
+        collection.push(builder(b"first"));
+        collection.push(builder(b"second"));
+        let data = collection.build().unwrap();
+        let fonts = FontDataRef::new(&data).unwrap();
+        assert_eq!(fonts.len(), 2);
+        let post = |index| {
+            let font = fonts.get(index).unwrap();
+            assert_eq!(checksum::verify(&font).count(), 0);
+            font.find_record(Tag::new(b"post")).unwrap().offset
+        };
+        assert_eq!(post(0), post(1));
+    }
+
+    #[test]
+    fn signature() {
+        let mut collection = CollectionBuilder::new();
+        collection.push(builder(b"first"));
+        collection.push(builder(b"first"));
+        collection.set_signature(&b"signature"[..]);
+        assert_eq!(collection.major_version(), 2);
+        let data = collection.build().unwrap();
+        let b = Buffer::new(&data);
+        assert_eq!(b.read_u16(4), Some(2));
+        assert_eq!(b.read_tag(20), Some(Tag::new(b"DSIG")));
+        assert_eq!(b.read_u32(24), Some(9));
+        let offset = b.read_u32(28).unwrap() as usize;
+        assert_eq!(&data[offset..], b"signature\0\0\0");
+        let fonts = FontDataRef::new(&data).unwrap();
+        let (first, second) = (fonts.get(0).unwrap(), fonts.get(1).unwrap());
+        // Identical tables are shared except for the font header, which
+        // holds the checksum adjustment of each font.
+        for tag in [Tag::new(b"name"), Tag::new(b"post"), HEAD] {
+            let offset = |font: &FontRef| font.find_record(tag).unwrap().offset;
+            assert_eq!(offset(&first) == offset(&second), tag != HEAD);
+        }
+        assert_eq!(checksum::verify(&second).count(), 0);
+    }
+
+    #[test]
+    fn truncated_head() {
+        let mut font = builder(b"font");
+        font.insert(HEAD, &[0; 8][..]);
+        assert_eq!(font.build(), Err(BuildError::TruncatedTable(HEAD)));
+        let mut collection = CollectionBuilder::new();
+        collection.push(builder(b"font"));
+        collection.push(font);
+        assert_eq!(collection.build(), Err(BuildError::TruncatedTable(HEAD)));
+    }
+
+    #[test]
+    fn directory_search_parameters() {
+        let header = |count: usize| {
+            let record = TableRecord {
+                tag: HEAD,
+                checksum: 0,
+                offset: 0,
+                len: 0,
+            };
+            let mut buf = Vec::new();
+            write_directory(&mut buf, 0x00010000, &std::vec![record; count]);
+            let b = Buffer::new(&buf);
+            (4..12)
+                .step_by(2)
+                .map(|offset| b.read_u16(offset).unwrap())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(header(0), [0, 0, 0, 0]);
+        assert_eq!(header(1), [1, 16, 0, 0]);
+        assert_eq!(header(3), [3, 32, 1, 16]);
+        assert_eq!(header(16), [16, 256, 4, 0]);
+    }
+}
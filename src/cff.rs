@@ -0,0 +1,320 @@
+//! Compact font format table.
+//!
+//! This provides access to the glyph names defined by the charset of a
+//! `CFF` table. Charstrings are not interpreted.
+
+use crate::parse_prelude::*;
+
+/// Tag for the `CFF` table.
+pub const CFF: Tag = Tag::new(b"CFF ");
+
+/// Number of standard strings. String identifiers below this value refer to
+/// standard strings.
+const STANDARD_STRING_COUNT: u16 = 391;
+
+/// Compact font format table.
+///
+/// <https://adobe-type-tools.github.io/font-tech-notes/pdfs/5176.CFF.pdf>
+#[derive(Copy, Clone)]
+pub struct Cff<'a> {
+    data: Buffer<'a>,
+    strings: Index,
+    char_strings: Index,
+    charset: u32,
+    is_cid: bool,
+}
+
+impl<'a> Cff<'a> {
+    /// Creates a new compact font format table from a byte slice containing
+    /// the table data.
+    pub fn new(data: &'a [u8]) -> Self {
+        let b = Buffer::new(data);
+        Self::parse(b).unwrap_or(Self {
+            data: b,
+            strings: Index::default(),
+            char_strings: Index::default(),
+            charset: 0,
+            is_cid: false,
+        })
+    }
+
+    fn parse(b: Buffer<'a>) -> Option<Self> {
+        if b.read_u8(0)? != 1 {
+            return None;
+        }
+        let header_size = b.read_u8(2)? as usize;
+        let names = Index::new(&b, header_size)?;
+        let top_dicts = Index::new(&b, names.end)?;
+        let strings = Index::new(&b, top_dicts.end)?;
+        let top_dict = top_dicts.get(&b, 0)?;
+        let mut charset = 0;
+        let mut char_strings = None;
+        let mut is_cid = false;
+        parse_dict(top_dict, |op, operand| {
+            match op {
+                15 => charset = operand as u32,
+                17 => char_strings = Index::new(&b, operand as usize),
+                // ROS
+                0x0C1E => is_cid = true,
+                _ => {}
+            }
+            Some(())
+        })?;
+        Some(Self {
+            data: b,
+            strings,
+            char_strings: char_strings?,
+            charset,
+            is_cid,
+        })
+    }
+
+    /// Returns the number of glyphs in the font.
+    pub fn num_glyphs(&self) -> u16 {
+        self.char_strings.count
+    }
+
+    /// Returns true if the font is CID-keyed. The charset of a CID-keyed font
+    /// maps glyphs to CIDs rather than names.
+    pub fn is_cid(&self) -> bool {
+        self.is_cid
+    }
+
+    /// Returns the string for the specified string identifier.
+    pub fn string(&self, sid: u16) -> Option<&'a str> {
+        if sid < STANDARD_STRING_COUNT {
+            return Some(STANDARD_STRINGS[sid as usize]);
+        }
+        let bytes = self
+            .strings
+            .get(&self.data, (sid - STANDARD_STRING_COUNT) as usize)?;
+        core::str::from_utf8(bytes).ok()
+    }
+
+    /// Returns the string identifier, or the CID for CID-keyed fonts, that
+    /// the charset assigns to the specified glyph.
+    ///
+    /// The predefined expert charsets are not supported.
+    pub fn glyph_sid(&self, glyph_id: GlyphId) -> Option<u16> {
+        if glyph_id >= self.num_glyphs() {
+            return None;
+        }
+        if glyph_id == 0 {
+            return Some(0);
+        }
+        let b = &self.data;
+        match self.charset {
+            // ISOAdobe
+            0 if glyph_id <= 228 => Some(glyph_id),
+            0..=2 => None,
+            offset => {
+                let base = offset as usize;
+                let format = b.read_u8(base)?;
+                if format == 0 {
+                    return b.read_u16(base + 1 + (glyph_id as usize - 1) * 2);
+                }
+                // Formats 1 and 2 contain ranges with a count of 8 or 16 bits
+                // respectively.
+                let range_size = if format == 1 {
+                    3
+                } else if format == 2 {
+                    4
+                } else {
+                    return None;
+                };
+                let mut first_glyph = 1;
+                let mut rec = base + 1;
+                while first_glyph < self.num_glyphs() {
+                    let first = b.read_u16(rec)?;
+                    let left = if format == 1 {
+                        b.read_u8(rec + 2)? as u16
+                    } else {
+                        b.read_u16(rec + 2)?
+                    };
+                    let count = left as u32 + 1;
+                    if (glyph_id as u32) < first_glyph as u32 + count {
+                        return first.checked_add(glyph_id - first_glyph);
+                    }
+                    first_glyph = (first_glyph as u32 + count).min(u16::MAX as u32) as u16;
+                    rec += range_size;
+                }
+                None
+            }
+        }
+    }
+
+    /// Returns the name of the specified glyph. Returns `None` for CID-keyed
+    /// fonts.
+    pub fn glyph_name(&self, glyph_id: GlyphId) -> Option<&'a str> {
+        if self.is_cid {
+            return None;
+        }
+        self.string(self.glyph_sid(glyph_id)?)
+    }
+}
+
+/// Location of an INDEX structure.
+#[derive(Copy, Clone, Default)]
+struct Index {
+    count: u16,
+    off_size: u8,
+    /// Offset of the offset array.
+    offsets: usize,
+    /// Offset of the byte preceding the object data. Offsets in the array
+    /// are relative to this position.
+    data: usize,
+    /// Offset of the first byte following the INDEX.
+    end: usize,
+}
+
+impl Index {
+    fn new(b: &Buffer, offset: usize) -> Option<Self> {
+        let count = b.read_u16(offset)?;
+        if count == 0 {
+            return Some(Self {
+                end: offset + 2,
+                ..Default::default()
+            });
+        }
+        let off_size = b.read_u8(offset + 2)?;
+        if !(1..=4).contains(&off_size) {
+            return None;
+        }
+        let offsets = offset + 3;
+        let data = offsets + (count as usize + 1) * off_size as usize - 1;
+        let mut index = Self {
+            count,
+            off_size,
+            offsets,
+            data,
+            end: 0,
+        };
+        index.end = data + index.offset(b, count as usize)?;
+        Some(index)
+    }
+
+    fn offset(&self, b: &Buffer, i: usize) -> Option<usize> {
+        let base = self.offsets + i * self.off_size as usize;
+        let bytes = b.read_bytes(base, self.off_size as usize)?;
+        Some(bytes.iter().fold(0, |acc, &byte| acc << 8 | byte as usize))
+    }
+
+    fn get<'a>(&self, b: &Buffer<'a>, i: usize) -> Option<&'a [u8]> {
+        if i >= self.count as usize {
+            return None;
+        }
+        let start = self.offset(b, i)?;
+        let end = self.offset(b, i + 1)?;
+        b.read_bytes(self.data + start, end.checked_sub(start)?)
+    }
+}
+
+/// Invokes the specified closure with each operator in the DICT along with
+/// its last integer operand. Two byte operators are passed as `0x0C00 | b1`.
+fn parse_dict(data: &[u8], mut f: impl FnMut(u16, i32) -> Option<()>) -> Option<()> {
+    let b = Buffer::new(data);
+    let mut pos = 0;
+    let mut operand = 0;
+    while pos < data.len() {
+        let b0 = b.read_u8(pos)?;
+        pos += 1;
+        match b0 {
+            0..=21 => {
+                let op = if b0 == 12 {
+                    pos += 1;
+                    0x0C00 | b.read_u8(pos - 1)? as u16
+                } else {
+                    b0 as u16
+                };
+                f(op, operand)?;
+                operand = 0;
+            }
+            28 => {
+                operand = b.read_i16(pos)? as i32;
+                pos += 2;
+            }
+            29 => {
+                operand = b.read_u32(pos)? as i32;
+                pos += 4;
+            }
+            30 => {
+                // Real numbers are not needed here. Skip nibbles until the
+                // end marker.
+                loop {
+                    let byte = b.read_u8(pos)?;
+                    pos += 1;
+                    if byte & 0x0F == 0x0F || byte & 0xF0 == 0xF0 {
+                        break;
+                    }
+                }
+                operand = 0;
+            }
+            32..=246 => operand = b0 as i32 - 139,
+            247..=250 => {
+                operand = (b0 as i32 - 247) * 256 + b.read_u8(pos)? as i32 + 108;
+                pos += 1;
+            }
+            251..=254 => {
+                operand = -(b0 as i32 - 251) * 256 - b.read_u8(pos)? as i32 - 108;
+                pos += 1;
+            }
+            _ => return None,
+        }
+    }
+    Some(())
+}
+
+#[rustfmt::skip]
+const STANDARD_STRINGS: [&str; STANDARD_STRING_COUNT as usize] = [
+    ".notdef", "space", "exclam", "quotedbl", "numbersign", "dollar", "percent", "ampersand",
+    "quoteright", "parenleft", "parenright", "asterisk", "plus", "comma", "hyphen", "period",
+    "slash", "zero", "one", "two", "three", "four", "five", "six", "seven", "eight", "nine",
+    "colon", "semicolon", "less", "equal", "greater", "question", "at", "A", "B", "C", "D", "E",
+    "F", "G", "H", "I", "J", "K", "L", "M", "N", "O", "P", "Q", "R", "S", "T", "U", "V", "W", "X",
+    "Y", "Z", "bracketleft", "backslash", "bracketright", "asciicircum", "underscore", "quoteleft",
+    "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p", "q", "r", "s",
+    "t", "u", "v", "w", "x", "y", "z", "braceleft", "bar", "braceright", "asciitilde", "exclamdown",
+    "cent", "sterling", "fraction", "yen", "florin", "section", "currency", "quotesingle",
+    "quotedblleft", "guillemotleft", "guilsinglleft", "guilsinglright", "fi", "fl", "endash",
+    "dagger", "daggerdbl", "periodcentered", "paragraph", "bullet", "quotesinglbase",
+    "quotedblbase", "quotedblright", "guillemotright", "ellipsis", "perthousand", "questiondown",
+    "grave", "acute", "circumflex", "tilde", "macron", "breve", "dotaccent", "dieresis", "ring",
+    "cedilla", "hungarumlaut", "ogonek", "caron", "emdash", "AE", "ordfeminine", "Lslash", "Oslash",
+    "OE", "ordmasculine", "ae", "dotlessi", "lslash", "oslash", "oe", "germandbls", "onesuperior",
+    "logicalnot", "mu", "trademark", "Eth", "onehalf", "plusminus", "Thorn", "onequarter", "divide",
+    "brokenbar", "degree", "thorn", "threequarters", "twosuperior", "registered", "minus", "eth",
+    "multiply", "threesuperior", "copyright", "Aacute", "Acircumflex", "Adieresis", "Agrave",
+    "Aring", "Atilde", "Ccedilla", "Eacute", "Ecircumflex", "Edieresis", "Egrave", "Iacute",
+    "Icircumflex", "Idieresis", "Igrave", "Ntilde", "Oacute", "Ocircumflex", "Odieresis", "Ograve",
+    "Otilde", "Scaron", "Uacute", "Ucircumflex", "Udieresis", "Ugrave", "Yacute", "Ydieresis",
+    "Zcaron", "aacute", "acircumflex", "adieresis", "agrave", "aring", "atilde", "ccedilla",
+    "eacute", "ecircumflex", "edieresis", "egrave", "iacute", "icircumflex", "idieresis", "igrave",
+    "ntilde", "oacute", "ocircumflex", "odieresis", "ograve", "otilde", "scaron", "uacute",
+    "ucircumflex", "udieresis", "ugrave", "yacute", "ydieresis", "zcaron", "exclamsmall",
+    "Hungarumlautsmall", "dollaroldstyle", "dollarsuperior", "ampersandsmall", "Acutesmall",
+    "parenleftsuperior", "parenrightsuperior", "twodotenleader", "onedotenleader", "zerooldstyle",
+    "oneoldstyle", "twooldstyle", "threeoldstyle", "fouroldstyle", "fiveoldstyle", "sixoldstyle",
+    "sevenoldstyle", "eightoldstyle", "nineoldstyle", "commasuperior", "threequartersemdash",
+    "periodsuperior", "questionsmall", "asuperior", "bsuperior", "centsuperior", "dsuperior",
+    "esuperior", "isuperior", "lsuperior", "msuperior", "nsuperior", "osuperior", "rsuperior",
+    "ssuperior", "tsuperior", "ff", "ffi", "ffl", "parenleftinferior", "parenrightinferior",
+    "Circumflexsmall", "hyphensuperior", "Gravesmall", "Asmall", "Bsmall", "Csmall", "Dsmall",
+    "Esmall", "Fsmall", "Gsmall", "Hsmall", "Ismall", "Jsmall", "Ksmall", "Lsmall", "Msmall",
+    "Nsmall", "Osmall", "Psmall", "Qsmall", "Rsmall", "Ssmall", "Tsmall", "Usmall", "Vsmall",
+    "Wsmall", "Xsmall", "Ysmall", "Zsmall", "colonmonetary", "onefitted", "rupiah", "Tildesmall",
+    "exclamdownsmall", "centoldstyle", "Lslashsmall", "Scaronsmall", "Zcaronsmall", "Dieresissmall",
+    "Brevesmall", "Caronsmall", "Dotaccentsmall", "Macronsmall", "figuredash", "hypheninferior",
+    "Ogoneksmall", "Ringsmall", "Cedillasmall", "questiondownsmall", "oneeighth", "threeeighths",
+    "fiveeighths", "seveneighths", "onethird", "twothirds", "zerosuperior", "foursuperior",
+    "fivesuperior", "sixsuperior", "sevensuperior", "eightsuperior", "ninesuperior", "zeroinferior",
+    "oneinferior", "twoinferior", "threeinferior", "fourinferior", "fiveinferior", "sixinferior",
+    "seveninferior", "eightinferior", "nineinferior", "centinferior", "dollarinferior",
+    "periodinferior", "commainferior", "Agravesmall", "Aacutesmall", "Acircumflexsmall",
+    "Atildesmall", "Adieresissmall", "Aringsmall", "AEsmall", "Ccedillasmall", "Egravesmall",
+    "Eacutesmall", "Ecircumflexsmall", "Edieresissmall", "Igravesmall", "Iacutesmall",
+    "Icircumflexsmall", "Idieresissmall", "Ethsmall", "Ntildesmall", "Ogravesmall", "Oacutesmall",
+    "Ocircumflexsmall", "Otildesmall", "Odieresissmall", "OEsmall", "Oslashsmall", "Ugravesmall",
+    "Uacutesmall", "Ucircumflexsmall", "Udieresissmall", "Yacutesmall", "Thornsmall",
+    "Ydieresissmall", "001.000", "001.001", "001.002", "001.003", "Black", "Bold", "Book", "Light",
+    "Medium", "Regular", "Roman", "Semibold",
+];
@@ -0,0 +1,199 @@
+//! Table checksum verification and repair.
+//!
+//! <https://docs.microsoft.com/en-us/typography/opentype/spec/otff#calculating-checksums>
+
+use crate::font::{FontRef, TableProvider};
+use crate::head::HEAD;
+use crate::parse_prelude::*;
+
+/// Value that the checksum of a complete font must equal after the checksum
+/// adjustment in the font header table is applied.
+pub const CHECKSUM_MAGIC: u32 = 0xB1B0AFBA;
+
+/// Offset of the checksum adjustment field in the font header table.
+const ADJUSTMENT_OFFSET: usize = 8;
+
+/// Returns the checksum for the table with the specified tag and data. The
+/// checksum adjustment field of the font header table is treated as zero.
+pub fn table_checksum(tag: Tag, data: &[u8]) -> u32 {
+    let sum = checksum(data);
+    if tag == HEAD {
+        let adjustment = Buffer::new(data).read_u32(ADJUSTMENT_OFFSET).unwrap_or(0);
+        sum.wrapping_sub(adjustment)
+    } else {
+        sum
+    }
+}
+
+/// Mismatch between a stored checksum and the value computed from the font
+/// data.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum Mismatch {
+    /// The checksum in a table record is incorrect.
+    Table {
+        /// Tag of the table.
+        tag: Tag,
+        /// Checksum in the table record.
+        expected: u32,
+        /// Checksum computed from the table data.
+        actual: u32,
+    },
+    /// The checksum adjustment in the font header table is incorrect.
+    Adjustment {
+        /// Checksum adjustment in the font header table.
+        expected: u32,
+        /// Checksum adjustment computed from the font data.
+        actual: u32,
+    },
+}
+
+/// Returns the checksum adjustment for the font header table computed from
+/// the table directory and table data, assuming that the checksums in all
+/// table records are correct. Returns `None` if the directory or any table
+/// is out of bounds.
+pub fn checksum_adjustment(font: &FontRef) -> Option<u32> {
+    let records = font.records();
+    let directory = Buffer::new(font.data)
+        .read_bytes(font.offset as usize, 12 + records.len() * TableRecord::SIZE)?;
+    let mut sum = checksum(directory);
+    for record in records.iter() {
+        let actual = table_checksum(record.tag, font.data.get(record.data_range())?);
+        // Replace the stored checksum in the directory with the computed
+        // value. Record checksums are word aligned so this is exact.
+        sum = sum.wrapping_sub(record.checksum).wrapping_add(actual);
+        sum = sum.wrapping_add(actual);
+    }
+    Some(CHECKSUM_MAGIC.wrapping_sub(sum))
+}
+
+/// Returns an iterator over the checksum mismatches in the font. Tables that
+/// are out of bounds are skipped and the checksum adjustment is only verified
+/// when all tables are readable.
+pub fn verify<'a>(font: &FontRef<'a>) -> impl Iterator<Item = Mismatch> + 'a + Clone {
+    let data = font.data;
+    let tables = font.records().iter().filter_map(move |record| {
+        let actual = table_checksum(record.tag, data.get(record.data_range())?);
+        if actual != record.checksum {
+            Some(Mismatch::Table {
+                tag: record.tag,
+                expected: record.checksum,
+                actual,
+            })
+        } else {
+            None
+        }
+    });
+    let adjustment = font
+        .table_data(HEAD)
+        .and_then(|head| Buffer::new(head).read_u32(ADJUSTMENT_OFFSET))
+        .zip(checksum_adjustment(font))
+        .filter(|(expected, actual)| expected != actual)
+        .map(|(expected, actual)| Mismatch::Adjustment { expected, actual });
+    tables.chain(adjustment)
+}
+
+/// Returns a copy of the font file with corrected table checksums and
+/// checksum adjustments. All fonts in a collection are repaired. The layout
+/// of the file is otherwise unchanged. Returns `None` if the data is not a
+/// font or any font contains a table that is out of bounds.
+///
+/// Font header tables that are shared between fonts in a collection receive
+/// the adjustment for the last font that refers to them.
+#[cfg(feature = "std")]
+pub fn repair(data: &[u8]) -> Option<std::vec::Vec<u8>> {
+    let font_data = crate::FontDataRef::new(data)?;
+    let mut repaired = data.to_vec();
+    for font in font_data.fonts() {
+        // Fonts in resource forks are embedded at an offset in the file.
+        let start = font.data.as_ptr() as usize - data.as_ptr() as usize;
+        let base = start + font.offset as usize;
+        let mut head = None;
+        for (i, record) in font.records().iter().enumerate() {
+            let sum = table_checksum(record.tag, font.data.get(record.data_range())?);
+            let offset = base + 12 + i * TableRecord::SIZE + 4;
+            repaired[offset..offset + 4].copy_from_slice(&sum.to_be_bytes());
+            if record.tag == HEAD && record.len as usize >= ADJUSTMENT_OFFSET + 4 {
+                head = Some(start + record.offset as usize + ADJUSTMENT_OFFSET);
+            }
+        }
+        if let Some(offset) = head {
+            let adjustment = checksum_adjustment(&font)?;
+            repaired[offset..offset + 4].copy_from_slice(&adjustment.to_be_bytes());
+        }
+    }
+    Some(repaired)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::build::{CollectionBuilder, FontBuilder};
+    use crate::testing::TestFont;
+    use crate::FontDataRef;
+    use std::vec::Vec;
+
+    #[test]
+    fn head_adjustment_is_ignored() {
+        let mut head = [0u8; 54];
+        head[3] = 1;
+        let sum = table_checksum(HEAD, &head);
+        head[ADJUSTMENT_OFFSET..ADJUSTMENT_OFFSET + 4].copy_from_slice(&[1, 2, 3, 4]);
+        assert_eq!(table_checksum(HEAD, &head), sum);
+        assert_ne!(table_checksum(Tag::new(b"post"), &head), sum);
+    }
+
+    #[test]
+    fn verify_and_repair() {
+        let mut data = TestFont::new(3).build();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        let post = font.find_record(Tag::new(b"post")).unwrap();
+        let expected = post.checksum;
+        data[post.offset as usize + 4] = 1;
+        let font = FontRef::from_index(&data, 0).unwrap();
+        let mismatches = verify(&font).collect::<Vec<_>>();
+        assert_eq!(mismatches.len(), 2);
+        assert_eq!(
+            mismatches[0],
+            Mismatch::Table {
+                tag: Tag::new(b"post"),
+                expected,
+                actual: expected.wrapping_add(0x0100_0000),
+            }
+        );
+        assert!(matches!(mismatches[1], Mismatch::Adjustment { .. }));
+        let repaired = repair(&data).unwrap();
+        assert_eq!(repaired.len(), data.len());
+        let font = FontRef::from_index(&repaired, 0).unwrap();
+        assert_eq!(verify(&font).count(), 0);
+        assert_eq!(
+            font.table_data(Tag::new(b"post")),
+            FontRef::from_index(&data, 0)
+                .unwrap()
+                .table_data(Tag::new(b"post"))
+        );
+    }
+
+    #[test]
+    fn repair_collection() {
+        let fonts = [TestFont::new(3).build(), TestFont::new(4).build()];
+        let mut collection = CollectionBuilder::new();
+        for data in &fonts {
+            let font = FontRef::from_index(data, 0).unwrap();
+            collection.push(FontBuilder::from_font(&font).unwrap());
+        }
+        let mut data = collection.build().unwrap();
+        let font = FontDataRef::new(&data).unwrap().get(1).unwrap();
+        let hmtx = font.find_record(Tag::new(b"hmtx")).unwrap().offset as usize;
+        data[hmtx] ^= 1;
+        let count = |data: &[u8]| {
+            FontDataRef::new(data)
+                .unwrap()
+                .fonts()
+                .map(|font| verify(&font).count())
+                .collect::<Vec<_>>()
+        };
+        assert_eq!(count(&data), [0, 2]);
+        assert_eq!(count(&repair(&data).unwrap()), [0, 0]);
+        assert!(repair(b"junk").is_none());
+    }
+}
@@ -2,6 +2,8 @@
 
 use crate::parse_prelude::*;
 use core::cmp::Ordering;
+#[cfg(feature = "std")]
+use std::vec::Vec;
 
 /// Tag for the `cmap` table.
 pub const CMAP: Tag = Tag::new(b"cmap");
@@ -19,6 +21,23 @@ impl<'a> Cmap<'a> {
         Self(Buffer::new(data))
     }
 
+    /// Creates a new character to glyph index mapping table from a byte slice
+    /// containing the table data, returning an error if the encoding records
+    /// are truncated or refer to subtables outside of the table.
+    pub fn try_new(data: &'a [u8]) -> Result<Self, ReadError> {
+        let b = Buffer::new(data);
+        let len = b.try_read::<u16>(2).map_err(|e| e.with_tag(CMAP))? as usize;
+        let records = b
+            .try_read_slice::<EncodingRecord>(4, len)
+            .map_err(|e| e.with_tag(CMAP))?;
+        for (i, record) in records.iter().enumerate() {
+            if !b.check_range(record.offset as usize, 2) {
+                return Err(ReadError::out_of_bounds(4 + i * 8).with_tag(CMAP));
+            }
+        }
+        Ok(Self(b))
+    }
+
     /// Returns the version.
     pub fn version(&self) -> u16 {
         self.0.read(0).unwrap_or(0)
@@ -39,12 +58,42 @@ impl<'a> Cmap<'a> {
     }
 
     /// Maps a codepoint to a glyph identifier.
+    ///
+    /// Only subtables in formats 4, 12 and 13 are searched. The byte
+    /// encodings of formats 0, 2, 6, 8 and 10 are used by legacy platform
+    /// subtables that do not map Unicode codepoints and would otherwise
+    /// shadow the Unicode subtables that follow them. Use [`Charmap`] to
+    /// map with a single preselected Unicode subtable in any format.
     pub fn map(&self, codepoint: u32) -> Option<GlyphId> {
         self.subtables()
+            .filter(|subtable| is_unicode_format(subtable.format()))
             .filter_map(|subtable| subtable.map(codepoint))
             .next()
     }
 
+    /// Maps a codepoint to a glyph identifier, searching the same subtables
+    /// as [`map`](Self::map). If no subtable contains a mapping, returns the
+    /// error for the first subtable that could not be used, if any.
+    pub fn try_map(&self, codepoint: u32) -> Result<Option<GlyphId>, ReadError> {
+        let mut error = None;
+        for subtable in self.subtables() {
+            if matches!(subtable.format(), 0 | 2 | 6 | 8 | 10 | 14) {
+                continue;
+            }
+            match subtable.try_map(codepoint) {
+                Ok(Some(glyph_id)) => return Ok(Some(glyph_id)),
+                Ok(None) => {}
+                Err(e) => {
+                    error.get_or_insert(e);
+                }
+            }
+        }
+        match error {
+            Some(e) => Err(e),
+            None => Ok(None),
+        }
+    }
+
     /// Maps a codepoint with variation selector to a glyph identifier.
     pub fn map_variant(&self, codepoint: u32, variation_selector: u32) -> Option<MapVariant> {
         self.subtables()
@@ -53,6 +102,12 @@ impl<'a> Cmap<'a> {
     }
 }
 
+/// Returns true if subtables of the specified format are searched by
+/// [`Cmap::map`].
+fn is_unicode_format(format: u16) -> bool {
+    matches!(format, 4 | 12 | 13)
+}
+
 /// Encoding and offset to subtable.
 #[derive(Copy, Clone, Debug)]
 pub struct EncodingRecord {
@@ -102,6 +157,54 @@ impl<'a> Subtable<'a> {
         )
     }
 
+    /// Maps a codepoint to a glyph identifier, returning an error if the
+    /// subtable format is not supported or the subtable is truncated.
+    pub fn try_map(&self, codepoint: u32) -> Result<Option<GlyphId>, ReadError> {
+        let offset = self.encoding.offset as usize;
+        self.check()
+            .map_err(|e| e.with_base(offset).with_tag(CMAP))?;
+        Ok(self.map(codepoint))
+    }
+
+    /// Returns an error if the subtable cannot be used for mapping
+    /// codepoints. Offsets are relative to the subtable.
+    pub(crate) fn check(&self) -> Result<(), ReadError> {
+        let b = Buffer::with_offset(self.cmap.0.data(), self.encoding.offset as usize)
+            .unwrap_or_default();
+        match b.try_read::<u16>(0)? {
+            0 => b.try_ensure_range(0, 6 + 256),
+            2 => b.try_ensure_range(0, 6 + 512 + 8),
+            4 => {
+                let segcount_x2 = b.try_read::<u16>(6)? as usize;
+                b.try_ensure_range(0, 16 + segcount_x2 * 4)
+            }
+            6 => {
+                let len = b.try_read::<u16>(8)? as usize;
+                b.try_ensure_range(0, 10 + len * 2)
+            }
+            8 => {
+                let len = b.try_read::<u32>(8204)? as usize;
+                b.try_ensure_range(0, 8208 + len * 12)
+            }
+            10 => {
+                let len = b.try_read::<u32>(16)? as usize;
+                b.try_ensure_range(0, 20 + len * 2)
+            }
+            12 | 13 => {
+                let len = b.try_read::<u32>(12)? as usize;
+                b.try_ensure_range(0, 16 + len * 12)
+            }
+            format => Err(ReadError::unsupported_format(format as u32, 0)),
+        }
+    }
+
+    /// Returns an iterator over all mappings in the subtable. For format 14
+    /// subtables, this yields the codepoints of the default and non-default
+    /// variation sequences for each variation selector.
+    pub fn mappings(&self) -> Mappings<'a> {
+        Mappings::new(self.cmap.0.data(), self.encoding.offset, self.format())
+    }
+
     /// Maps a codepoint with variation selector to a glyph identifier.
     pub fn map_variant(&self, codepoint: u32, variation_selector: u32) -> Option<MapVariant> {
         if self.format() == 14 {
@@ -117,22 +220,340 @@ impl<'a> Subtable<'a> {
     }
 }
 
+/// Character map with a preselected Unicode subtable.
+///
+/// Unlike [`Cmap::map`], which searches the subtables for each codepoint,
+/// this selects the best Unicode subtable once and dispatches directly to
+/// it. Subtables are preferred in the following order of platform and
+/// encoding identifiers: 3/10, 0/4, 3/1, 0/3 and finally the 3/0 symbol
+/// subtable.
+#[derive(Copy, Clone, Default)]
+pub struct Charmap<'a> {
+    data: &'a [u8],
+    proxy: CharmapProxy,
+}
+
+impl<'a> Charmap<'a> {
+    /// Creates a new character map from the specified character to glyph
+    /// index mapping table.
+    pub fn new(cmap: &Cmap<'a>) -> Self {
+        CharmapProxy::new(cmap).materialize(cmap)
+    }
+
+    /// Returns true if the character map contains a Unicode or symbol
+    /// subtable.
+    pub fn has_map(&self) -> bool {
+        self.proxy.offset != 0
+    }
+
+    /// Returns true if the selected subtable uses the symbol encoding.
+    pub fn is_symbol(&self) -> bool {
+        self.proxy.is_symbol
+    }
+
+    /// Returns true if the character map contains a Unicode variation
+    /// sequences subtable.
+    pub fn has_variants(&self) -> bool {
+        self.proxy.variant_offset != 0
+    }
+
+    /// Returns the selected subtable.
+    pub fn subtable(&self) -> Option<Subtable<'a>> {
+        if !self.has_map() {
+            return None;
+        }
+        let cmap = Cmap::new(self.data);
+        let encoding = cmap.records().get(self.proxy.index as usize)?;
+        Some(Subtable { cmap, encoding })
+    }
+
+    /// Maps a codepoint to a glyph identifier.
+    ///
+    /// For symbol subtables, codepoints in the range 0x00..=0xFF that are
+    /// not mapped directly are also tried in the private use area at 0xF000.
+    pub fn map(&self, codepoint: u32) -> Option<GlyphId> {
+        let proxy = &self.proxy;
+        if proxy.offset == 0 {
+            return None;
+        }
+        let glyph_id = map(self.data, proxy.offset, proxy.format, codepoint);
+        if proxy.is_symbol && codepoint <= 0xFF && glyph_id.unwrap_or(0) == 0 {
+            return map(self.data, proxy.offset, proxy.format, codepoint + 0xF000);
+        }
+        glyph_id
+    }
+
+    /// Maps a codepoint with variation selector to a glyph identifier. If
+    /// the font does not contain a variant for the sequence, or the sequence
+    /// uses the default glyph, returns the default mapping for the codepoint.
+    pub fn map_variant(&self, codepoint: u32, variation_selector: u32) -> Option<GlyphId> {
+        if self.proxy.variant_offset != 0 {
+            match map_variant(
+                self.data,
+                self.proxy.variant_offset,
+                codepoint,
+                variation_selector,
+            ) {
+                Some(MapVariant::Variant(glyph_id)) => return Some(glyph_id),
+                Some(MapVariant::UseDefault) | None => {}
+            }
+        }
+        self.map(codepoint)
+    }
+
+    /// Returns an iterator over all mappings in the selected subtable.
+    pub fn mappings(&self) -> Mappings<'a> {
+        Mappings::new(self.data, self.proxy.offset, self.proxy.format)
+    }
+
+    /// Returns an iterator over the default and non-default mappings in the
+    /// Unicode variation sequences subtable.
+    pub fn variant_mappings(&self) -> Mappings<'a> {
+        Mappings::new(self.data, self.proxy.variant_offset, 14)
+    }
+
+    /// Invokes the specified closure with each codepoint that maps to the
+    /// glyph identifier. The second argument to the closure is the variation
+    /// selector for mappings that are defined by a variation sequence.
+    /// Returning `false` from the closure will end enumeration early.
+    ///
+    /// This performs a linear scan of the character map and does not
+    /// allocate. Use [`ReverseCharmap`] for repeated queries.
+    pub fn codepoints_with(
+        &self,
+        glyph_id: GlyphId,
+        mut f: impl FnMut(u32, Option<u32>) -> bool,
+    ) -> bool {
+        for mapping in self.mappings() {
+            if mapping.glyph_id == Some(glyph_id) && !f(mapping.codepoint, None) {
+                return false;
+            }
+        }
+        for mapping in self.variant_mappings() {
+            let mapped = match mapping.glyph_id {
+                Some(id) => id == glyph_id,
+                None => self.map(mapping.codepoint) == Some(glyph_id),
+            };
+            if mapped && !f(mapping.codepoint, mapping.variation_selector) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Reverse character map from glyph identifiers to codepoints.
+///
+/// This is built from the selected subtable of a [`Charmap`] along with
+/// the default and non-default Unicode variation sequences.
+#[cfg(feature = "std")]
+#[derive(Clone, Default, Debug)]
+pub struct ReverseCharmap {
+    /// Mappings sorted by glyph identifier, codepoint and variation
+    /// selector. All glyph identifiers are present.
+    mappings: Vec<Mapping>,
+}
+
+#[cfg(feature = "std")]
+impl ReverseCharmap {
+    /// Creates a new reverse character map from the specified character map.
+    pub fn new(charmap: &Charmap) -> Self {
+        let mut mappings: Vec<Mapping> = charmap.mappings().collect();
+        for mapping in charmap.variant_mappings() {
+            let glyph_id = match mapping.glyph_id {
+                Some(id) => id,
+                None => match charmap.map(mapping.codepoint) {
+                    Some(id) if id != 0 => id,
+                    _ => continue,
+                },
+            };
+            mappings.push(Mapping {
+                glyph_id: Some(glyph_id),
+                ..mapping
+            });
+        }
+        mappings.sort_unstable_by_key(|m| (m.glyph_id, m.codepoint, m.variation_selector));
+        mappings.dedup();
+        Self { mappings }
+    }
+
+    /// Returns the number of mappings.
+    pub fn len(&self) -> usize {
+        self.mappings.len()
+    }
+
+    /// Returns true if there are no mappings.
+    pub fn is_empty(&self) -> bool {
+        self.mappings.is_empty()
+    }
+
+    /// Returns the mappings for the specified glyph identifier, sorted by
+    /// codepoint. Mappings without a variation selector precede those with
+    /// one for the same codepoint.
+    pub fn get(&self, glyph_id: GlyphId) -> &[Mapping] {
+        let start = self
+            .mappings
+            .partition_point(|m| m.glyph_id < Some(glyph_id));
+        let len = self.mappings[start..].partition_point(|m| m.glyph_id == Some(glyph_id));
+        &self.mappings[start..start + len]
+    }
+
+    /// Returns an iterator over the distinct codepoints that map to the
+    /// specified glyph identifier in increasing order.
+    pub fn codepoints(&self, glyph_id: GlyphId) -> impl Iterator<Item = u32> + '_ + Clone {
+        let mappings = self.get(glyph_id);
+        mappings
+            .iter()
+            .enumerate()
+            .filter(move |(i, m)| *i == 0 || mappings[i - 1].codepoint != m.codepoint)
+            .map(|(_, m)| m.codepoint)
+    }
+
+    /// Returns an iterator over all mappings, sorted by glyph identifier.
+    pub fn iter(&self) -> impl Iterator<Item = Mapping> + '_ + Clone {
+        self.mappings.iter().copied()
+    }
+}
+
+/// Lifetime free representation of a character map.
+///
+/// This records the offsets and format of the selected subtables so that
+/// the selection can be cached and later applied to the same table with
+/// [`CharmapProxy::materialize`].
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+pub struct CharmapProxy {
+    index: u16,
+    offset: u32,
+    format: u16,
+    is_symbol: bool,
+    variant_offset: u32,
+}
+
+impl CharmapProxy {
+    /// Selects the best Unicode and variation sequence subtables from the
+    /// specified character to glyph index mapping table.
+    pub fn new(cmap: &Cmap) -> Self {
+        let mut proxy = Self::default();
+        let mut best = usize::MAX;
+        for (index, subtable) in cmap.subtables().enumerate() {
+            let encoding = subtable.encoding;
+            let format = subtable.format();
+            if encoding.offset == 0 {
+                continue;
+            }
+            if format == 14 {
+                if encoding.platform_id == 0 && encoding.encoding_id == 5 {
+                    proxy.variant_offset = encoding.offset;
+                }
+                continue;
+            }
+            if !matches!(format, 0 | 2 | 4 | 6 | 8 | 10 | 12 | 13) {
+                continue;
+            }
+            let rank = match (encoding.platform_id, encoding.encoding_id) {
+                (3, 10) => 0,
+                (0, 4) => 1,
+                (3, 1) => 2,
+                (0, 3) => 3,
+                (3, 0) => 4,
+                _ => continue,
+            };
+            if rank < best {
+                best = rank;
+                proxy.index = index as u16;
+                proxy.offset = encoding.offset;
+                proxy.format = format;
+                proxy.is_symbol = rank == 4;
+            }
+        }
+        proxy
+    }
+
+    /// Creates a character map from the proxy and the same character to
+    /// glyph index mapping table that was used to create it.
+    pub fn materialize<'a>(&self, cmap: &Cmap<'a>) -> Charmap<'a> {
+        Charmap {
+            data: cmap.0.data(),
+            proxy: *self,
+        }
+    }
+}
+
 /// Maps a codepoint to a glyph identifer using the subtable of the given
 /// format at the specified offset in data.
 ///
 /// Supports the following formats:
+/// - Format 0: <https://docs.microsoft.com/en-us/typography/opentype/spec/cmap#format-0-byte-encoding-table>
+/// - Format 2: <https://docs.microsoft.com/en-us/typography/opentype/spec/cmap#format-2-high-byte-mapping-through-table>
 /// - Format 4: <https://docs.microsoft.com/en-us/typography/opentype/spec/cmap#format-4-segment-mapping-to-delta-values>
+/// - Format 6: <https://docs.microsoft.com/en-us/typography/opentype/spec/cmap#format-6-trimmed-table-mapping>
+/// - Format 8: <https://docs.microsoft.com/en-us/typography/opentype/spec/cmap#format-8-mixed-16-bit-and-32-bit-coverage>
+/// - Format 10: <https://docs.microsoft.com/en-us/typography/opentype/spec/cmap#format-10-trimmed-array>
 /// - Format 12: <https://docs.microsoft.com/en-us/typography/opentype/spec/cmap#format-12-segmented-coverage>
 /// - Format 13: <https://docs.microsoft.com/en-us/typography/opentype/spec/cmap#format-13-many-to-one-range-mappings>
 pub fn map(data: &[u8], offset: u32, format: u16, codepoint: u32) -> Option<GlyphId> {
     match format {
+        0 => map_format0(data, offset, codepoint),
+        2 => map_format2(data, offset, codepoint),
         4 => map_format4(data, offset, codepoint),
+        6 => map_format6(data, offset, codepoint),
+        8 => map_format8(data, offset, codepoint),
+        10 => map_format10(data, offset, codepoint),
         12 => map_format12(data, offset, codepoint),
         13 => map_format13(data, offset, codepoint),
         _ => None,
     }
 }
 
+fn map_format0(data: &[u8], offset: u32, codepoint: u32) -> Option<GlyphId> {
+    if codepoint >= 256 {
+        return None;
+    }
+    let b = Buffer::with_offset(data, offset as usize)?;
+    Some(b.read_u8(6 + codepoint as usize)? as GlyphId)
+}
+
+fn map_format2(data: &[u8], offset: u32, codepoint: u32) -> Option<GlyphId> {
+    if codepoint > 0xFFFF {
+        return None;
+    }
+    let b = Buffer::with_offset(data, offset as usize)?;
+    let high = (codepoint >> 8) as usize;
+    let low = codepoint & 0xFF;
+    let subheader = if high == 0 {
+        // Single byte codes use the first subheader but must not also be
+        // the first byte of a two byte code.
+        if b.read_u16(6 + low as usize * 2)? != 0 {
+            return None;
+        }
+        0
+    } else {
+        match b.read_u16(6 + high * 2)? {
+            0 => return None,
+            key => key as usize,
+        }
+    };
+    format2_glyph(&b, 6 + 512 + subheader, low)
+}
+
+/// Returns the glyph for the low byte of a codepoint using the format 2
+/// subheader at the specified offset.
+fn format2_glyph(b: &Buffer, subheader: usize, low: u32) -> Option<GlyphId> {
+    let first = b.read_u16(subheader)? as u32;
+    let count = b.read_u16(subheader + 2)? as u32;
+    if low < first || low >= first + count {
+        return None;
+    }
+    let delta = b.read_i16(subheader + 4)?;
+    let range = b.read_u16(subheader + 6)? as usize;
+    let id = b.read_u16(subheader + 6 + range + (low - first) as usize * 2)?;
+    if id != 0 {
+        Some((id as i32 + delta as i32) as u16)
+    } else {
+        Some(0)
+    }
+}
+
 fn map_format4(data: &[u8], offset: u32, codepoint: u32) -> Option<GlyphId> {
     if codepoint >= 65535 {
         return None;
@@ -155,42 +576,88 @@ fn map_format4(data: &[u8], offset: u32, codepoint: u32) -> Option<GlyphId> {
         } else if codepoint > unsafe { b.read_unchecked::<u16>(end_codes_offset + i2) } {
             lo = i + 1;
         } else {
-            let deltas_offset = start_codes_offset + segcount_x2;
-            let ranges_offset = deltas_offset + segcount_x2;
-            let mut range_base = ranges_offset + i2;
-            let range = unsafe { b.read_unchecked::<u16>(range_base) as usize };
-            let delta = unsafe { b.read_unchecked::<i16>(deltas_offset + i2) as i32 };
-            if range == 0 {
-                return Some((codepoint as i32 + delta) as u16);
-            }
-            range_base += range;
-            let diff = (codepoint - start) as usize * 2;
-            let id = b.read::<u16>(range_base + diff).unwrap_or(0);
-            return if id != 0 {
-                Some((id as i32 + delta as i32) as u16)
-            } else {
-                Some(0)
-            };
+            return Some(format4_glyph(&b, segcount_x2, i, start, codepoint));
         }
     }
     None
 }
 
+/// Returns the glyph for a codepoint in the format 4 segment at the
+/// specified index.
+fn format4_glyph(b: &Buffer, segcount_x2: usize, i: usize, start: u16, codepoint: u16) -> GlyphId {
+    let i2 = i * 2;
+    let deltas_offset = 16 + segcount_x2 * 2;
+    let ranges_offset = deltas_offset + segcount_x2;
+    let range_base = ranges_offset + i2;
+    let range = b.read_u16(range_base).unwrap_or(0) as usize;
+    let delta = b.read_i16(deltas_offset + i2).unwrap_or(0) as i32;
+    if range == 0 {
+        return (codepoint as i32 + delta) as u16;
+    }
+    let diff = (codepoint - start) as usize * 2;
+    let id = b.read::<u16>(range_base + range + diff).unwrap_or(0);
+    if id != 0 {
+        (id as i32 + delta) as u16
+    } else {
+        0
+    }
+}
+
+fn map_format6(data: &[u8], offset: u32, codepoint: u32) -> Option<GlyphId> {
+    let b = Buffer::with_offset(data, offset as usize)?;
+    let first = b.read_u16(6)? as u32;
+    let count = b.read_u16(8)? as u32;
+    let index = codepoint.checked_sub(first)?;
+    if index >= count {
+        return None;
+    }
+    b.read_u16(10 + index as usize * 2)
+}
+
+fn map_format8(data: &[u8], offset: u32, codepoint: u32) -> Option<GlyphId> {
+    let (start, glyph_id) = map_groups(data, offset, 8204, codepoint, false)?;
+    Some((codepoint - start + glyph_id) as u16)
+}
+
+fn map_format10(data: &[u8], offset: u32, codepoint: u32) -> Option<GlyphId> {
+    let b = Buffer::with_offset(data, offset as usize)?;
+    let first = b.read_u32(12)?;
+    let count = b.read_u32(16)?;
+    let index = codepoint.checked_sub(first)?;
+    if index >= count {
+        return None;
+    }
+    b.read_u16(20 + index as usize * 2)
+}
+
 fn map_format12(data: &[u8], offset: u32, codepoint: u32) -> Option<GlyphId> {
-    let (start, delta) = map_format12_13(data, offset, codepoint)?;
-    Some((codepoint.wrapping_sub(start).wrapping_add(delta)) as u16)
+    let (start, glyph_id) = map_groups(data, offset, 12, codepoint, false)?;
+    Some((codepoint - start + glyph_id) as u16)
 }
 
 fn map_format13(data: &[u8], offset: u32, codepoint: u32) -> Option<GlyphId> {
-    let (_, glyph_id) = map_format12_13(data, offset, codepoint)?;
+    let (_, glyph_id) = map_groups(data, offset, 12, codepoint, true)?;
     Some(glyph_id as u16)
 }
 
-/// Common code for formats 12 and 13.
-fn map_format12_13(data: &[u8], offset: u32, codepoint: u32) -> Option<(u32, u32)> {
+/// Common code for formats 8, 12 and 13. Returns the start codepoint and
+/// start glyph identifier of the group containing the
+/// codepoint where the number of groups is at the specified offset in the
+/// subtable. Groups with glyph identifiers that do not fit in 16 bits are
+/// ignored.
+fn map_groups(
+    data: &[u8],
+    offset: u32,
+    len_offset: usize,
+    codepoint: u32,
+    is_constant: bool,
+) -> Option<(u32, u32)> {
+    if codepoint > MAX_CODEPOINT {
+        return None;
+    }
     let b = Buffer::with_offset(data, offset as usize)?;
-    let base = 16;
-    let len = b.read_u32(base - 4)? as usize;
+    let base = len_offset + 4;
+    let len = b.read_u32(len_offset)? as usize;
     b.ensure_range(base, len * 12)?;
     let mut lo = 0;
     let mut hi = len;
@@ -203,12 +670,27 @@ fn map_format12_13(data: &[u8], offset: u32, codepoint: u32) -> Option<(u32, u32
         } else if codepoint > unsafe { b.read_unchecked::<u32>(rec + 4) } {
             lo = i + 1;
         } else {
-            return Some((start, unsafe { b.read_unchecked::<u32>(rec + 8) }));
+            let end = unsafe { b.read_unchecked::<u32>(rec + 4) }.min(MAX_CODEPOINT);
+            let glyph_id = unsafe { b.read_unchecked::<u32>(rec + 8) };
+            if group_last_glyph(start, end, glyph_id, is_constant)? > 0xFFFF {
+                return None;
+            }
+            return Some((start, glyph_id));
         }
     }
     None
 }
 
+/// Returns the last glyph identifier of a sequential or constant map group
+/// or `None` if it overflows.
+fn group_last_glyph(start: u32, end: u32, start_glyph: u32, is_constant: bool) -> Option<u32> {
+    if is_constant {
+        Some(start_glyph)
+    } else {
+        start_glyph.checked_add(end.checked_sub(start)?)
+    }
+}
+
 /// Result of the mapping a codepoint with a variation selector.
 #[derive(Copy, Clone, PartialEq, Eq, Debug)]
 pub enum MapVariant {
@@ -288,3 +770,540 @@ pub fn map_variant(
     }
     None
 }
+
+/// Mapping from a codepoint to a glyph identifier.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Mapping {
+    /// Unicode codepoint or character code in the encoding of the subtable.
+    pub codepoint: u32,
+    /// Variation selector for mappings in a format 14 subtable.
+    pub variation_selector: Option<u32>,
+    /// Glyph identifier. This is `None` for default variation sequences
+    /// which use the default mapping for the codepoint.
+    pub glyph_id: Option<GlyphId>,
+}
+
+impl Mapping {
+    fn new(codepoint: u32, glyph_id: GlyphId) -> Self {
+        Self {
+            codepoint,
+            variation_selector: None,
+            glyph_id: Some(glyph_id),
+        }
+    }
+}
+
+/// Largest valid Unicode codepoint. Ranges in segmented subtables are
+/// clamped to this value.
+const MAX_CODEPOINT: u32 = 0x10FFFF;
+
+/// Iterator over the mappings in a character to glyph index mapping
+/// subtable.
+///
+/// Codepoints that map to glyph 0 are skipped, as are groups in formats 8,
+/// 12 and 13 with glyph identifiers that do not fit in 16 bits.
+#[derive(Copy, Clone)]
+pub struct Mappings<'a> {
+    data: Buffer<'a>,
+    format: u16,
+    /// Mappings to glyphs at or beyond this limit are skipped.
+    num_glyphs: u32,
+    /// Index of the current byte, segment, group or variation selector
+    /// record.
+    index: u32,
+    /// Index of the current range or mapping in a variation selector record.
+    entry: u32,
+    /// Position in the current range.
+    pos: u32,
+    /// Default (0) or non-default (1) variation sequences.
+    phase: u8,
+}
+
+impl Iterator for Mappings<'_> {
+    type Item = Mapping;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let mapping = match self.format {
+                0 => self.next_format0()?,
+                2 => self.next_format2()?,
+                4 => self.next_format4()?,
+                6 => self.next_array(6, 8, false)?,
+                8 => self.next_group(8204, false)?,
+                10 => self.next_array(12, 16, true)?,
+                12 => self.next_group(12, false)?,
+                13 => self.next_group(12, true)?,
+                14 => return self.next_format14(),
+                _ => return None,
+            };
+            if let Some(mapping) = mapping {
+                let glyph_id = mapping.glyph_id.map_or(1, |id| id as u32);
+                if glyph_id != 0 && glyph_id < self.num_glyphs {
+                    return Some(mapping);
+                }
+            }
+        }
+    }
+}
+
+impl<'a> Mappings<'a> {
+    fn new(data: &'a [u8], offset: u32, format: u16) -> Self {
+        let data = if offset != 0 {
+            Buffer::with_offset(data, offset as usize).unwrap_or_default()
+        } else {
+            Buffer::default()
+        };
+        Self {
+            data,
+            format,
+            num_glyphs: u32::MAX,
+            index: 0,
+            entry: 0,
+            pos: 0,
+            phase: 0,
+        }
+    }
+
+    /// Limits the iterator to mappings with glyph identifiers less than the
+    /// specified number of glyphs. Groups in formats 8, 12 and 13 that reach
+    /// the limit are skipped entirely.
+    pub fn with_num_glyphs(self, num_glyphs: u16) -> Self {
+        Self {
+            num_glyphs: num_glyphs as u32,
+            ..self
+        }
+    }
+}
+
+// Each of the following returns `None` when the iterator is exhausted and
+// `Some(None)` to advance without producing a mapping.
+impl Mappings<'_> {
+    fn next_format0(&mut self) -> Option<Option<Mapping>> {
+        if self.index >= 256 {
+            return None;
+        }
+        let codepoint = self.index;
+        self.index += 1;
+        let glyph_id = self.data.read_u8(6 + codepoint as usize)?;
+        Some(Some(Mapping::new(codepoint, glyph_id as GlyphId)))
+    }
+
+    fn next_format2(&mut self) -> Option<Option<Mapping>> {
+        if self.index >= 256 {
+            return None;
+        }
+        let b = &self.data;
+        let high = self.index;
+        let key = b.read_u16(6 + high as usize * 2)? as usize;
+        if key == 0 {
+            // Single byte code mapped through the first subheader.
+            self.index += 1;
+            return Some(format2_glyph(b, 6 + 512, high).map(|id| Mapping::new(high, id)));
+        }
+        let subheader = 6 + 512 + key;
+        let first = b.read_u16(subheader)? as u32;
+        let count = b.read_u16(subheader + 2)? as u32;
+        let low = first + self.pos;
+        if self.pos >= count || low > 0xFF {
+            self.index += 1;
+            self.pos = 0;
+            return Some(None);
+        }
+        self.pos += 1;
+        let codepoint = high << 8 | low;
+        Some(format2_glyph(b, subheader, low).map(|id| Mapping::new(codepoint, id)))
+    }
+
+    fn next_format4(&mut self) -> Option<Option<Mapping>> {
+        let b = &self.data;
+        let segcount_x2 = b.read_u16(6)? as usize;
+        let i = self.index as usize;
+        if i * 2 >= segcount_x2 {
+            return None;
+        }
+        let end = b.read_u16(14 + i * 2)? as u32;
+        let start = b.read_u16(16 + segcount_x2 + i * 2)? as u32;
+        let codepoint = start + self.pos;
+        // The final segment maps 0xFFFF to glyph 0.
+        if codepoint > end || codepoint >= 0xFFFF {
+            self.index += 1;
+            self.pos = 0;
+            return Some(None);
+        }
+        self.pos += 1;
+        let glyph_id = format4_glyph(b, segcount_x2, i, start as u16, codepoint as u16);
+        Some(Some(Mapping::new(codepoint, glyph_id)))
+    }
+
+    /// Formats 6 and 10 which contain an array of glyphs for a contiguous
+    /// range of codepoints.
+    fn next_array(
+        &mut self,
+        first_offset: usize,
+        count_offset: usize,
+        is_32bit: bool,
+    ) -> Option<Option<Mapping>> {
+        let b = &self.data;
+        let (first, count, base) = if is_32bit {
+            (b.read_u32(first_offset)?, b.read_u32(count_offset)?, 20)
+        } else {
+            (
+                b.read_u16(first_offset)? as u32,
+                b.read_u16(count_offset)? as u32,
+                10,
+            )
+        };
+        if self.index >= count {
+            return None;
+        }
+        let index = self.index;
+        self.index += 1;
+        let glyph_id = b.read_u16(base + index as usize * 2)?;
+        Some(Some(Mapping::new(first.checked_add(index)?, glyph_id)))
+    }
+
+    /// Formats 8, 12 and 13 which contain sequential map groups.
+    fn next_group(&mut self, count_offset: usize, is_constant: bool) -> Option<Option<Mapping>> {
+        let b = &self.data;
+        let count = b.read_u32(count_offset)?;
+        if self.index >= count {
+            return None;
+        }
+        let rec = count_offset + 4 + self.index as usize * 12;
+        let start = b.read_u32(rec)?;
+        let end = b.read_u32(rec + 4)?.min(MAX_CODEPOINT);
+        let start_glyph = b.read_u32(rec + 8)?;
+        let codepoint = start.saturating_add(self.pos);
+        let in_range = start <= end
+            && codepoint <= end
+            && matches!(
+                group_last_glyph(start, end, start_glyph, is_constant),
+                Some(last) if last <= 0xFFFF && last < self.num_glyphs
+            );
+        if !in_range {
+            self.index += 1;
+            self.pos = 0;
+            return Some(None);
+        }
+        self.pos += 1;
+        let glyph_id = if is_constant {
+            start_glyph
+        } else {
+            start_glyph + (codepoint - start)
+        };
+        Some(Some(Mapping::new(codepoint, glyph_id as GlyphId)))
+    }
+
+    fn next_format14(&mut self) -> Option<Mapping> {
+        let b = self.data;
+        let count = b.read_u32(6)?;
+        while self.index < count {
+            let rec = 10 + self.index as usize * 11;
+            let selector = b.read_u24(rec)?;
+            let offset = b.read_u32(rec + 3 + self.phase as usize * 4)? as usize;
+            let len = if offset != 0 { b.read_u32(offset)? } else { 0 };
+            if self.entry >= len {
+                self.entry = 0;
+                self.pos = 0;
+                if self.phase == 0 {
+                    self.phase = 1;
+                } else {
+                    self.phase = 0;
+                    self.index += 1;
+                }
+                continue;
+            }
+            if self.phase == 0 {
+                let range = offset + 4 + self.entry as usize * 4;
+                let start = b.read_u24(range)?;
+                let additional = b.read_u8(range + 3)? as u32;
+                let pos = self.pos;
+                if pos >= additional {
+                    self.entry += 1;
+                    self.pos = 0;
+                } else {
+                    self.pos += 1;
+                }
+                return Some(Mapping {
+                    codepoint: start + pos,
+                    variation_selector: Some(selector),
+                    glyph_id: None,
+                });
+            } else {
+                let mapping = offset + 4 + self.entry as usize * 5;
+                self.entry += 1;
+                return Some(Mapping {
+                    codepoint: b.read_u24(mapping)?,
+                    variation_selector: Some(selector),
+                    glyph_id: Some(b.read_u16(mapping + 3)?),
+                });
+            }
+        }
+        None
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::testing::{self, Writer};
+    use std::vec::Vec;
+
+    /// Returns a character map with the specified platform and encoding
+    /// identifiers and subtables.
+    fn cmap(subtables: &[(u16, u16, Vec<u8>)]) -> Vec<u8> {
+        let mut w = Writer::new();
+        w.u16(0).u16(subtables.len() as u16);
+        let mut offset = 4 + subtables.len() as u32 * 8;
+        for (platform_id, encoding_id, data) in subtables {
+            w.u16(*platform_id).u16(*encoding_id).u32(offset);
+            offset += data.len() as u32;
+        }
+        for (_, _, data) in subtables {
+            w.bytes(data);
+        }
+        w.finish()
+    }
+
+    /// Returns a format 12 or 13 subtable containing the specified groups.
+    fn groups(format: u16, groups: &[(u32, u32, u32)]) -> Vec<u8> {
+        let mut w = Writer::new();
+        w.u16(format)
+            .u16(0)
+            .u32(16 + groups.len() as u32 * 12)
+            .u32(0);
+        w.u32(groups.len() as u32);
+        for &(start, end, glyph_id) in groups {
+            w.u32(start).u32(end).u32(glyph_id);
+        }
+        w.finish()
+    }
+
+    /// Returns a character map with a single format 12 or 13 subtable
+    /// containing the specified groups.
+    fn cmap_groups(format: u16, mapping: &[(u32, u32, u32)]) -> Vec<u8> {
+        cmap(&[(3, 10, groups(format, mapping))])
+    }
+
+    /// Returns a format 4 subtable for the specified sorted mapping.
+    fn format4(mapping: &[(u32, GlyphId)]) -> Vec<u8> {
+        testing::cmap4(mapping)[12..].to_vec()
+    }
+
+    /// Returns a format 14 subtable with a single variation selector that
+    /// has the specified default ranges and non-default mappings.
+    fn format14(selector: u32, ranges: &[(u32, u8)], mappings: &[(u32, GlyphId)]) -> Vec<u8> {
+        let u24 = |w: &mut Writer, value: u32| {
+            w.u8((value >> 16) as u8).u16(value as u16);
+        };
+        let default_offset = 21;
+        let non_default_offset = default_offset + 4 + ranges.len() as u32 * 4;
+        let len = non_default_offset + 4 + mappings.len() as u32 * 5;
+        let mut w = Writer::new();
+        w.u16(14).u32(len).u32(1);
+        u24(&mut w, selector);
+        w.u32(default_offset).u32(non_default_offset);
+        w.u32(ranges.len() as u32);
+        for &(start, additional) in ranges {
+            u24(&mut w, start);
+            w.u8(additional);
+        }
+        w.u32(mappings.len() as u32);
+        for &(codepoint, glyph_id) in mappings {
+            u24(&mut w, codepoint);
+            w.u16(glyph_id);
+        }
+        w.finish()
+    }
+
+    fn pairs(mappings: Mappings) -> Vec<(u32, GlyphId)> {
+        mappings
+            .map(|m| (m.codepoint, m.glyph_id.unwrap()))
+            .collect()
+    }
+
+    #[test]
+    fn sequential_groups() {
+        let data = cmap_groups(
+            12,
+            &[
+                (0x41, 0x42, 1),
+                (0x100, 0x10F, 0xFFF8),
+                (0x1F600, 0x1F601, 10),
+                (0x10FFFE, u32::MAX, 20),
+            ],
+        );
+        let charmap = Charmap::new(&Cmap::new(&data));
+        assert_eq!(charmap.map(0x42), Some(2));
+        assert_eq!(charmap.map(0x100), None);
+        assert_eq!(charmap.map(0x10F), None);
+        assert_eq!(charmap.map(0x1F601), Some(11));
+        assert_eq!(charmap.map(0x10FFFF), Some(21));
+        assert_eq!(charmap.map(0x110000), None);
+        let expected = [
+            (0x41, 1),
+            (0x42, 2),
+            (0x1F600, 10),
+            (0x1F601, 11),
+            (0x10FFFE, 20),
+            (0x10FFFF, 21),
+        ];
+        assert_eq!(pairs(charmap.mappings()), expected);
+        assert_eq!(
+            pairs(charmap.mappings().with_num_glyphs(21)),
+            &expected[..4]
+        );
+    }
+
+    #[test]
+    fn constant_groups() {
+        let data = cmap_groups(13, &[(0x20, 0x22, 5), (0x30, 0x31, 0x10000)]);
+        let subtable = Cmap::new(&data).subtables().next().unwrap();
+        assert_eq!(subtable.map(0x21), Some(5));
+        assert_eq!(subtable.map(0x30), None);
+        assert_eq!(
+            pairs(subtable.mappings()),
+            [(0x20, 5), (0x21, 5), (0x22, 5)]
+        );
+        assert!(subtable.mappings().with_num_glyphs(5).next().is_none());
+    }
+
+    #[test]
+    fn subtable_selection() {
+        let data = cmap(&[
+            (3, 1, format4(&[(0x41, 1), (0x42, 2)])),
+            (3, 10, groups(12, &[(0x41, 0x41, 7)])),
+        ]);
+        let cmap = Cmap::new(&data);
+        let charmap = Charmap::new(&cmap);
+        assert!(charmap.has_map() && !charmap.is_symbol() && !charmap.has_variants());
+        let subtable = charmap.subtable().unwrap();
+        assert_eq!(
+            (subtable.encoding.platform_id, subtable.encoding.encoding_id),
+            (3, 10)
+        );
+        assert_eq!(charmap.map(0x41), Some(7));
+        assert_eq!(charmap.map(0x42), None);
+        assert_eq!(cmap.map(0x42), Some(2));
+        let proxy = CharmapProxy::new(&cmap);
+        assert_eq!(proxy.materialize(&cmap).map(0x41), Some(7));
+        assert!(!Charmap::new(&Cmap::new(&[])).has_map());
+    }
+
+    #[test]
+    fn legacy_subtables_are_skipped() {
+        let mut format0 = Writer::new();
+        format0.u16(0).u16(262).u16(0).bytes(&[0; 256]);
+        let data = cmap(&[
+            (1, 0, format0.finish()),
+            (3, 1, format4(&[(0x42, 7), (0xE9, 7)])),
+        ]);
+        let cmap = Cmap::new(&data);
+        assert_eq!(cmap.subtables().next().unwrap().map(0xE9), Some(0));
+        assert_eq!(cmap.map(0x42), Some(7));
+        assert_eq!(cmap.map(0xE9), Some(7));
+        assert_eq!(cmap.map(0x43), None);
+        assert_eq!(cmap.try_map(0xE9), Ok(Some(7)));
+        assert_eq!(Charmap::new(&cmap).map(0xE9), Some(7));
+    }
+
+    #[test]
+    fn symbol_fallback() {
+        let data = cmap(&[
+            (1, 0, format4(&[(0x41, 3)])),
+            (3, 0, format4(&[(0x20, 1), (0xF041, 2)])),
+        ]);
+        let charmap = Charmap::new(&Cmap::new(&data));
+        assert!(charmap.is_symbol());
+        assert_eq!(charmap.map(0x20), Some(1));
+        assert_eq!(charmap.map(0x41), Some(2));
+        assert_eq!(charmap.map(0xF041), Some(2));
+        assert_eq!(charmap.map(0x141), None);
+    }
+
+    #[test]
+    fn variants_and_reverse_mapping() {
+        let data = cmap(&[
+            (
+                3,
+                1,
+                format4(&[(0x41, 1), (0x61, 2), (0x391, 1), (0x4E00, 3)]),
+            ),
+            (
+                0,
+                5,
+                format14(0xFE00, &[(0x4E00, 0)], &[(0x41, 4), (0x42, 5)]),
+            ),
+        ]);
+        let charmap = Charmap::new(&Cmap::new(&data));
+        assert!(charmap.has_variants());
+        assert_eq!(charmap.map_variant(0x41, 0xFE00), Some(4));
+        assert_eq!(charmap.map_variant(0x4E00, 0xFE00), Some(3));
+        assert_eq!(charmap.map_variant(0x61, 0xFE01), Some(2));
+        let reverse = ReverseCharmap::new(&charmap);
+        assert_eq!(reverse.codepoints(1).collect::<Vec<_>>(), [0x41, 0x391]);
+        let variants = reverse
+            .get(3)
+            .iter()
+            .map(|m| (m.codepoint, m.variation_selector))
+            .collect::<Vec<_>>();
+        assert_eq!(variants, [(0x4E00, None), (0x4E00, Some(0xFE00))]);
+        assert_eq!(reverse.codepoints(5).collect::<Vec<_>>(), [0x42]);
+        assert!(reverse.get(6).is_empty());
+        let mut found = Vec::new();
+        charmap.codepoints_with(4, |codepoint, selector| {
+            found.push((codepoint, selector));
+            true
+        });
+        assert_eq!(found, [(0x41, Some(0xFE00))]);
+    }
+
+    #[test]
+    fn byte_and_array_formats() {
+        let mut format0 = Writer::new();
+        format0.u16(0).u16(262).u16(0);
+        for byte in 0..=255u8 {
+            format0.u8(if byte == b'A' { 3 } else { 0 });
+        }
+        let mut format2 = Writer::new();
+        format2.u16(2).u16(540).u16(0);
+        for high in 0..256 {
+            format2.u16(if high == 0x81 { 8 } else { 0 });
+        }
+        format2.u16(0x41).u16(1).i16(0).u16(10);
+        format2.u16(0x40).u16(2).i16(5).u16(4);
+        format2.u16(1).u16(10).u16(11);
+        let mut format6 = Writer::new();
+        format6.u16(6).u16(16).u16(0).u16(0x30).u16(3);
+        format6.u16(4).u16(0).u16(6);
+        let mut format10 = Writer::new();
+        format10.u16(10).u16(0).u32(24).u32(0).u32(0x10000).u32(2);
+        format10.u16(7).u16(8);
+        let data = cmap(&[
+            (1, 0, format0.finish()),
+            (1, 1, format2.finish()),
+            (1, 2, format6.finish()),
+            (3, 10, format10.finish()),
+        ]);
+        let subtables = Cmap::new(&data).subtables().collect::<Vec<_>>();
+        let formats = subtables.iter().map(|s| s.format()).collect::<Vec<_>>();
+        assert_eq!(formats, [0, 2, 6, 10]);
+        let expected: [&[(u32, GlyphId)]; 4] = [
+            &[(0x41, 3)],
+            &[(0x41, 1), (0x8140, 15), (0x8141, 16)],
+            &[(0x30, 4), (0x32, 6)],
+            &[(0x10000, 7), (0x10001, 8)],
+        ];
+        for (subtable, expected) in subtables.iter().zip(expected.iter()) {
+            assert_eq!(pairs(subtable.mappings()), *expected);
+            for &(codepoint, glyph_id) in expected.iter() {
+                assert_eq!(subtable.map(codepoint), Some(glyph_id));
+                assert_eq!(subtable.try_map(codepoint), Ok(Some(glyph_id)));
+            }
+        }
+        assert_eq!(subtables[1].map(0x81), None);
+        assert_eq!(subtables[1].map(0x8142), None);
+        assert_eq!(subtables[2].map(0x31), Some(0));
+        assert_eq!(subtables[3].map(0x10002), None);
+    }
+}
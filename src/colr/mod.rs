@@ -48,8 +48,8 @@ impl<'a> Colr<'a> {
         let offset = d.read_u32(4)? as usize + index as usize * 6;
         let gid = d.read_u16(offset)?;
         let first = d.read_u16(offset + 2)? as usize;
-        let offset = d.read_u32(8)? as usize + first * 4;
         let len = d.read_u16(offset + 4)? as usize;
+        let offset = d.read_u32(8)? as usize + first * 4;
         let layers = d.read_slice(offset, len)?;
         Some(Glyph { gid, layers })
     }
@@ -70,8 +70,8 @@ impl<'a> Colr<'a> {
                 Greater => lo = i + 1,
                 Equal => {
                     let first = d.read_u16(offset + 2)? as usize;
-                    let offset = d.read_u32(8)? as usize + first * 4;
                     let len = d.read_u16(offset + 4)? as usize;
+                    let offset = d.read_u32(8)? as usize + first * 4;
                     let layers = d.read_slice(offset, len)?;
                     return Some(Glyph { gid, layers });
                 }
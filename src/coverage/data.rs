@@ -0,0 +1,1763 @@
+//! Unicode character database and CLDR data used for coverage reports.
+//!
+//! Generated from Unicode 15.0 and CLDR 42.
+
+/// Unicode blocks with assigned characters as (first, last, number of
+/// assigned characters, name).
+pub const BLOCKS: &[(u32, u32, u32, &str)] = &[
+    (0x0000, 0x007F, 128, "Basic Latin"),
+    (0x0080, 0x00FF, 128, "Latin-1 Supplement"),
+    (0x0100, 0x017F, 128, "Latin Extended-A"),
+    (0x0180, 0x024F, 208, "Latin Extended-B"),
+    (0x0250, 0x02AF, 96, "IPA Extensions"),
+    (0x02B0, 0x02FF, 80, "Spacing Modifier Letters"),
+    (0x0300, 0x036F, 112, "Combining Diacritical Marks"),
+    (0x0370, 0x03FF, 135, "Greek and Coptic"),
+    (0x0400, 0x04FF, 256, "Cyrillic"),
+    (0x0500, 0x052F, 48, "Cyrillic Supplement"),
+    (0x0530, 0x058F, 91, "Armenian"),
+    (0x0590, 0x05FF, 88, "Hebrew"),
+    (0x0600, 0x06FF, 256, "Arabic"),
+    (0x0700, 0x074F, 77, "Syriac"),
+    (0x0750, 0x077F, 48, "Arabic Supplement"),
+    (0x0780, 0x07BF, 50, "Thaana"),
+    (0x07C0, 0x07FF, 62, "NKo"),
+    (0x0800, 0x083F, 61, "Samaritan"),
+    (0x0840, 0x085F, 29, "Mandaic"),
+    (0x0860, 0x086F, 11, "Syriac Supplement"),
+    (0x0870, 0x089F, 41, "Arabic Extended-B"),
+    (0x08A0, 0x08FF, 96, "Arabic Extended-A"),
+    (0x0900, 0x097F, 128, "Devanagari"),
+    (0x0980, 0x09FF, 96, "Bengali"),
+    (0x0A00, 0x0A7F, 80, "Gurmukhi"),
+    (0x0A80, 0x0AFF, 91, "Gujarati"),
+    (0x0B00, 0x0B7F, 91, "Oriya"),
+    (0x0B80, 0x0BFF, 72, "Tamil"),
+    (0x0C00, 0x0C7F, 100, "Telugu"),
+    (0x0C80, 0x0CFF, 91, "Kannada"),
+    (0x0D00, 0x0D7F, 118, "Malayalam"),
+    (0x0D80, 0x0DFF, 91, "Sinhala"),
+    (0x0E00, 0x0E7F, 87, "Thai"),
+    (0x0E80, 0x0EFF, 83, "Lao"),
+    (0x0F00, 0x0FFF, 211, "Tibetan"),
+    (0x1000, 0x109F, 160, "Myanmar"),
+    (0x10A0, 0x10FF, 88, "Georgian"),
+    (0x1100, 0x11FF, 256, "Hangul Jamo"),
+    (0x1200, 0x137F, 358, "Ethiopic"),
+    (0x1380, 0x139F, 26, "Ethiopic Supplement"),
+    (0x13A0, 0x13FF, 92, "Cherokee"),
+    (0x1400, 0x167F, 640, "Unified Canadian Aboriginal Syllabics"),
+    (0x1680, 0x169F, 29, "Ogham"),
+    (0x16A0, 0x16FF, 89, "Runic"),
+    (0x1700, 0x171F, 23, "Tagalog"),
+    (0x1720, 0x173F, 23, "Hanunoo"),
+    (0x1740, 0x175F, 20, "Buhid"),
+    (0x1760, 0x177F, 18, "Tagbanwa"),
+    (0x1780, 0x17FF, 114, "Khmer"),
+    (0x1800, 0x18AF, 158, "Mongolian"),
+    (
+        0x18B0,
+        0x18FF,
+        70,
+        "Unified Canadian Aboriginal Syllabics Extended",
+    ),
+    (0x1900, 0x194F, 68, "Limbu"),
+    (0x1950, 0x197F, 35, "Tai Le"),
+    (0x1980, 0x19DF, 83, "New Tai Lue"),
+    (0x19E0, 0x19FF, 32, "Khmer Symbols"),
+    (0x1A00, 0x1A1F, 30, "Buginese"),
+    (0x1A20, 0x1AAF, 127, "Tai Tham"),
+    (0x1AB0, 0x1AFF, 31, "Combining Diacritical Marks Extended"),
+    (0x1B00, 0x1B7F, 124, "Balinese"),
+    (0x1B80, 0x1BBF, 64, "Sundanese"),
+    (0x1BC0, 0x1BFF, 56, "Batak"),
+    (0x1C00, 0x1C4F, 74, "Lepcha"),
+    (0x1C50, 0x1C7F, 48, "Ol Chiki"),
+    (0x1C80, 0x1C8F, 9, "Cyrillic Extended-C"),
+    (0x1C90, 0x1CBF, 46, "Georgian Extended"),
+    (0x1CC0, 0x1CCF, 8, "Sundanese Supplement"),
+    (0x1CD0, 0x1CFF, 43, "Vedic Extensions"),
+    (0x1D00, 0x1D7F, 128, "Phonetic Extensions"),
+    (0x1D80, 0x1DBF, 64, "Phonetic Extensions Supplement"),
+    (0x1DC0, 0x1DFF, 64, "Combining Diacritical Marks Supplement"),
+    (0x1E00, 0x1EFF, 256, "Latin Extended Additional"),
+    (0x1F00, 0x1FFF, 233, "Greek Extended"),
+    (0x2000, 0x206F, 111, "General Punctuation"),
+    (0x2070, 0x209F, 42, "Superscripts and Subscripts"),
+    (0x20A0, 0x20CF, 33, "Currency Symbols"),
+    (
+        0x20D0,
+        0x20FF,
+        33,
+        "Combining Diacritical Marks for Symbols",
+    ),
+    (0x2100, 0x214F, 80, "Letterlike Symbols"),
+    (0x2150, 0x218F, 60, "Number Forms"),
+    (0x2190, 0x21FF, 112, "Arrows"),
+    (0x2200, 0x22FF, 256, "Mathematical Operators"),
+    (0x2300, 0x23FF, 256, "Miscellaneous Technical"),
+    (0x2400, 0x243F, 39, "Control Pictures"),
+    (0x2440, 0x245F, 11, "Optical Character Recognition"),
+    (0x2460, 0x24FF, 160, "Enclosed Alphanumerics"),
+    (0x2500, 0x257F, 128, "Box Drawing"),
+    (0x2580, 0x259F, 32, "Block Elements"),
+    (0x25A0, 0x25FF, 96, "Geometric Shapes"),
+    (0x2600, 0x26FF, 256, "Miscellaneous Symbols"),
+    (0x2700, 0x27BF, 192, "Dingbats"),
+    (0x27C0, 0x27EF, 48, "Miscellaneous Mathematical Symbols-A"),
+    (0x27F0, 0x27FF, 16, "Supplemental Arrows-A"),
+    (0x2800, 0x28FF, 256, "Braille Patterns"),
+    (0x2900, 0x297F, 128, "Supplemental Arrows-B"),
+    (0x2980, 0x29FF, 128, "Miscellaneous Mathematical Symbols-B"),
+    (0x2A00, 0x2AFF, 256, "Supplemental Mathematical Operators"),
+    (0x2B00, 0x2BFF, 253, "Miscellaneous Symbols and Arrows"),
+    (0x2C00, 0x2C5F, 96, "Glagolitic"),
+    (0x2C60, 0x2C7F, 32, "Latin Extended-C"),
+    (0x2C80, 0x2CFF, 123, "Coptic"),
+    (0x2D00, 0x2D2F, 40, "Georgian Supplement"),
+    (0x2D30, 0x2D7F, 59, "Tifinagh"),
+    (0x2D80, 0x2DDF, 79, "Ethiopic Extended"),
+    (0x2DE0, 0x2DFF, 32, "Cyrillic Extended-A"),
+    (0x2E00, 0x2E7F, 94, "Supplemental Punctuation"),
+    (0x2E80, 0x2EFF, 115, "CJK Radicals Supplement"),
+    (0x2F00, 0x2FDF, 214, "Kangxi Radicals"),
+    (0x2FF0, 0x2FFF, 12, "Ideographic Description Characters"),
+    (0x3000, 0x303F, 64, "CJK Symbols and Punctuation"),
+    (0x3040, 0x309F, 93, "Hiragana"),
+    (0x30A0, 0x30FF, 96, "Katakana"),
+    (0x3100, 0x312F, 43, "Bopomofo"),
+    (0x3130, 0x318F, 94, "Hangul Compatibility Jamo"),
+    (0x3190, 0x319F, 16, "Kanbun"),
+    (0x31A0, 0x31BF, 32, "Bopomofo Extended"),
+    (0x31C0, 0x31EF, 36, "CJK Strokes"),
+    (0x31F0, 0x31FF, 16, "Katakana Phonetic Extensions"),
+    (0x3200, 0x32FF, 255, "Enclosed CJK Letters and Months"),
+    (0x3300, 0x33FF, 256, "CJK Compatibility"),
+    (0x3400, 0x4DBF, 6592, "CJK Unified Ideographs Extension A"),
+    (0x4DC0, 0x4DFF, 64, "Yijing Hexagram Symbols"),
+    (0x4E00, 0x9FFF, 20992, "CJK Unified Ideographs"),
+    (0xA000, 0xA48F, 1165, "Yi Syllables"),
+    (0xA490, 0xA4CF, 55, "Yi Radicals"),
+    (0xA4D0, 0xA4FF, 48, "Lisu"),
+    (0xA500, 0xA63F, 300, "Vai"),
+    (0xA640, 0xA69F, 96, "Cyrillic Extended-B"),
+    (0xA6A0, 0xA6FF, 88, "Bamum"),
+    (0xA700, 0xA71F, 32, "Modifier Tone Letters"),
+    (0xA720, 0xA7FF, 193, "Latin Extended-D"),
+    (0xA800, 0xA82F, 45, "Syloti Nagri"),
+    (0xA830, 0xA83F, 10, "Common Indic Number Forms"),
+    (0xA840, 0xA87F, 56, "Phags-pa"),
+    (0xA880, 0xA8DF, 82, "Saurashtra"),
+    (0xA8E0, 0xA8FF, 32, "Devanagari Extended"),
+    (0xA900, 0xA92F, 48, "Kayah Li"),
+    (0xA930, 0xA95F, 37, "Rejang"),
+    (0xA960, 0xA97F, 29, "Hangul Jamo Extended-A"),
+    (0xA980, 0xA9DF, 91, "Javanese"),
+    (0xA9E0, 0xA9FF, 31, "Myanmar Extended-B"),
+    (0xAA00, 0xAA5F, 83, "Cham"),
+    (0xAA60, 0xAA7F, 32, "Myanmar Extended-A"),
+    (0xAA80, 0xAADF, 72, "Tai Viet"),
+    (0xAAE0, 0xAAFF, 23, "Meetei Mayek Extensions"),
+    (0xAB00, 0xAB2F, 32, "Ethiopic Extended-A"),
+    (0xAB30, 0xAB6F, 60, "Latin Extended-E"),
+    (0xAB70, 0xABBF, 80, "Cherokee Supplement"),
+    (0xABC0, 0xABFF, 56, "Meetei Mayek"),
+    (0xAC00, 0xD7AF, 11172, "Hangul Syllables"),
+    (0xD7B0, 0xD7FF, 72, "Hangul Jamo Extended-B"),
+    (0xE000, 0xF8FF, 6400, "Private Use Area"),
+    (0xF900, 0xFAFF, 472, "CJK Compatibility Ideographs"),
+    (0xFB00, 0xFB4F, 58, "Alphabetic Presentation Forms"),
+    (0xFB50, 0xFDFF, 631, "Arabic Presentation Forms-A"),
+    (0xFE00, 0xFE0F, 16, "Variation Selectors"),
+    (0xFE10, 0xFE1F, 10, "Vertical Forms"),
+    (0xFE20, 0xFE2F, 16, "Combining Half Marks"),
+    (0xFE30, 0xFE4F, 32, "CJK Compatibility Forms"),
+    (0xFE50, 0xFE6F, 26, "Small Form Variants"),
+    (0xFE70, 0xFEFF, 141, "Arabic Presentation Forms-B"),
+    (0xFF00, 0xFFEF, 225, "Halfwidth and Fullwidth Forms"),
+    (0xFFF0, 0xFFFF, 5, "Specials"),
+    (0x10000, 0x1007F, 88, "Linear B Syllabary"),
+    (0x10080, 0x100FF, 123, "Linear B Ideograms"),
+    (0x10100, 0x1013F, 57, "Aegean Numbers"),
+    (0x10140, 0x1018F, 79, "Ancient Greek Numbers"),
+    (0x10190, 0x101CF, 14, "Ancient Symbols"),
+    (0x101D0, 0x101FF, 46, "Phaistos Disc"),
+    (0x10280, 0x1029F, 29, "Lycian"),
+    (0x102A0, 0x102DF, 49, "Carian"),
+    (0x102E0, 0x102FF, 28, "Coptic Epact Numbers"),
+    (0x10300, 0x1032F, 39, "Old Italic"),
+    (0x10330, 0x1034F, 27, "Gothic"),
+    (0x10350, 0x1037F, 43, "Old Permic"),
+    (0x10380, 0x1039F, 31, "Ugaritic"),
+    (0x103A0, 0x103DF, 50, "Old Persian"),
+    (0x10400, 0x1044F, 80, "Deseret"),
+    (0x10450, 0x1047F, 48, "Shavian"),
+    (0x10480, 0x104AF, 40, "Osmanya"),
+    (0x104B0, 0x104FF, 72, "Osage"),
+    (0x10500, 0x1052F, 40, "Elbasan"),
+    (0x10530, 0x1056F, 53, "Caucasian Albanian"),
+    (0x10570, 0x105BF, 70, "Vithkuqi"),
+    (0x10600, 0x1077F, 341, "Linear A"),
+    (0x10780, 0x107BF, 57, "Latin Extended-F"),
+    (0x10800, 0x1083F, 55, "Cypriot Syllabary"),
+    (0x10840, 0x1085F, 31, "Imperial Aramaic"),
+    (0x10860, 0x1087F, 32, "Palmyrene"),
+    (0x10880, 0x108AF, 40, "Nabataean"),
+    (0x108E0, 0x108FF, 26, "Hatran"),
+    (0x10900, 0x1091F, 29, "Phoenician"),
+    (0x10920, 0x1093F, 27, "Lydian"),
+    (0x10980, 0x1099F, 32, "Meroitic Hieroglyphs"),
+    (0x109A0, 0x109FF, 90, "Meroitic Cursive"),
+    (0x10A00, 0x10A5F, 68, "Kharoshthi"),
+    (0x10A60, 0x10A7F, 32, "Old South Arabian"),
+    (0x10A80, 0x10A9F, 32, "Old North Arabian"),
+    (0x10AC0, 0x10AFF, 51, "Manichaean"),
+    (0x10B00, 0x10B3F, 61, "Avestan"),
+    (0x10B40, 0x10B5F, 30, "Inscriptional Parthian"),
+    (0x10B60, 0x10B7F, 27, "Inscriptional Pahlavi"),
+    (0x10B80, 0x10BAF, 29, "Psalter Pahlavi"),
+    (0x10C00, 0x10C4F, 73, "Old Turkic"),
+    (0x10C80, 0x10CFF, 108, "Old Hungarian"),
+    (0x10D00, 0x10D3F, 50, "Hanifi Rohingya"),
+    (0x10E60, 0x10E7F, 31, "Rumi Numeral Symbols"),
+    (0x10E80, 0x10EBF, 47, "Yezidi"),
+    (0x10EC0, 0x10EFF, 3, "Arabic Extended-C"),
+    (0x10F00, 0x10F2F, 40, "Old Sogdian"),
+    (0x10F30, 0x10F6F, 42, "Sogdian"),
+    (0x10F70, 0x10FAF, 26, "Old Uyghur"),
+    (0x10FB0, 0x10FDF, 28, "Chorasmian"),
+    (0x10FE0, 0x10FFF, 23, "Elymaic"),
+    (0x11000, 0x1107F, 115, "Brahmi"),
+    (0x11080, 0x110CF, 68, "Kaithi"),
+    (0x110D0, 0x110FF, 35, "Sora Sompeng"),
+    (0x11100, 0x1114F, 71, "Chakma"),
+    (0x11150, 0x1117F, 39, "Mahajani"),
+    (0x11180, 0x111DF, 96, "Sharada"),
+    (0x111E0, 0x111FF, 20, "Sinhala Archaic Numbers"),
+    (0x11200, 0x1124F, 65, "Khojki"),
+    (0x11280, 0x112AF, 38, "Multani"),
+    (0x112B0, 0x112FF, 69, "Khudawadi"),
+    (0x11300, 0x1137F, 86, "Grantha"),
+    (0x11400, 0x1147F, 97, "Newa"),
+    (0x11480, 0x114DF, 82, "Tirhuta"),
+    (0x11580, 0x115FF, 92, "Siddham"),
+    (0x11600, 0x1165F, 79, "Modi"),
+    (0x11660, 0x1167F, 13, "Mongolian Supplement"),
+    (0x11680, 0x116CF, 68, "Takri"),
+    (0x11700, 0x1174F, 65, "Ahom"),
+    (0x11800, 0x1184F, 60, "Dogra"),
+    (0x118A0, 0x118FF, 84, "Warang Citi"),
+    (0x11900, 0x1195F, 72, "Dives Akuru"),
+    (0x119A0, 0x119FF, 65, "Nandinagari"),
+    (0x11A00, 0x11A4F, 72, "Zanabazar Square"),
+    (0x11A50, 0x11AAF, 83, "Soyombo"),
+    (
+        0x11AB0,
+        0x11ABF,
+        16,
+        "Unified Canadian Aboriginal Syllabics Extended-A",
+    ),
+    (0x11AC0, 0x11AFF, 57, "Pau Cin Hau"),
+    (0x11B00, 0x11B5F, 10, "Devanagari Extended-A"),
+    (0x11C00, 0x11C6F, 97, "Bhaiksuki"),
+    (0x11C70, 0x11CBF, 68, "Marchen"),
+    (0x11D00, 0x11D5F, 75, "Masaram Gondi"),
+    (0x11D60, 0x11DAF, 63, "Gunjala Gondi"),
+    (0x11EE0, 0x11EFF, 25, "Makasar"),
+    (0x11F00, 0x11F5F, 86, "Kawi"),
+    (0x11FB0, 0x11FBF, 1, "Lisu Supplement"),
+    (0x11FC0, 0x11FFF, 51, "Tamil Supplement"),
+    (0x12000, 0x123FF, 922, "Cuneiform"),
+    (0x12400, 0x1247F, 116, "Cuneiform Numbers and Punctuation"),
+    (0x12480, 0x1254F, 196, "Early Dynastic Cuneiform"),
+    (0x12F90, 0x12FFF, 99, "Cypro-Minoan"),
+    (0x13000, 0x1342F, 1072, "Egyptian Hieroglyphs"),
+    (0x13430, 0x1345F, 38, "Egyptian Hieroglyph Format Controls"),
+    (0x14400, 0x1467F, 583, "Anatolian Hieroglyphs"),
+    (0x16800, 0x16A3F, 569, "Bamum Supplement"),
+    (0x16A40, 0x16A6F, 43, "Mro"),
+    (0x16A70, 0x16ACF, 89, "Tangsa"),
+    (0x16AD0, 0x16AFF, 36, "Bassa Vah"),
+    (0x16B00, 0x16B8F, 127, "Pahawh Hmong"),
+    (0x16E40, 0x16E9F, 91, "Medefaidrin"),
+    (0x16F00, 0x16F9F, 149, "Miao"),
+    (0x16FE0, 0x16FFF, 7, "Ideographic Symbols and Punctuation"),
+    (0x17000, 0x187FF, 6136, "Tangut"),
+    (0x18800, 0x18AFF, 768, "Tangut Components"),
+    (0x18B00, 0x18CFF, 470, "Khitan Small Script"),
+    (0x18D00, 0x18D7F, 9, "Tangut Supplement"),
+    (0x1AFF0, 0x1AFFF, 13, "Kana Extended-B"),
+    (0x1B000, 0x1B0FF, 256, "Kana Supplement"),
+    (0x1B100, 0x1B12F, 35, "Kana Extended-A"),
+    (0x1B130, 0x1B16F, 9, "Small Kana Extension"),
+    (0x1B170, 0x1B2FF, 396, "Nushu"),
+    (0x1BC00, 0x1BC9F, 143, "Duployan"),
+    (0x1BCA0, 0x1BCAF, 4, "Shorthand Format Controls"),
+    (0x1CF00, 0x1CFCF, 185, "Znamenny Musical Notation"),
+    (0x1D000, 0x1D0FF, 246, "Byzantine Musical Symbols"),
+    (0x1D100, 0x1D1FF, 233, "Musical Symbols"),
+    (0x1D200, 0x1D24F, 70, "Ancient Greek Musical Notation"),
+    (0x1D2C0, 0x1D2DF, 20, "Kaktovik Numerals"),
+    (0x1D2E0, 0x1D2FF, 20, "Mayan Numerals"),
+    (0x1D300, 0x1D35F, 87, "Tai Xuan Jing Symbols"),
+    (0x1D360, 0x1D37F, 25, "Counting Rod Numerals"),
+    (0x1D400, 0x1D7FF, 996, "Mathematical Alphanumeric Symbols"),
+    (0x1D800, 0x1DAAF, 672, "Sutton SignWriting"),
+    (0x1DF00, 0x1DFFF, 37, "Latin Extended-G"),
+    (0x1E000, 0x1E02F, 38, "Glagolitic Supplement"),
+    (0x1E030, 0x1E08F, 63, "Cyrillic Extended-D"),
+    (0x1E100, 0x1E14F, 71, "Nyiakeng Puachue Hmong"),
+    (0x1E290, 0x1E2BF, 31, "Toto"),
+    (0x1E2C0, 0x1E2FF, 59, "Wancho"),
+    (0x1E4D0, 0x1E4FF, 42, "Nag Mundari"),
+    (0x1E7E0, 0x1E7FF, 28, "Ethiopic Extended-B"),
+    (0x1E800, 0x1E8DF, 213, "Mende Kikakui"),
+    (0x1E900, 0x1E95F, 88, "Adlam"),
+    (0x1EC70, 0x1ECBF, 68, "Indic Siyaq Numbers"),
+    (0x1ED00, 0x1ED4F, 61, "Ottoman Siyaq Numbers"),
+    (
+        0x1EE00,
+        0x1EEFF,
+        143,
+        "Arabic Mathematical Alphabetic Symbols",
+    ),
+    (0x1F000, 0x1F02F, 44, "Mahjong Tiles"),
+    (0x1F030, 0x1F09F, 100, "Domino Tiles"),
+    (0x1F0A0, 0x1F0FF, 82, "Playing Cards"),
+    (0x1F100, 0x1F1FF, 200, "Enclosed Alphanumeric Supplement"),
+    (0x1F200, 0x1F2FF, 64, "Enclosed Ideographic Supplement"),
+    (
+        0x1F300,
+        0x1F5FF,
+        768,
+        "Miscellaneous Symbols and Pictographs",
+    ),
+    (0x1F600, 0x1F64F, 80, "Emoticons"),
+    (0x1F650, 0x1F67F, 48, "Ornamental Dingbats"),
+    (0x1F680, 0x1F6FF, 118, "Transport and Map Symbols"),
+    (0x1F700, 0x1F77F, 124, "Alchemical Symbols"),
+    (0x1F780, 0x1F7FF, 103, "Geometric Shapes Extended"),
+    (0x1F800, 0x1F8FF, 150, "Supplemental Arrows-C"),
+    (
+        0x1F900,
+        0x1F9FF,
+        256,
+        "Supplemental Symbols and Pictographs",
+    ),
+    (0x1FA00, 0x1FA6F, 98, "Chess Symbols"),
+    (0x1FA70, 0x1FAFF, 107, "Symbols and Pictographs Extended-A"),
+    (0x1FB00, 0x1FBFF, 212, "Symbols for Legacy Computing"),
+    (
+        0x20000,
+        0x2A6DF,
+        42720,
+        "CJK Unified Ideographs Extension B",
+    ),
+    (0x2A700, 0x2B73F, 4154, "CJK Unified Ideographs Extension C"),
+    (0x2B740, 0x2B81F, 222, "CJK Unified Ideographs Extension D"),
+    (0x2B820, 0x2CEAF, 5762, "CJK Unified Ideographs Extension E"),
+    (0x2CEB0, 0x2EBEF, 7473, "CJK Unified Ideographs Extension F"),
+    (
+        0x2F800,
+        0x2FA1F,
+        542,
+        "CJK Compatibility Ideographs Supplement",
+    ),
+    (0x30000, 0x3134F, 4939, "CJK Unified Ideographs Extension G"),
+    (0x31350, 0x323AF, 4192, "CJK Unified Ideographs Extension H"),
+    (0xE0000, 0xE007F, 97, "Tags"),
+    (0xE0100, 0xE01EF, 240, "Variation Selectors Supplement"),
+    (0xF0000, 0xFFFFF, 65534, "Supplementary Private Use Area-A"),
+    (
+        0x100000,
+        0x10FFFF,
+        65534,
+        "Supplementary Private Use Area-B",
+    ),
+];
+
+/// Scripts as (ISO 15924 code, name, number of assigned characters).
+pub const SCRIPTS: &[(&str, &str, u32)] = &[
+    ("Adlm", "Adlam", 88),
+    ("Aghb", "Caucasian Albanian", 53),
+    ("Ahom", "Ahom", 65),
+    ("Arab", "Arabic", 1368),
+    ("Armi", "Imperial Aramaic", 31),
+    ("Armn", "Armenian", 96),
+    ("Avst", "Avestan", 61),
+    ("Bali", "Balinese", 124),
+    ("Bamu", "Bamum", 657),
+    ("Bass", "Bassa Vah", 36),
+    ("Batk", "Batak", 56),
+    ("Beng", "Bengali", 96),
+    ("Bhks", "Bhaiksuki", 97),
+    ("Bopo", "Bopomofo", 77),
+    ("Brah", "Brahmi", 115),
+    ("Brai", "Braille", 256),
+    ("Bugi", "Buginese", 30),
+    ("Buhd", "Buhid", 20),
+    ("Cakm", "Chakma", 71),
+    ("Cans", "Canadian Aboriginal", 726),
+    ("Cari", "Carian", 49),
+    ("Cham", "Cham", 83),
+    ("Cher", "Cherokee", 172),
+    ("Chrs", "Chorasmian", 28),
+    ("Copt", "Coptic", 137),
+    ("Cpmn", "Cypro Minoan", 99),
+    ("Cprt", "Cypriot", 55),
+    ("Cyrl", "Cyrillic", 506),
+    ("Deva", "Devanagari", 164),
+    ("Diak", "Dives Akuru", 72),
+    ("Dogr", "Dogra", 60),
+    ("Dsrt", "Deseret", 80),
+    ("Dupl", "Duployan", 143),
+    ("Egyp", "Egyptian Hieroglyphs", 1110),
+    ("Elba", "Elbasan", 40),
+    ("Elym", "Elymaic", 23),
+    ("Ethi", "Ethiopic", 523),
+    ("Geor", "Georgian", 173),
+    ("Glag", "Glagolitic", 134),
+    ("Gong", "Gunjala Gondi", 63),
+    ("Gonm", "Masaram Gondi", 75),
+    ("Goth", "Gothic", 27),
+    ("Gran", "Grantha", 85),
+    ("Grek", "Greek", 518),
+    ("Gujr", "Gujarati", 91),
+    ("Guru", "Gurmukhi", 80),
+    ("Hang", "Hangul", 11739),
+    ("Hani", "Han", 98408),
+    ("Hano", "Hanunoo", 21),
+    ("Hatr", "Hatran", 26),
+    ("Hebr", "Hebrew", 134),
+    ("Hira", "Hiragana", 381),
+    ("Hluw", "Anatolian Hieroglyphs", 583),
+    ("Hmng", "Pahawh Hmong", 127),
+    ("Hmnp", "Nyiakeng Puachue Hmong", 71),
+    ("Hung", "Old Hungarian", 108),
+    ("Ital", "Old Italic", 39),
+    ("Java", "Javanese", 90),
+    ("Kali", "Kayah Li", 47),
+    ("Kana", "Katakana", 321),
+    ("Kawi", "Kawi", 86),
+    ("Khar", "Kharoshthi", 68),
+    ("Khmr", "Khmer", 146),
+    ("Khoj", "Khojki", 65),
+    ("Kits", "Khitan Small Script", 471),
+    ("Knda", "Kannada", 91),
+    ("Kthi", "Kaithi", 68),
+    ("Lana", "Tai Tham", 127),
+    ("Laoo", "Lao", 83),
+    ("Latn", "Latin", 1481),
+    ("Lepc", "Lepcha", 74),
+    ("Limb", "Limbu", 68),
+    ("Lina", "Linear A", 341),
+    ("Linb", "Linear B", 211),
+    ("Lisu", "Lisu", 49),
+    ("Lyci", "Lycian", 29),
+    ("Lydi", "Lydian", 27),
+    ("Mahj", "Mahajani", 39),
+    ("Maka", "Makasar", 25),
+    ("Mand", "Mandaic", 29),
+    ("Mani", "Manichaean", 51),
+    ("Marc", "Marchen", 68),
+    ("Medf", "Medefaidrin", 91),
+    ("Mend", "Mende Kikakui", 213),
+    ("Merc", "Meroitic Cursive", 90),
+    ("Mero", "Meroitic Hieroglyphs", 32),
+    ("Mlym", "Malayalam", 118),
+    ("Modi", "Modi", 79),
+    ("Mong", "Mongolian", 168),
+    ("Mroo", "Mro", 43),
+    ("Mtei", "Meetei Mayek", 79),
+    ("Mult", "Multani", 38),
+    ("Mymr", "Myanmar", 223),
+    ("Nagm", "Nag Mundari", 42),
+    ("Nand", "Nandinagari", 65),
+    ("Narb", "Old North Arabian", 32),
+    ("Nbat", "Nabataean", 40),
+    ("Newa", "Newa", 97),
+    ("Nkoo", "Nko", 62),
+    ("Nshu", "Nushu", 397),
+    ("Ogam", "Ogham", 29),
+    ("Olck", "Ol Chiki", 48),
+    ("Orkh", "Old Turkic", 73),
+    ("Orya", "Oriya", 91),
+    ("Osge", "Osage", 72),
+    ("Osma", "Osmanya", 40),
+    ("Ougr", "Old Uyghur", 26),
+    ("Palm", "Palmyrene", 32),
+    ("Pauc", "Pau Cin Hau", 57),
+    ("Perm", "Old Permic", 43),
+    ("Phag", "Phags Pa", 56),
+    ("Phli", "Inscriptional Pahlavi", 27),
+    ("Phlp", "Psalter Pahlavi", 29),
+    ("Phnx", "Phoenician", 29),
+    ("Plrd", "Miao", 149),
+    ("Prti", "Inscriptional Parthian", 30),
+    ("Rjng", "Rejang", 37),
+    ("Rohg", "Hanifi Rohingya", 50),
+    ("Runr", "Runic", 86),
+    ("Samr", "Samaritan", 61),
+    ("Sarb", "Old South Arabian", 32),
+    ("Saur", "Saurashtra", 82),
+    ("Sgnw", "SignWriting", 672),
+    ("Shaw", "Shavian", 48),
+    ("Shrd", "Sharada", 96),
+    ("Sidd", "Siddham", 92),
+    ("Sind", "Khudawadi", 69),
+    ("Sinh", "Sinhala", 111),
+    ("Sogd", "Sogdian", 42),
+    ("Sogo", "Old Sogdian", 40),
+    ("Sora", "Sora Sompeng", 35),
+    ("Soyo", "Soyombo", 83),
+    ("Sund", "Sundanese", 72),
+    ("Sylo", "Syloti Nagri", 45),
+    ("Syrc", "Syriac", 88),
+    ("Tagb", "Tagbanwa", 18),
+    ("Takr", "Takri", 68),
+    ("Tale", "Tai Le", 35),
+    ("Talu", "New Tai Lue", 83),
+    ("Taml", "Tamil", 123),
+    ("Tang", "Tangut", 6914),
+    ("Tavt", "Tai Viet", 72),
+    ("Telu", "Telugu", 100),
+    ("Tfng", "Tifinagh", 59),
+    ("Tglg", "Tagalog", 23),
+    ("Thaa", "Thaana", 50),
+    ("Thai", "Thai", 86),
+    ("Tibt", "Tibetan", 207),
+    ("Tirh", "Tirhuta", 82),
+    ("Tnsa", "Tangsa", 89),
+    ("Toto", "Toto", 31),
+    ("Ugar", "Ugaritic", 31),
+    ("Vaii", "Vai", 300),
+    ("Vith", "Vithkuqi", 70),
+    ("Wara", "Warang Citi", 84),
+    ("Wcho", "Wancho", 59),
+    ("Xpeo", "Old Persian", 50),
+    ("Xsux", "Cuneiform", 1234),
+    ("Yezi", "Yezidi", 47),
+    ("Yiii", "Yi", 1220),
+    ("Zanb", "Zanabazar Square", 72),
+    ("Zinh", "Inherited", 657),
+    ("Zyyy", "Common", 8301),
+    ("Zzzz", "Unknown", 137468),
+];
+
+/// Ranges of assigned characters as (first, last, index in [`SCRIPTS`]).
+/// Surrogates are excluded.
+pub const SCRIPT_RANGES: &[(u32, u32, u8)] = &[
+    (0x0000, 0x0040, 162),
+    (0x0041, 0x005A, 69),
+    (0x005B, 0x0060, 162),
+    (0x0061, 0x007A, 69),
+    (0x007B, 0x00A9, 162),
+    (0x00AA, 0x00AA, 69),
+    (0x00AB, 0x00B9, 162),
+    (0x00BA, 0x00BA, 69),
+    (0x00BB, 0x00BF, 162),
+    (0x00C0, 0x00D6, 69),
+    (0x00D7, 0x00D7, 162),
+    (0x00D8, 0x00F6, 69),
+    (0x00F7, 0x00F7, 162),
+    (0x00F8, 0x02B8, 69),
+    (0x02B9, 0x02DF, 162),
+    (0x02E0, 0x02E4, 69),
+    (0x02E5, 0x02E9, 162),
+    (0x02EA, 0x02EB, 13),
+    (0x02EC, 0x02FF, 162),
+    (0x0300, 0x036F, 161),
+    (0x0370, 0x0373, 43),
+    (0x0374, 0x0374, 162),
+    (0x0375, 0x0377, 43),
+    (0x037A, 0x037D, 43),
+    (0x037E, 0x037E, 162),
+    (0x037F, 0x037F, 43),
+    (0x0384, 0x0384, 43),
+    (0x0385, 0x0385, 162),
+    (0x0386, 0x0386, 43),
+    (0x0387, 0x0387, 162),
+    (0x0388, 0x038A, 43),
+    (0x038C, 0x038C, 43),
+    (0x038E, 0x03A1, 43),
+    (0x03A3, 0x03E1, 43),
+    (0x03E2, 0x03EF, 24),
+    (0x03F0, 0x03FF, 43),
+    (0x0400, 0x0484, 27),
+    (0x0485, 0x0486, 161),
+    (0x0487, 0x052F, 27),
+    (0x0531, 0x0556, 5),
+    (0x0559, 0x058A, 5),
+    (0x058D, 0x058F, 5),
+    (0x0591, 0x05C7, 50),
+    (0x05D0, 0x05EA, 50),
+    (0x05EF, 0x05F4, 50),
+    (0x0600, 0x0604, 3),
+    (0x0605, 0x0605, 162),
+    (0x0606, 0x060B, 3),
+    (0x060C, 0x060C, 162),
+    (0x060D, 0x061A, 3),
+    (0x061B, 0x061B, 162),
+    (0x061C, 0x061E, 3),
+    (0x061F, 0x061F, 162),
+    (0x0620, 0x063F, 3),
+    (0x0640, 0x0640, 162),
+    (0x0641, 0x064A, 3),
+    (0x064B, 0x0655, 161),
+    (0x0656, 0x066F, 3),
+    (0x0670, 0x0670, 161),
+    (0x0671, 0x06DC, 3),
+    (0x06DD, 0x06DD, 162),
+    (0x06DE, 0x06FF, 3),
+    (0x0700, 0x070D, 134),
+    (0x070F, 0x074A, 134),
+    (0x074D, 0x074F, 134),
+    (0x0750, 0x077F, 3),
+    (0x0780, 0x07B1, 145),
+    (0x07C0, 0x07FA, 98),
+    (0x07FD, 0x07FF, 98),
+    (0x0800, 0x082D, 119),
+    (0x0830, 0x083E, 119),
+    (0x0840, 0x085B, 79),
+    (0x085E, 0x085E, 79),
+    (0x0860, 0x086A, 134),
+    (0x0870, 0x088E, 3),
+    (0x0890, 0x0891, 3),
+    (0x0898, 0x08E1, 3),
+    (0x08E2, 0x08E2, 162),
+    (0x08E3, 0x08FF, 3),
+    (0x0900, 0x0950, 28),
+    (0x0951, 0x0954, 161),
+    (0x0955, 0x0963, 28),
+    (0x0964, 0x0965, 162),
+    (0x0966, 0x097F, 28),
+    (0x0980, 0x0983, 11),
+    (0x0985, 0x098C, 11),
+    (0x098F, 0x0990, 11),
+    (0x0993, 0x09A8, 11),
+    (0x09AA, 0x09B0, 11),
+    (0x09B2, 0x09B2, 11),
+    (0x09B6, 0x09B9, 11),
+    (0x09BC, 0x09C4, 11),
+    (0x09C7, 0x09C8, 11),
+    (0x09CB, 0x09CE, 11),
+    (0x09D7, 0x09D7, 11),
+    (0x09DC, 0x09DD, 11),
+    (0x09DF, 0x09E3, 11),
+    (0x09E6, 0x09FE, 11),
+    (0x0A01, 0x0A03, 45),
+    (0x0A05, 0x0A0A, 45),
+    (0x0A0F, 0x0A10, 45),
+    (0x0A13, 0x0A28, 45),
+    (0x0A2A, 0x0A30, 45),
+    (0x0A32, 0x0A33, 45),
+    (0x0A35, 0x0A36, 45),
+    (0x0A38, 0x0A39, 45),
+    (0x0A3C, 0x0A3C, 45),
+    (0x0A3E, 0x0A42, 45),
+    (0x0A47, 0x0A48, 45),
+    (0x0A4B, 0x0A4D, 45),
+    (0x0A51, 0x0A51, 45),
+    (0x0A59, 0x0A5C, 45),
+    (0x0A5E, 0x0A5E, 45),
+    (0x0A66, 0x0A76, 45),
+    (0x0A81, 0x0A83, 44),
+    (0x0A85, 0x0A8D, 44),
+    (0x0A8F, 0x0A91, 44),
+    (0x0A93, 0x0AA8, 44),
+    (0x0AAA, 0x0AB0, 44),
+    (0x0AB2, 0x0AB3, 44),
+    (0x0AB5, 0x0AB9, 44),
+    (0x0ABC, 0x0AC5, 44),
+    (0x0AC7, 0x0AC9, 44),
+    (0x0ACB, 0x0ACD, 44),
+    (0x0AD0, 0x0AD0, 44),
+    (0x0AE0, 0x0AE3, 44),
+    (0x0AE6, 0x0AF1, 44),
+    (0x0AF9, 0x0AFF, 44),
+    (0x0B01, 0x0B03, 103),
+    (0x0B05, 0x0B0C, 103),
+    (0x0B0F, 0x0B10, 103),
+    (0x0B13, 0x0B28, 103),
+    (0x0B2A, 0x0B30, 103),
+    (0x0B32, 0x0B33, 103),
+    (0x0B35, 0x0B39, 103),
+    (0x0B3C, 0x0B44, 103),
+    (0x0B47, 0x0B48, 103),
+    (0x0B4B, 0x0B4D, 103),
+    (0x0B55, 0x0B57, 103),
+    (0x0B5C, 0x0B5D, 103),
+    (0x0B5F, 0x0B63, 103),
+    (0x0B66, 0x0B77, 103),
+    (0x0B82, 0x0B83, 139),
+    (0x0B85, 0x0B8A, 139),
+    (0x0B8E, 0x0B90, 139),
+    (0x0B92, 0x0B95, 139),
+    (0x0B99, 0x0B9A, 139),
+    (0x0B9C, 0x0B9C, 139),
+    (0x0B9E, 0x0B9F, 139),
+    (0x0BA3, 0x0BA4, 139),
+    (0x0BA8, 0x0BAA, 139),
+    (0x0BAE, 0x0BB9, 139),
+    (0x0BBE, 0x0BC2, 139),
+    (0x0BC6, 0x0BC8, 139),
+    (0x0BCA, 0x0BCD, 139),
+    (0x0BD0, 0x0BD0, 139),
+    (0x0BD7, 0x0BD7, 139),
+    (0x0BE6, 0x0BFA, 139),
+    (0x0C00, 0x0C0C, 142),
+    (0x0C0E, 0x0C10, 142),
+    (0x0C12, 0x0C28, 142),
+    (0x0C2A, 0x0C39, 142),
+    (0x0C3C, 0x0C44, 142),
+    (0x0C46, 0x0C48, 142),
+    (0x0C4A, 0x0C4D, 142),
+    (0x0C55, 0x0C56, 142),
+    (0x0C58, 0x0C5A, 142),
+    (0x0C5D, 0x0C5D, 142),
+    (0x0C60, 0x0C63, 142),
+    (0x0C66, 0x0C6F, 142),
+    (0x0C77, 0x0C7F, 142),
+    (0x0C80, 0x0C8C, 65),
+    (0x0C8E, 0x0C90, 65),
+    (0x0C92, 0x0CA8, 65),
+    (0x0CAA, 0x0CB3, 65),
+    (0x0CB5, 0x0CB9, 65),
+    (0x0CBC, 0x0CC4, 65),
+    (0x0CC6, 0x0CC8, 65),
+    (0x0CCA, 0x0CCD, 65),
+    (0x0CD5, 0x0CD6, 65),
+    (0x0CDD, 0x0CDE, 65),
+    (0x0CE0, 0x0CE3, 65),
+    (0x0CE6, 0x0CEF, 65),
+    (0x0CF1, 0x0CF3, 65),
+    (0x0D00, 0x0D0C, 86),
+    (0x0D0E, 0x0D10, 86),
+    (0x0D12, 0x0D44, 86),
+    (0x0D46, 0x0D48, 86),
+    (0x0D4A, 0x0D4F, 86),
+    (0x0D54, 0x0D63, 86),
+    (0x0D66, 0x0D7F, 86),
+    (0x0D81, 0x0D83, 127),
+    (0x0D85, 0x0D96, 127),
+    (0x0D9A, 0x0DB1, 127),
+    (0x0DB3, 0x0DBB, 127),
+    (0x0DBD, 0x0DBD, 127),
+    (0x0DC0, 0x0DC6, 127),
+    (0x0DCA, 0x0DCA, 127),
+    (0x0DCF, 0x0DD4, 127),
+    (0x0DD6, 0x0DD6, 127),
+    (0x0DD8, 0x0DDF, 127),
+    (0x0DE6, 0x0DEF, 127),
+    (0x0DF2, 0x0DF4, 127),
+    (0x0E01, 0x0E3A, 146),
+    (0x0E3F, 0x0E3F, 162),
+    (0x0E40, 0x0E5B, 146),
+    (0x0E81, 0x0E82, 68),
+    (0x0E84, 0x0E84, 68),
+    (0x0E86, 0x0E8A, 68),
+    (0x0E8C, 0x0EA3, 68),
+    (0x0EA5, 0x0EA5, 68),
+    (0x0EA7, 0x0EBD, 68),
+    (0x0EC0, 0x0EC4, 68),
+    (0x0EC6, 0x0EC6, 68),
+    (0x0EC8, 0x0ECE, 68),
+    (0x0ED0, 0x0ED9, 68),
+    (0x0EDC, 0x0EDF, 68),
+    (0x0F00, 0x0F47, 147),
+    (0x0F49, 0x0F6C, 147),
+    (0x0F71, 0x0F97, 147),
+    (0x0F99, 0x0FBC, 147),
+    (0x0FBE, 0x0FCC, 147),
+    (0x0FCE, 0x0FD4, 147),
+    (0x0FD5, 0x0FD8, 162),
+    (0x0FD9, 0x0FDA, 147),
+    (0x1000, 0x109F, 92),
+    (0x10A0, 0x10C5, 37),
+    (0x10C7, 0x10C7, 37),
+    (0x10CD, 0x10CD, 37),
+    (0x10D0, 0x10FA, 37),
+    (0x10FB, 0x10FB, 162),
+    (0x10FC, 0x10FF, 37),
+    (0x1100, 0x11FF, 46),
+    (0x1200, 0x1248, 36),
+    (0x124A, 0x124D, 36),
+    (0x1250, 0x1256, 36),
+    (0x1258, 0x1258, 36),
+    (0x125A, 0x125D, 36),
+    (0x1260, 0x1288, 36),
+    (0x128A, 0x128D, 36),
+    (0x1290, 0x12B0, 36),
+    (0x12B2, 0x12B5, 36),
+    (0x12B8, 0x12BE, 36),
+    (0x12C0, 0x12C0, 36),
+    (0x12C2, 0x12C5, 36),
+    (0x12C8, 0x12D6, 36),
+    (0x12D8, 0x1310, 36),
+    (0x1312, 0x1315, 36),
+    (0x1318, 0x135A, 36),
+    (0x135D, 0x137C, 36),
+    (0x1380, 0x1399, 36),
+    (0x13A0, 0x13F5, 22),
+    (0x13F8, 0x13FD, 22),
+    (0x1400, 0x167F, 19),
+    (0x1680, 0x169C, 100),
+    (0x16A0, 0x16EA, 118),
+    (0x16EB, 0x16ED, 162),
+    (0x16EE, 0x16F8, 118),
+    (0x1700, 0x1715, 144),
+    (0x171F, 0x171F, 144),
+    (0x1720, 0x1734, 48),
+    (0x1735, 0x1736, 162),
+    (0x1740, 0x1753, 17),
+    (0x1760, 0x176C, 135),
+    (0x176E, 0x1770, 135),
+    (0x1772, 0x1773, 135),
+    (0x1780, 0x17DD, 62),
+    (0x17E0, 0x17E9, 62),
+    (0x17F0, 0x17F9, 62),
+    (0x1800, 0x1801, 88),
+    (0x1802, 0x1803, 162),
+    (0x1804, 0x1804, 88),
+    (0x1805, 0x1805, 162),
+    (0x1806, 0x1819, 88),
+    (0x1820, 0x1878, 88),
+    (0x1880, 0x18AA, 88),
+    (0x18B0, 0x18F5, 19),
+    (0x1900, 0x191E, 71),
+    (0x1920, 0x192B, 71),
+    (0x1930, 0x193B, 71),
+    (0x1940, 0x1940, 71),
+    (0x1944, 0x194F, 71),
+    (0x1950, 0x196D, 137),
+    (0x1970, 0x1974, 137),
+    (0x1980, 0x19AB, 138),
+    (0x19B0, 0x19C9, 138),
+    (0x19D0, 0x19DA, 138),
+    (0x19DE, 0x19DF, 138),
+    (0x19E0, 0x19FF, 62),
+    (0x1A00, 0x1A1B, 16),
+    (0x1A1E, 0x1A1F, 16),
+    (0x1A20, 0x1A5E, 67),
+    (0x1A60, 0x1A7C, 67),
+    (0x1A7F, 0x1A89, 67),
+    (0x1A90, 0x1A99, 67),
+    (0x1AA0, 0x1AAD, 67),
+    (0x1AB0, 0x1ACE, 161),
+    (0x1B00, 0x1B4C, 7),
+    (0x1B50, 0x1B7E, 7),
+    (0x1B80, 0x1BBF, 132),
+    (0x1BC0, 0x1BF3, 10),
+    (0x1BFC, 0x1BFF, 10),
+    (0x1C00, 0x1C37, 70),
+    (0x1C3B, 0x1C49, 70),
+    (0x1C4D, 0x1C4F, 70),
+    (0x1C50, 0x1C7F, 101),
+    (0x1C80, 0x1C88, 27),
+    (0x1C90, 0x1CBA, 37),
+    (0x1CBD, 0x1CBF, 37),
+    (0x1CC0, 0x1CC7, 132),
+    (0x1CD0, 0x1CD2, 161),
+    (0x1CD3, 0x1CD3, 162),
+    (0x1CD4, 0x1CE0, 161),
+    (0x1CE1, 0x1CE1, 162),
+    (0x1CE2, 0x1CE8, 161),
+    (0x1CE9, 0x1CEC, 162),
+    (0x1CED, 0x1CED, 161),
+    (0x1CEE, 0x1CF3, 162),
+    (0x1CF4, 0x1CF4, 161),
+    (0x1CF5, 0x1CF7, 162),
+    (0x1CF8, 0x1CF9, 161),
+    (0x1CFA, 0x1CFA, 162),
+    (0x1D00, 0x1D25, 69),
+    (0x1D26, 0x1D2A, 43),
+    (0x1D2B, 0x1D2B, 27),
+    (0x1D2C, 0x1D5C, 69),
+    (0x1D5D, 0x1D61, 43),
+    (0x1D62, 0x1D65, 69),
+    (0x1D66, 0x1D6A, 43),
+    (0x1D6B, 0x1D77, 69),
+    (0x1D78, 0x1D78, 27),
+    (0x1D79, 0x1DBE, 69),
+    (0x1DBF, 0x1DBF, 43),
+    (0x1DC0, 0x1DFF, 161),
+    (0x1E00, 0x1EFF, 69),
+    (0x1F00, 0x1F15, 43),
+    (0x1F18, 0x1F1D, 43),
+    (0x1F20, 0x1F45, 43),
+    (0x1F48, 0x1F4D, 43),
+    (0x1F50, 0x1F57, 43),
+    (0x1F59, 0x1F59, 43),
+    (0x1F5B, 0x1F5B, 43),
+    (0x1F5D, 0x1F5D, 43),
+    (0x1F5F, 0x1F7D, 43),
+    (0x1F80, 0x1FB4, 43),
+    (0x1FB6, 0x1FC4, 43),
+    (0x1FC6, 0x1FD3, 43),
+    (0x1FD6, 0x1FDB, 43),
+    (0x1FDD, 0x1FEF, 43),
+    (0x1FF2, 0x1FF4, 43),
+    (0x1FF6, 0x1FFE, 43),
+    (0x2000, 0x200B, 162),
+    (0x200C, 0x200D, 161),
+    (0x200E, 0x2064, 162),
+    (0x2066, 0x2070, 162),
+    (0x2071, 0x2071, 69),
+    (0x2074, 0x207E, 162),
+    (0x207F, 0x207F, 69),
+    (0x2080, 0x208E, 162),
+    (0x2090, 0x209C, 69),
+    (0x20A0, 0x20C0, 162),
+    (0x20D0, 0x20F0, 161),
+    (0x2100, 0x2125, 162),
+    (0x2126, 0x2126, 43),
+    (0x2127, 0x2129, 162),
+    (0x212A, 0x212B, 69),
+    (0x212C, 0x2131, 162),
+    (0x2132, 0x2132, 69),
+    (0x2133, 0x214D, 162),
+    (0x214E, 0x214E, 69),
+    (0x214F, 0x215F, 162),
+    (0x2160, 0x2188, 69),
+    (0x2189, 0x218B, 162),
+    (0x2190, 0x2426, 162),
+    (0x2440, 0x244A, 162),
+    (0x2460, 0x27FF, 162),
+    (0x2800, 0x28FF, 15),
+    (0x2900, 0x2B73, 162),
+    (0x2B76, 0x2B95, 162),
+    (0x2B97, 0x2BFF, 162),
+    (0x2C00, 0x2C5F, 38),
+    (0x2C60, 0x2C7F, 69),
+    (0x2C80, 0x2CF3, 24),
+    (0x2CF9, 0x2CFF, 24),
+    (0x2D00, 0x2D25, 37),
+    (0x2D27, 0x2D27, 37),
+    (0x2D2D, 0x2D2D, 37),
+    (0x2D30, 0x2D67, 143),
+    (0x2D6F, 0x2D70, 143),
+    (0x2D7F, 0x2D7F, 143),
+    (0x2D80, 0x2D96, 36),
+    (0x2DA0, 0x2DA6, 36),
+    (0x2DA8, 0x2DAE, 36),
+    (0x2DB0, 0x2DB6, 36),
+    (0x2DB8, 0x2DBE, 36),
+    (0x2DC0, 0x2DC6, 36),
+    (0x2DC8, 0x2DCE, 36),
+    (0x2DD0, 0x2DD6, 36),
+    (0x2DD8, 0x2DDE, 36),
+    (0x2DE0, 0x2DFF, 27),
+    (0x2E00, 0x2E5D, 162),
+    (0x2E80, 0x2E99, 47),
+    (0x2E9B, 0x2EF3, 47),
+    (0x2F00, 0x2FD5, 47),
+    (0x2FF0, 0x2FFB, 162),
+    (0x3000, 0x3004, 162),
+    (0x3005, 0x3005, 47),
+    (0x3006, 0x3006, 162),
+    (0x3007, 0x3007, 47),
+    (0x3008, 0x3020, 162),
+    (0x3021, 0x3029, 47),
+    (0x302A, 0x302D, 161),
+    (0x302E, 0x302F, 46),
+    (0x3030, 0x3037, 162),
+    (0x3038, 0x303B, 47),
+    (0x303C, 0x303F, 162),
+    (0x3041, 0x3096, 51),
+    (0x3099, 0x309A, 161),
+    (0x309B, 0x309C, 162),
+    (0x309D, 0x309F, 51),
+    (0x30A0, 0x30A0, 162),
+    (0x30A1, 0x30FA, 59),
+    (0x30FB, 0x30FC, 162),
+    (0x30FD, 0x30FF, 59),
+    (0x3105, 0x312F, 13),
+    (0x3131, 0x318E, 46),
+    (0x3190, 0x319F, 162),
+    (0x31A0, 0x31BF, 13),
+    (0x31C0, 0x31E3, 162),
+    (0x31F0, 0x31FF, 59),
+    (0x3200, 0x321E, 46),
+    (0x3220, 0x325F, 162),
+    (0x3260, 0x327E, 46),
+    (0x327F, 0x32CF, 162),
+    (0x32D0, 0x32FE, 59),
+    (0x32FF, 0x32FF, 162),
+    (0x3300, 0x3357, 59),
+    (0x3358, 0x33FF, 162),
+    (0x3400, 0x4DBF, 47),
+    (0x4DC0, 0x4DFF, 162),
+    (0x4E00, 0x9FFF, 47),
+    (0xA000, 0xA48C, 159),
+    (0xA490, 0xA4C6, 159),
+    (0xA4D0, 0xA4FF, 74),
+    (0xA500, 0xA62B, 152),
+    (0xA640, 0xA69F, 27),
+    (0xA6A0, 0xA6F7, 8),
+    (0xA700, 0xA721, 162),
+    (0xA722, 0xA787, 69),
+    (0xA788, 0xA78A, 162),
+    (0xA78B, 0xA7CA, 69),
+    (0xA7D0, 0xA7D1, 69),
+    (0xA7D3, 0xA7D3, 69),
+    (0xA7D5, 0xA7D9, 69),
+    (0xA7F2, 0xA7FF, 69),
+    (0xA800, 0xA82C, 133),
+    (0xA830, 0xA839, 162),
+    (0xA840, 0xA877, 110),
+    (0xA880, 0xA8C5, 121),
+    (0xA8CE, 0xA8D9, 121),
+    (0xA8E0, 0xA8FF, 28),
+    (0xA900, 0xA92D, 58),
+    (0xA92E, 0xA92E, 162),
+    (0xA92F, 0xA92F, 58),
+    (0xA930, 0xA953, 116),
+    (0xA95F, 0xA95F, 116),
+    (0xA960, 0xA97C, 46),
+    (0xA980, 0xA9CD, 57),
+    (0xA9CF, 0xA9CF, 162),
+    (0xA9D0, 0xA9D9, 57),
+    (0xA9DE, 0xA9DF, 57),
+    (0xA9E0, 0xA9FE, 92),
+    (0xAA00, 0xAA36, 21),
+    (0xAA40, 0xAA4D, 21),
+    (0xAA50, 0xAA59, 21),
+    (0xAA5C, 0xAA5F, 21),
+    (0xAA60, 0xAA7F, 92),
+    (0xAA80, 0xAAC2, 141),
+    (0xAADB, 0xAADF, 141),
+    (0xAAE0, 0xAAF6, 90),
+    (0xAB01, 0xAB06, 36),
+    (0xAB09, 0xAB0E, 36),
+    (0xAB11, 0xAB16, 36),
+    (0xAB20, 0xAB26, 36),
+    (0xAB28, 0xAB2E, 36),
+    (0xAB30, 0xAB5A, 69),
+    (0xAB5B, 0xAB5B, 162),
+    (0xAB5C, 0xAB64, 69),
+    (0xAB65, 0xAB65, 43),
+    (0xAB66, 0xAB69, 69),
+    (0xAB6A, 0xAB6B, 162),
+    (0xAB70, 0xABBF, 22),
+    (0xABC0, 0xABED, 90),
+    (0xABF0, 0xABF9, 90),
+    (0xAC00, 0xD7A3, 46),
+    (0xD7B0, 0xD7C6, 46),
+    (0xD7CB, 0xD7FB, 46),
+    (0xE000, 0xF8FF, 163),
+    (0xF900, 0xFA6D, 47),
+    (0xFA70, 0xFAD9, 47),
+    (0xFB00, 0xFB06, 69),
+    (0xFB13, 0xFB17, 5),
+    (0xFB1D, 0xFB36, 50),
+    (0xFB38, 0xFB3C, 50),
+    (0xFB3E, 0xFB3E, 50),
+    (0xFB40, 0xFB41, 50),
+    (0xFB43, 0xFB44, 50),
+    (0xFB46, 0xFB4F, 50),
+    (0xFB50, 0xFBC2, 3),
+    (0xFBD3, 0xFD3D, 3),
+    (0xFD3E, 0xFD3F, 162),
+    (0xFD40, 0xFD8F, 3),
+    (0xFD92, 0xFDC7, 3),
+    (0xFDCF, 0xFDCF, 3),
+    (0xFDF0, 0xFDFF, 3),
+    (0xFE00, 0xFE0F, 161),
+    (0xFE10, 0xFE19, 162),
+    (0xFE20, 0xFE2D, 161),
+    (0xFE2E, 0xFE2F, 27),
+    (0xFE30, 0xFE52, 162),
+    (0xFE54, 0xFE66, 162),
+    (0xFE68, 0xFE6B, 162),
+    (0xFE70, 0xFE74, 3),
+    (0xFE76, 0xFEFC, 3),
+    (0xFEFF, 0xFEFF, 162),
+    (0xFF01, 0xFF20, 162),
+    (0xFF21, 0xFF3A, 69),
+    (0xFF3B, 0xFF40, 162),
+    (0xFF41, 0xFF5A, 69),
+    (0xFF5B, 0xFF65, 162),
+    (0xFF66, 0xFF6F, 59),
+    (0xFF70, 0xFF70, 162),
+    (0xFF71, 0xFF9D, 59),
+    (0xFF9E, 0xFF9F, 162),
+    (0xFFA0, 0xFFBE, 46),
+    (0xFFC2, 0xFFC7, 46),
+    (0xFFCA, 0xFFCF, 46),
+    (0xFFD2, 0xFFD7, 46),
+    (0xFFDA, 0xFFDC, 46),
+    (0xFFE0, 0xFFE6, 162),
+    (0xFFE8, 0xFFEE, 162),
+    (0xFFF9, 0xFFFD, 162),
+    (0x10000, 0x1000B, 73),
+    (0x1000D, 0x10026, 73),
+    (0x10028, 0x1003A, 73),
+    (0x1003C, 0x1003D, 73),
+    (0x1003F, 0x1004D, 73),
+    (0x10050, 0x1005D, 73),
+    (0x10080, 0x100FA, 73),
+    (0x10100, 0x10102, 162),
+    (0x10107, 0x10133, 162),
+    (0x10137, 0x1013F, 162),
+    (0x10140, 0x1018E, 43),
+    (0x10190, 0x1019C, 162),
+    (0x101A0, 0x101A0, 43),
+    (0x101D0, 0x101FC, 162),
+    (0x101FD, 0x101FD, 161),
+    (0x10280, 0x1029C, 75),
+    (0x102A0, 0x102D0, 20),
+    (0x102E0, 0x102E0, 161),
+    (0x102E1, 0x102FB, 162),
+    (0x10300, 0x10323, 56),
+    (0x1032D, 0x1032F, 56),
+    (0x10330, 0x1034A, 41),
+    (0x10350, 0x1037A, 109),
+    (0x10380, 0x1039D, 151),
+    (0x1039F, 0x1039F, 151),
+    (0x103A0, 0x103C3, 156),
+    (0x103C8, 0x103D5, 156),
+    (0x10400, 0x1044F, 31),
+    (0x10450, 0x1047F, 123),
+    (0x10480, 0x1049D, 105),
+    (0x104A0, 0x104A9, 105),
+    (0x104B0, 0x104D3, 104),
+    (0x104D8, 0x104FB, 104),
+    (0x10500, 0x10527, 34),
+    (0x10530, 0x10563, 1),
+    (0x1056F, 0x1056F, 1),
+    (0x10570, 0x1057A, 153),
+    (0x1057C, 0x1058A, 153),
+    (0x1058C, 0x10592, 153),
+    (0x10594, 0x10595, 153),
+    (0x10597, 0x105A1, 153),
+    (0x105A3, 0x105B1, 153),
+    (0x105B3, 0x105B9, 153),
+    (0x105BB, 0x105BC, 153),
+    (0x10600, 0x10736, 72),
+    (0x10740, 0x10755, 72),
+    (0x10760, 0x10767, 72),
+    (0x10780, 0x10785, 69),
+    (0x10787, 0x107B0, 69),
+    (0x107B2, 0x107BA, 69),
+    (0x10800, 0x10805, 26),
+    (0x10808, 0x10808, 26),
+    (0x1080A, 0x10835, 26),
+    (0x10837, 0x10838, 26),
+    (0x1083C, 0x1083C, 26),
+    (0x1083F, 0x1083F, 26),
+    (0x10840, 0x10855, 4),
+    (0x10857, 0x1085F, 4),
+    (0x10860, 0x1087F, 107),
+    (0x10880, 0x1089E, 96),
+    (0x108A7, 0x108AF, 96),
+    (0x108E0, 0x108F2, 49),
+    (0x108F4, 0x108F5, 49),
+    (0x108FB, 0x108FF, 49),
+    (0x10900, 0x1091B, 113),
+    (0x1091F, 0x1091F, 113),
+    (0x10920, 0x10939, 76),
+    (0x1093F, 0x1093F, 76),
+    (0x10980, 0x1099F, 85),
+    (0x109A0, 0x109B7, 84),
+    (0x109BC, 0x109CF, 84),
+    (0x109D2, 0x109FF, 84),
+    (0x10A00, 0x10A03, 61),
+    (0x10A05, 0x10A06, 61),
+    (0x10A0C, 0x10A13, 61),
+    (0x10A15, 0x10A17, 61),
+    (0x10A19, 0x10A35, 61),
+    (0x10A38, 0x10A3A, 61),
+    (0x10A3F, 0x10A48, 61),
+    (0x10A50, 0x10A58, 61),
+    (0x10A60, 0x10A7F, 120),
+    (0x10A80, 0x10A9F, 95),
+    (0x10AC0, 0x10AE6, 80),
+    (0x10AEB, 0x10AF6, 80),
+    (0x10B00, 0x10B35, 6),
+    (0x10B39, 0x10B3F, 6),
+    (0x10B40, 0x10B55, 115),
+    (0x10B58, 0x10B5F, 115),
+    (0x10B60, 0x10B72, 111),
+    (0x10B78, 0x10B7F, 111),
+    (0x10B80, 0x10B91, 112),
+    (0x10B99, 0x10B9C, 112),
+    (0x10BA9, 0x10BAF, 112),
+    (0x10C00, 0x10C48, 102),
+    (0x10C80, 0x10CB2, 55),
+    (0x10CC0, 0x10CF2, 55),
+    (0x10CFA, 0x10CFF, 55),
+    (0x10D00, 0x10D27, 117),
+    (0x10D30, 0x10D39, 117),
+    (0x10E60, 0x10E7E, 3),
+    (0x10E80, 0x10EA9, 158),
+    (0x10EAB, 0x10EAD, 158),
+    (0x10EB0, 0x10EB1, 158),
+    (0x10EFD, 0x10EFF, 3),
+    (0x10F00, 0x10F27, 129),
+    (0x10F30, 0x10F59, 128),
+    (0x10F70, 0x10F89, 106),
+    (0x10FB0, 0x10FCB, 23),
+    (0x10FE0, 0x10FF6, 35),
+    (0x11000, 0x1104D, 14),
+    (0x11052, 0x11075, 14),
+    (0x1107F, 0x1107F, 14),
+    (0x11080, 0x110C2, 66),
+    (0x110CD, 0x110CD, 66),
+    (0x110D0, 0x110E8, 130),
+    (0x110F0, 0x110F9, 130),
+    (0x11100, 0x11134, 18),
+    (0x11136, 0x11147, 18),
+    (0x11150, 0x11176, 77),
+    (0x11180, 0x111DF, 124),
+    (0x111E1, 0x111F4, 127),
+    (0x11200, 0x11211, 63),
+    (0x11213, 0x11241, 63),
+    (0x11280, 0x11286, 91),
+    (0x11288, 0x11288, 91),
+    (0x1128A, 0x1128D, 91),
+    (0x1128F, 0x1129D, 91),
+    (0x1129F, 0x112A9, 91),
+    (0x112B0, 0x112EA, 126),
+    (0x112F0, 0x112F9, 126),
+    (0x11300, 0x11303, 42),
+    (0x11305, 0x1130C, 42),
+    (0x1130F, 0x11310, 42),
+    (0x11313, 0x11328, 42),
+    (0x1132A, 0x11330, 42),
+    (0x11332, 0x11333, 42),
+    (0x11335, 0x11339, 42),
+    (0x1133B, 0x1133B, 161),
+    (0x1133C, 0x11344, 42),
+    (0x11347, 0x11348, 42),
+    (0x1134B, 0x1134D, 42),
+    (0x11350, 0x11350, 42),
+    (0x11357, 0x11357, 42),
+    (0x1135D, 0x11363, 42),
+    (0x11366, 0x1136C, 42),
+    (0x11370, 0x11374, 42),
+    (0x11400, 0x1145B, 97),
+    (0x1145D, 0x11461, 97),
+    (0x11480, 0x114C7, 148),
+    (0x114D0, 0x114D9, 148),
+    (0x11580, 0x115B5, 125),
+    (0x115B8, 0x115DD, 125),
+    (0x11600, 0x11644, 87),
+    (0x11650, 0x11659, 87),
+    (0x11660, 0x1166C, 88),
+    (0x11680, 0x116B9, 136),
+    (0x116C0, 0x116C9, 136),
+    (0x11700, 0x1171A, 2),
+    (0x1171D, 0x1172B, 2),
+    (0x11730, 0x11746, 2),
+    (0x11800, 0x1183B, 30),
+    (0x118A0, 0x118F2, 154),
+    (0x118FF, 0x118FF, 154),
+    (0x11900, 0x11906, 29),
+    (0x11909, 0x11909, 29),
+    (0x1190C, 0x11913, 29),
+    (0x11915, 0x11916, 29),
+    (0x11918, 0x11935, 29),
+    (0x11937, 0x11938, 29),
+    (0x1193B, 0x11946, 29),
+    (0x11950, 0x11959, 29),
+    (0x119A0, 0x119A7, 94),
+    (0x119AA, 0x119D7, 94),
+    (0x119DA, 0x119E4, 94),
+    (0x11A00, 0x11A47, 160),
+    (0x11A50, 0x11AA2, 131),
+    (0x11AB0, 0x11ABF, 19),
+    (0x11AC0, 0x11AF8, 108),
+    (0x11B00, 0x11B09, 28),
+    (0x11C00, 0x11C08, 12),
+    (0x11C0A, 0x11C36, 12),
+    (0x11C38, 0x11C45, 12),
+    (0x11C50, 0x11C6C, 12),
+    (0x11C70, 0x11C8F, 81),
+    (0x11C92, 0x11CA7, 81),
+    (0x11CA9, 0x11CB6, 81),
+    (0x11D00, 0x11D06, 40),
+    (0x11D08, 0x11D09, 40),
+    (0x11D0B, 0x11D36, 40),
+    (0x11D3A, 0x11D3A, 40),
+    (0x11D3C, 0x11D3D, 40),
+    (0x11D3F, 0x11D47, 40),
+    (0x11D50, 0x11D59, 40),
+    (0x11D60, 0x11D65, 39),
+    (0x11D67, 0x11D68, 39),
+    (0x11D6A, 0x11D8E, 39),
+    (0x11D90, 0x11D91, 39),
+    (0x11D93, 0x11D98, 39),
+    (0x11DA0, 0x11DA9, 39),
+    (0x11EE0, 0x11EF8, 78),
+    (0x11F00, 0x11F10, 60),
+    (0x11F12, 0x11F3A, 60),
+    (0x11F3E, 0x11F59, 60),
+    (0x11FB0, 0x11FB0, 74),
+    (0x11FC0, 0x11FF1, 139),
+    (0x11FFF, 0x11FFF, 139),
+    (0x12000, 0x12399, 157),
+    (0x12400, 0x1246E, 157),
+    (0x12470, 0x12474, 157),
+    (0x12480, 0x12543, 157),
+    (0x12F90, 0x12FF2, 25),
+    (0x13000, 0x13455, 33),
+    (0x14400, 0x14646, 52),
+    (0x16800, 0x16A38, 8),
+    (0x16A40, 0x16A5E, 89),
+    (0x16A60, 0x16A69, 89),
+    (0x16A6E, 0x16A6F, 89),
+    (0x16A70, 0x16ABE, 149),
+    (0x16AC0, 0x16AC9, 149),
+    (0x16AD0, 0x16AED, 9),
+    (0x16AF0, 0x16AF5, 9),
+    (0x16B00, 0x16B45, 53),
+    (0x16B50, 0x16B59, 53),
+    (0x16B5B, 0x16B61, 53),
+    (0x16B63, 0x16B77, 53),
+    (0x16B7D, 0x16B8F, 53),
+    (0x16E40, 0x16E9A, 82),
+    (0x16F00, 0x16F4A, 114),
+    (0x16F4F, 0x16F87, 114),
+    (0x16F8F, 0x16F9F, 114),
+    (0x16FE0, 0x16FE0, 140),
+    (0x16FE1, 0x16FE1, 99),
+    (0x16FE2, 0x16FE3, 47),
+    (0x16FE4, 0x16FE4, 64),
+    (0x16FF0, 0x16FF1, 47),
+    (0x17000, 0x187F7, 140),
+    (0x18800, 0x18AFF, 140),
+    (0x18B00, 0x18CD5, 64),
+    (0x18D00, 0x18D08, 140),
+    (0x1AFF0, 0x1AFF3, 59),
+    (0x1AFF5, 0x1AFFB, 59),
+    (0x1AFFD, 0x1AFFE, 59),
+    (0x1B000, 0x1B000, 59),
+    (0x1B001, 0x1B11F, 51),
+    (0x1B120, 0x1B122, 59),
+    (0x1B132, 0x1B132, 51),
+    (0x1B150, 0x1B152, 51),
+    (0x1B155, 0x1B155, 59),
+    (0x1B164, 0x1B167, 59),
+    (0x1B170, 0x1B2FB, 99),
+    (0x1BC00, 0x1BC6A, 32),
+    (0x1BC70, 0x1BC7C, 32),
+    (0x1BC80, 0x1BC88, 32),
+    (0x1BC90, 0x1BC99, 32),
+    (0x1BC9C, 0x1BC9F, 32),
+    (0x1BCA0, 0x1BCA3, 162),
+    (0x1CF00, 0x1CF2D, 161),
+    (0x1CF30, 0x1CF46, 161),
+    (0x1CF50, 0x1CFC3, 162),
+    (0x1D000, 0x1D0F5, 162),
+    (0x1D100, 0x1D126, 162),
+    (0x1D129, 0x1D166, 162),
+    (0x1D167, 0x1D169, 161),
+    (0x1D16A, 0x1D17A, 162),
+    (0x1D17B, 0x1D182, 161),
+    (0x1D183, 0x1D184, 162),
+    (0x1D185, 0x1D18B, 161),
+    (0x1D18C, 0x1D1A9, 162),
+    (0x1D1AA, 0x1D1AD, 161),
+    (0x1D1AE, 0x1D1EA, 162),
+    (0x1D200, 0x1D245, 43),
+    (0x1D2C0, 0x1D2D3, 162),
+    (0x1D2E0, 0x1D2F3, 162),
+    (0x1D300, 0x1D356, 162),
+    (0x1D360, 0x1D378, 162),
+    (0x1D400, 0x1D454, 162),
+    (0x1D456, 0x1D49C, 162),
+    (0x1D49E, 0x1D49F, 162),
+    (0x1D4A2, 0x1D4A2, 162),
+    (0x1D4A5, 0x1D4A6, 162),
+    (0x1D4A9, 0x1D4AC, 162),
+    (0x1D4AE, 0x1D4B9, 162),
+    (0x1D4BB, 0x1D4BB, 162),
+    (0x1D4BD, 0x1D4C3, 162),
+    (0x1D4C5, 0x1D505, 162),
+    (0x1D507, 0x1D50A, 162),
+    (0x1D50D, 0x1D514, 162),
+    (0x1D516, 0x1D51C, 162),
+    (0x1D51E, 0x1D539, 162),
+    (0x1D53B, 0x1D53E, 162),
+    (0x1D540, 0x1D544, 162),
+    (0x1D546, 0x1D546, 162),
+    (0x1D54A, 0x1D550, 162),
+    (0x1D552, 0x1D6A5, 162),
+    (0x1D6A8, 0x1D7CB, 162),
+    (0x1D7CE, 0x1D7FF, 162),
+    (0x1D800, 0x1DA8B, 122),
+    (0x1DA9B, 0x1DA9F, 122),
+    (0x1DAA1, 0x1DAAF, 122),
+    (0x1DF00, 0x1DF1E, 69),
+    (0x1DF25, 0x1DF2A, 69),
+    (0x1E000, 0x1E006, 38),
+    (0x1E008, 0x1E018, 38),
+    (0x1E01B, 0x1E021, 38),
+    (0x1E023, 0x1E024, 38),
+    (0x1E026, 0x1E02A, 38),
+    (0x1E030, 0x1E06D, 27),
+    (0x1E08F, 0x1E08F, 27),
+    (0x1E100, 0x1E12C, 54),
+    (0x1E130, 0x1E13D, 54),
+    (0x1E140, 0x1E149, 54),
+    (0x1E14E, 0x1E14F, 54),
+    (0x1E290, 0x1E2AE, 150),
+    (0x1E2C0, 0x1E2F9, 155),
+    (0x1E2FF, 0x1E2FF, 155),
+    (0x1E4D0, 0x1E4F9, 93),
+    (0x1E7E0, 0x1E7E6, 36),
+    (0x1E7E8, 0x1E7EB, 36),
+    (0x1E7ED, 0x1E7EE, 36),
+    (0x1E7F0, 0x1E7FE, 36),
+    (0x1E800, 0x1E8C4, 83),
+    (0x1E8C7, 0x1E8D6, 83),
+    (0x1E900, 0x1E94B, 0),
+    (0x1E950, 0x1E959, 0),
+    (0x1E95E, 0x1E95F, 0),
+    (0x1EC71, 0x1ECB4, 162),
+    (0x1ED01, 0x1ED3D, 162),
+    (0x1EE00, 0x1EE03, 3),
+    (0x1EE05, 0x1EE1F, 3),
+    (0x1EE21, 0x1EE22, 3),
+    (0x1EE24, 0x1EE24, 3),
+    (0x1EE27, 0x1EE27, 3),
+    (0x1EE29, 0x1EE32, 3),
+    (0x1EE34, 0x1EE37, 3),
+    (0x1EE39, 0x1EE39, 3),
+    (0x1EE3B, 0x1EE3B, 3),
+    (0x1EE42, 0x1EE42, 3),
+    (0x1EE47, 0x1EE47, 3),
+    (0x1EE49, 0x1EE49, 3),
+    (0x1EE4B, 0x1EE4B, 3),
+    (0x1EE4D, 0x1EE4F, 3),
+    (0x1EE51, 0x1EE52, 3),
+    (0x1EE54, 0x1EE54, 3),
+    (0x1EE57, 0x1EE57, 3),
+    (0x1EE59, 0x1EE59, 3),
+    (0x1EE5B, 0x1EE5B, 3),
+    (0x1EE5D, 0x1EE5D, 3),
+    (0x1EE5F, 0x1EE5F, 3),
+    (0x1EE61, 0x1EE62, 3),
+    (0x1EE64, 0x1EE64, 3),
+    (0x1EE67, 0x1EE6A, 3),
+    (0x1EE6C, 0x1EE72, 3),
+    (0x1EE74, 0x1EE77, 3),
+    (0x1EE79, 0x1EE7C, 3),
+    (0x1EE7E, 0x1EE7E, 3),
+    (0x1EE80, 0x1EE89, 3),
+    (0x1EE8B, 0x1EE9B, 3),
+    (0x1EEA1, 0x1EEA3, 3),
+    (0x1EEA5, 0x1EEA9, 3),
+    (0x1EEAB, 0x1EEBB, 3),
+    (0x1EEF0, 0x1EEF1, 3),
+    (0x1F000, 0x1F02B, 162),
+    (0x1F030, 0x1F093, 162),
+    (0x1F0A0, 0x1F0AE, 162),
+    (0x1F0B1, 0x1F0BF, 162),
+    (0x1F0C1, 0x1F0CF, 162),
+    (0x1F0D1, 0x1F0F5, 162),
+    (0x1F100, 0x1F1AD, 162),
+    (0x1F1E6, 0x1F1FF, 162),
+    (0x1F200, 0x1F200, 51),
+    (0x1F201, 0x1F202, 162),
+    (0x1F210, 0x1F23B, 162),
+    (0x1F240, 0x1F248, 162),
+    (0x1F250, 0x1F251, 162),
+    (0x1F260, 0x1F265, 162),
+    (0x1F300, 0x1F6D7, 162),
+    (0x1F6DC, 0x1F6EC, 162),
+    (0x1F6F0, 0x1F6FC, 162),
+    (0x1F700, 0x1F776, 162),
+    (0x1F77B, 0x1F7D9, 162),
+    (0x1F7E0, 0x1F7EB, 162),
+    (0x1F7F0, 0x1F7F0, 162),
+    (0x1F800, 0x1F80B, 162),
+    (0x1F810, 0x1F847, 162),
+    (0x1F850, 0x1F859, 162),
+    (0x1F860, 0x1F887, 162),
+    (0x1F890, 0x1F8AD, 162),
+    (0x1F8B0, 0x1F8B1, 162),
+    (0x1F900, 0x1FA53, 162),
+    (0x1FA60, 0x1FA6D, 162),
+    (0x1FA70, 0x1FA7C, 162),
+    (0x1FA80, 0x1FA88, 162),
+    (0x1FA90, 0x1FABD, 162),
+    (0x1FABF, 0x1FAC5, 162),
+    (0x1FACE, 0x1FADB, 162),
+    (0x1FAE0, 0x1FAE8, 162),
+    (0x1FAF0, 0x1FAF8, 162),
+    (0x1FB00, 0x1FB92, 162),
+    (0x1FB94, 0x1FBCA, 162),
+    (0x1FBF0, 0x1FBF9, 162),
+    (0x20000, 0x2A6DF, 47),
+    (0x2A700, 0x2B739, 47),
+    (0x2B740, 0x2B81D, 47),
+    (0x2B820, 0x2CEA1, 47),
+    (0x2CEB0, 0x2EBE0, 47),
+    (0x2F800, 0x2FA1D, 47),
+    (0x30000, 0x3134A, 47),
+    (0x31350, 0x323AF, 47),
+    (0xE0001, 0xE0001, 162),
+    (0xE0020, 0xE007F, 162),
+    (0xE0100, 0xE01EF, 161),
+    (0xF0000, 0xFFFFD, 163),
+    (0x100000, 0x10FFFD, 163),
+];
+
+/// Main exemplar characters for each language along with their uppercase
+/// forms, as (BCP 47 tag, number of characters, ranges). Ranges are encoded
+/// as consecutive pairs of first and last characters.
+pub const LANGUAGES: &[(&str, u32, &str)] = &[
+    ("af", 74, "AZazÁÂÈËÎÏÔÔÖÖÛÛáâèëîïôôööûû"),
+    ("agq", 95, "AIKPSWYZaikpswyzÀÀÂÂÈÈÊÊÌÌÎÎÒÒÔÔÙÙÛÛààââèèêêììîîòòôôùùûûĀāĒēĚěĪīŊōŪūƆƆƐƐƗƗǍǔɄɄɔɔɛɛɨɨʉʉʔʔ"),
+    ("ak", 44, "ABDIKPRUWWYYabdikpruwwyyƆƆƐƐɔɔɛɛ"),
+    ("am", 282, "ሀሆለቆቈቈቊቍበኆኈኈኊኍነኮኰኰኲኵኸኾወዎዐዖዘዮደዷጀጎጐጐጒጕጠፆፈፗ"),
+    ("ar", 45, "ءغفْٰٰ"),
+    ("as", 59, "ঁঃঅঋএঐওনপযললশহ়়াৃেৈো্ৰৱ"),
+    ("asa", 48, "APRWYZaprwyz"),
+    ("ast", 64, "AILVXZailvxzÁÁÉÉÍÍÑÑÓÓÚÚÜÜááééííññóóúúüüḤḥḶḷ"),
+    ("az", 64, "AVXZavxzÇÇÖÖÜÜççööüüĞğİıŞşƏƏəə"),
+    ("az-Cyrl", 66, "ЈЈАХЧШЫЫахчшыыјјҒғҜҝҮүҸһӘәӨө"),
+    ("bas", 110, "APRWYZaprwyzÀÂÈÊÌÎÒÔÙÛàâèêìîòôùûĀāĒēĚěĪīŃńŊōŪūƁƁƆƆƐƐǍǔǸǹɓɔɛɛ"),
+    ("be", 64, "ЁЁІІЎЎАЗЙШЫзйшыяёёііўў"),
+    ("bem", 38, "ACEGIPSUWWYYacegipsuwwyy"),
+    ("bez", 50, "AWYZawyz"),
+    ("bg", 60, "АЪЬЬЮъььюя"),
+    ("bgc", 58, "ँँःःअऋएऐओनपरललवहाृॅॅेैो्"),
+    ("bho", 48, "ँःकघचनपरललवह़़ाृॅॅेैो्"),
+    ("bm", 54, "APRUWWYZapruwwyzŊŋƆƆƐƐƝƝɔɔɛɛɲɲ"),
+    ("bn", 68, "ঁঃঅঌএঐওনপরললশহ়ৄেৈোৎৗৗৠৣ৺৺"),
+    ("bo", 86, "ཀགངཇཉཌཎདནབམཛཝཨཪཪིིུུཷཷཹ྄྄ྀྐྒྔྗྙྜྞྡྣྦྨྫྭྸྺྼ"),
+    ("br", 54, "ABDPRZabdprzÊÊÑÑÙÙêêññùù"),
+    ("brx", 61, "ँंअऊऍऍएऑओघचनपरलळवह़़ाृॅॅेॉो्"),
+    ("bs", 54, "APRVZZaprvzzĆćČčĐđŠšŽž"),
+    ("bs-Cyrl", 60, "ЂЂЈЋЏИКШаикшђђјћџџ"),
+    ("ca", 73, "AZaz··ÀÀÇÉÍÍÏÏÒÓÚÚÜÜààçéííïïòóúúüü"),
+    ("ccp", 53, "𑄴𑄀"),
+    ("ce", 66, "ЁЁАяёё"),
+    ("ceb", 38, "ABDEGIKPRUWWYYabdegikpruwwyy"),
+    ("cgg", 52, "AZaz"),
+    ("chr", 170, "ᎠᏴᏸᏼꭰꮿ"),
+    ("ckb", 33, "ئبتتجدرشعغفقلنووپپچچڕڕژژڤڤککگگڵڵھھۆۆییێێەە"),
+    ("cs", 82, "AZazÁÁÉÉÍÍÓÓÚÚÝÝááééííóóúúýýČďĚěŇňŘřŠšŤťŮůŽž"),
+    ("cv", 74, "ЁЁАяёёҪҫӐӑӖӗӲӳ"),
+    ("cy", 98, "AJLPRUWWYYajlpruwwyyÀÂÄÄÈÏÒÔÖÖÙÝàâääèïòôööùýÿÿŴŸẀẅỲỳ"),
+    ("da", 58, "AZazÅÆØØåæøø"),
+    ("de", 59, "AZazÄÄÖÖÜÜßßääööüü"),
+    ("dje", 64, "AUWZauwzÃÃÕÕããõõŊŋŠšŽžƝƝɲɲẼẽ"),
+    ("doi", 70, "ँःअऌएऐओनपरलळवह़ॄेैो्ॐ॒ॠॣ"),
+    ("dsb", 74, "AZazÓÓóóĆćČčĚěŁńŔŕŚśŠšŹźŽž"),
+    ("dua", 64, "AGIPRUWWYYagipruwwyyÁÁÉÉÍÍÓÓÚÚááééííóóúúŊŋŪūƁƁƆƆƊƊƐƐɓɔɗɗɛɛ"),
+    ("dyo", 64, "AYayÁÁÉÉÍÍÑÑÓÓÚÚááééííññóóúúŊŋ"),
+    ("dz", 58, "ཀགངཇཉཉཏདནབམཛཝཤསཨིིེེོོུུྐྒྔྔྗྗྙྙྟྡྣྦྨྫྭྭྱླྵྷ"),
+    ("ebu", 56, "AZazĨĩŨũ"),
+    ("ee", 90, "ABDIKPRZabdikprzÀÁÃÃÈÉÌÍÒÓÕÕÙÚàáããèéìíòóõõùúĨĩŊŋŨũƆƆƉƉƐƒƔƔƲƲɔɔɖɖɛɛɣɣʋʋẼẽ"),
+    ("el", 69, "ΆΆΈΊΌΌΎΡΣώ"),
+    ("eo", 56, "APRVZZaprvzzĈĉĜĝĤĥĴĵŜŝŬŭ"),
+    ("es", 66, "AZazÁÁÉÉÍÍÑÑÓÓÚÚÜÜááééííññóóúúüü"),
+    ("et", 64, "AZazÄÄÕÖÜÜääõöüüŠšŽž"),
+    ("eu", 56, "AZazÇÇÑÑççññ"),
+    ("ewo", 96, "ABDIKPRWYZabdikprwyzÀÂÈÊÌÎÒÔÙÛàâèêìîòôùûĚěŃńŊŋƆƆƏƐǍǔǸǹɔɔəəɛɛ"),
+    ("fa", 43, "ءؤئغفقلؤًٍّّٔپپچچژژککگگیی"),
+    ("ff", 54, "APRUWWYYapruwwyyÑÑññŊŋƁƁƊƊƳƴɓɓɗɗ"),
+    ("ff-Adlm", 60, "𞤀𞤛𞤢𞤽𞥄𞥆𞥋𞥋"),
+    ("fi", 62, "AZazÄÅÖÖäåööŠšŽž"),
+    ("fil", 54, "AZazÑÑññ"),
+    ("fo", 58, "ABDPRVYYabdprvyyÁÁÆÆÍÍÐÐÓÓØØÚÚÝÝááææííððóóøøúúýý"),
+    ("fr", 84, "AZazÀÀÂÂÆËÎÏÔÔÙÙÛÜààââæëîïôôùùûüÿÿŒœŸŸ"),
+    ("fur", 74, "AZazÀÀÂÂÇÈÊÊÌÌÎÎÒÒÔÔÙÙÛÛààââçèêêììîîòòôôùùûû"),
+    ("fy", 82, "APRWYZaprwyzÀÂÄÄÈËÍÍÏÏÓÔÖÖÚÝàâääèëííïïóôööúý"),
+    ("ga", 46, "AILPRUailpruÁÁÉÉÍÍÓÓÚÚááééííóóúú"),
+    ("gd", 46, "AILPRUailpruÀÀÈÈÌÌÒÒÙÙààèèììòòùù"),
+    ("gl", 68, "AZazÁÁÉÉÍÍÏÏÑÑÓÓÚÚÜÜááééííïïññóóúúüü"),
+    ("gsw", 58, "AZazÄÄÖÖÜÜääööüü"),
+    ("gu", 68, "ઁઃઅઋઍઍએઑઓનપરલળવહ઼ૅેૉો્ૐૐૠૠ"),
+    ("gv", 54, "AZazÇÇçç"),
+    ("ha", 53, "AORUWWYZaoruwwyzƁƁƊƊƘƙƳƴɓɓɗɗʼʼ"),
+    ("haw", 35, "AAEEHIKPUUWWaaeehikpuuwwĀāĒēĪīŌōŪūʻʻ"),
+    ("he", 27, "את"),
+    ("hi", 67, "ँःअऍएऑओनपरलळवह़ृॅॅेॉो्ॐॐ"),
+    ("hsb", 70, "AZazÓÓóóĆćČčĚěŁńŘřŠšŽž"),
+    ("hu", 62, "APRVZZaprvzzÁÁÉÉÍÍÓÓÖÖÚÚÜÜááééííóóööúúüüŐőŰű"),
+    ("hy", 76, "ԱՖաֆ"),
+    ("ig", 56, "ABDPRWYZabdprwyzṄṅẸẹỊọỤụ"),
+    ("ii", 1165, "ꀀꒌ"),
+    ("is", 64, "ABDPRVXYabdprvxyÁÁÆÆÉÉÍÍÐÐÓÓÖÖÚÚÝÞááææééííððóóööúúýþ"),
+    ("it", 66, "AZazÀÀÈÉÌÌÒÓÙÙààèéììòóùù"),
+    ("ja", 2311, "々々ぁんゝゞァヶーヾ一丁七七万下不与且且世世丘丙両両並並中中串串丸丹主丼久久乏乏乗乗乙乙九乞乱乱乳乳乾乾亀亀了了予争事二互互五井亜亜亡亡交交享亭人人仁仁今介仏仏仕他付仙代以仮仮仰仰仲仲件件任任企企伎休会会伝伝伯伯伴伴伸伸伺伺似似但但位佐体体何何余余作作佳佳併併使使例例侍侍供供依依価価侮侯侵侶便便係促俊俊俗俗保保信信修修俳俳俵俵俸俸俺俺倉倉個個倍倍倒倒候候借借倣値倫倫倹倹偉偉偏偏停停健健側偶偽偽傍傍傑傑傘備催催傲傲債債傷傷傾傾僅僅働働像像僕僕僚僚僧僧儀儀億億儒儒償償優優元兆先光克克免免児児党党入入全全八六共共兵兵具典兼兼内円冊冊再再冒冒冗冗写写冠冠冥冥冬冬冶冷凄凄准准凍凍凝凝凡凡処処凶凶凸出刀刀刃刃分刈刊刊刑刑列列初初判別利利到到制刻則則削削前前剖剖剛剛剣剥副剰割割創創劇劇力力功加劣劣助努励励労労効効劾劾勃勃勅勅勇勇勉勉動動勘務勝勝募募勢勢勤勤勧勧勲勲勾勾匂匂包包化北匠匠匹医匿匿十十千千升午半半卑協南単博博占占印危即卵卸卸厄厄厘厘厚厚原原厳厳去去参参又又及収叔叔取受叙叙口句叫召可右号司各各合吉同向君君吟吟否否含含吸吹呂呂呈告周周呪呪味味呼命和和咲咲咽咽哀品員員哲哲哺哺唄唄唆唇唐唐唯唯唱唱唾唾商商問問啓啓善善喉喉喚喚喜喝喩喫営営嗅嗅嗣嗣嘆嘆嘱嘲器器噴噴嚇嚇囚四回回因因団団困困囲図固固国国圏圏園園土土圧在地地坂坂均均坊坊坑坑坪坪垂垂型型垣垣埋埋城城域域執執培基埼埼堀堀堂堂堅堆堕堕堤堤堪堪報報場場塀塁塊塊塑塑塔塔塗塗塚塚塞塞塩塩填填塾塾境境墓墓増増墜墜墨墨墳墳墾墾壁壁壇壇壊壊壌壌士士壮壮声売変変夏夏夕外多多夜夜夢夢大大天夫央央失失奇奉奏奏契契奔奔奥奥奨奨奪奪奮奮女奴好好如妄妊妊妖妖妙妙妥妥妨妨妬妬妹妹妻妻姉姉始始姓委姫姫姻姻姿姿威威娘娘娠娠娯娯婆婆婚婚婦婦婿婿媒媒媛媛嫁嫁嫉嫉嫌嫌嫡嫡嬢嬢子子孔孔字存孝孝季孤学学孫孫宅宅宇安完完宗宝実実客室宮宮宰宰害家容容宿宿寂寂寄寄密密富富寒寒寛寛寝寝察察寡寡寧寧審審寮寮寸寸寺寺対寿封専射射将将尉尋導小少少尚尚就就尺局居居屈屈届屋展展属属層履屯屯山山岐岐岡岡岩岩岬岬岳岳岸岸峠峡峰峰島島崇崇崎崎崖崖崩崩嵐嵐川州巡巡巣巣工巨差差己己巻巻巾巾市布帆帆希希帝帝帥帥師師席席帯帰帳帳常常帽帽幅幅幕幕幣幣干年幸幹幻幾庁庁広広床床序序底底店店府府度座庫庫庭庭庶庸廃廃廉廊延廷建建弁弁弄弄弊弊式弐弓引弟弟弥弧弱弱張張強強弾弾当当彙彙形形彩彩彫彫彰影役役彼彼往征径待律後徐徐徒従得得御御復循微微徳徴徹徹心心必必忌忍志忙応応忠忠快快念念怒怒怖怖思思怠怠急急性怨怪怪恋恋恐恐恒恒恣恣恥恥恨恩恭恭息息恵恵悔悔悟悠患患悦悦悩悪悲悲悼悼情情惑惑惜惜惧惨惰惰想想愁愁愉愉意意愚愛感感慄慄慈慈態慌慎慎慕慕慢慣慨慨慮慮慰慰慶慶憂憂憎憎憤憤憧憧憩憩憬憬憲憲憶憶憾憾懇懇懐懐懲懲懸懸成戒戚戚戦戦戯戯戴戴戸戸戻戻房所扇扇扉扉手手才才打打払払扱扱扶扶批批承技抄抄把把抑抑投投抗折抜抜択択披披抱抱抵抵抹抹押抽担担拉拉拍拍拐拐拒拓拘拙招招拝拝拠拡括拭拳拳拶拷拾拾持持指指挑挑挙挙挟挟挨挨挫挫振振挿挿捉捉捕捕捗捗捜捜捨捨据据捻捻掃掃授授掌掌排排掘掘掛掛採探接接控推措措掲掲描提揚換握握揮揮援援揺揺損損搬搭携携搾搾摂摂摘摘摩摩摯摯撃撃撤撤撮撮撲撲擁擁操操擦擦擬擬支支改改攻攻放政故故敏敏救救敗敗教教敢散敬敬数数整敵敷敷文文斉斉斎斎斑斑斗斗料料斜斜斤斥斬断新新方方施施旅旅旋旋族族旗旗既既日早旬旬旺旺昆昇明明易昔星映春春昧昨昭昭是是昼昼時時晩晩普景晴晴晶晶暁暁暇暇暑暑暖暗暦暦暫暫暮暮暴暴曇曇曖曖曜曜曲曲更更書曹曽曽替最月有服服朕朕朗朗望望朝朝期期木木未札朱朱朴朴机机朽朽杉杉材村束束条条来来杯杯東東松板析析枕枕林林枚枚果枝枠枠枢枢枯枯架架柄柄某某染柔柱柱柳柳柵柵査査柿柿栃栄栓栓校校株株核根格栽桁桁桃桃案案桑桑桜桜桟桟梅梅梗梗梨梨械械棄棄棋棋棒棒棚棚棟棟森森棺棺椅椅植椎検検業業極極楷楷楼楽概概構構様様槽槽標標模模権横樹樹橋橋機機欄欄欠次欧欧欲欲欺欺款款歌歌歓歓止正武武歩歩歯歯歳歴死死殉残殖殖殴段殺殻殿毀母毎毒毒比比毛毛氏氏民民気気水水氷永氾氾汁求汎汎汗汗汚汚江池汰汰決決汽汽沃沃沈沈沖沖沙沙没沢河河沸油治沼沿沿況況泉泊泌泌法法泡泣泥泥注注泰泰泳泳洋洋洗洗洞洞津津洪洪活活派派流流浄浅浜浜浦浦浪浪浮浮浴浴海浸消消涙涙涯涯液液涼涼淑淑淡淡淫淫深深混混添添清清渇渉渋渋渓渓減減渡渡渦渦温温測測港港湖湖湧湧湯湯湾満源源準準溝溝溶溶溺溺滅滅滋滋滑滑滝滞滴滴漁漂漆漆漏漏演演漠漠漢漢漫漬漸漸潔潔潜潜潟潟潤潤潮潮潰潰澄澄激濁濃濃濫濫濯濯瀬瀬火火灯灰災災炉炊炎炎炭炭点為烈烈無無焦焦然然焼焼煎煎煙煙照照煩煩煮煮熊熊熟熟熱熱燃燃燥燥爆爆爪爪爵父爽爽片版牙牙牛牛牧牧物物牲牲特特犠犠犬犬犯犯状状狂狂狙狙狩狩独狭猛猛猟猟猫猫献献猶猶猿猿獄獄獣獣獲獲玄玄率率玉玉王王玩玩珍珍珠珠班班現現球球理理琴琴瑠瑠璃璃璧璧環環璽璽瓦瓦瓶瓶甘甘甚甚生生産産用用田申男男町画界界畏畏畑畑畔畔留留畜畝略略番番異異畳畳畿畿疎疎疑疑疫疫疲疲疾疾病病症症痕痕痘痘痛痛痢痢痩痩痴痴瘍瘍療療癒癒癖癖発登白百的的皆皇皮皮皿皿盆盆益益盗盗盛盛盟盟監盤目目盲盲直直相相盾盾省省眉眉看県真眠眺眺眼眼着着睡睡督督睦睦瞬瞭瞳瞳矛矛矢矢知知短短矯矯石石砂砂研砕砲砲破破硝硝硫硬碁碁碑碑確確磁磁磨磨礁礁礎礎示示礼礼社社祈祉祖祖祝神祥祥票票祭祭禁禁禅禅禍禍福福秀私秋秋科秒秘秘租租秩秩称称移移程程税税稚稚種種稲稲稼稽稿穀穂穂積積穏穏穫穫穴穴究究空空突突窃窃窒窓窟窟窮窯立立竜竜章章童童端端競競竹竹笑笑笛笛符符第第筆筆等等筋筋筒筒答答策策箇箇箋箋算算管管箱箱箸箸節節範範築築篤篤簡簡簿簿籍籍籠籠米米粉粉粋粋粒粒粗粘粛粛粧粧精精糖糖糧糧糸糸系系糾糾紀紀約紅紋紋納納純純紙紛素索紫紫累細紳紳紹紺終終組組経経結結絞絞絡絡給給統統絵絶絹絹継続維維綱網綻綻綿綿緊緊総総緑緒線線締締編緩緯緯練練緻緻縁縁縄縄縛縛縦縦縫縫縮縮績績繁繁繊繊織繕繭繭繰繰缶缶罪罪置置罰罰署署罵罵罷罷羅羅羊羊美美羞羞群群羨義羽羽翁翁翌翌習習翻翼老老考考者者耐耐耕耕耗耗耳耳聖聖聞聞聴聴職職肉肉肌肌肖肖肘肘肝肝股肢肥肥肩肪肯肯育育肺肺胃胃胆胆背背胎胎胞胞胴胴胸胸能能脂脂脅脅脇脈脊脊脚脚脱脱脳脳腎腎腐腐腕腕腫腫腰腰腸腺膚膚膜膝膨膨膳膳臆臆臓臓臣臣臨臨自自臭臭至致臼臼興興舌舌舎舎舗舗舞舟航航般般舶舷船船艇艇艦艦良良色色艶艶芋芋芝芝芯芯花花芳芳芸芸芽芽苗苗苛苛若苦英英茂茂茎茎茨茨茶茶草草荒荒荘荘荷荷菊菊菌菌菓菓菜菜華華萎萎落落葉葉著著葛葛葬葬蒸蒸蓄蓄蓋蓋蔑蔑蔵蔵蔽蔽薄薄薦薦薪薬藍藍藤藤藩藩藻藻虎虎虐虐虚虚虜虜虞虞虫虫虹虹蚊蚊蚕蚕蛇蛇蛍蛍蛮蛮蜂蜂蜜蜜融融血血衆衆行行術術街街衛衛衝衝衡衡衣衣表表衰衰衷衷袋袋袖袖被被裁裂装装裏裏裕裕補補裸裸製裾複複褐褐褒褒襟襟襲襲西西要要覆覇見見規規視視覚覚覧覧親親観観角角解解触触言言訂訃計計討討訓訓託記訟訟訪訪設設許許訳訴診診証証詐詐詔評詞詞詠詠詣詣試試詩詩詮詮詰詳誇誇誉誉誌認誓誓誕誕誘誘語語誠誠誤誤説読誰誰課課調調談談請請論論諦諧諭諮諸諸諾諾謀謁謄謄謎謎謙謙講講謝謝謡謡謹謹識識譜譜警警議議譲譲護護谷谷豆豆豊豊豚豚象象豪豪貌貌貝貞負貢貧責貯貯貴貴買貸費貼貿賀賂賄資資賊賊賓賓賛賜賞賞賠賠賢賢賦賦質質賭賭購購贈贈赤赤赦赦走走赴赴起起超超越越趣趣足足距距跡跡路路跳跳践践踊踊踏踏踪踪蹴蹴躍躍身身車車軌軍軒軒軟軟転転軸軸軽軽較較載載輝輝輩輪輸輸轄轄辛辛辞辞辣辣辱農辺辺込込迅迅迎迎近近返返迫迫迭迭述述迷迷追追退送逃逃逆逆透逐逓途通通逝逝速造連連逮逮週進逸逸遂遂遅遅遇遇遊運遍過道違遜遜遠遡遣遣適適遭遮遵遵遷選遺遺避避還還那那邦邦邪邪邸邸郊郊郎郎郡郡部部郭郭郵郵郷郷都都酌酎酒酒酔酔酢酢酪酪酬酬酵酵酷酸醒醒醜醜醸醸采釈里量金金釜針釣釣鈍鈍鈴鈴鉄鉄鉛鉛鉢鉢鉱鉱銀銀銃銃銅銅銘銘銭銭鋭鋭鋳鋳鋼鋼錠錠錦錦錬錬錮錯録録鍋鍋鍛鍛鍵鍵鎌鎌鎖鎖鎮鎮鏡鏡鐘鐘鑑鑑長長門門閉閉開開閑閑間間関閣閥閥閲閲闇闇闘闘阜阜阪阪防防阻阻附附降降限限陛陛院陥陪陪陰陰陳陳陵陶陸陸険険陽陽隅隆隊隊階随隔隔隙隙際障隠隠隣隣隷隷隻隻雄雇雌雌雑雑離難雨雨雪雪雰雰雲雲零雷電電需需震震霊霊霜霜霧霧露露青青静静非非面面革革靴靴韓韓音音韻韻響響頂頃項順須須預頓領領頬頭頻頼題顎顔顕願願類類顧顧風風飛飛食食飢飢飯飯飲飲飼飾餅餅養養餌餌餓餓館館首首香香馬馬駄駆駐駐駒駒騎騎騒験騰騰驚驚骨骨骸骸髄髄高高髪髪鬱鬱鬼鬼魂魂魅魅魔魔魚魚鮮鮮鯨鯨鳥鳥鳴鳴鶏鶏鶴鶴鹿鹿麓麓麗麗麦麦麺麻黄黄黒黒黙黙鼓鼓鼻鼻齢齢"),
+    ("jgo", 78, "ADFNPPSWYZadfnppswyzÁÂÍÎÚÛáâíîúûŃńŊŋƆƆƐƐǍǐǓǔǸǹɄɄɔɔɛɛʉʉḾḿẄẅꞋꞌ"),
+    ("jv", 58, "AEGPRUWWYYaegpruwwyyÂÂÅÅÈÊÌÌÒÒÙÙââååèêììòòùù"),
+    ("ka", 66, "აჰᲐᲰ"),
+    ("kab", 68, "ANPUWZanpuwzČčƐƐƔƔǦǧɛɛɣɣḌḍḤḥṚṛṢṣṬṭẒẓ"),
+    ("kam", 54, "AWYZawyzĨĩŨũ"),
+    ("kea", 48, "ABDPRVXZabdprvxzÑÑññ"),
+    ("kgp", 52, "AAEKMPRVYYaaekmprvyyÁÁÃÃÉÉÓÓááããééóóĨĩŨũẼẽỸỹ"),
+    ("ki", 40, "AEGKMORRTUWWYYaegkmorrtuwwyyĨĩŨũ"),
+    ("kk", 84, "ЁЁІІАяёёііҒғҚқҢңҮұҺһӘәӨө"),
+    ("kkj", 86, "APRWYYaprwyyÀÂÈÊÌÎÒÔÙÛàâèêìîòôùûŊŋƁƁƆƆƊƊƐƐɓɔɗɗɛɛ"),
+    ("kln", 42, "AEGPRUWWYYaegpruwwyy"),
+    ("km", 72, "កវសអឥឧឩឳា់៍៍័័្្"),
+    ("kn", 81, "ಂಃಅಌಎಐಒನಪಳವಹ಼ೄೆೈೊ್ೕೖೠೡ೦೯"),
+    ("ko", 11172, "가힣"),
+    ("kok", 77, "ँःअऍएऑओनपरलळवह़ृॅॅेॉो्ॐॐ०९"),
+    ("ks", 47, "ؠؤابتغفقلنووٲٲٹٹپپچچڈڈڑڑژژککگگںںھھہہۄۄۆۆیۍےے"),
+    ("ks-Deva", 50, "ँंअऊएएऑऑओओकगचजटडतदननपबमरललवशसह़़ाॅेॉो्"),
+    ("ksb", 46, "APSWYZapswyz"),
+    ("ksf", 66, "APRWYZaprwyzÁÁÉÉÍÍÓÓÚÚááééííóóúúŊŋƆƆƎƎƐƐǝǝɔɔɛɛ"),
+    ("ksh", 71, "AZazÄÆËËÖÖÜÜßßäæëëööüüĖėŒœŮů"),
+    ("ku", 62, "AZazÇÇÊÊÎÎÛÛççêêîîûûŞş"),
+    ("ky", 62, "ЁЁАБГУХХЧШЪЫЭбгуххчшъыэяёёҢңҮүӨө"),
+    ("lag", 66, "AZazÁÁÉÉÍÍÓÓÚÚááééííóóúúƗƗɄɄɨɨʉʉ"),
+    ("lb", 58, "AZazÄÄÉÉËËääééëë"),
+    ("lg", 48, "AGIPRWYZagiprwyzŊŋ"),
+    ("lkt", 59, "ABEEGIKPSUWWYZabeegikpsuwwyzÁÁÉÉÍÍÓÓÚÚááééííóóúúČčŊŋŠšŽžǦǧȞȟʼʼ"),
+    ("ln", 76, "AIKPRWYZaikprwyzÁÂÉÊÍÎÓÔÚÚáâéêíîóôúúĚěƆƆƐƐǍǒɔɔɛɛ"),
+    ("lo", 55, "ກຂຄຄງຈຊຊຍຍດທນຟມຣລລວວສຫອູົຽເໄໆໆ່ໍໜໝ"),
+    ("lrc", 43, "آؤئبتغؽؽفقلنووٙٙٛٛپپچچژژڤڤککگگھھۉۊییەە"),
+    ("lt", 64, "APRVYZaprvyzĄąČčĖęĮįŠšŪūŲųŽž"),
+    ("lu", 70, "AFHQSWYZafhqswyzÀÁÈÉÌÍÒÓÙÚàáèéìíòóùúƆƆƐƐɔɔɛɛ"),
+    ("luo", 46, "APRWYYaprwyy"),
+    ("lv", 66, "APRVZZaprvzzĀāČčĒēĢģĪīĶķĻļŅņŠšŪūŽž"),
+    ("mai", 44, "ंःकघचनपरललवह़़ाूेैोौ"),
+    ("mas", 92, "AEGPRUWWYYaegpruwwyyÀÂÈÊÌÎÒÔÙÛàâèêìîòôùûĀāĒēĪīŊōŪūƆƆƐƐƗƗɄɄɔɔɛɛɨɨʉʉ"),
+    ("mfe", 50, "APRZaprz"),
+    ("mg", 64, "ABDPRTVVYZabdprtvvyzÀÀÂÂÈÌÎÏÑÑÔÔààââèìîïññôô"),
+    ("mgo", 57, "ABDGIKMPRUWWYZabdgikmpruwwyzÀÀÈÈÌÌÒÒÙÙààèèììòòùùŊŋƆƆƏƏɔɔəəʼʼ"),
+    ("mi", 36, "AAEEHIKKMPRRTUWWaaeehikkmprrtuwwĀāĒēĪīŌōŪū"),
+    ("mk", 62, "ЃЃЅЅЈЊЌЌЏИКШаикшѓѓѕѕјњќќџџ"),
+    ("ml", 76, "ംഃഅഌഎഐഒനപഹാൃെൈൊ്ൗൗൠൡൺൿ\u{200C}\u{200D}"),
+    ("mn", 70, "ЁЁАяёёҮүӨө"),
+    ("mni", 59, "ঁঃঅঋএঐওনপরললশহ়়াৃেৈো্ৱৱ"),
+    ("mr", 68, "ँःअऍएऑओनपळवह़ृॅॅेॉो्ॐॐ"),
+    ("mt", 66, "ABDXZZabdxzzÀÀÈÈÌÌÒÒÙÙààèèììòòùùĊċĠġĦħŻż"),
+    ("mua", 66, "APRWYZaprwyzÃÃËËÕÕããëëõõĨĩŊŋƁƁƊƊƎƎǝǝɓɓɗɗṼṽ"),
+    ("my", 60, "ကအဣဧဩဲံဿ၏၏"),
+    ("naq", 58, "AIKKMUWZaikkmuwzÂÂÎÎÔÔÛÛââîîôôûûǀǃ"),
+    ("nb", 68, "AZazÀÀÅÆÉÉÒÔØØààåæééòôøø"),
+    ("nd", 50, "AQSZaqsz"),
+    ("nl", 72, "AZazÁÁÄÄÉÉËËÍÍÏÏÓÓÖÖÚÚÜÜááääééëëííïïóóööúúüü"),
+    ("nmg", 106, "APRWYYaprwyyÁÂÄÄÉÊÍÏÓÔÖÖÚÛáâääéêíïóôööúûĀāĒēĚěĪīŃńŊōŔŕŪūƁƁƆƆƎƎƐƐǍǔǝǝɓɔɛɛ"),
+    ("nnh", 99, "APSWYZapswyzÀÂÈÊÌÍÒÔÙÛàâèêìíòôùûÿÿĚěŃńŊŋŸŸƆƆƐƐǍǎǑǔɄɄɔɔɛɛʉʉʼʼḾḿẄẅ"),
+    ("nus", 68, "AZazÄÄËËÏÏÖÖääëëïïööŊŋƆƆƐƐƔƔɔɔɛɛɣɣ"),
+    ("or", 62, "ଁଃଅଋଏଐଓନପରଲଳଵହ଼଼ାୃେୈୋ୍ୟୟୱୱ"),
+    ("os", 68, "ЁЁАяёёӔӕ"),
+    ("pa", 68, "ਅਊਏਐਓਨਪਰਲਲਵਵਸਹ਼਼ਾੂੇੈੋ੍ੜੜ੦ੴ"),
+    ("pa-Arab", 44, "ءآؤؤئبتغفقلوُُٹٹپپچچڈڈڑڑژژککگگںںھھہہییےے"),
+    ("pcm", 60, "ABDPRWYZabdprwyzÁÁÉÉÍÍÓÓÚÚááééííóóúúẸẹỌọ"),
+    ("pl", 64, "APRUWWYZapruwwyzÓÓóóĄćĘęŁńŚśŹż"),
+    ("ps", 60, "ءؤئغفقلوئْٰٰٔټټپپځځڅچډډړړږږژژښښککګګگگڼڼیۍېې"),
+    ("pt", 78, "AZazÀÃÇÇÉÊÍÍÒÕÚÚàãççéêííòõúú"),
+    ("qu", 30, "AAHIKNPQSUWWYYaahiknpqsuwwyyÑÑññ"),
+    ("raj", 49, "ँःअअकनपरललवहाृॅॅेैो्"),
+    ("rm", 64, "AZazÀÀÈÉÌÌÒÒÙÙààèéììòòùù"),
+    ("ro", 62, "AZazÂÂÎÎââîîĂăȘț"),
+    ("sah", 50, "АБГДИУХХЧЧЫЫЭЭабгдиуххччыыээҔҕҤҥҮүҺһӨө"),
+    ("saq", 44, "AEGPRWYYaegprwyy"),
+    ("sat", 36, "ᱚᱽ"),
+    ("sbp", 44, "APSWYYapswyy"),
+    ("sc", 52, "AJLPRVZZajlprvzzÀÀÈÈÌÌÒÒÙÙààèèììòòùù"),
+    ("sd", 52, "ءآابتغفقلوييٺٻٽڀڃڄچڇڊڊڌڍڏڏڙڙڦڦکڪگگڱڱڳڳڻڻھھ"),
+    ("sd-Deva", 63, "ंंअऊएऐओनपरललवह़़ाॅेॉो्ॻॼॾॿ"),
+    ("se", 58, "APRVZZaprvzzÁÁááČčĐđŊŋŠšŦŧŽž"),
+    ("sg", 68, "ABDPRWYZabdprwyzÂÂÄÄÊËÎÏÔÔÖÖÙÙÛÜââääêëîïôôööùùûü"),
+    ("shi", 31, "ⴰⴱⴳⴳⴷⴷⴹⴹⴻⴽⵀⵀⵃⵅⵇⵇⵉⵊⵍⵏⵓⵖⵙⵜⵟⵟⵡⵣⵥⵥ"),
+    ("shi-Latn", 60, "ANQUWZanquwzƐƐƔƔɛɛɣɣḌḍḤḥṚṛṢṣṬṭ"),
+    ("si", 74, "ංඃඅඍඑඖකඥටනඳරලලවෆ්්ාුූූෘෟෲෲ"),
+    ("sk", 86, "AZazÁÁÄÄÉÉÍÍÓÔÚÚÝÝááääééííóôúúýýČďĹĺĽľŇňŔŕŠšŤťŽž"),
+    ("sl", 50, "APRVZZaprvzzČčŠšŽž"),
+    ("smn", 62, "APRVYZaprvyzÁÂÄÄáâääČčĐđŊŋŠšŽž"),
+    ("so", 36, "BDFHJNQTWYbdfhjnqtwy"),
+    ("sq", 54, "AVXZavxzÇÇËËççëë"),
+    ("su", 54, "AZazÉÉéé"),
+    ("sv", 62, "AZazÀÀÄÅÉÉÖÖààäåééöö"),
+    ("sw", 46, "ABDPRWYZabdprwyz"),
+    ("ta", 47, "ஃஃஅஊஎஐஒகஙசஜஜஞடணதநபமவஷஹாூெைொ்"),
+    ("te", 70, "ఁఃఅఌఎఐఒనపళవహాౄెైొ్ౕౖౠౡ"),
+    ("teo", 46, "AEGPRYaegpry"),
+    ("tg", 70, "ЁЁАХЧШЪЪЭхчшъъэяёёҒғҚқҲҳҶҷӢӣӮӯ"),
+    ("th", 73, "กฺเ๎"),
+    ("ti", 301, "ሀሆለቆቈቈቊቍቐቖቘቘቚቝበኆኈኈኊኍነኮኰኰኲኵኸኾዀዀዂዅወዎዐዖዘዮደዷጀጎጐጐጒጕጠፗ፟፟"),
+    ("tk", 60, "ABDPRUWWYZabdpruwwyzÄÄÇÇÖÖÜÝääççööüýŇňŞşŽž"),
+    ("to", 51, "AAEFHIKPSVaaefhikpsvÁÁÉÉÍÍÓÓÚÚááééííóóúúĀāĒēĪīŌōŪūʻʻ"),
+    ("tr", 58, "APRVYZaprvyzÇÇÖÖÜÜççööüüĞğİıŞş"),
+    ("tt", 78, "ЁЁАяёёҖҗҢңҮүҺһӘәӨө"),
+    ("ug", 32, "ابتتججخدرشغغفنويپپچچژژڭڭگگھھۆۈۋۋېېەە"),
+    ("uk", 67, "ʼʼЄЄІЇАЩЬЬЮщььюяєєіїҐґ"),
+    ("ur", 38, "ءءابتغفقلنووٹٹپپچچڈڈڑڑژژککگگھھہہییےے"),
+    ("uz", 49, "ABDVXZabdvxzʼʼ"),
+    ("uz-Arab", 50, "ءؤئغفقلؤًْٰٰٔپپچچژژککگگۇۇۉۉیی"),
+    ("uz-Cyrl", 66, "ЁЁЎЎАХЧШЪЪЭхчшъъэяёёўўҒғҚқҲҳ"),
+    ("vai", 274, "ꔀꘌꘐꘒꘪꘫ"),
+    ("vai-Latn", 82, "AZazÁÁÃÃÉÉÍÍÓÓÕÕÚÚááããééííóóõõúúĨĩŊŋŨũƁƁƆƆƊƊƐƐɓɔɗɗɛɛẼẽ"),
+    ("vi", 178, "AEGIKVXYaegikvxyÀÃÈÊÌÍÒÕÙÚÝÝàãèêìíòõùúýýĂăĐđĨĩŨũƠơƯưẠỹ"),
+    ("wae", 78, "AZazÁÁÃÄÉÉÍÍÓÓÕÖÚÚÜÜááãäééííóóõöúúüüČčŠšŨũ"),
+    ("wo", 58, "AGIUWYagiuwyÀÀÉÉËËÑÑÓÓààééëëññóóŊŋ"),
+    ("yav", 88, "AFHIKPSWYYafhikpswyyÀÂÈÉÌÎÒÔÙÛàâèéìîòôùûĀāĪīŊōŪūƆƆƐƐǍǎǑǔɔɔɛɛ"),
+    ("yi", 26, "אףץת"),
+    ("yo", 74, "ABDPRUWWYYabdpruwwyyÀÁÈÉÌÍÒÓÙÚàáèéìíòóùúŃńǸǹḾḿṢṣẸẹỌọ"),
+    ("yrl", 42, "ABDEGGIIKKMNPPRUWYabdeggiikkmnppruwyÃÃããĨĩŨũẼẽ"),
+    ("yue", 2470, "一丁七七丈不丑丑且且世世丘丙丟丟並並中中串串丸丹主主乃乃久久么么之之乎乏乖乖乘乙九九也也乾乾亂亂了了予予事二于于云互五井些些亞亞亡亡交亦亨亨享京亮亮人人什仁仇仇今介仍仍仔仔他他付仙代以仰仰仲仲件件任任份份企企伊伊伍伍伐休伙伙伯估伴伴伸伸似伽但但佈佉位住佔何余余佛作你你佩佩佳佳使使來來例例供供依依侯侯侵侵便便係俄俊俊俏俏俗俗保保俠信修修俱俱俾俾倉倉個個倍倍們倒候倚借借倫倫值值假假偉偉偏偏做做停停健健側偷偽偽傅傅傑傑傘備傢傣傲傳傷傷傻傻傾傾僅僅像像僑僑僧僧價價儀儀億億儒儒儘儘優優允允元充兇光克克免免兒兒兔兔入入內兩八兮共共兵典兼兼冊冊再再冒冒冠冠冬冬冰冰冷冷准准凋凍凝凝凡凡凰凱出出函函刀刀分切刊刊列列初初判別刨刪刮刮到到制刷刺刻剃剃則則剌前剛剛剩剪副副割割創創劃劃劇劇劉劉劍劍力力功加助劫勁勁勇勇勉勉勒勒動動務務勝勞勢勢勤勤勵勵勸勸勾勿包包匈匈化北匯匯匹匹區十千千升午半半卒協南南博博卜卜卡卡卯危即即卷卷卹卹卻卻厄厄厘厘厚厚原原厭厭厲厲去去參參又又及友反反叔叔取受口另只叭可台史右司司吃各合吊同后吐吒君君吝吠否吧含含吳吳吵吵吸吹吾吾呀呀呂呂呆呆告告呢呢周周味味呵呵呼命和和咖咖咦咧咪咪咬咬咱咱哀品哇哉哎哎員員哥哦哩哪哭哭哲哲唇唇唉唉唐唐唔唔唬唬售唯唱唱唵唵唷唸商商啊啊問問啟啟啡啡啤啦啪啪喀喀喂喂善善喇喇喊喊喔喔喜喝喪喪喬喬單單喲喲喵喵嗎嗎嗚嗚嗨嗨嗯嗯嘆嘆嘉嘉嘗嘗嘛嘛嘴嘴嘻嘻嘿嘿噁噁噓噓器器噴噴嚇嚇嚏嚏嚴嚴囉囉四四回回因因困困固固圈圈國國圍圍園圓圖圖團團圜圜土土在在圭圭地地圾圾址址均均坎坎坐坑坡坡坤坤坦坦坪坪垂垃型型埃埃城城埔埔域域執執培基堂堂堅堆堡堡堪堪報報場場塊塊塔塔塗塗塞塞填填塵塵境境墅墅墓墓增墟墨墨墮墮墳墳壁壁壇壇壓壓壘壘壞壞壢壢壩壩士壬壯壯壺壺壽壽夏夏夕外多多夜夜夠夠夢夢夥夥大大天夫央央失失夷夸夾夾奇奉奎奏契契奔奔套套奧奧奪奪奮奮女奴奶奶她她好好如如妙妙妝妝妥妥妨妨妮妮妳妳妹妹妻妻姆姆姊始姐姑姓委姿姿威威娃娃娘娘娛娛婁婁婆婆婚婚婦婦媒媒媽媽嫌嫌嫩嫩子子孔孕字存孝孝孟孟季孤孩孩孫孫孵孵學學它它宅宅宇安宋完宏宏宗宜客室宮宮害害家家容容宿宿寂寂寄密富富寒寒寞察寢寢實審寫寬寮寮寵寶寺寺封封射射將專尊尋對小少少尖尖尚尚尤尤就就尺尺尼尼尾屁居屆屋屋屍屍屏屏屑屑展展屠屠層層屬屬山山岡岡岩岩岸岸峰峰島島峽峽崇崇崙崙崴崴嵐嵐嶺嶺川州巡巡工巨巫巫差差己巴巷巷市布希希帕帖帚帛帝帝帥帥師師席席帳帳帶帶常常帽帽幅幅幕幕幟幟幣幣幫幫干年幸幹幻幾庇庇床床序序底底店店庚庚府府度座庫庫庭庭康庸廈廉廖廖廟廠廢廣廳廳延廷建建弄弄式式引引弗弘弟弟弦弦弱弱張張強強彈彈彊彊彌彌彎彎彝彞形形彥彥彩彩彬彭彰影役役彼彼往征待待很很律後徐徒得得從從復復微微徵徵德德徹徹心心必必忌忍志忙忠忡快快念念忽忽怎怎怒怒怕怖思思怡怡急急性怨怪怪恆恆恐恐恢恢恥恥恨恩恭恭息恰悅悅悉悉悔悔悟悠您您悲悲悶悶情情惑惑惜惜惠惡惱惱想想惹惹愁愁愈愉意意愚愛感感慈慈態態慕慕慘慘慢慣慧慧慮慮慰慰慶慶慾慾憂憂憊憊憐憑憲憲憶憶憾憾懂懂應應懨懨懶懷懼懼戀戀戈戈戊戊戌戌成戒或或截截戰戰戲戲戴戴戶戶房扁扇扇手手才扎打打托托扣扣扥扥扭扯批批找技抄抄把把抓抓投投抗折披抬抱抱抵抵抹抹抽抽拆拆拉拉拋拋拍拍拏拏拒拒拔拔拖拖招拜括括拳拳拼拼拾拿持持指指按按挑挑挖挖挪挪振振挺挺捏捐捕捕捧捨捲捲捷捷掃掃授掉掌掌排排掛掛掠探接接控推措措掰掰描提插插揚換握握揮揮援援揹揹損損搏搏搖搖搜搜搞搞搬搭搶搶摀摀摘摘摩摩摸摸撐撐撒撒撕撕撞撞撣撣撥撥播播撲撲撾撿擁擁擇擇擊擋操擎擔擔據據擠擠擦擦擬擬擴擴擺擺擾擾攀攀攝攝攤攤支支收收改改攻攻放政故故效效敍敍敏敏救救敗教敝敞敢散敦敦敬敬整敵數數文文斐斑斗斗料料斜斜斧斧斯新斷斷方方於施旁旁旅旅旋旋族族旗旗既既日旦早早旭旭旺旺昂昂昆昇昌昌明昏易易星映春春昨昨昭昭是是時時晉晉晒晒晚晚晨晨普景晴晴晶晶智智暑暑暖暗暫暫暮暮暴暴曆曇曉曉曬曬曰曰曲更書書曼曼曾最會會月有朋朋服服朔朔朗朗望望朝朝期期木木未札朱朱朵朵杉杉李李材村杖杖杜杜束束杯東松板析析林林果枝枯枯架架柏某染柔查查柬柬柯柯柳柴栓栓校校核根格栽桃桃案案桌桌桑桑梁梁梅梅條條梨梨梯械梵梵棄棄棉棉棋棋棍棍棒棒棕棕棚棚森森棺棺椅椅植植椒椒椰椰楊楊楓楓楚楚業業極極概概榜榜榮榮構槍樂樂樓樓標標樞樞模模樣樣樹樹橄橄橇橇橋橋橘橙機機橫橫檀檀檔檔檢檢檬檬檸檸櫚櫚櫻櫻欄欄權權欖欖欠次欣欣欲欲欺欺欽款歉歉歌歌歐歐歡武歲歲歷歸死死殊残殘殘殭殭段段殺殺殼殼毀毀毅毅母母每每毒毒比比毛毛毫毫氏氏民民氣氣水水永永汁求汗汗汝汝江污汪汪汶汶決決汽汽沃沃沈沉沒沒沖沖沙沙沫沫沮沮河河油油治治沿沿況況泉泊法法泡泣泥泥注注泰泰泳泳洋洋洗洗洛洛洞洞洩洪洲洲活活洽派流流浣浣浦浦浩浪浮浮浴浴海海涇涉涎涎涮涯液液涵涵涼涼淇淇淋淋淑淑淚淚淡淡淨淨深深混混淺淺清清減減渡渡測測港港游游湖湖湘湘湯湯源源準準溜溝溪溫滄滅滋滋滑滑滴滴滾滿漂漂漏漏演演漠漠漢漢漫漫漲漲漸漸漿漿潔潔潘潘潛潛潮潮澡澡澤澤澳澳激激濃濃濕濕濟濟濤濤濫濫濱濱瀏瀏灌灌灣灣火火灰灰災災炎炎炮炮炸炸為為烈烈烏烏烘烘烤烤烹烹焊焊焙焙無無焦焦焰焰然然煙煙煞煞照照煩煩煮煮熊熊熟熟熱熱燃燃燈燈燒燒燙燙營營爆爆爍爍爐爐爛爛爪爪爬爭爵父爸爸爺爺爽爾牆版牌牌牙牙牛牛牠牠牧牧物物牲牲特特牽牽犀犀犧犧犬犬犯犯狀狀狂狂狐狐狗狗狠狡狸狸狼狼猛猜猩猩猴猴猶猶猾猿獄獅獎獎獨獨獲獲獸獸獺獻獾獾玄玄率率玉玉王王玩玩玫玫玲玲玻玻珊珊珍珍珠珠珥珥班班現現球球理理琉琉琪琪琴琴瑙瑙瑜瑜瑞瑟瑤瑤瑪瑪瑰瑰環環瓜瓜瓢瓢瓦瓦瓶瓶甕甕甘甘甚甚甜甜生生產產用用田申男甸界界留留畢畢略略番畫異異當當疆疆疏疏疑疑疲疲疼疼疾疾病病痕痕痛痛痴痴瘋瘋瘦瘧療療癡癡癸癸登百皂皂的的皆皇皮皮皿皿盃盃盆盆盈盈益益盔盔盛盜盟盟盡盡監盥盧盧目目盲盲直直相相盼盼盾盾省省眉眉看看真眠眼眼眾眾睏睏睛睛睡睡督督瞇瞇瞌瞌瞧瞧瞪瞪瞭瞭矛矛矣矣知知短短石石砂砂砍砍研研砲砲破破硬硬碎碎碗碗碟碟碧碧碩碩碰碰確確碼碼磁磁磚磚磨磨磯磯礎礎礙礙礫礫示示社社祈祈祕祖祚祛祝神祥祥票票祿祿禁禁禍福禪禪禮禮禱禱禿私秋秋科秒秘秘租租秤秤秦秦移移稅稅程程稍稍種種稱稱稻稻稿穀穆穆穌積穩穩究究穹空穿穿突突窄窄窗窗窩窩窮窮窶窶立立站站竟章童童端端競競竹竹竿竿笑笑笛笛符符笨笨第第筆筆等等筋筋答答策策筷筷箏箏箔箔算算管管箭箭箱箱節節範範篇篇築築篷篷簡簡簫簫簽簽簿簿籃籃籌籍籠籠籤籤米米粉粉粗粗粵粵精精糊糊糕糖糟糟糥糥系系糾糾紀紀約紅紉紉納納紐紐純純紙紛素素索索紫紫紮細紳紳紹紹終終組組結結絕絕絡絡給給統絲經經綜綜綠綠維維綱網綽綽綿綿緊緊緒緒線線緣緣編緩緬緬緯緯練練縛縛縣縣縫縫縮縮縱縱總績繁繁繃繃繆繆織織繞繞繡繡繩繪繳繳繼繼續續纖纖缸缸缺缺罈罈罐罐罕罕罩罪置置罰罰署署罵罵罷罷羅羅羊羊美美羞羞群群義義羽羽翁翁習習翔翔翰翰翹翹翻翼耀老考考者者而耍耐耐耗耗耳耳耶耶聊聊聖聖聚聚聞聞聯聰聲聲職職聽聾肉肉肌肌肚肚股股肥肥肩肩肯肯育育肺肺背背胎胎胖胖胞胞胡胡胸胸能能脆脆脈脈脖脖脫脫腐腐腓腔腦腦腰腰腳腳腹腹腿腿膚膚膠膠膽膽臂臂臉臉臘臘臟臟臣臣臥臥臨臨自自臭臭至致臺臺與舊舌舍舒舒舞舟航航般般船船艦艦良良色色艾艾芙芙芝芝芬芭花花芳芳芽芽苣苣若苦英英茄茅茫茫茲茲茵茶茸茸草草荒荒荷荷荼荼莉莊莎莎莓莓莖莖莫莫菇菇菌菌菜菜菩菩華華菲菲萄萄萊萊萎萎萬萬萵萵落落葉葉著著葛葛葡葡葵葵蒂蒂蒙蒙蒜蒜蒲蒲蒸蒸蒼蒼蓄蓄蓉蓉蓋蓋蓮蓮蔔蔕蔡蔡蔣蔣蔥蔥蔬蔬蕉蕉蕭蕭蕾蕾薄薄薑薑薦薦薩薪薯薯藉藉藍藍藏藏藝藝藤藥蘆蘇蘋蘋蘑蘑蘭蘭蘿蘿虎虎處處虛虛號號虧虧蚊蚊蚓蚓蚯蚯蛇蛇蛋蛋蛙蛙蜂蜂蜜蜜蜥蜥蜴蜴蝙蝙蝟蝠蝦蝦蝶蝶螂螃融融螞螞螢螢螺螺蟀蟀蟄蟄蟋蟋蟑蟑蟲蟳蟹蟹蟻蟻蠅蠅蠍蠍蠕蠕蠣蠣蠻蠻血血行行術術街街衛衛衝衝衡衡衣衣表表衫衫袋袋袍袍被被裁裂裏裏裕裕補裝裡裡裱裱裹裹製製複複褐褐褲褲襪襪襯襯西西要要覆覆見見規規視視親親覺覺覽覽觀觀角角解解觸觸言言訂訂計計訊訊討討訓訓託記訝訝訥訥訪訪設設許許訴訴診証評評詞詞詢詢試試詩詩話詳誇誇誌認誓誓誕誕語語誠誠誤誤說說誰誰課課誼誼調調談談請請諒諒論論諸諸諺諺諾諾謀謀謂謂謎謎講講謝謝證證識識譜譜警警譯議護護譽譽讀讀變變讓讓讚讚谷谷豆豆豈豈豎豎豐豐豔豔象象豪豪豬豬豹豹貌貌貓貓貝貞負貢貨貨貪責貴貴買買費貼賀賀資賈賓賓賜賜賞賞賢賤賦賦質質賭賭賴賴賺賺購賽贈贈贊贊贏贏贛贛赤赤赫赫走走起起超超越越趕趕趙趙趣趣趨趨足足跆跆跌跌跎跎跑跑距距跟跟跡跡跪跪路路跳跳踏踏踢踢踩踩蹟蹟蹤蹤躍躍身身躲躲車車軌軍軒軒軟軟軸軸較較載載輔輕輛輛輝輝輩輪輯輯輸輸轉轉轎轎轟轟辛辜辣辣辦辦辨辨辭辭辯農迅迅迎迎近近返返迦迦迪迫述述迴迴迷迷追追退送逃逃逆逆透逐途途這逛逝逝速造逢連週進逸逸逼逼遇遇遊運遍過道違遙遙遜遜遠遠適適遭遮遲遲遷選遺遺避邁還還邊邊邏邏那那邦邦邪邪邱邱郎郎部部郭郭郵郵都都鄂鄂鄉鄉鄙鄙鄭鄭鄰鄰酉酉配配酒酒酪酪酷酸醉醉醒醒醜醜醫醬采采釋量金金針針釣釣鈴鈴鉅鉅鉢鉢鉤鉤銀銀銅銅銖銖銘銘銳銳銷銷鋁鋁鋒鋒鋼鋼錄錄錢錢錦錦錨錨錫錫錯錯錶錶鍊鍋鍵鍵鍾鍾鎊鎊鎖鎖鎮鎮鏈鏈鏡鏢鐘鐘鐡鐡鐵鐵鑑鑑鑿鑿長長門門閃閃閉閉開開閏閏閒間閣閣閩閩閱閱闆闆闊闊闍闍闐闐關關闡闡阱防阻阻阿陀附附降降限限院除陪陪陰陰陳陳陵陸陽陽隆隆隊隊階階隔隔際障隨隨險險隱隱隻隻雄集雉雉雌雌雖雖雙雙雜雜雞雞離難雨雨雪雪雲雲零雷電電需需震震霍霍霜霜霧霧露露霸霹靂靂靈靈青青靖靖静静靜靜非非靠靠面面革革靴靴靼靼鞋鞋鞭鞭韃韃韋韋韓韓音音韻韻響響頁頂項順須須頌頌預頑頓頓頗領頞頞頭頭頸頸頻頻顆顆題額顏顏願願顛顛類類顧顧顯顯風風颱颱飄飄飆飆飛飛食食飪飪飯飯飲飲飽飾餃餃餅餅養養餌餌餐餐餘餘餚餚館館餾餾首首香香馬馬駐駐駕駕駛駛駝駝駱駱騎騎騙騙騷騷驅驅驕驕驗驗驚驚骨骨體體高高髮髮鬆鬆鬍鬍鬥鬥鬧鬧鬱鬱鬼鬼魁魂魅魅魔魔魚魚魯魯魷魷鮑鮑鮮鮮鯊鯊鯨鯨鱷鱷鳥鳥鳩鳩鳳鳴鴨鴨鴻鴻鵝鵝鵡鵡鶴鶴鷹鷹鸚鸚鹽鹽鹿鹿麗麗麥麥麵麵麻麼黃黃黎黎黑黑默默黛黛點點黨黨鼓鼓鼠鼠鼬鼬鼻鼻齊齋齒齒齡齡龍龍龐龐龜龜"),
+    ("yue-Hans", 2124, "一丁七七万与丑丑专且世世丘业东丝丢丢两严个个中中丰丰串串临临丸主丽举乃乃久久么义之乌乎乐乔乔乖乖乘乙九九也乡书书买乱了了予争事二于亏云互五井亚些亡亡交亨享京亮亮亲亲人人亿仁仅仅仇仇今介仍从仑仑仔仔他他付仙代以仪仪们们仰仰仲仲件价任任份份企企伊伊伍伍伐休众会伟传伤伤伦伦伯估伴伴伸伸似伽但但佉佉位住体体何何余余佛作你你佩佩佳佳使使例例供供依依侠侠侦侨侯侯侵侵便便促俄俊俊俗俗保保信信修修俾俾倍倍倒倒候倚借借值值倾倾假假偏偏做做停停健健偶偷傣傣傲傲傻傻像像僧僧儒儒儿儿允允元充先光克克免免兔兔党党入入全全八兮兰共关兹养兽内内冈冈册再冒冒写写军农冠冠冬冬冰冰冲决况况冷冷净净准准凉凉凌凌减减凝凝几凡凤凤凭凭凯凰凶凶出击函函刀刀分切刊刊划划列创初初删删判判利利别别到到制刷刺刻剌前剑剑剧剧剩剪副副割割力力劝务动劫励劳势势勇勇勉勉勒勒勤勤勿勿包包匈匈化北匹医十十千千升午半半华协卒卓单南博博卜卜占卢卧卧卫卫卯危即却卷卷厂厂厄历厉厉压厌厘厘厚厚原原去去县县参参又又及反发发叔叔取叙口另只叭可台史右叶叹吃各合吊同后吐向吓吓吕吕吗吗君君吝吠否吧含听启启吴吵吸吹吾吾呀呀呆呆告告员员呜呜呢呢周周味味呵呵呼命和和咖咖咤咤咦咧咪咪咬咬咱咱哀品哇哉响哎哥哦哩哪哭哭哲哲唉唉唐唐唔唔唬唬售唯唱唱唷唷商商啊啊啡啡啥啦啪啪啰啰喀喀喂喂善善喇喇喊喊喔喔喜喝喵喵喷喷嗨嗨嗯嗯嘉嘉嘛嘛嘴嘴嘻嘻嘿嘿器器四四回回因因团团园园困困围围固固国图圆圆圈圈圜圜土土圣圣在在圭圭地地场场圾圾址址均均坎坐块块坚坜坡坡坤坤坦坦坪坪垂垃型型垒垒埃埃城城埔埔域域培基堂堂堆堆堕堕堡堡堪堪塔塔塞塞填填境境墙墙增增墨墨壁壁士壬壮壮声声壳壳处处备备复复夏夏夕外多多夜夜够够大大天夫央央失失头头夷夺奇奉奋奋奎奏契契奔奔奖套奥奥女奴奶奶她她好好如如妆妈妙妙妥妥妨妨妮妮妳妳妹妹妻妻姆姆始始姐姑姓委姿姿威威娃娄娘娘娱娱婆婆婚婚媒媒嫌嫌嫩嫩子子孔孔字孙孝孝孟孟季孤学学孩孩宁宁它它宅宅宇安宋完宏宏宗实宠室宪宫害害家家容容宽宿寂寂寄密富富寒寒寝察寨寨寮寮对对寻导寿寿封封射射将将尊尊小小少少尔尔尖尖尘尘尚尚尝尝尤尤就就尺尺尼尾局层居居届屋屏屏展展属属屠屠山山岁岂岚岛岩岩岭岭岸岸峡峡峰峰崇崇崴崴川州巡巡工巨巫巫差差己巴巷巷币布帅帅师师希希帐帐帕帖帛帛帝帝带带席帮常常帽帽幅幅幕幕干年并并幸幸幻幽广广庄庄庆庇床床序序库底店店庚庚府府废废度座庭庭康庸廉廉廖廖延廷建建开开异弄式式引引弗弘弟张弥弦弯弯弱弱弹强彊彊归当录录彝彝形形彦彦彩彩彬彭彰影役役彻彼往征径待很很律律徐徐徒徒得得微微德德心心必忆忌忍志忙忠忠忧忧快快念念忽忽怀态怎怎怒怒怕怖怜思怡怡急急性怨怪怪总总恋恋恐恐恒恒恢恢恨恩恭恭息恰恶恶恼恼悉悉悔悔悟悠悦悦您您悲悲情情惊惊惑惑惜惜惠惠惧惨惯惯想想惹惹愁愁愈愉意意愚愚感感愿愿慈慈慕慕慢慢慧慧慰慰憾憾懂懂懒懒戈戈戊戊戌戌戏戒或或战战截截戴戴户户房扁扇扇手手才扎打打托托扣扣扥扥执执扩扩扫扭扯扰批批找技抄抄把把抓抓投投抗折抛抛抢抢护报披抬抱抱抵抵抹抹抽抽担拆拉拉拍拍拏拏拒拒拔拔拖拖招拜拟拟拥拥拨择括括拳拳拼拼拾拿持挂指指按按挑挑挖挖挝挝挡挡挤挥挪挪振振挺挺捐捐捕捕损损捡换据据捷捷授掉掌掌排排探探接接控推措措掸掸描提插插握握援援搜搜搞搞搬搭摄摄摆摇摘摘摩摩摸摸撑撒撞撞播播操擎擦擦支支收收改改攻攻放政故故效效敌敍敏敏救救教教敝敝敢散敦敦敬敬数数整整文文斋斋斐斐斗斗料料断断斯新方方施施旁旁旅旅旋旋族族旗旗无无既既日旧早早旭旭时时旺旺昂昂昆昆昌昌明昏易易星映春春昨昨昭昭是是显显晋晋晒晓晚晚晨晨普景晴晴晶晶智智暂暂暑暑暖暗暴暴曰曰曲曲更更曼曼曾最月有朋朋服服朗朗望望朝朝期期木木未札术术朱朱朵朵机机杀杀杂权杉杉李李材村杜杜束束条条来来杨杨杯杰松板极极构构析析林林果枝枢枢枪枫架架柏某染柔查查柬柬柯柯柳柴标标栏栏树树校校样根格格桃桃案案桌桌桑桑档档桥桥梁梁梅梅梦梦梨梨梯械梵梵检检棉棉棋棋棒棒棚棚森森椅椅植植椰椰楚楚楼楼概概榜榜模模横横檀檀次欣欧欧欲欲欺欺款款歉歉歌歌止武死死殊残段段毁毁毅毅母母每每毒毒比毕毛毛毫毫氏氏民民气气水水永永求求汉汉汗汗汝汝江污汤汤汪汪汶汶汽汽沃沃沈沉沙沙沟沟没没沧沧河河油油治治沿沿泄泄泉泊法法泡波泥泥注注泪泪泰泰泳泳泽泾洁洁洋洋洗洗洛洛洞洞洪洪洲洲活活洽派流流浅浅测测济浏浓浓浦浦浩浪浮浮海海涂涂消涉涛涛涨涨涯涯液液涵涵淑淑淡淡深深混混清清渐渐渡渡温温港港游游湖湖湾湾源源溪溪滋滋滑滑滚滚满满滥滥滨滨滴滴漂漂漏漏演演漠漠漫漫潘潘潜潜潮潮澳澳激激灌灌火火灭灭灯灰灵灵灾灾炉炉炎炎炮炮炸点烂烂烈烈烟烟烤烤烦烧热热焦焦然然煞煞照照熊熊熟熟燃燃爆爆爪爪爬爬爱爱爵爸爽爽片版牌牌牙牙牛牛牠牠牧牧物物牲牲牵牵特牺犯犯状状犹犹狂狂狐狐狗狗狠狠独独狮狮狱狱狼狼猛猜猪猫献献猴猴玄玄率率玉玉王王玛玛玩玩玫玫环现玲玲玻玻珊珊珍珍珠珠珥珥班班球球理理琉琉琪琪琴琴瑙瑙瑜瑜瑞瑟瑰瑰瑶瑶瓜瓜瓦瓦瓶瓶甘甘甚甚甜甜生生用用田申电电男甸画画界界留留略略番番疆疆疏疏疑疑疗疗疯疯疼疼病病痕痕痛痛痴痴癸癸登登白百的的皆皇皮皮益益监监盖盘盛盛盟盟目目盲盲直直相相盼盼盾盾省省眉眉看看真眠眼眼着着睛睛睡睡督督瞧瞧矛矛矣矣知知短短石石矶矶码砂砍砍研研破破础础硕硕硬硬确确碍碎碗碗碟碟碧碧碰碰磁磁磨磨示示礼礼社社祖祖祚祛祝神祥祥票票祯祯祸祸禁禁禄禅福福离离秀私秋秋种种科秒秘秘租租秤秤秦秦积称移移程程稍税稣稣稳稳稿稿穆穆究穷穹空穿穿突突窗窗窝窝窭窭立立站站竞章童童端端竹竹笑笑笔笔笛笛符符笨笨第第等等筋筋筑筑答答策策筹筹签签简简算算管管箫箫箭箭箱箱篇篇篮篮簿簿籍籍米米类类粉粉粗粗粤粤精精糊糊糕糕糟糟系系素素索索紧紧紫紫累累繁繁纠纠红红约级纪纪纬纬纯纯纲纳纵纵纷纸纽纽线线练组细终绍绍经经结结绕绕绘给络绝统统继继绩绪续续维维综综绿绿缅缅缓缓编编缘缘缚缚缩缪缴缴缸缸缺缺网网罕罕罗罗罚罚罢罢罪罪置置署署羊羊美美羞羞群群羽羽翁翁翔翔翘翘翰翰翻翼耀老考考者者而耍耐耐耗耗耳耳耶耶耻耻聊聊职职联联聚聚聪聪肉肉肚肚股股肥肥肩肩肯肯育育胆胆背背胎胎胖胖胜胜胞胞胡胡胸胸能能脆脆脑脑脚脚脱脱脸脸腊腊腓腔腰腰腿腿臣臣自自臭臭至致舌舍舒舒舞舟航航般般舰舰船船良良色色艺艺艾艾节节芝芝芦芦芬芬花花芳芳苍苍苏苏若苦英英范范茅茅茫茫茶茶草草荐荐荒荒荣荣药药荷荷荼荼莉莉莎莎莫莫莱莲获获菜菜菩菩菲菲萄萄萤营萧萨落落葛葛葡葡蒂蒂蒋蒋蒙蒙蒲蒲蓝蓝蔕蔕蔡蔡薄薄薪薪藏藏藤藤虎虎虑虑虚虚虫虫虽虽蛇蛇蛋蛋蛙蛙蛮蛮蜂蜂蜜蜜蝎蝎蝶蝶融融蟹蟹血血行行街街衡衡衣衣补补表表袋袋被被裁裂装装裕裕裤裤西西要要覆覆见观规规视视览觉角角解解触触言言誉誉誓誓警警计订认认讨让训记讲讲讷许论论设访证证评评识识诉诉词词译译试试诗诗诚诚话诞询询该详语语误误说说请诸诺读课课谁谁调调谅谅谈谈谊谋谓谓谚谚谢谢谱谱谷谷豆豆象象豪豪貌貌贝负贡败货质贪贪购购贯贯贱贱贴贵费贺贾贾资资赋赌赏赐赖赖赚赛赞赞赠赠赢赢赤赤赫赫走走赵起超超越趋趣趣足足跃跃跌跌跎跎跑跑距距跟跟路路跳跳踏踏踢踢踪踪身身躲躲车车轨轩转转轮轰轻轻载载较较辅辆辈辉辑辑输输辛辛辞辞辨辩辰辱边边达达迁迁迅迅过迈迎迎运近返返还这进迟迦迦迪迫述述迷迷迹迹追追退逃逆逆选逊透逐途途通逛逝逝速造逢逢逸逸逻逼遇遇遍遍道道遗遗遥遥遭遮避邀那那邦邦邪邪邮邮邱邱邻邻郁郁郎郎郑郑部部郭郭都都鄂鄂酉酉配配酒酒酷酸醉醉醒醒采采释释里量金金鉴鉴针针钓钓钟钟钢钢钦钦钱钱钵钵铁铁铃铃铜铜铢铢铭铭银银销锁锅锅锋锋锐锐错错锡锡锦锦键键镇镇镑镑镜镜长长门门闪闪闭问闰闰闲闲间间闷闷闹闹闻闻阁阁阅阅阇阇阐阐阔阔阗阗队队防阶阻阻阿陀附陆陈陈降降限限院院除除险陪陵陷隆隆随隐隔隔障障难难雄集雉雉雨雨雪雪雳雳零雷雾雾需需震震霍霍露露霸霹青青靖靖静静非非靠靠面面革革靼靼鞋鞋鞑鞑韦韦韩韩音音韵韵頞頞页顶项须顽顿预预领颇频频颗题颜额风风飘飘飞食餐餐饭饮饰饱饼饼馆馆首首香香马马驱驱驶驶驻驻驾驾骂骂验验骑骑骗骗骚骚骨骨高高鬼鬼魁魂魅魅魔魔鱼鱼鲁鲁鲜鲜鸟鸟鸡鸡鸣鸣鸿鸿鹅鹅鹰鹰鹿鹿麦麦麻麻黄黄黎黎黑黑默默鼓鼓鼠鼠鼻鼻齐齐齿齿龄龄龙龙龟龟"),
+    ("zh", 2210, "一丁七七万与丑丑专且世世丘业东丝丢丢两严丧丧个个中中丰丰串串临临丸主丽举乃乃久久么义之乐乔乔乖乖乘乙九九也乡书书买乱乾乾了了予争事二于亏云互五井亚些亡亡交亨享京亮亮亲亲人人亿仁仅仅仇仇今介仍从仔仔他他付仙代以仪仪们们仰仰仲仲件价任任份份仿仿企企伊伊伍伍伏休众会伟传伤伤伦伦伯估伴伴伸伸似伽但但位佑体体何何余余佛作你你佤佤佩佩佳佳使使例例供供依依侠侠侦侨侬侬侯侯侵侵便便促俄俊俊俗俗保保信信俩俩修修俱俱俾俾倍倍倒倒候倚借借倦倦值值倾倾假假偌偌偏偏做做停停健健偶偷储储催催傲傲傻傻像像僧僧儒儒儿儿允允元兆先光克克免免兑兑兔兔党党入入全全八兮兰共关兹养兽内内冈冈册再冒冒写写军农冠冠冬冬冰冰冲决况况冷冷准准凌凌减减凝凝几凡凤凤凭凭凯凰出击函函刀刀分切刊刊刑划列创初初判判利利别别到到制券刺刻剂剂前前剑剑剧剧剩剪副副割割力力劝务劣劣动劫励劳势势勇勇勉勉勋勋勒勒勤勤勾勿包匆匈匈化北匙匙匹医十十千千升午半半华协卒卓单南博博占卢卫卫卯危即却卷卷厂厂厄历厉厉压厍厚厚原原去去县县参参又反发发叔叔取叙口另只叭可台史右叶叹吃各合吊同后吐向吓吓吗吗君君吝吝吟吟否吧含听启启吵吵吸吹吻吻吾吾呀呀呆呆呈呈告告呐呐员员呜呜呢呢呦呦周周味味呵呵呼命和和咖咖咦咨咪咪咬咬咯咯咱咱哀品哇哉响哎哟哟哥哦哩哪哭哭哲哲唉唉唐唐唤唤唬唬售唯唱唱唷唷商商啊啊啡啡啥啦啪啪喀喀喂喂善善喇喇喊喊喏喏喔喔喜喝喵喵喷喷喻喻嗒嗒嗨嗨嗯嗯嘉嘉嘛嘛嘴嘴嘻嘻嘿嘿器器四四回回因因团团园园困困围围固固国图圆圆圈圈土土圣圣在在圭圭地地圳圳场场圾圾址址均均坎坎坐坑块块坚坜坡坡坤坤坦坦坪坪垂垃型型垒垒埃埃埋埋城城埔埔域域培基堂堂堆堆堕堕堡堡堪堪塑塑塔塔塞塞填填境境增增墨墨壁壁壤壤士壬壮壮声声处处备备复复夏夏夕外多多夜夜够够夥夥大大天夫央央失失头头夷夺奇奉奋奋奏奏契契奔奔奖套奥奥女奴奶奶她她好好如如妇妈妖妖妙妙妥妥妨妨妮妮妹妹妻妻姆姆姊始姐姑姓委姿姿威威娃娄娘娘娜娜娟娟娱娱婆婆婚婚媒媒嫁嫁嫌嫌嫩嫩子子孔孕字孙孜孝孟孟季孤学学孩孩宁宁它它宇安宋完宏宏宗实审室宪宪害宴家家容容宽宿寂寂寄寇富富寒寒寝察寡寡寨寨寸对寻导寿寿封封射射将将尊尊小小少少尔尔尖尖尘尘尚尚尝尝尤尤就就尺尺尼尾局层居居屋屋屏屏展展属属屠屠山山岁岂岗岘岚岛岳岳岸岸峡峡峰峰崇崇崩崩崴崴川州巡巡工巨巫巫差差己巴巷巷币布帅帅师师希希帐帐帕帖帝帝带带席帮常常帽帽幅幅幕幕干年并并幸幸幻幽广广庆庆床床序序库底店店庙庚府府庞废度座庭庭康庸廉廉廖廖延廷建建开开异弄弊弊式式引引弗弘弟张弥弦弯弯弱弱弹强归当录录彝彝形形彩彩彬彭彰影彷彷役役彻彼往征径待很很律後徐徐徒徒得得循循微微徵徵德德心心必忆忌忍志忙忠忠忧忧快快念念忽忽怀态怎怎怒怒怕怖思思怡怡急急性怨怪怪总总恋恋恐恐恢恢恨恩恭恭息恰恶恶恼恼悄悄悉悉悔悔悟悠患患您您悲悲情情惑惑惜惜惠惠惧惨惯惯想想惹惹愁愁愈愉意意愚愚感感愧愧慈慈慎慎慕慕慢慢慧慧慰慰憾憾懂懂懒懒戈戈戊戊戌戌戏戒或或战战截截戴戴户户房扁扇扇手手才扎扑扑打打托托扣扣执执扩扩扫扯批批找技抄抄把把抑抑抓抓投投抗折抢抢护报披抬抱抱抵抵抹抹抽抽担拆拉拉拍拍拒拒拔拔拖拖拘拘招拜拟拟拥拦拨择括括拳拳拷拷拼拼拾拿持持指指按按挑挑挖挖挝挝挡挡挤挥挪挪振振挺挺捉捉捐捐捕捕损损捡换据据捷捷授掉掌掌排排探探接接控措掸掸描提插插握握援援搜搜搞搞搬搭摄摄摆摆摊摊摔摔摘摘摩摩摸摸撒撒撞撞播播操擎擦擦支支收收改改攻攻放政故故效效敌敌敏敏救救教教敝敝敢散敦敦敬敬数数敲敲整整文文斋斋斐斐斗斗料料斜斜斥斥断断斯新方方於施旁旁旅旅旋旋族族旗旗无无既既日早旭旭时时旺旺昂昂昆昆昌昌明昏易易星映春春昨昨昭昭是是显显晃晃晋晋晒晓晚晚晨晨普景晴晴晶晶智智暂暂暑暑暖暗暮暮暴暴曰曰曲曲更更曹曹曼曼曾最月有朋朋服服朗朗望望朝朝期期木木未札术术朱朱朵朵机机杀杀杂权杉杉李李材村杜杜束束条条来来杨杨杯杰松板极极构构析析林林果枝枢枢枪枫架架柏某染柔查查柬柬柯柯柳柴标标栋栋栏栏树树校校样根格格桃桃框框案案桌桌桑桑档档桥桥梁梁梅梅梦梦梯械梵梵检检棉棉棋棋棒棒棚棚森森椅椅植植椰椰楚楚楼楼概概榜榜模模樱樱檀檀欠欣欧欧欲欲欺欺款款歉歉歌歌止武歪歪死死殊残段段毅毅母母每每毒毒比毕毛毛毫毫氏氏民民气气氛氛水水永永求求汇汇汉汉汗汗汝汝江污汤汤汪汪汶汶汽汽沃沃沈沉沙沙沟沟没没沧沧河河油油治治沿沿泉泊法法泛泛泡泣泥泥注注泰泰泳泳泽泽洋洋洗洗洛洛洞洞津津洪洪洲洲活活洽派流流浅浅测测济浏浑浑浓浓浙浙浦浦浩浪浮浮浴浴海海涅涅消涉涛涛涨涨涯涯液液涵涵淋淋淑淑淘淘淡淡深深混混添添清清渐渐渡渡渣渣温温港港渴渴游游湖湖湾湾源源溜溜溪溪滋滋滑滑满满滥滥滨滨滴滴漂漂漏漏演演漠漠漫漫潘潘潜潜潮潮澎澎澳澳激激灌灌火火灭灭灯灰灵灵灿灿炉炉炎炎炮炮炸点烂烂烈烈烤烤烦烧热热焦焦然然煌煌煞煞照照煮煮熊熊熟熟燃燃燕燕爆爆爪爪爬爬爱爱爵爸爽爽片版牌牌牙牙牛牛牡牢牧牧物物牲牲牵牵特牺犯犯状状犹犹狂狂狐狐狗狗狠狠独独狮狮狱狱狼狼猛猜猪猪献献猴猴玄玄率率玉玉王王玛玛玩玩玫玫环现玲玲玻玻珀珀珊珊珍珍珠珠班班球球理理琊琊琪琪琳琴琼琼瑙瑙瑜瑜瑞瑟瑰瑰瑶瑶璃璃瓜瓜瓦瓦瓶瓶甘甘甚甚甜甜生生用用田申电电男甸画画畅畅界界留留略略番番疆疆疏疏疑疑疗疗疯疯疲疲疼疼疾疾病病痕痕痛痛痴痴癸癸登登白百的的皆皇皮皮盈盈益益监盒盖盖盘盘盛盛盟盟目目直直相相盼盼盾盾省省眉眉看看真眠眼眼着着睛睛睡睡督督瞧瞧矛矛矣矣知知短短石石矶矶码砂砍砍研研破破础础硕硕硬硬确确碍碎碗碗碟碟碧碧碰碰磁磁磅磅磨磨示示礼礼社社祖祖祚祚祝神祥祥票票祯祯祸祸禁禁禅禅福福离离秀私秋秋种种科秒秘秘租租秤秤秦秦秩秩积称移移稀稀程程稍税稣稣稳稳稿稿穆穆究穷穹空穿穿突突窗窗窝窝立立站站竞章童童端端竹竹笑笑笔笔笛笛符符笨笨第第等等筋筋筑筑答答策策筹筹签签简简算算管管箭箭箱箱篇篇篮篮簿簿籍籍米米类类粉粉粒粒粗粗粤粤粹粹精精糊糊糕糖糟糟系系素素索索紧紧紫紫累累繁繁红红约级纪纪纯纯纲纳纵纵纷纸纽纽线线练组细终绍绍经经结结绕绕绘给络绝统统继继绩绪续续维绵综综绿绿缅缅缓缓编编缘缘缠缠缩缩缴缴缶缶缸缸缺缺罐网罕罕罗罗罚罚罢罢罪罪置置署署羊羊美美羞羞群群羯羯羽羽翁翁翅翅翔翔翘翘翠翠翰翰翻翼耀老考考者者而耍耐耐耗耗耳耳耶耶聊聊职职联联聘聘聚聚聪聪肉肉肖肖肚肚股股肤肥肩肩肯肯育育胁胁胆胆背背胎胎胖胖胜胜胞胞胡胡胶胶胸胸能能脆脆脑脑脱脱脸脸腊腊腐腐腓腓腰腰腹腹腾腿臂臂臣臣自自臭臭至致舌舍舒舒舞舟航航般般舰舰船船良良色色艺艺艾艾节节芒芒芝芝芦芦芬芭花花芳芳苍苍苏苏苗苗若苦英英茂范茨茨茫茫茶茶草草荐荐荒荒荣荣药药荷荷莉莉莎莎莪莫莱莲获获菜菜菩菩菲菲萄萄萍萍萤营萧萨落落著著葛葛葡葡蒂蒂蒋蒋蒙蒙蓉蓉蓝蓝蓬蓬蔑蔑蔡蔡薄薄薪薪藉藉藏藏藤藤虎虎虑虑虫虫虹虹虽虾蚁蚁蛇蛇蛋蛋蛙蛙蛮蛮蜂蜂蜜蜜蝶蝶融融蟹蟹蠢蠢血血行行街街衡衡衣衣补补表表袋袋被被袭袭裁裂装装裕裕裤裤西西要要覆覆见观规规视视览觉角角解解言言誉誉誓誓警警计订认认讨让训记讲讲讷许论论设访证证评评识识诉诉词词译译试试诗诗诚诚话诞询询该详语语误误说说请诸诺读课课谁谁调调谅谅谈谈谊谋谓谓谜谜谢谢谨谨谱谱谷谷豆豆象象豪豪貌貌贝负贡败货贪购购贯贯贱贱贴贵贸贺贼贼贾贾资资赋赌赏赐赔赔赖赖赚赛赞赞赠赠赢赢赤赤赫赫走走赵赵起起趁趁超超越趋趣趣足足跃跃跌跌跑跑距距跟跟路路跳跳踏踏踢踢踩踩身身躲躲车车轨轩转转轮轰轻轻载载较较辅辆辈辉辑辑输输辛辛辞辞辨辩辰辱边边达达迁迁迅迅过迈迎迎运近返返还这进迟迦迦迪迫述述迷迷追追退逃逆逆选逊透逐递递途途通逛逝逝速造逢逢逸逸逻逼遇遇遍遍道道遗遗遭遮遵遵避邀邓邓那那邦邦邪邪邮邮邱邱邻邻郎郎郑郑部部郭郭都都鄂鄂酉酉酋酋配配酒酒酷酸醉醉醒醒采采释释里量金金针针钓钓钟钟钢钢钦钦钱钱钻钻铁铁铃铃铜铜铢铢铭铭银银铺铺链链销锁锅锅锋锋错错锡锡锦锦键键锺锺镇镇镜镜镭镭长长门门闪闪闭问闰闰闲闲间间闷闷闹闹闻闻阁阁阅阅阐阐阔阔队队阮阮防阶阻阻阿陀附陆陈陈降降限限院院除除险陪陵陷隆隆随隐隔隔障障难难雄集雉雉雨雨雪雪雯雯雳雳零雷雾雾需需震震霍霍霖霖露露霸霹青青靖靖静静非非靠靠面面革革靼靼鞋鞋鞑鞑韦韦韩韩音音页顶项须顽顿预预领颇频频颗题额额风风飘飙飞食餐餐饭饮饰饱饼饼馆馆首首香香馨馨马马驱驱驶驶驻驻驾驾验验骑骑骗骗骚骚骤骤骨骨高高鬼鬼魂魂魅魅魔魔鱼鱼鲁鲁鲜鲜鸟鸟鸡鸡鸣鸣鸭鸭鸿鸿鹅鹅鹤鹤鹰鹰鹿鹿麦麦麻麻黄黄黎黎黑黑默默鼓鼓鼠鼠鼻鼻齐齐齿齿龄龄龙龙龟龟"),
+    ("zh-Hant", 2179, "一丁七七丈不丑丑且且世世丘丙丟丟並並中中串串丸丹主主乃乃久久么么之之乎乏乖乖乘乙九九也也乾乾亂亂了了予予事二于于云互五井些些亞亞亡亡交亦亨亨享京亮亮人人什仁仇仇今介仍仍仔仔他他付仙代以仰仰仲仲件件任任份份企企伊伊伍伍伐休伙伙伯估伴伴伸伸似伽但但佈佉位住佔何余余佛作你你佩佩佳佳使使來來例例供供依依侯侯侵侵便便係俄俊俊俗俗保保俠信修修俱俱俾俾個個倍倍們倒候倚借借倫倫值值假假偉偉偏偏做做停停健健側偷傑傑備備傢傣傲傳傷傷傻傻傾傾僅僅像像僑僑僧僧價價儀儀億億儒儒儘儘優優允允元充兇光克克免免兒兒兔兔入入內兩八兮共共兵典兼兼冊冊再再冒冒冠冠冬冬冰冰冷冷准准凌凌凝凝凡凡凰凱出出函函刀刀分切刊刊列列初初判別利刪到到制刷刺刻則則剌前剛剛剩剪副副割割創創劃劃劇劇劉劉劍劍力力功加助劫勁勁勇勇勉勉勒勒動動務務勝勞勢勢勤勤勵勵勸勸勿勿包包匈匈化北匹匹區十千千升午半半卒協南南博博卜卜卡卡卯危即即卷卷卻卻厄厄厘厘厚厚原原厭厭厲厲去去參參又又及友反反叔叔取受口另只叭可台史右司司吃各合吊同后吐吒君君吝吠否吧含含吳吳吵吵吸吹吾吾呀呀呂呂呆呆告告呢呢周周味味呵呵呼命和和咖咖咦咧咪咪咬咬咱咱哀品哇哉哎哎員員哥哦哩哪哭哭哲哲唉唉唐唐唔唔唬唬售唯唱唱唷唸商商啊啊問問啟啟啡啡啥啦啪啪喀喀喂喂善善喇喇喊喊喔喔喜喝喬喬單單喵喵嗎嗎嗚嗚嗨嗨嗯嗯嘆嘆嘉嘉嘗嘗嘛嘛嘴嘴嘻嘻嘿嘿器器噴噴嚇嚇嚴嚴囉囉四四回回因因困困固固圈圈國國圍圍園圓圖圖團團圜圜土土在在圭圭地地圾圾址址均均坎坎坐坐坡坡坤坤坦坦坪坪垂垃型型埃埃城城埔埔域域執執培基堂堂堅堆堡堡堪堪報報場場塊塊塔塔塗塗塞塞填填塵塵境境增增墨墨墮墮壁壁壇壇壓壓壘壘壞壞壢壢士壬壯壯壽壽夏夏夕外多多夜夜夠夠夢夢夥夥大大天夫央央失失夷夸夾夾奇奉奎奏契契奔奔套套奧奧奪奪奮奮女奴奶奶她她好好如如妙妙妝妝妥妥妨妨妮妮妳妳妹妹妻妻姆姆姊始姐姑姓委姿姿威威娃娃娘娘娛娛婁婁婆婆婚婚婦婦媒媒媽媽嫌嫌嫩嫩子子孔孔字存孝孝孟孟季孤孩孩孫孫學學它它宅宅宇安宋完宏宏宗宜客室宮宮害害家家容容宿宿寂寂寄密富富寒寒寞察寢寢實審寫寬寮寮寵寶封封射射將專尊尋對小少少尖尖尚尚尤尤就就尺尺尼尼尾尾局屁居屆屋屋屏屏展展屠屠層層屬屬山山岡岡岩岩岸岸峰峰島島峽峽崇崇崙崙崴崴嵐嵐嶺嶺川州巡巡工巨巫巫差差己巴巷巷市布希希帕帖帛帛帝帝帥帥師師席席帳帳帶帶常常帽帽幅幅幕幕幣幣幫幫干年幸幹幻幾庇庇床床序序底底店店庚庚府府度座庫庫庭庭康庸廉廉廖廖廠廠廢廣廳廳延廷建建弄弄式式引引弗弘弟弟弦弦弱弱張張強強彈彈彊彊彌彌彎彎彝彞形形彥彥彩彩彬彭彰影役役彼彼往征待待很很律後徐徒得得從從復復微微徵徵德德徹徹心心必必忌忍志忙忠忠快快念念忽忽怎怎怒怒怕怖思思怡怡急急性怨怪怪恆恆恐恐恢恢恥恥恨恩恭恭息恰悅悅悉悉悔悔悟悠您您悲悲悶悶情情惑惑惜惜惠惡惱惱想想惹惹愁愁愈愉意意愚愛感感慈慈態態慕慕慘慘慢慣慧慧慮慮慰慰慶慶慾慾憂憂憐憑憲憲憶憶憾憾懂懂應應懶懷懼懼戀戀戈戈戊戊戌戌成戒或或截截戰戰戲戲戴戴戶戶房扁扇扇手手才扎打打托托扣扣扥扥扭扭扯扯批批找技抄抄把把抓抓投投抗折披抬抱抱抵抵抹抹抽抽拆拆拉拉拋拋拍拍拏拏拒拒拔拔拖拖招拜括括拳拳拼拼拾拿持持指指按按挑挑挖挖挪挪振振挺挺捐捐捕捕捨捨捲捲捷捷掃掃授掉掌掌排排掛掛採探接接控推措措描提插插揚換握握揮揮援援損損搖搖搜搜搞搞搬搭搶搶摘摘摩摩摸摸撐撐撒撒撞撞撣撣撥撥播播撾撿擁擁擇擇擊擋操擎擔擔據據擠擠擦擦擬擬擴擴擺擺擾擾攝攝支支收收改改攻攻放政故故效效敍敍敏敏救救敗教敝敝敢散敦敦敬敬整敵數數文文斐斐斗斗料料斯新斷斷方方於施旁旁旅旅旋旋族族旗旗既既日旦早早旭旭旺旺昂昂昆昇昌昌明昏易易星映春春昨昨昭昭是是時時晉晉晒晒晚晚晨晨普景晴晴晶晶智智暑暑暖暗暫暫暴暴曆曆曉曉曰曰曲曲更更書書曼曼曾最會會月有朋朋服服朗朗望望朝朝期期木木未札朱朱朵朵杉杉李李材村杜杜束束杯東松板析析林林果枝架架柏某染柔查查柬柬柯柯柳柴校校核根格格桃桃案案桌桌桑桑梁梁梅梅條條梨梨梯械梵梵棄棄棉棉棋棋棒棒棚棚森森椅椅植植椰椰楊楊楓楓楚楚業業極極概概榜榜榮榮構構槍槍樂樂樓樓標標樞樞模模樣樣樹樹橋橋機機橫橫檀檀檔檔檢檢欄欄權權次次欣欣欲欲欺欺欽款歉歉歌歌歐歐歡武歲歲歷歸死死殊殊殘殘段段殺殺殼殼毀毀毅毅母母每每毒毒比比毛毛毫毫氏氏民民氣氣水水永永求求汗汗汝汝江污汪汪汶汶決決汽汽沃沃沈沉沒沒沖沖沙沙河河油油治治沿沿況況泉泊法法泡波泥泥注注泰泰泳泳洋洋洗洗洛洛洞洞洩洪洲洲活活洽派流流浦浦浩浪浮浮海海涇涉涯涯液液涵涵涼涼淑淑淚淚淡淡淨淨深深混混淺淺清清減減渡渡測測港港游游湖湖湯湯源源準準溝溝溪溫滄滅滋滋滑滑滴滴滾滿漂漂漏漏演演漠漠漢漢漫漫漲漲漸漸潔潔潘潘潛潛潮潮澤澤澳澳激激濃濃濟濟濤濤濫濫濱濱瀏瀏灌灌灣灣火火灰灰災災炎炎炮炮炸炸為為烈烈烏烏烤烤無無焦焦然然煙煙煞煞照照煩煩熊熊熟熟熱熱燃燃燈燈燒燒營營爆爆爐爐爛爛爪爪爬爭爵父爸爸爺爺爽爾牆版牌牌牙牙牛牛牠牠牧牧物物牲牲特特牽牽犧犧犯犯狀狀狂狂狐狐狗狗狠狠狼狼猛猜猴猴猶猶獄獅獎獎獨獨獲獲獸獸獻獻玄玄率率玉玉王王玩玩玫玫玲玲玻玻珊珊珍珍珠珠珥珥班班現現球球理理琉琉琪琪琴琴瑙瑙瑜瑜瑞瑟瑤瑤瑪瑪瑰瑰環環瓜瓜瓦瓦瓶瓶甘甘甚甚甜甜生生產產用用田申男甸界界留留畢畢略略番畫異異當當疆疆疏疏疑疑疼疼病病痕痕痛痛痴痴瘋瘋療療癡癡癸癸登百的的皆皇皮皮盃盃益益盛盜盟盟盡盡監盤盧盧目目盲盲直直相相盼盼盾盾省省眉眉看看真眠眼眼眾眾睛睛睡睡督督瞧瞧瞭瞭矛矛矣矣知知短短石石砂砂砍砍研研砲砲破破硬硬碎碎碗碗碟碟碧碧碩碩碰碰確確碼碼磁磁磨磨磯磯礎礎礙礙示示社社祕祖祚祛祝神祥祥票票祿祿禁禁禍福禪禪禮禮秀私秋秋科秒秘秘租租秤秤秦秦移移稅稅程程稍稍種種稱稱稿稿穆穆穌積穩穩究究穹空穿穿突突窗窗窩窩窮窮窶窶立立站站竟章童童端端競競竹竹笑笑笛笛符符笨笨第第筆筆等等筋筋答答策策算算管管箭箭箱箱節節範範篇篇築築簡簡簫簫簽簽簿簿籃籃籌籍籤籤米米粉粉粗粗粵粵精精糊糊糕糕糟糟系系糾糾紀紀約紅納納紐紐純純紙紛素素索索紫紫累細紹紹終終組組結結絕絕絡絡給給統絲經經綜綜綠綠維維綱網緊緊緒緒線線緣緣編緩緬緬緯緯練練縛縛縣縣縮縮縱縱總績繁繁繆繆織織繞繞繪繪繳繳繼繼續續缸缸缺缺罕罕罪罪置置罰罰署署罵罵罷罷羅羅羊羊美美羞羞群群義義羽羽翁翁習習翔翔翰翰翹翹翻翼耀老考考者者而耍耐耐耗耗耳耳耶耶聊聊聖聖聚聚聞聞聯聰聲聲職職聽聽肉肉肚肚股股肥肥肩肩肯肯育育背背胎胎胖胖胞胞胡胡胸胸能能脆脆脫脫腓腔腦腦腰腰腳腳腿腿膽膽臉臉臘臘臣臣臥臥臨臨自自臭臭至致臺臺與舊舌舍舒舒舞舟航航般般船船艦艦良良色色艾艾芝芝芬芬花花芳芳若苦英英茅茅茫茫茲茲茶茶草草荒荒荷荷荼荼莉莊莎莎莫莫菜菜菩菩華華菲菲萄萄萊萊萬萬落落葉葉著著葛葛葡葡蒂蒂蒙蒙蒲蒲蒼蒼蓋蓋蓮蓮蔕蔕蔡蔡蔣蔣蕭蕭薄薄薦薦薩薪藉藉藍藍藏藏藝藝藤藥蘆蘇蘭蘭虎虎處處虛虛號號虧虧蛇蛇蛋蛋蛙蛙蜂蜂蜜蜜蝶蝶融融螢螢蟲蟲蟹蟹蠍蠍蠻蠻血血行行術術街街衛衛衝衝衡衡衣衣表表袋袋被被裁裂裕裕補裝裡裡製製複複褲褲西西要要覆覆見見規規視視親親覺覺覽覽觀觀角角解解觸觸言言訂訂計計訊訊討討訓訓託記訥訥訪訪設設許許訴訴註証評評詞詞詢詢試試詩詩話詳誇誇誌認誓誓誕誕語語誠誠誤誤說說誰誰課課誼誼調調談談請請諒諒論論諸諸諺諺諾諾謀謀謂謂講講謝謝證證識識譜譜警警譯議護護譽譽讀讀變變讓讓讚讚谷谷豆豆豈豈豐豐象象豪豪豬豬貌貌貓貓貝貞負貢貨貨貪責貴貴買買費貼賀賀資賈賓賓賜賜賞賞賢賤賦賦質質賭賭賴賴賺賺購賽贈贈贊贊贏贏赤赤赫赫走走起起超超越越趕趕趙趙趣趣趨趨足足跌跌跎跎跑跑距距跟跟跡跡路路跳跳踏踏踢踢蹟蹟蹤蹤躍躍身身躲躲車車軌軍軒軒軟軟較較載載輔輕輛輛輝輝輩輪輯輯輸輸轉轉轟轟辛辛辦辦辨辨辭辭辯農迅迅迎迎近近返返迦迦迪迫述述迴迴迷迷追追退送逃逃逆逆透逐途途這逛逝逝速造逢連週進逸逸逼逼遇遇遊運遍過道違遙遙遜遜遠遠適適遭遮遲遲遷選遺遺避邁還還邊邊邏邏那那邦邦邪邪邱邱郎郎部部郭郭郵郵都都鄂鄂鄉鄉鄭鄭鄰鄰酉酉配配酒酒酷酸醉醉醒醒醜醜醫醫采采釋量金金針針釣釣鈴鈴鉢鉢銀銀銅銅銖銖銘銘銳銳銷銷鋒鋒鋼鋼錄錄錢錢錦錦錫錫錯錯鍋鍋鍵鍵鍾鍾鎊鎊鎖鎖鎮鎮鏡鏡鐘鐘鐵鐵鑑鑑長長門門閃閃閉閉開開閏閏閒間閣閣閱閱闆闆闊闊闍闍闐闐關關闡闡防防阻阻阿陀附附降降限限院除陪陪陰陰陳陳陵陸陽陽隆隆隊隊階階隔隔際障隨隨險險隱隱隻隻雄集雉雉雖雖雙雙雜雜雞雞離難雨雨雪雪雲雲零雷電電需需震震霍霍霧霧露露霸霹靂靂靈靈青青靖靖靜靜非非靠靠面面革革靼靼鞋鞋韃韃韋韋韓韓音音韻韻響響頁頂項順須須預頑頓頓頗領頞頞頭頭頻頻顆顆題額顏顏願願類類顧顧顯顯風風飄飄飛飛食食飯飯飲飲飽飾餅餅養養餐餐餘餘館館首首香香馬馬駐駐駕駕駛駛騎騎騙騙騷騷驅驅驗驗驚驚骨骨體體高高髮髮鬆鬆鬥鬥鬧鬧鬱鬱鬼鬼魁魂魅魅魔魔魚魚魯魯鮮鮮鳥鳥鳳鳴鴻鴻鵝鵝鷹鷹鹿鹿麗麗麥麥麵麵麻麼黃黃黎黎黑黑默默點點黨黨鼓鼓鼠鼠鼻鼻齊齋齒齒齡齡龍龍龜龜"),
+];
+
+/// Languages with the same exemplar characters as another language, as
+/// (BCP 47 tag, tag in [`LANGUAGES`]).
+pub const LANGUAGE_ALIASES: &[(&str, &str)] = &[
+    ("az-Latn", "az"),
+    ("bs-Latn", "bs"),
+    ("dav", "asa"),
+    ("en", "cgg"),
+    ("ff-Latn", "ff"),
+    ("guz", "asa"),
+    ("hi-Latn", "cgg"),
+    ("hr", "bs"),
+    ("ia", "cgg"),
+    ("id", "cgg"),
+    ("jmc", "asa"),
+    ("kde", "cgg"),
+    ("khq", "dje"),
+    ("kl", "da"),
+    ("ks-Arab", "ks"),
+    ("kw", "cgg"),
+    ("luy", "cgg"),
+    ("mer", "ebu"),
+    ("mgh", "asa"),
+    ("mni-Beng", "mni"),
+    ("ms", "cgg"),
+    ("mzn", "fa"),
+    ("ne", "hi"),
+    ("nn", "nb"),
+    ("no", "nb"),
+    ("nyn", "cgg"),
+    ("om", "cgg"),
+    ("pa-Guru", "pa"),
+    ("rn", "cgg"),
+    ("rof", "asa"),
+    ("ru", "ce"),
+    ("rw", "cgg"),
+    ("rwk", "asa"),
+    ("sa", "doi"),
+    ("sat-Olck", "sat"),
+    ("sd-Arab", "sd"),
+    ("seh", "pt"),
+    ("ses", "dje"),
+    ("shi-Tfng", "shi"),
+    ("sn", "asa"),
+    ("sr", "bs-Cyrl"),
+    ("sr-Cyrl", "bs-Cyrl"),
+    ("sr-Latn", "bs"),
+    ("su-Latn", "su"),
+    ("twq", "dje"),
+    ("tzm", "shi-Latn"),
+    ("uz-Latn", "uz"),
+    ("vai-Vaii", "vai"),
+    ("vun", "asa"),
+    ("xh", "cgg"),
+    ("xog", "cgg"),
+    ("yue-Hant", "yue"),
+    ("zgh", "shi"),
+    ("zh-Hans", "zh"),
+    ("zu", "cgg"),
+];
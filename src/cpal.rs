@@ -83,7 +83,7 @@ impl<'a> Cpal<'a> {
             _ => Theme::Any,
         };
         let len = d.read::<u16>(2)? as usize;
-        let first = d.read_u32(12 + index as usize * 2)? as usize;
+        let first = d.read_u16(12 + index as usize * 2)? as usize;
         let offset = self.offset as usize + first;
         let colors = d.read_slice(offset, len)?;
         Some(Palette {
@@ -2,22 +2,26 @@ use super::parse_prelude::*;
 
 use super::{
     avar::*,
+    cff::*,
     cmap::*,
     colr::{Colr, COLR},
     cpal::*,
     fvar::*,
     gdef::*,
+    glyf::*,
     gpos::*,
     gsub::*,
     head::*,
     hhea::*,
     hmtx::*,
     hvar::*,
+    ltag::*,
     math::*,
     maxp::*,
     name::*,
     os2::*,
     post::*,
+    var::instance::Variations,
     vhea::*,
     vmtx::*,
     vorg::*,
@@ -30,6 +34,8 @@ const FONT: Tag = Tag(0x10000);
 const DFNT: Tag = Tag::new(&[0, 0, 1, 0]);
 const TRUE: Tag = Tag::new(b"true");
 const SFNT: Tag = Tag::new(b"sfnt");
+const WOFF: Tag = Tag::new(b"wOFF");
+const WOFF2: Tag = Tag::new(b"wOF2");
 
 /// Kind of a font.
 #[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
@@ -60,6 +66,12 @@ pub enum FontDataKind {
     Collection,
     /// Resource fork.
     ResourceFork,
+    /// Font compressed with WOFF 1.0. The data must be decoded to an sfnt
+    /// before the fonts can be accessed.
+    Woff,
+    /// Font or collection compressed with WOFF 2.0. The data must be decoded
+    /// to an sfnt before the fonts can be accessed.
+    Woff2,
 }
 
 impl FontDataKind {
@@ -68,6 +80,8 @@ impl FontDataKind {
         Some(match Buffer::new(data).read_tag(offset as usize)? {
             TTCF => Self::Collection,
             DFNT => Self::ResourceFork,
+            WOFF => Self::Woff,
+            WOFF2 => Self::Woff2,
             _ => Self::Font(FontKind::parse(data, offset)?),
         })
     }
@@ -168,6 +182,19 @@ impl<'a> FontRef<'a> {
         })
     }
 
+    /// Returns the data for the table with the specified tag or an error if
+    /// the table is not present or its record extends beyond the end of the
+    /// font data. Out of bounds errors report the offset of the table record
+    /// from the beginning of the font data.
+    pub fn try_table_data(&self, tag: Tag) -> Result<&'a [u8], ReadError> {
+        let record = self.find_record(tag).ok_or(ReadError::missing_table(tag))?;
+        self.data.get(record.data_range()).ok_or_else(|| {
+            let index = self.records().iter().position(|r| r.tag == tag);
+            let offset = self.offset as usize + 12 + index.unwrap_or(0) * 16;
+            ReadError::out_of_bounds(offset).with_tag(tag)
+        })
+    }
+
     /// Returns the table for the specified tag.
     pub fn find_table(&self, tag: Tag) -> Option<Table<'a>> {
         let record = self.find_record(tag)?;
@@ -227,6 +254,12 @@ pub trait TableProvider<'a> {
     /// Returns the data for the table with the specified tag.
     fn table_data(&self, tag: Tag) -> Option<&'a [u8]>;
 
+    /// Returns the data for the table with the specified tag or an error if
+    /// the table is not present.
+    fn try_table_data(&self, tag: Tag) -> Result<&'a [u8], ReadError> {
+        self.table_data(tag).ok_or(ReadError::missing_table(tag))
+    }
+
     /// Returns the font header table.
     fn head(&self) -> Option<Head<'a>> {
         Some(Head::new(self.table_data(HEAD)?))
@@ -286,16 +319,42 @@ pub trait TableProvider<'a> {
         Some(Vvar::new(self.table_data(VVAR)?))
     }
 
+    /// Returns the index to location table.
+    fn loca(&self) -> Option<Loca<'a>> {
+        let format = self.head()?.index_to_location_format();
+        Some(Loca::new(self.table_data(LOCA)?, format))
+    }
+
+    /// Returns the glyph data table.
+    fn glyf(&self) -> Option<Glyf<'a>> {
+        Some(Glyf::new(self.table_data(GLYF)?))
+    }
+
     /// Returns the naming table.
     fn name(&self) -> Option<Name<'a>> {
         Some(Name::new(self.table_data(NAME)?))
     }
 
+    /// Returns the language tag table.
+    fn ltag(&self) -> Option<Ltag<'a>> {
+        Some(Ltag::new(self.table_data(LTAG)?))
+    }
+
     /// Returns the character mapping table.
     fn cmap(&self) -> Option<Cmap<'a>> {
         Some(Cmap::new(self.table_data(CMAP)?))
     }
 
+    /// Returns the character map for the preferred Unicode subtable.
+    fn charmap(&self) -> Option<Charmap<'a>> {
+        Some(Charmap::new(&self.cmap()?))
+    }
+
+    /// Returns the compact font format table.
+    fn cff(&self) -> Option<Cff<'a>> {
+        Some(Cff::new(self.table_data(CFF)?))
+    }
+
     /// Returns the font variations table.
     fn fvar(&self) -> Option<Fvar<'a>> {
         Some(Fvar::new(self.table_data(FVAR)?))
@@ -306,6 +365,14 @@ pub trait TableProvider<'a> {
         Some(Avar::new(self.table_data(AVAR)?))
     }
 
+    /// Returns the variation axes and named instances.
+    fn variations(&self) -> Option<Variations<'a>>
+    where
+        Self: Sized,
+    {
+        Variations::new(self)
+    }
+
     /// Returns the color palette table.
     fn cpal(&self) -> Option<Cpal<'a>> {
         Some(Cpal::new(self.table_data(CPAL)?))
@@ -335,18 +402,98 @@ pub trait TableProvider<'a> {
     fn math(&self) -> Option<Math<'a>> {
         Some(Math::new(self.table_data(MATH)?))
     }
+
+    /// Returns the font header table or an error if it is missing or
+    /// truncated.
+    fn try_head(&self) -> Result<Head<'a>, ReadError> {
+        Head::try_new(self.try_table_data(HEAD)?)
+    }
+
+    /// Returns the PostScript table or an error if it is missing, truncated
+    /// or has an unsupported version.
+    fn try_post(&self) -> Result<Post<'a>, ReadError> {
+        Post::try_new(self.try_table_data(POST)?)
+    }
+
+    /// Returns the maximum profile table or an error if it is missing,
+    /// truncated or has an unsupported version.
+    fn try_maxp(&self) -> Result<Maxp<'a>, ReadError> {
+        Maxp::try_new(self.try_table_data(MAXP)?)
+    }
+
+    /// Returns the OS/2 and Windows metrics table or an error if it is
+    /// missing or truncated.
+    fn try_os2(&self) -> Result<Os2<'a>, ReadError> {
+        Os2::try_new(self.try_table_data(OS2)?)
+    }
+
+    /// Returns the horizontal header table or an error if it is missing or
+    /// truncated.
+    fn try_hhea(&self) -> Result<Hhea<'a>, ReadError> {
+        Hhea::try_new(self.try_table_data(HHEA)?)
+    }
+
+    /// Returns the horizontal metrics table or an error if it or any of the
+    /// tables it depends on are missing or truncated.
+    fn try_hmtx(&self) -> Result<Hmtx<'a>, ReadError> {
+        let num_glyphs = self.try_maxp()?.num_glyphs();
+        let num_metrics = self.try_hhea()?.num_long_metrics();
+        Hmtx::try_new(self.try_table_data(HMTX)?, num_glyphs, num_metrics)
+    }
+
+    /// Returns the vertical header table or an error if it is missing or
+    /// truncated.
+    fn try_vhea(&self) -> Result<Vhea<'a>, ReadError> {
+        Vhea::try_new(self.try_table_data(VHEA)?)
+    }
+
+    /// Returns the vertical metrics table or an error if it or any of the
+    /// tables it depends on are missing or truncated.
+    fn try_vmtx(&self) -> Result<Vmtx<'a>, ReadError> {
+        let num_glyphs = self.try_maxp()?.num_glyphs();
+        let num_metrics = self.try_vhea()?.num_long_metrics();
+        Vmtx::try_new(self.try_table_data(VMTX)?, num_glyphs, num_metrics)
+    }
+
+    /// Returns the index to location table or an error if it or any of the
+    /// tables it depends on are missing or truncated.
+    fn try_loca(&self) -> Result<Loca<'a>, ReadError> {
+        let format = self.try_head()?.index_to_location_format();
+        let num_glyphs = self.try_maxp()?.num_glyphs();
+        Loca::try_new(self.try_table_data(LOCA)?, format, num_glyphs)
+    }
+
+    /// Returns the naming table or an error if it is missing, has an
+    /// unsupported version or contains out of bounds records.
+    fn try_name(&self) -> Result<Name<'a>, ReadError> {
+        Name::try_new(self.try_table_data(NAME)?)
+    }
+
+    /// Returns the character mapping table or an error if it is missing or
+    /// contains out of bounds encoding records.
+    fn try_cmap(&self) -> Result<Cmap<'a>, ReadError> {
+        Cmap::try_new(self.try_table_data(CMAP)?)
+    }
 }
 
 impl<'a> TableProvider<'a> for FontRef<'a> {
     fn table_data(&self, tag: Tag) -> Option<&'a [u8]> {
         self.data.get(self.find_record(tag)?.data_range())
     }
+
+    fn try_table_data(&self, tag: Tag) -> Result<&'a [u8], ReadError> {
+        FontRef::try_table_data(self, tag)
+    }
 }
 
 impl<'a> TableProvider<'a> for &'_ FontRef<'a> {
     fn table_data(&self, tag: Tag) -> Option<&'a [u8]> {
         self.data.get(self.find_record(tag)?.data_range())
     }
+
+    fn try_table_data(&self, tag: Tag) -> Result<&'a [u8], ReadError> {
+        FontRef::try_table_data(self, tag)
+    }
 }
 
 #[derive(Copy, Clone)]
@@ -360,6 +507,7 @@ fn font_count(data: &[u8]) -> u32 {
         match kind {
             FontDataKind::Collection => Buffer::new(data).read_u32(8).unwrap_or(0),
             FontDataKind::ResourceFork => dfont_count(data).unwrap_or(0),
+            FontDataKind::Woff | FontDataKind::Woff2 => 0,
             _ => 1,
         }
     } else {
@@ -418,3 +566,54 @@ fn dfont_range(data: &[u8], index: u32) -> Option<(u32, u32)> {
     }
     None
 }
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::parse::ReadErrorKind;
+    use crate::testing::{TestFont, Writer};
+    use std::vec;
+
+    #[test]
+    fn table_errors() {
+        let data = TestFont::new(3).table(b"hmtx", vec![0; 6]).build();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        assert_eq!(font.try_maxp().unwrap().num_glyphs(), 3);
+        assert_eq!(
+            font.try_hmtx().err(),
+            Some(ReadError::out_of_bounds(6).with_tag(HMTX))
+        );
+        assert_eq!(font.try_vhea().err(), Some(ReadError::missing_table(VHEA)));
+        let maxp = vec![0, 2, 0, 0, 0, 3];
+        let data = TestFont::new(3).table(b"maxp", maxp).build();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        let error = ReadError::unsupported_format(0x20000, 0).with_tag(MAXP);
+        assert_eq!(font.try_maxp().err(), Some(error));
+        assert_eq!(font.try_hmtx().err(), Some(error));
+        assert!(font.try_head().is_ok());
+    }
+
+    #[test]
+    fn cmap_errors() {
+        let mut w = Writer::new();
+        w.u16(0).u16(1).u16(3).u16(1).u32(12);
+        w.u16(6).u16(30).u16(0).u16(0x41).u16(10).u16(1).u16(2);
+        let data = w.finish();
+        let cmap = Cmap::try_new(&data).unwrap();
+        let subtable = cmap.subtables().next().unwrap();
+        // Lenient lookups read the glyphs that are present.
+        assert_eq!(subtable.map(0x41), Some(1));
+        assert_eq!(subtable.map(0x45), None);
+        // Format 6 subtables are not searched by the table itself.
+        assert_eq!(cmap.try_map(0x41), Ok(None));
+        let error = subtable.try_map(0x41).unwrap_err();
+        assert_eq!(error.kind, ReadErrorKind::OutOfBounds);
+        assert_eq!(error.tag, Some(CMAP));
+        let mut data = data;
+        data[11] = 200;
+        assert_eq!(
+            Cmap::try_new(&data).err(),
+            Some(ReadError::out_of_bounds(4).with_tag(CMAP))
+        );
+    }
+}
@@ -101,7 +101,7 @@ impl<'a> Fvar<'a> {
         let coords = b.read_slice::<Fixed>(offset + 4, self.num_axes as usize)?;
         let ps_name_offset = 4 + self.num_axes as usize * 4;
         let postscript_name_id = if ps_name_offset == self.instance_size as usize - 2 {
-            b.read_u16(ps_name_offset)
+            b.read_u16(offset + ps_name_offset)
         } else {
             None
         };
@@ -161,6 +161,17 @@ impl Axis {
         };
         value.min(Fixed::ONE).max(-Fixed::ONE)
     }
+
+    /// Returns the value in 16.16 fixed point format for the specified
+    /// normalized coordinate. This is the inverse of [`normalize`](Self::normalize).
+    pub fn denormalize(&self, coord: Fixed) -> Fixed {
+        let coord = coord.min(Fixed::ONE).max(-Fixed::ONE);
+        if coord < Fixed::ZERO {
+            self.default_value + coord * (self.default_value - self.min_value)
+        } else {
+            self.default_value + coord * (self.max_value - self.default_value)
+        }
+    }
 }
 
 /// Named instance in a variable font.
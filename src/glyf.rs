@@ -0,0 +1,228 @@
+//! Glyph data and index to location tables.
+
+use super::parse_prelude::*;
+use core::ops::Range;
+
+/// Tag for the `glyf` table.
+pub const GLYF: Tag = Tag::new(b"glyf");
+
+/// Tag for the `loca` table.
+pub const LOCA: Tag = Tag::new(b"loca");
+
+/// Index to location table.
+///
+/// <https://docs.microsoft.com/en-us/typography/opentype/spec/loca>
+#[derive(Copy, Clone)]
+pub struct Loca<'a> {
+    data: Buffer<'a>,
+    is_long: bool,
+}
+
+impl<'a> Loca<'a> {
+    /// Creates a new index to location table from a byte slice containing
+    /// the table data and the index to location format from the `head`
+    /// table.
+    pub fn new(data: &'a [u8], index_to_location_format: i16) -> Self {
+        Self {
+            data: Buffer::new(data),
+            is_long: index_to_location_format != 0,
+        }
+    }
+
+    /// Creates a new index to location table from a byte slice containing
+    /// the table data, the index to location format from the `head` table
+    /// and the number of glyphs from the `maxp` table, returning an error if
+    /// the format is not supported or the table is too short.
+    pub fn try_new(
+        data: &'a [u8],
+        index_to_location_format: i16,
+        num_glyphs: u16,
+    ) -> Result<Self, ReadError> {
+        let entry_size = match index_to_location_format {
+            0 => 2,
+            1 => 4,
+            format => {
+                return Err(ReadError::unsupported_format(format as u32, 0).with_tag(LOCA));
+            }
+        };
+        let len = (num_glyphs as usize + 1) * entry_size;
+        if data.len() < len {
+            return Err(ReadError::out_of_bounds(data.len()).with_tag(LOCA));
+        }
+        Ok(Self {
+            data: Buffer::new(data),
+            is_long: entry_size == 4,
+        })
+    }
+
+    /// Returns true if the table contains 32-bit offsets.
+    pub fn is_long(&self) -> bool {
+        self.is_long
+    }
+
+    /// Returns the number of glyphs in the table.
+    pub fn num_glyphs(&self) -> u16 {
+        let entry_size = if self.is_long { 4 } else { 2 };
+        (self.data.len() / entry_size).saturating_sub(1).min(0xFFFF) as u16
+    }
+
+    /// Returns the range of the specified glyph in the glyph data table.
+    pub fn get(&self, glyph_id: GlyphId) -> Option<Range<usize>> {
+        let index = glyph_id as usize;
+        let (start, end) = if self.is_long {
+            (
+                self.data.read_u32(index * 4)? as usize,
+                self.data.read_u32(index * 4 + 4)? as usize,
+            )
+        } else {
+            (
+                self.data.read_u16(index * 2)? as usize * 2,
+                self.data.read_u16(index * 2 + 2)? as usize * 2,
+            )
+        };
+        if end < start {
+            return None;
+        }
+        Some(start..end)
+    }
+}
+
+/// Glyph data table.
+///
+/// <https://docs.microsoft.com/en-us/typography/opentype/spec/glyf>
+#[derive(Copy, Clone)]
+pub struct Glyf<'a>(Buffer<'a>);
+
+impl<'a> Glyf<'a> {
+    /// Creates a new glyph data table from a byte slice containing the
+    /// table data.
+    pub fn new(data: &'a [u8]) -> Self {
+        Self(Buffer::new(data))
+    }
+
+    /// Returns the glyph for the specified range from the index to location
+    /// table. Returns `None` for glyphs without outlines.
+    pub fn get(&self, range: Range<usize>) -> Option<Glyph<'a>> {
+        if range.is_empty() {
+            return None;
+        }
+        let data = Buffer::with_range(self.0.data(), range)?;
+        data.ensure_range(0, 10)?;
+        Some(Glyph(data))
+    }
+}
+
+/// Outline for a single glyph.
+#[derive(Copy, Clone)]
+pub struct Glyph<'a>(Buffer<'a>);
+
+impl<'a> Glyph<'a> {
+    /// Returns the underlying glyph data.
+    pub fn data(&self) -> &'a [u8] {
+        self.0.data()
+    }
+
+    /// Returns the number of contours. This is negative for composite
+    /// glyphs.
+    pub fn num_contours(&self) -> i16 {
+        self.0.read(0).unwrap_or(0)
+    }
+
+    /// Returns true if the glyph is composed of other glyphs.
+    pub fn is_composite(&self) -> bool {
+        self.num_contours() < 0
+    }
+
+    /// Returns the minimum x coordinate.
+    pub fn x_min(&self) -> i16 {
+        self.0.read(2).unwrap_or(0)
+    }
+
+    /// Returns the minimum y coordinate.
+    pub fn y_min(&self) -> i16 {
+        self.0.read(4).unwrap_or(0)
+    }
+
+    /// Returns the maximum x coordinate.
+    pub fn x_max(&self) -> i16 {
+        self.0.read(6).unwrap_or(0)
+    }
+
+    /// Returns the maximum y coordinate.
+    pub fn y_max(&self) -> i16 {
+        self.0.read(8).unwrap_or(0)
+    }
+
+    /// Returns an iterator over the components of a composite glyph.
+    pub fn components(&self) -> impl Iterator<Item = Component> + 'a + Clone {
+        Components {
+            data: self.0,
+            offset: if self.is_composite() { 10 } else { usize::MAX },
+        }
+    }
+}
+
+/// Component of a composite glyph.
+#[derive(Copy, Clone, Debug)]
+pub struct Component {
+    /// Component flags.
+    pub flags: u16,
+    /// Identifier of the component glyph.
+    pub glyph_id: GlyphId,
+    /// Offset of the glyph identifier from the beginning of the glyph data.
+    pub glyph_id_offset: usize,
+}
+
+impl Component {
+    /// Flag indicating that the arguments are 16-bit values.
+    pub const ARG_1_AND_2_ARE_WORDS: u16 = 0x0001;
+    /// Flag indicating that the component has a simple scale.
+    pub const WE_HAVE_A_SCALE: u16 = 0x0008;
+    /// Flag indicating that at least one more component follows.
+    pub const MORE_COMPONENTS: u16 = 0x0020;
+    /// Flag indicating that x and y are scaled independently.
+    pub const WE_HAVE_AN_X_AND_Y_SCALE: u16 = 0x0040;
+    /// Flag indicating that the component has a 2 by 2 transformation.
+    pub const WE_HAVE_A_TWO_BY_TWO: u16 = 0x0080;
+    /// Flag indicating that instructions follow the last component.
+    pub const WE_HAVE_INSTRUCTIONS: u16 = 0x0100;
+}
+
+#[derive(Copy, Clone)]
+struct Components<'a> {
+    data: Buffer<'a>,
+    offset: usize,
+}
+
+impl<'a> Iterator for Components<'a> {
+    type Item = Component;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let offset = self.offset;
+        let flags = self.data.read_u16(offset)?;
+        let glyph_id = self.data.read_u16(offset + 2)?;
+        let mut len = 4;
+        len += if flags & Component::ARG_1_AND_2_ARE_WORDS != 0 {
+            4
+        } else {
+            2
+        };
+        if flags & Component::WE_HAVE_A_SCALE != 0 {
+            len += 2;
+        } else if flags & Component::WE_HAVE_AN_X_AND_Y_SCALE != 0 {
+            len += 4;
+        } else if flags & Component::WE_HAVE_A_TWO_BY_TWO != 0 {
+            len += 8;
+        }
+        self.offset = if flags & Component::MORE_COMPONENTS != 0 {
+            offset + len
+        } else {
+            usize::MAX
+        };
+        Some(Component {
+            flags,
+            glyph_id,
+            glyph_id_offset: offset + 2,
+        })
+    }
+}
@@ -73,4 +73,45 @@ impl<'a> Gsub<'a> {
     pub fn lookups(&'a self) -> impl Iterator<Item = Lookup<'a>> + 'a + Clone {
         self.0.lookups()
     }
+
+    /// Returns the set of glyphs that are reachable from the specified
+    /// glyphs by applying the lookups with the specified indices, including
+    /// the glyphs themselves.
+    #[cfg(feature = "std")]
+    pub fn closure(&self, lookups: &[u16], glyphs: &GlyphSet) -> GlyphSet {
+        let mut closure = glyphs.clone();
+        close_glyphs(&self.0, lookups, &mut closure);
+        closure
+    }
+
+    /// Returns the set of glyphs that are reachable from the specified
+    /// glyphs by applying the lookups of all features with the specified
+    /// tags, including the glyphs themselves. This considers the features
+    /// of every script and language along with their substitutions in the
+    /// feature variations table.
+    #[cfg(feature = "std")]
+    pub fn feature_closure(&self, features: &[Tag], glyphs: &GlyphSet) -> GlyphSet {
+        let mut indices = std::vec::Vec::new();
+        let mut add_feature = |feature: Feature| {
+            indices.extend(feature.lookup_indices().iter());
+        };
+        for feature in self.0.features() {
+            if features.contains(&feature.record.tag) {
+                add_feature(feature);
+            }
+        }
+        if let Some(variations) = self.0.feature_variations() {
+            for condition_set in variations.iter() {
+                for feature in condition_set.features().iter() {
+                    let tag = self.0.feature(feature.record.index).map(|f| f.record.tag);
+                    if tag.is_some_and(|tag| features.contains(&tag)) {
+                        add_feature(feature);
+                    }
+                }
+            }
+        }
+        indices.sort_unstable();
+        indices.dedup();
+        self.closure(&indices, glyphs)
+    }
 }
@@ -2,18 +2,31 @@
 
 #![no_std]
 
+#[cfg(feature = "std")]
+extern crate std;
+
+#[cfg(feature = "std")]
+pub mod agl;
 pub mod avar;
+#[cfg(feature = "std")]
+pub mod build;
+pub mod cff;
+pub mod checksum;
 pub mod cmap;
 pub mod colr;
+#[cfg(feature = "std")]
+pub mod coverage;
 pub mod cpal;
 pub mod fvar;
 pub mod gdef;
+pub mod glyf;
 pub mod gpos;
 pub mod gsub;
 pub mod head;
 pub mod hhea;
 pub mod hmtx;
 pub mod hvar;
+pub mod ltag;
 pub mod math;
 pub mod maxp;
 pub mod name;
@@ -21,14 +34,26 @@ pub mod os2;
 pub mod otl;
 pub mod parse;
 pub mod post;
+#[cfg(feature = "std")]
+pub mod shape;
+#[cfg(feature = "std")]
+pub mod subset;
 pub mod types;
+#[cfg(feature = "std")]
+pub mod validate;
 pub mod var;
 pub mod vhea;
 pub mod vmtx;
 pub mod vorg;
 pub mod vvar;
+#[cfg(feature = "woff")]
+pub mod woff;
+#[cfg(feature = "woff2")]
+pub mod woff2;
 
 mod font;
+#[cfg(all(test, feature = "std"))]
+mod testing;
 
 pub use font::*;
 
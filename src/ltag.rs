@@ -0,0 +1,57 @@
+//! Language tag table.
+
+use super::parse_prelude::*;
+
+/// Tag for the `ltag` table.
+pub const LTAG: Tag = Tag::new(b"ltag");
+
+/// Language tag table.
+///
+/// <https://developer.apple.com/fonts/TrueType-Reference-Manual/RM06/Chap6ltag.html>
+#[derive(Copy, Clone)]
+pub struct Ltag<'a>(Buffer<'a>);
+
+impl<'a> Ltag<'a> {
+    /// Creates a new language tag table from a byte slice containing the
+    /// table data.
+    pub fn new(data: &'a [u8]) -> Self {
+        Self(Buffer::new(data))
+    }
+
+    /// Returns the version.
+    pub fn version(&self) -> u32 {
+        self.0.read(0).unwrap_or(0)
+    }
+
+    /// Returns the flags.
+    pub fn flags(&self) -> u32 {
+        self.0.read(4).unwrap_or(0)
+    }
+
+    /// Returns the number of language tags.
+    pub fn len(&self) -> u32 {
+        self.0.read(8).unwrap_or(0)
+    }
+
+    /// Returns true if the table contains no language tags.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the BCP 47 language tag at the specified index.
+    pub fn get(&self, index: u32) -> Option<&'a str> {
+        if index >= self.len() {
+            return None;
+        }
+        let record = 12 + index as usize * 4;
+        let offset = self.0.read_u16(record)? as usize;
+        let len = self.0.read_u16(record + 2)? as usize;
+        core::str::from_utf8(self.0.read_bytes(offset, len)?).ok()
+    }
+
+    /// Returns an iterator over the language tags.
+    pub fn tags(&self) -> impl Iterator<Item = Option<&'a str>> + 'a + Clone {
+        let copy = *self;
+        (0..self.len()).map(move |index| copy.get(index))
+    }
+}
@@ -18,6 +18,20 @@ impl<'a> Maxp<'a> {
         Self(Buffer::new(data))
     }
 
+    /// Creates a new maximum profile table from a byte slice containing the
+    /// table data, returning an error if the version is not supported or the
+    /// table is truncated.
+    pub fn try_new(data: &'a [u8]) -> Result<Self, ReadError> {
+        let b = Buffer::new(data);
+        let len = match b.try_read::<u32>(0).map_err(|e| e.with_tag(MAXP))? {
+            0x00005000 => 6,
+            0x00010000 => 32,
+            version => return Err(ReadError::unsupported_format(version, 0).with_tag(MAXP)),
+        };
+        b.try_ensure_range(0, len).map_err(|e| e.with_tag(MAXP))?;
+        Ok(Self(b))
+    }
+
     /// Returns the version of the table.
     /// - 0x00005000: Version 0.5 - only `num_glyphs` will return a meaningful value.
     /// - 0x00010000: Version 1.0
@@ -0,0 +1,413 @@
+//! Legacy character encodings for name records.
+//!
+//! Unicode and Windows records are decoded as UTF-16, with the Windows
+//! Chinese, Japanese and Korean encodings decoded through double byte tables
+//! when the `cjk-names` feature is enabled. Macintosh records are decoded
+//! for the Roman (including the Icelandic, Turkish, Croatian and Romanian
+//! variants), Arabic, Farsi, Greek, Cyrillic and Central European script
+//! codes along with Japanese, Chinese and Korean behind the same feature.
+//!
+//! The remaining Macintosh script codes are not supported. In particular,
+//! Hebrew (5) and Thai (21) map several bytes to sequences of codepoints
+//! or private use presentation hints so they do not fit a single byte table,
+//! and the Indic, Southeast Asian, Ethiopic and symbol scripts are
+//! practically absent from fonts. Records in these encodings report false
+//! from [`NameRecord::is_decodable`](super::NameRecord::is_decodable) and
+//! decode to an empty string.
+
+#[cfg(feature = "cjk-names")]
+use super::cjk;
+use crate::parse_prelude::*;
+use core::char::{from_u32, REPLACEMENT_CHARACTER};
+
+/// Character encoding of a name record.
+#[derive(Copy, Clone)]
+pub(super) enum Encoding {
+    /// UTF-16 big endian.
+    Utf16,
+    /// Single byte encoding with ASCII in the lower half and the specified
+    /// mapping for the upper half.
+    SingleByte(&'static [u16; 128]),
+    /// Double byte Chinese, Japanese or Korean encoding. Macintosh variants
+    /// map a few additional single bytes.
+    #[cfg(feature = "cjk-names")]
+    DoubleByte { charset: Charset, mac: bool },
+    /// Encoding that cannot be decoded.
+    Unsupported,
+}
+
+impl Encoding {
+    /// Returns the encoding for the specified platform, encoding and
+    /// language identifiers.
+    pub fn new(platform_id: u16, encoding_id: u16, language_id: u16) -> Self {
+        use Encoding::*;
+        match (platform_id, encoding_id) {
+            (0, _) | (3, 0) | (3, 1) | (3, 10) => Utf16,
+            (1, 0) => SingleByte(match language_id {
+                15 | 30 => &MAC_ICELANDIC,
+                17 => &MAC_TURKISH,
+                18 => &MAC_CROATIAN,
+                37 => &MAC_ROMANIAN,
+                _ => &MAC_ROMAN,
+            }),
+            (1, 4) if language_id == 31 => SingleByte(&MAC_FARSI),
+            (1, 4) => SingleByte(&MAC_ARABIC),
+            (1, 6) => SingleByte(&MAC_GREEK),
+            (1, 7) => SingleByte(&MAC_CYRILLIC),
+            (1, 29) => SingleByte(&MAC_CENTRAL_EUROPEAN),
+            #[cfg(feature = "cjk-names")]
+            (1, 1) | (3, 2) => DoubleByte {
+                charset: Charset::ShiftJis,
+                mac: platform_id == 1,
+            },
+            #[cfg(feature = "cjk-names")]
+            (1, 2) | (3, 4) => DoubleByte {
+                charset: Charset::Big5,
+                mac: platform_id == 1,
+            },
+            #[cfg(feature = "cjk-names")]
+            (1, 3) | (3, 5) => DoubleByte {
+                charset: Charset::EucKr,
+                mac: platform_id == 1,
+            },
+            #[cfg(feature = "cjk-names")]
+            (1, 25) | (3, 3) => DoubleByte {
+                charset: Charset::Gb2312,
+                mac: platform_id == 1,
+            },
+            _ => Unsupported,
+        }
+    }
+
+    /// Returns true if the encoding can be decoded.
+    pub fn is_decodable(self) -> bool {
+        !matches!(self, Self::Unsupported)
+    }
+
+    /// Decodes the next character at the specified position, advancing it
+    /// past the consumed bytes. Returns `None` at the end of the data.
+    pub fn decode(self, data: &[u8], pos: &mut usize) -> Option<char> {
+        match self {
+            Self::Utf16 => {
+                let d = Buffer::new(data);
+                let mut c = d.read::<u16>(*pos)? as u32;
+                *pos += 2;
+                if (0xD800..0xDC00).contains(&c) {
+                    let c2 = d.read::<u16>(*pos)? as u32;
+                    *pos += 2;
+                    c = ((c & 0x3FF) << 10) + (c2 & 0x3FF) + 0x10000;
+                }
+                Some(from_u32(c).unwrap_or(REPLACEMENT_CHARACTER))
+            }
+            Self::SingleByte(table) => {
+                let c = *data.get(*pos)? as u32;
+                *pos += 1;
+                let c = if c > 127 {
+                    table[c as usize - 128] as u32
+                } else {
+                    c
+                };
+                Some(from_u32(c).unwrap_or(REPLACEMENT_CHARACTER))
+            }
+            #[cfg(feature = "cjk-names")]
+            Self::DoubleByte { charset, mac } => charset.decode(mac, data, pos),
+            Self::Unsupported => None,
+        }
+    }
+}
+
+/// Double byte character set.
+#[cfg(feature = "cjk-names")]
+#[derive(Copy, Clone)]
+pub(super) enum Charset {
+    /// Japanese Shift JIS.
+    ShiftJis,
+    /// Traditional Chinese Big5.
+    Big5,
+    /// Simplified Chinese GB 2312.
+    Gb2312,
+    /// Korean KS X 1001.
+    EucKr,
+}
+
+#[cfg(feature = "cjk-names")]
+impl Charset {
+    fn decode(self, mac: bool, data: &[u8], pos: &mut usize) -> Option<char> {
+        let lead = next_byte(data, pos)?;
+        if lead < 0x80 {
+            return Some(lead as char);
+        }
+        if mac {
+            let c = match (self, lead) {
+                (Self::ShiftJis, 0x80) | (Self::Big5, 0x80) => Some('\u{5C}'),
+                (Self::Gb2312, 0x80) => Some('\u{FC}'),
+                (Self::EucKr, _) => None,
+                (_, 0xA0) => Some('\u{A0}'),
+                (_, 0xFD) => Some('\u{A9}'),
+                (_, 0xFE) => Some('\u{2122}'),
+                (_, 0xFF) => Some('\u{2026}'),
+                _ => None,
+            };
+            if c.is_some() {
+                return c;
+            }
+        }
+        if let (Self::ShiftJis, 0xA1..=0xDF) = (self, lead) {
+            // Half width katakana.
+            return from_u32(0xFF61 + (lead - 0xA1) as u32);
+        }
+        let (table, trail_count) = match self {
+            Self::ShiftJis => (cjk::SHIFT_JIS, 188),
+            Self::Big5 => (cjk::BIG5, 157),
+            Self::Gb2312 => (cjk::GB2312, 94),
+            Self::EucKr => (cjk::EUC_KR, 94),
+        };
+        let lead_index = match (self, lead) {
+            (Self::ShiftJis, 0x81..=0x9F) => lead - 0x81,
+            (Self::ShiftJis, 0xE0..=0xFC) => lead - 0xE0 + 31,
+            (Self::Big5, 0xA1..=0xF9)
+            | (Self::Gb2312, 0xA1..=0xF7)
+            | (Self::EucKr, 0xA1..=0xFE) => lead - 0xA1,
+            _ => return Some(REPLACEMENT_CHARACTER),
+        };
+        let start = *pos;
+        let trail_index = next_byte(data, pos).and_then(|trail| match (self, trail) {
+            (Self::ShiftJis, 0x40..=0x7E) | (Self::Big5, 0x40..=0x7E) => Some(trail - 0x40),
+            (Self::ShiftJis, 0x80..=0xFC) => Some(trail - 0x80 + 63),
+            (Self::Big5, 0xA1..=0xFE) => Some(trail - 0xA1 + 63),
+            (Self::Gb2312, 0xA1..=0xFE) | (Self::EucKr, 0xA1..=0xFE) => Some(trail - 0xA1),
+            _ => None,
+        });
+        let trail_index = match trail_index {
+            Some(index) => index,
+            None => {
+                // Leave an invalid trail byte for the next character.
+                *pos = start;
+                return Some(REPLACEMENT_CHARACTER);
+            }
+        };
+        let c = table[lead_index as usize * trail_count + trail_index as usize];
+        Some(
+            from_u32(c as u32)
+                .filter(|_| c != 0)
+                .unwrap_or(REPLACEMENT_CHARACTER),
+        )
+    }
+}
+
+/// Returns the next non-zero byte. Windows records store each byte of a
+/// double byte encoding in a 16-bit code unit so the zero padding is
+/// skipped.
+#[cfg(feature = "cjk-names")]
+fn next_byte(data: &[u8], pos: &mut usize) -> Option<u8> {
+    while let Some(&byte) = data.get(*pos) {
+        *pos += 1;
+        if byte != 0 {
+            return Some(byte);
+        }
+    }
+    None
+}
+
+/// Upper half of the Macintosh Roman encoding.
+#[rustfmt::skip]
+const MAC_ROMAN: [u16; 128] = [
+    196, 197, 199, 201, 209, 214, 220, 225, 224, 226, 228, 227, 229, 231, 233, 232,
+    234, 235, 237, 236, 238, 239, 241, 243, 242, 244, 246, 245, 250, 249, 251, 252,
+    8224, 176, 162, 163, 167, 8226, 182, 223, 174, 169, 8482, 180, 168, 8800, 198,
+    216, 8734, 177, 8804, 8805, 165, 181, 8706, 8721, 8719, 960, 8747, 170, 186,
+    937, 230, 248, 191, 161, 172, 8730, 402, 8776, 8710, 171, 187, 8230, 160, 192,
+    195, 213, 338, 339, 8211, 8212, 8220, 8221, 8216, 8217, 247, 9674, 255, 376,
+    8260, 8364, 8249, 8250, 64257, 64258, 8225, 183, 8218, 8222, 8240, 194, 202,
+    193, 203, 200, 205, 206, 207, 204, 211, 212, 63743, 210, 218, 219, 217, 305,
+    710, 732, 175, 728, 729, 730, 184, 733, 731, 711,
+];
+
+/// Upper half of the Macintosh Icelandic encoding.
+#[rustfmt::skip]
+const MAC_ICELANDIC: [u16; 128] = [
+    196, 197, 199, 201, 209, 214, 220, 225, 224, 226, 228, 227, 229, 231, 233, 232,
+    234, 235, 237, 236, 238, 239, 241, 243, 242, 244, 246, 245, 250, 249, 251, 252,
+    221, 176, 162, 163, 167, 8226, 182, 223, 174, 169, 8482, 180, 168, 8800, 198,
+    216, 8734, 177, 8804, 8805, 165, 181, 8706, 8721, 8719, 960, 8747, 170, 186,
+    937, 230, 248, 191, 161, 172, 8730, 402, 8776, 8710, 171, 187, 8230, 160, 192,
+    195, 213, 338, 339, 8211, 8212, 8220, 8221, 8216, 8217, 247, 9674, 255, 376,
+    8260, 8364, 208, 240, 222, 254, 253, 183, 8218, 8222, 8240, 194, 202, 193, 203,
+    200, 205, 206, 207, 204, 211, 212, 63743, 210, 218, 219, 217, 305, 710, 732,
+    175, 728, 729, 730, 184, 733, 731, 711,
+];
+
+/// Upper half of the Macintosh Turkish encoding.
+#[rustfmt::skip]
+const MAC_TURKISH: [u16; 128] = [
+    196, 197, 199, 201, 209, 214, 220, 225, 224, 226, 228, 227, 229, 231, 233, 232,
+    234, 235, 237, 236, 238, 239, 241, 243, 242, 244, 246, 245, 250, 249, 251, 252,
+    8224, 176, 162, 163, 167, 8226, 182, 223, 174, 169, 8482, 180, 168, 8800, 198,
+    216, 8734, 177, 8804, 8805, 165, 181, 8706, 8721, 8719, 960, 8747, 170, 186,
+    937, 230, 248, 191, 161, 172, 8730, 402, 8776, 8710, 171, 187, 8230, 160, 192,
+    195, 213, 338, 339, 8211, 8212, 8220, 8221, 8216, 8217, 247, 9674, 255, 376,
+    286, 287, 304, 305, 350, 351, 8225, 183, 8218, 8222, 8240, 194, 202, 193, 203,
+    200, 205, 206, 207, 204, 211, 212, 63743, 210, 218, 219, 217, 63648, 710, 732,
+    175, 728, 729, 730, 184, 733, 731, 711,
+];
+
+/// Upper half of the Macintosh Croatian encoding.
+#[rustfmt::skip]
+const MAC_CROATIAN: [u16; 128] = [
+    196, 197, 199, 201, 209, 214, 220, 225, 224, 226, 228, 227, 229, 231, 233, 232,
+    234, 235, 237, 236, 238, 239, 241, 243, 242, 244, 246, 245, 250, 249, 251, 252,
+    8224, 176, 162, 163, 167, 8226, 182, 223, 174, 352, 8482, 180, 168, 8800, 381,
+    216, 8734, 177, 8804, 8805, 8710, 181, 8706, 8721, 8719, 353, 8747, 170, 186,
+    937, 382, 248, 191, 161, 172, 8730, 402, 8776, 262, 171, 268, 8230, 160, 192,
+    195, 213, 338, 339, 272, 8212, 8220, 8221, 8216, 8217, 247, 9674, 63743, 169,
+    8260, 8364, 8249, 8250, 198, 187, 8211, 183, 8218, 8222, 8240, 194, 263, 193,
+    269, 200, 205, 206, 207, 204, 211, 212, 273, 210, 218, 219, 217, 305, 710, 732,
+    175, 960, 203, 730, 184, 202, 230, 711,
+];
+
+/// Upper half of the Macintosh Romanian encoding.
+#[rustfmt::skip]
+const MAC_ROMANIAN: [u16; 128] = [
+    196, 197, 199, 201, 209, 214, 220, 225, 224, 226, 228, 227, 229, 231, 233, 232,
+    234, 235, 237, 236, 238, 239, 241, 243, 242, 244, 246, 245, 250, 249, 251, 252,
+    8224, 176, 162, 163, 167, 8226, 182, 223, 174, 169, 8482, 180, 168, 8800, 258,
+    536, 8734, 177, 8804, 8805, 165, 181, 8706, 8721, 8719, 960, 8747, 170, 186,
+    937, 259, 537, 191, 161, 172, 8730, 402, 8776, 8710, 171, 187, 8230, 160, 192,
+    195, 213, 338, 339, 8211, 8212, 8220, 8221, 8216, 8217, 247, 9674, 255, 376,
+    8260, 8364, 8249, 8250, 538, 539, 8225, 183, 8218, 8222, 8240, 194, 202, 193,
+    203, 200, 205, 206, 207, 204, 211, 212, 63743, 210, 218, 219, 217, 305, 710,
+    732, 175, 728, 729, 730, 184, 733, 731, 711,
+];
+
+/// Upper half of the Macintosh Arabic encoding.
+#[rustfmt::skip]
+const MAC_ARABIC: [u16; 128] = [
+    196, 160, 199, 201, 209, 214, 220, 225, 224, 226, 228, 1722, 171, 231, 233,
+    232, 234, 235, 237, 8230, 238, 239, 241, 243, 187, 244, 246, 247, 250, 249,
+    251, 252, 32, 33, 34, 35, 36, 1642, 38, 39, 40, 41, 42, 43, 1548, 45, 46, 47,
+    1632, 1633, 1634, 1635, 1636, 1637, 1638, 1639, 1640, 1641, 58, 1563, 60, 61,
+    62, 1567, 10058, 1569, 1570, 1571, 1572, 1573, 1574, 1575, 1576, 1577, 1578,
+    1579, 1580, 1581, 1582, 1583, 1584, 1585, 1586, 1587, 1588, 1589, 1590, 1591,
+    1592, 1593, 1594, 91, 92, 93, 94, 95, 1600, 1601, 1602, 1603, 1604, 1605, 1606,
+    1607, 1608, 1609, 1610, 1611, 1612, 1613, 1614, 1615, 1616, 1617, 1618, 1662,
+    1657, 1670, 1749, 1700, 1711, 1672, 1681, 123, 124, 125, 1688, 1746,
+];
+
+/// Upper half of the Macintosh Farsi encoding.
+#[rustfmt::skip]
+const MAC_FARSI: [u16; 128] = [
+    196, 160, 199, 201, 209, 214, 220, 225, 224, 226, 228, 1722, 171, 231, 233,
+    232, 234, 235, 237, 8230, 238, 239, 241, 243, 187, 244, 246, 247, 250, 249,
+    251, 252, 32, 33, 34, 35, 36, 1642, 38, 39, 40, 41, 42, 43, 1548, 45, 46, 47,
+    1776, 1777, 1778, 1779, 1780, 1781, 1782, 1783, 1784, 1785, 58, 1563, 60, 61,
+    62, 1567, 10058, 1569, 1570, 1571, 1572, 1573, 1574, 1575, 1576, 1577, 1578,
+    1579, 1580, 1581, 1582, 1583, 1584, 1585, 1586, 1587, 1588, 1589, 1590, 1591,
+    1592, 1593, 1594, 91, 92, 93, 94, 95, 1600, 1601, 1602, 1603, 1604, 1605, 1606,
+    1607, 1608, 1609, 1610, 1611, 1612, 1613, 1614, 1615, 1616, 1617, 1618, 1662,
+    1657, 1670, 1749, 1700, 1711, 1672, 1681, 123, 124, 125, 1688, 1746,
+];
+
+/// Upper half of the Macintosh Greek encoding.
+#[rustfmt::skip]
+const MAC_GREEK: [u16; 128] = [
+    196, 185, 178, 201, 179, 214, 220, 901, 224, 226, 228, 900, 168, 231, 233, 232,
+    234, 235, 163, 8482, 238, 239, 8226, 189, 8240, 244, 246, 166, 8364, 249, 251,
+    252, 8224, 915, 916, 920, 923, 926, 928, 223, 174, 169, 931, 938, 167, 8800,
+    176, 183, 913, 177, 8804, 8805, 165, 914, 917, 918, 919, 921, 922, 924, 934,
+    939, 936, 937, 940, 925, 172, 927, 929, 8776, 932, 171, 187, 8230, 160, 933,
+    935, 902, 904, 339, 8211, 8213, 8220, 8221, 8216, 8217, 247, 905, 906, 908,
+    910, 941, 942, 943, 972, 911, 973, 945, 946, 968, 948, 949, 966, 947, 951, 953,
+    958, 954, 955, 956, 957, 959, 960, 974, 961, 963, 964, 952, 969, 962, 967, 965,
+    950, 970, 971, 912, 944, 173,
+];
+
+/// Upper half of the Macintosh Cyrillic encoding.
+#[rustfmt::skip]
+const MAC_CYRILLIC: [u16; 128] = [
+    1040, 1041, 1042, 1043, 1044, 1045, 1046, 1047, 1048, 1049, 1050, 1051, 1052,
+    1053, 1054, 1055, 1056, 1057, 1058, 1059, 1060, 1061, 1062, 1063, 1064, 1065,
+    1066, 1067, 1068, 1069, 1070, 1071, 8224, 176, 1168, 163, 167, 8226, 182, 1030,
+    174, 169, 8482, 1026, 1106, 8800, 1027, 1107, 8734, 177, 8804, 8805, 1110, 181,
+    1169, 1032, 1028, 1108, 1031, 1111, 1033, 1113, 1034, 1114, 1112, 1029, 172,
+    8730, 402, 8776, 8710, 171, 187, 8230, 160, 1035, 1115, 1036, 1116, 1109, 8211,
+    8212, 8220, 8221, 8216, 8217, 247, 8222, 1038, 1118, 1039, 1119, 8470, 1025,
+    1105, 1103, 1072, 1073, 1074, 1075, 1076, 1077, 1078, 1079, 1080, 1081, 1082,
+    1083, 1084, 1085, 1086, 1087, 1088, 1089, 1090, 1091, 1092, 1093, 1094, 1095,
+    1096, 1097, 1098, 1099, 1100, 1101, 1102, 8364,
+];
+
+/// Upper half of the Macintosh Central European encoding.
+#[rustfmt::skip]
+const MAC_CENTRAL_EUROPEAN: [u16; 128] = [
+    196, 256, 257, 201, 260, 214, 220, 225, 261, 268, 228, 269, 262, 263, 233, 377,
+    378, 270, 237, 271, 274, 275, 278, 243, 279, 244, 246, 245, 250, 282, 283, 252,
+    8224, 176, 280, 163, 167, 8226, 182, 223, 174, 169, 8482, 281, 168, 8800, 291,
+    302, 303, 298, 8804, 8805, 299, 310, 8706, 8721, 322, 315, 316, 317, 318, 313,
+    314, 325, 326, 323, 172, 8730, 324, 327, 8710, 171, 187, 8230, 160, 328, 336,
+    213, 337, 332, 8211, 8212, 8220, 8221, 8216, 8217, 247, 9674, 333, 340, 341,
+    344, 8249, 8250, 345, 342, 343, 352, 8218, 8222, 353, 346, 347, 193, 356, 357,
+    205, 381, 382, 362, 211, 212, 363, 366, 218, 367, 368, 369, 370, 371, 221, 253,
+    311, 379, 321, 380, 290, 711,
+];
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use std::string::String;
+
+    fn decode(encoding: Encoding, data: &[u8]) -> String {
+        let mut pos = 0;
+        core::iter::from_fn(|| encoding.decode(data, &mut pos)).collect()
+    }
+
+    #[test]
+    fn utf16() {
+        let data = [0, b'A', 0xD8, 0x3D, 0xDE, 0x00, 0xDC, 0x00];
+        assert_eq!(
+            decode(Encoding::new(3, 1, 0x409), &data),
+            "A\u{1F600}\u{FFFD}"
+        );
+    }
+
+    #[test]
+    fn mac_single_byte() {
+        let data = [b'A', 0x80, 0xA5, 0xDC];
+        assert_eq!(
+            decode(Encoding::new(1, 0, 0), &data),
+            "A\u{C4}\u{2022}\u{2039}"
+        );
+        assert_eq!(
+            decode(Encoding::new(1, 0, 15), &data),
+            "A\u{C4}\u{2022}\u{D0}"
+        );
+        assert_eq!(decode(Encoding::new(1, 6, 14), &[0xC1]), "\u{39D}");
+        assert_eq!(
+            decode(Encoding::new(1, 7, 32), &[0x80, 0xE0]),
+            "\u{410}\u{430}"
+        );
+    }
+
+    #[test]
+    fn unsupported() {
+        for script in [5, 8, 9, 21, 32] {
+            let encoding = Encoding::new(1, script, 0);
+            assert!(!encoding.is_decodable());
+            assert_eq!(decode(encoding, b"abc"), "");
+        }
+        assert!(!Encoding::new(3, 6, 0).is_decodable());
+    }
+
+    #[cfg(feature = "cjk-names")]
+    #[test]
+    fn double_byte() {
+        // "日本" in Shift JIS, stored with zero padding in a Windows record.
+        let sjis = [0x00, 0x93, 0x00, 0xFA, 0x00, 0x96, 0x00, 0x7B];
+        assert_eq!(decode(Encoding::new(3, 2, 0x411), &sjis), "日本");
+        assert_eq!(
+            decode(Encoding::new(1, 1, 11), &[0x93, 0xFA, 0xFE]),
+            "日\u{2122}"
+        );
+        // Truncated trail byte.
+        assert_eq!(decode(Encoding::new(1, 3, 23), &[0xB0]), "\u{FFFD}");
+    }
+}
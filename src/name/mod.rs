@@ -0,0 +1,647 @@
+//! Naming table.
+
+#[cfg(feature = "cjk-names")]
+mod cjk;
+mod encoding;
+
+use super::ltag::Ltag;
+use super::parse_prelude::*;
+use core::fmt;
+use core::ops::Range;
+use encoding::Encoding;
+
+/// Tag for the `name` table.
+pub const NAME: Tag = Tag::new(b"name");
+
+/// Naming table.
+///
+/// <https://docs.microsoft.com/en-us/typography/opentype/spec/name>
+#[derive(Copy, Clone)]
+pub struct Name<'a>(Buffer<'a>);
+
+impl<'a> Name<'a> {
+    /// Creates a new naming table from a byte slice containing the table
+    /// data.
+    pub fn new(data: &'a [u8]) -> Self {
+        Self(Buffer::new(data))
+    }
+
+    /// Creates a new naming table from a byte slice containing the table
+    /// data, returning an error if the version is not supported or the
+    /// records or storage area are out of bounds.
+    pub fn try_new(data: &'a [u8]) -> Result<Self, ReadError> {
+        Self::validate(data).map_err(|e| e.with_tag(NAME))
+    }
+
+    fn validate(data: &'a [u8]) -> Result<Self, ReadError> {
+        let b = Buffer::new(data);
+        let version = b.try_read::<u16>(0)?;
+        if version > 1 {
+            return Err(ReadError::unsupported_format(version as u32, 0));
+        }
+        let len = b.try_read::<u16>(2)? as usize;
+        let storage = b.try_read::<u16>(4)? as usize;
+        let records = b.try_read_slice::<NameRecord>(6, len)?;
+        if version == 1 {
+            let offset = 6 + len * NameRecord::SIZE;
+            let count = b.try_read::<u16>(offset)? as usize;
+            b.try_ensure_range(offset + 2, count * 4)?;
+        }
+        for (i, record) in records.iter().enumerate() {
+            let start = storage + record.offset as usize;
+            if record.len != 0 && !b.check_range(start, record.len as usize) {
+                return Err(ReadError::out_of_bounds(6 + i * NameRecord::SIZE));
+            }
+        }
+        Ok(Self(b))
+    }
+
+    /// Returns the version.
+    pub fn version(&self) -> u16 {
+        self.0.read(0).unwrap_or(0)
+    }
+
+    /// Returns the list of name records.
+    pub fn records(&self) -> Slice<'a, NameRecord> {
+        let len = self.0.read_u16(2).unwrap_or_default() as usize;
+        self.0.read_slice(6, len).unwrap_or_default()
+    }
+
+    /// Returns an iterator over the entries in the table.
+    pub fn entries(&self) -> impl Iterator<Item = Entry<'a>> + 'a + Clone {
+        let copy = *self;
+        self.records()
+            .iter()
+            .map(move |record| Entry { name: copy, record })
+    }
+
+    /// Returns the BCP 47 language tag for the specified record. Language
+    /// identifiers of Unicode platform records are indices into the
+    /// optional language tag table.
+    pub fn language_tag(
+        &self,
+        record: &NameRecord,
+        ltag: Option<&Ltag<'a>>,
+    ) -> Option<LanguageTag<'a>> {
+        let id = record.language_id;
+        if id >= 0x8000 && self.version() == 1 {
+            let offset = 6 + self.records().len() * NameRecord::SIZE;
+            let count = self.0.read_u16(offset)?;
+            let index = id - 0x8000;
+            if index >= count {
+                return None;
+            }
+            let record = offset + 2 + index as usize * 4;
+            let len = self.0.read_u16(record)? as usize;
+            let start = self.0.read_u16(record + 2)? as usize;
+            let data = self.storage().get(start..start + len)?;
+            return Some(LanguageTag { data, utf16: true });
+        }
+        let tag = match record.platform_id {
+            0 if id != 0xFFFF => return ltag?.get(id as u32).map(LanguageTag::new),
+            1 => *MAC_LANGUAGES.get(id as usize)?,
+            3 => {
+                let index = WINDOWS_LANGUAGES
+                    .binary_search_by(|entry| entry.0.cmp(&id))
+                    .ok()?;
+                WINDOWS_LANGUAGES[index].1
+            }
+            _ => "",
+        };
+        if tag.is_empty() {
+            None
+        } else {
+            Some(LanguageTag::new(tag))
+        }
+    }
+
+    /// Returns the entry for the specified name identifier that best matches
+    /// a list of BCP 47 language tags in order of preference.
+    ///
+    /// Language tags are compared ignoring case and records that share more
+    /// leading subtags with a preferred tag are chosen first, so `de-CH`
+    /// matches a record tagged `de-CH` before one tagged `de` or `de-DE`.
+    /// When no record matches, English is preferred followed by any other
+    /// record. Among equally ranked records, Windows
+    /// Unicode records are chosen over Unicode platform records and those
+    /// over Macintosh records.
+    ///
+    /// Missing typographic family and subfamily names fall back to the
+    /// legacy family and subfamily names, and missing WWS family and
+    /// subfamily names fall back to the typographic names.
+    pub fn find(
+        &self,
+        name_id: NameId,
+        languages: &[&str],
+        ltag: Option<&Ltag<'a>>,
+    ) -> Option<Entry<'a>> {
+        let mut name_id = name_id;
+        loop {
+            let best = self
+                .entries()
+                .filter(|entry| entry.record.name_id == name_id && entry.record.is_decodable())
+                .min_by_key(|entry| {
+                    let tag = self.language_tag(&entry.record, ltag);
+                    (
+                        language_rank(tag.as_ref(), languages),
+                        platform_rank(&entry.record),
+                    )
+                });
+            if best.is_some() {
+                return best;
+            }
+            name_id = match name_id {
+                TYPOGRAPHIC_FAMILY_NAME => FAMILY_NAME,
+                TYPOGRAPHIC_SUBFAMILY_NAME => SUBFAMILY_NAME,
+                WWS_FAMILY_NAME => TYPOGRAPHIC_FAMILY_NAME,
+                WWS_SUBFAMILY_NAME => TYPOGRAPHIC_SUBFAMILY_NAME,
+                _ => return None,
+            };
+        }
+    }
+
+    /// Returns the storage area for the string data.
+    pub fn storage(&self) -> &'a [u8] {
+        if let Some(offset) = self.0.read_offset16(4, 0) {
+            self.0.data().get(offset as usize..).unwrap_or(&[])
+        } else {
+            &[]
+        }
+    }
+}
+
+/// Record for an entry in the naming table.
+#[derive(Copy, Clone, Default)]
+#[repr(C)]
+pub struct NameRecord {
+    /// Platform identifier.
+    pub platform_id: u16,
+    /// Encoding identifier.
+    pub encoding_id: u16,
+    /// Language identifier,
+    pub language_id: u16,
+    /// Name identifier.
+    pub name_id: NameId,
+    /// Length of the string in the storage area.
+    pub len: u16,
+    /// Offset to the string in the storage area.
+    pub offset: u16,
+}
+
+impl NameRecord {
+    /// Returns true if the string data can be decoded.
+    pub fn is_decodable(&self) -> bool {
+        Encoding::new(self.platform_id, self.encoding_id, self.language_id).is_decodable()
+    }
+
+    /// Returns the byte range for the string data in the storage area.
+    pub fn storage_range(&self) -> Range<usize> {
+        let start = self.offset as usize;
+        start..start + self.len as usize
+    }
+}
+
+impl ReadData for NameRecord {
+    unsafe fn read_data_unchecked(buf: &[u8], offset: usize) -> Self {
+        Self {
+            platform_id: u16::read_data_unchecked(buf, offset),
+            encoding_id: u16::read_data_unchecked(buf, offset + 2),
+            language_id: u16::read_data_unchecked(buf, offset + 4),
+            name_id: u16::read_data_unchecked(buf, offset + 6),
+            len: u16::read_data_unchecked(buf, offset + 8),
+            offset: u16::read_data_unchecked(buf, offset + 10),
+        }
+    }
+}
+
+/// Entry for a name in the naming table.
+#[derive(Copy, Clone)]
+pub struct Entry<'a> {
+    /// Parent table.
+    pub name: Name<'a>,
+    /// Record for the name.
+    pub record: NameRecord,
+}
+
+impl<'a> Entry<'a> {
+    /// Returns the raw string data for the name entry.
+    pub fn data(&self) -> Option<&'a [u8]> {
+        self.name.storage().get(self.record.storage_range())
+    }
+
+    /// Returns an iterator over the characters in the name entry.
+    pub fn decode(&self) -> Decode<'a> {
+        let record = &self.record;
+        Decode {
+            data: self.data().unwrap_or(&[]),
+            encoding: Encoding::new(record.platform_id, record.encoding_id, record.language_id),
+            pos: 0,
+        }
+    }
+}
+
+/// BCP 47 language tag for a name record.
+#[derive(Copy, Clone)]
+pub struct LanguageTag<'a> {
+    data: &'a [u8],
+    utf16: bool,
+}
+
+impl<'a> LanguageTag<'a> {
+    fn new(tag: &'a str) -> Self {
+        Self {
+            data: tag.as_bytes(),
+            utf16: false,
+        }
+    }
+
+    /// Returns the tag as a string slice. Returns `None` for tags stored
+    /// as UTF-16 in the naming table.
+    pub fn as_str(&self) -> Option<&'a str> {
+        if self.utf16 {
+            None
+        } else {
+            core::str::from_utf8(self.data).ok()
+        }
+    }
+
+    /// Returns an iterator over the characters in the tag.
+    pub fn chars(&self) -> impl Iterator<Item = char> + 'a + Clone {
+        let data = self.data;
+        let utf16 = self.utf16;
+        let step = if utf16 { 2 } else { 1 };
+        (0..data.len() / step).map(move |i| {
+            let c = if utf16 {
+                u16::from_be_bytes([data[i * 2], data[i * 2 + 1]])
+            } else {
+                data[i] as u16
+            };
+            core::char::from_u32(c as u32).unwrap_or(core::char::REPLACEMENT_CHARACTER)
+        })
+    }
+
+    /// Returns true if the tag is equal to the specified tag, ignoring ASCII
+    /// case.
+    pub fn matches(&self, tag: &str) -> bool {
+        let mut chars = self.chars();
+        tag.chars()
+            .all(|c| chars.next().map(|d| d.eq_ignore_ascii_case(&c)) == Some(true))
+            && chars.next().is_none()
+    }
+
+    /// Returns the number of leading subtags that are equal to those of the
+    /// specified tag, ignoring ASCII case.
+    fn common_subtags(&self, tag: &str) -> usize {
+        let mut count = 0;
+        let mut chars = self.chars();
+        let mut other = tag.chars();
+        loop {
+            match (chars.next(), other.next()) {
+                (Some(c), Some(d)) if c != '-' && c.eq_ignore_ascii_case(&d) => {}
+                (Some('-'), Some('-')) => count += 1,
+                (None | Some('-'), None | Some('-')) => return count + 1,
+                _ => return count,
+            }
+        }
+    }
+}
+
+impl fmt::Display for LanguageTag<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        use fmt::Write;
+        for c in self.chars() {
+            f.write_char(c)?;
+        }
+        Ok(())
+    }
+}
+
+impl fmt::Debug for LanguageTag<'_> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "\"{}\"", self)
+    }
+}
+
+/// Iterator over the chars of a name record.
+#[derive(Copy, Clone)]
+pub struct Decode<'a> {
+    data: &'a [u8],
+    encoding: Encoding,
+    pos: usize,
+}
+
+impl<'a> Iterator for Decode<'a> {
+    type Item = char;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        self.encoding.decode(self.data, &mut self.pos)
+    }
+}
+
+/// Type for a name identifier.
+pub type NameId = u16;
+
+/// Copyright notice.
+pub const COPYRIGHT_NOTICE: NameId = 0;
+/// Family name.
+pub const FAMILY_NAME: NameId = 1;
+/// Subfamily name.
+pub const SUBFAMILY_NAME: NameId = 2;
+/// Unique identifier.
+pub const UNIQUE_ID: NameId = 3;
+/// Full name.
+pub const FULL_NAME: NameId = 4;
+/// Version string.
+pub const VERSION_STRING: NameId = 5;
+/// PostScript name.
+pub const POSTSCRIPT_NAME: NameId = 6;
+/// Trademark.
+pub const TRADEMARK: NameId = 7;
+/// Manufacturer name.
+pub const MANUFACTURER: NameId = 8;
+/// Designer name.
+pub const DESIGNER: NameId = 9;
+/// Description of the typeface.
+pub const DESCRIPTION: NameId = 10;
+/// URL of the font vendor.
+pub const VENDOR_URL: NameId = 11;
+/// URL of the font designer.
+pub const DESIGNER_URL: NameId = 12;
+/// License description.
+pub const LICENSE_DESCRIPTION: NameId = 13;
+/// URL where additional licensing information can be found.
+pub const LICENSE_URL: NameId = 14;
+/// Typographic family name.
+pub const TYPOGRAPHIC_FAMILY_NAME: NameId = 16;
+/// Typographic subfamily name.
+pub const TYPOGRAPHIC_SUBFAMILY_NAME: NameId = 17;
+/// Compatible full name (Macintosh only).
+pub const COMPATIBLE_FULL_NAME: NameId = 18;
+/// Sample text.
+pub const SAMPLE_TEXT: NameId = 19;
+/// PostScript CID findfont name.
+pub const POSTSCRIPT_CID_NAME: NameId = 20;
+/// WWS family name.
+pub const WWS_FAMILY_NAME: NameId = 21;
+/// WWS subfamily name.
+pub const WWS_SUBFAMILY_NAME: NameId = 22;
+/// Light background palette name.
+pub const LIGHT_BACKGROUND_PALETTE: NameId = 23;
+/// Dark background palette name.
+pub const DARK_BACKGROUND_PALETTE: NameId = 24;
+/// Variations PostScript name prefix.
+pub const VARIATIONS_POSTSCRIPT_NAME_PREFIX: NameId = 25;
+
+/// Returns the rank of a language tag against a list of preferred tags
+/// where lower is better. Tags that share more leading subtags with a
+/// preferred tag rank higher.
+fn language_rank(tag: Option<&LanguageTag>, languages: &[&str]) -> usize {
+    const SLOTS: usize = 8;
+    let rank = |i: usize, common: usize| i * SLOTS + SLOTS - common.min(SLOTS);
+    let tag = match tag {
+        Some(tag) => tag,
+        None => return rank(languages.len() + 1, 0),
+    };
+    for (i, language) in languages.iter().enumerate() {
+        let common = tag.common_subtags(language);
+        if common != 0 {
+            return rank(i, common);
+        }
+    }
+    rank(languages.len(), tag.common_subtags("en-US"))
+}
+
+/// Returns the rank of the platform and encoding of a record where lower
+/// is better.
+fn platform_rank(record: &NameRecord) -> u8 {
+    match (record.platform_id, record.encoding_id) {
+        (3, 1) | (3, 10) => 0,
+        (0, _) => 1,
+        (3, _) => 2,
+        _ => 3,
+    }
+}
+
+/// Language tags for Macintosh language identifiers.
+#[rustfmt::skip]
+const MAC_LANGUAGES: [&str; 151] = [
+    "en", "fr", "de", "it", "nl", "sv", "es", "da", "pt", "nb", "he", "ja", "ar", "fi", "el",
+    "is", "mt", "tr", "hr", "zh-Hant", "ur", "hi", "th", "ko", "lt", "pl", "hu", "et", "lv",
+    "se", "fo", "fa", "ru", "zh-Hans", "nl-BE", "ga", "sq", "ro", "cs", "sk", "sl", "yi", "sr",
+    "mk", "bg", "uk", "be", "uz", "kk", "az-Cyrl", "az-Arab", "hy", "ka", "ro-MD", "ky", "tg",
+    "tk", "mn-Mong", "mn-Cyrl", "ps", "ku", "ks", "sd", "bo", "ne", "sa", "mr", "bn", "as",
+    "gu", "pa", "or", "ml", "kn", "ta", "te", "si", "my", "km", "lo", "vi", "id", "tl", "ms",
+    "ms-Arab", "am", "ti", "om", "so", "sw", "rw", "rn", "ny", "mg", "eo", "", "", "", "", "",
+    "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "", "",
+    "", "", "", "", "", "cy", "eu", "ca", "la", "qu", "gn", "ay", "tt", "ug", "dz", "jv", "su",
+    "gl", "af", "br", "iu", "gd", "gv", "ga", "to", "el-polyton", "kl", "az",
+];
+
+/// Language tags for Windows language identifiers, sorted by identifier.
+#[rustfmt::skip]
+const WINDOWS_LANGUAGES: &[(u16, &str)] = &[
+    (0x0001, "ar"), (0x0002, "bg"), (0x0003, "ca"), (0x0004, "zh-Hans"), (0x0005, "cs"),
+    (0x0006, "da"), (0x0007, "de"), (0x0008, "el"), (0x0009, "en"), (0x000A, "es"), (0x000B, "fi"),
+    (0x000C, "fr"), (0x000D, "he"), (0x000E, "hu"), (0x000F, "is"), (0x0010, "it"), (0x0011, "ja"),
+    (0x0012, "ko"), (0x0013, "nl"), (0x0014, "no"), (0x0015, "pl"), (0x0016, "pt"), (0x0017, "rm"),
+    (0x0018, "ro"), (0x0019, "ru"), (0x001A, "hr"), (0x001B, "sk"), (0x001C, "sq"), (0x001D, "sv"),
+    (0x001E, "th"), (0x001F, "tr"), (0x0020, "ur"), (0x0021, "id"), (0x0022, "uk"), (0x0023, "be"),
+    (0x0024, "sl"), (0x0025, "et"), (0x0026, "lv"), (0x0027, "lt"), (0x0028, "tg"), (0x0029, "fa"),
+    (0x002A, "vi"), (0x002B, "hy"), (0x002C, "az"), (0x002D, "eu"), (0x002E, "hsb"), (0x002F, "mk"),
+    (0x0030, "st"), (0x0031, "ts"), (0x0032, "tn"), (0x0033, "ve"), (0x0034, "xh"), (0x0035, "zu"),
+    (0x0036, "af"), (0x0037, "ka"), (0x0038, "fo"), (0x0039, "hi"), (0x003A, "mt"), (0x003B, "se"),
+    (0x003C, "ga"), (0x003D, "yi"), (0x003E, "ms"), (0x003F, "kk"), (0x0040, "ky"), (0x0041, "sw"),
+    (0x0042, "tk"), (0x0043, "uz"), (0x0044, "tt"), (0x0045, "bn"), (0x0046, "pa"), (0x0047, "gu"),
+    (0x0048, "or"), (0x0049, "ta"), (0x004A, "te"), (0x004B, "kn"), (0x004C, "ml"), (0x004D, "as"),
+    (0x004E, "mr"), (0x004F, "sa"), (0x0050, "mn"), (0x0051, "bo"), (0x0052, "cy"), (0x0053, "km"),
+    (0x0054, "lo"), (0x0055, "my"), (0x0056, "gl"), (0x0057, "kok"), (0x0058, "mni"),
+    (0x0059, "sd"), (0x005A, "syr"), (0x005B, "si"), (0x005C, "chr"), (0x005D, "iu"),
+    (0x005E, "am"), (0x005F, "tzm"), (0x0060, "ks"), (0x0061, "ne"), (0x0062, "fy"), (0x0063, "ps"),
+    (0x0064, "fil"), (0x0065, "dv"), (0x0066, "bin"), (0x0067, "ff"), (0x0068, "ha"),
+    (0x0069, "ibb"), (0x006A, "yo"), (0x006B, "qu"), (0x006C, "nso"), (0x006D, "ba"),
+    (0x006E, "lb"), (0x006F, "kl"), (0x0070, "ig"), (0x0071, "kr"), (0x0072, "om"), (0x0073, "ti"),
+    (0x0074, "gn"), (0x0075, "haw"), (0x0076, "la"), (0x0077, "so"), (0x0078, "ii"),
+    (0x0079, "pap"), (0x007A, "arn"), (0x007C, "moh"), (0x007E, "br"), (0x0080, "ug"),
+    (0x0081, "mi"), (0x0082, "oc"), (0x0083, "co"), (0x0084, "gsw"), (0x0085, "sah"),
+    (0x0086, "qut"), (0x0087, "rw"), (0x0088, "wo"), (0x0091, "gd"), (0x0092, "ckb"),
+    (0x0093, "quc"), (0x0401, "ar-SA"), (0x0402, "bg-BG"), (0x0403, "ca-ES"),
+    (0x0404, "zh-Hant-TW"), (0x0405, "cs-CZ"), (0x0406, "da-DK"), (0x0407, "de-DE"),
+    (0x0408, "el-GR"), (0x0409, "en-US"), (0x040A, "es-ES"), (0x040B, "fi-FI"), (0x040C, "fr-FR"),
+    (0x040D, "he-IL"), (0x040E, "hu-HU"), (0x040F, "is-IS"), (0x0410, "it-IT"), (0x0411, "ja-JP"),
+    (0x0412, "ko-KR"), (0x0413, "nl-NL"), (0x0414, "nb-NO"), (0x0415, "pl-PL"), (0x0416, "pt-BR"),
+    (0x0417, "rm-CH"), (0x0418, "ro-RO"), (0x0419, "ru-RU"), (0x041A, "hr-HR"), (0x041B, "sk-SK"),
+    (0x041C, "sq-AL"), (0x041D, "sv-SE"), (0x041E, "th-TH"), (0x041F, "tr-TR"), (0x0420, "ur-PK"),
+    (0x0421, "id-ID"), (0x0422, "uk-UA"), (0x0423, "be-BY"), (0x0424, "sl-SI"), (0x0425, "et-EE"),
+    (0x0426, "lv-LV"), (0x0427, "lt-LT"), (0x0428, "tg-Cyrl-TJ"), (0x0429, "fa-IR"),
+    (0x042A, "vi-VN"), (0x042B, "hy-AM"), (0x042C, "az-Latn-AZ"), (0x042D, "eu-ES"),
+    (0x042E, "hsb-DE"), (0x042F, "mk-MK"), (0x0430, "st-ZA"), (0x0431, "ts-ZA"), (0x0432, "tn-ZA"),
+    (0x0433, "ve-ZA"), (0x0434, "xh-ZA"), (0x0435, "zu-ZA"), (0x0436, "af-ZA"), (0x0437, "ka-GE"),
+    (0x0438, "fo-FO"), (0x0439, "hi-IN"), (0x043A, "mt-MT"), (0x043B, "se-NO"), (0x043D, "yi-001"),
+    (0x043E, "ms-MY"), (0x043F, "kk-KZ"), (0x0440, "ky-KG"), (0x0441, "sw-KE"), (0x0442, "tk-TM"),
+    (0x0443, "uz-Latn-UZ"), (0x0444, "tt-RU"), (0x0445, "bn-IN"), (0x0446, "pa-IN"),
+    (0x0447, "gu-IN"), (0x0448, "or-IN"), (0x0449, "ta-IN"), (0x044A, "te-IN"), (0x044B, "kn-IN"),
+    (0x044C, "ml-IN"), (0x044D, "as-IN"), (0x044E, "mr-IN"), (0x044F, "sa-IN"), (0x0450, "mn-MN"),
+    (0x0451, "bo-CN"), (0x0452, "cy-GB"), (0x0453, "km-KH"), (0x0454, "lo-LA"), (0x0455, "my-MM"),
+    (0x0456, "gl-ES"), (0x0457, "kok-IN"), (0x0458, "mni-IN"), (0x0459, "sd-Deva-IN"),
+    (0x045A, "syr-SY"), (0x045B, "si-LK"), (0x045C, "chr-Cher-US"), (0x045D, "iu-Cans-CA"),
+    (0x045E, "am-ET"), (0x045F, "tzm-Arab-MA"), (0x0460, "ks-Arab-IN"), (0x0461, "ne-NP"),
+    (0x0462, "fy-NL"), (0x0463, "ps-AF"), (0x0464, "fil-PH"), (0x0465, "dv-MV"), (0x0466, "bin-NG"),
+    (0x0467, "ff-NG"), (0x0468, "ha-Latn-NG"), (0x0469, "ibb-NG"), (0x046A, "yo-NG"),
+    (0x046B, "qu-BO"), (0x046C, "nso-ZA"), (0x046D, "ba-RU"), (0x046E, "lb-LU"), (0x046F, "kl-GL"),
+    (0x0470, "ig-NG"), (0x0471, "kr-NG"), (0x0472, "om-ET"), (0x0473, "ti-ET"), (0x0474, "gn-PY"),
+    (0x0475, "haw-US"), (0x0476, "la-001"), (0x0477, "so-SO"), (0x0478, "ii-CN"),
+    (0x0479, "pap-029"), (0x047A, "arn-CL"), (0x047C, "moh-CA"), (0x047E, "br-FR"),
+    (0x0480, "ug-CN"), (0x0481, "mi-NZ"), (0x0482, "oc-FR"), (0x0483, "co-FR"), (0x0484, "gsw-FR"),
+    (0x0485, "sah-RU"), (0x0486, "qut-GT"), (0x0487, "rw-RW"), (0x0488, "wo-SN"), (0x048C, "fa-AF"),
+    (0x0491, "gd-GB"), (0x0492, "ckb-Arab-IQ"), (0x0493, "quc-CO"), (0x0801, "ar-IQ"),
+    (0x0803, "ca-ES-valencia"), (0x0804, "zh-CN"), (0x0807, "de-CH"), (0x0809, "en-GB"),
+    (0x080A, "es-MX"), (0x080C, "fr-BE"), (0x0810, "it-CH"), (0x0812, "ko-KP"), (0x0813, "nl-BE"),
+    (0x0814, "nn-NO"), (0x0816, "pt-PT"), (0x0818, "ro-MD"), (0x0819, "ru-MD"),
+    (0x081A, "sr-Latn-CS"), (0x081D, "sv-FI"), (0x0820, "ur-IN"), (0x082C, "az-Cyrl-AZ"),
+    (0x082E, "dsb-DE"), (0x0832, "tn-BW"), (0x083B, "se-SE"), (0x083C, "ga-IE"), (0x083E, "ms-BN"),
+    (0x0843, "uz-Cyrl-UZ"), (0x0845, "bn-BD"), (0x0846, "pa-Arab-PK"), (0x0849, "ta-LK"),
+    (0x0850, "mn-Mong-CN"), (0x0851, "bo-BT"), (0x0859, "sd-Arab-PK"), (0x085D, "iu-Latn-CA"),
+    (0x085F, "tzm-Latn-DZ"), (0x0860, "ks-Deva-IN"), (0x0861, "ne-IN"), (0x0867, "ff-Latn-SN"),
+    (0x086B, "qu-EC"), (0x0873, "ti-ER"), (0x0C01, "ar-EG"), (0x0C04, "zh-Hant-HK"),
+    (0x0C07, "de-AT"), (0x0C09, "en-AU"), (0x0C0A, "es-ES"), (0x0C0C, "fr-CA"),
+    (0x0C1A, "sr-Cyrl-CS"), (0x0C3B, "se-FI"), (0x0C50, "mn-Mong-MN"), (0x0C51, "dz-BT"),
+    (0x0C6B, "qu-PE"), (0x1001, "ar-LY"), (0x1004, "zh-Hans-SG"), (0x1007, "de-LU"),
+    (0x1009, "en-CA"), (0x100A, "es-GT"), (0x100C, "fr-CH"), (0x101A, "hr-BA"), (0x103B, "smj-NO"),
+    (0x105F, "tzm-Tfng-MA"), (0x1401, "ar-DZ"), (0x1404, "zh-Hant-MO"), (0x1407, "de-LI"),
+    (0x1409, "en-NZ"), (0x140A, "es-CR"), (0x140C, "fr-LU"), (0x141A, "bs-Latn-BA"),
+    (0x143B, "smj-SE"), (0x1801, "ar-MA"), (0x1809, "en-IE"), (0x180A, "es-PA"), (0x180C, "fr-MC"),
+    (0x181A, "sr-Latn-BA"), (0x183B, "sma-NO"), (0x1C01, "ar-TN"), (0x1C09, "en-ZA"),
+    (0x1C0A, "es-DO"), (0x1C0C, "fr-029"), (0x1C1A, "sr-Cyrl-BA"), (0x1C3B, "sma-SE"),
+    (0x2001, "ar-OM"), (0x2009, "en-JM"), (0x200A, "es-VE"), (0x200C, "fr-RE"),
+    (0x201A, "bs-Cyrl-BA"), (0x203B, "sms-FI"), (0x2401, "ar-YE"), (0x2409, "en-029"),
+    (0x240A, "es-CO"), (0x240C, "fr-CD"), (0x241A, "sr-Latn-RS"), (0x243B, "smn-FI"),
+    (0x2801, "ar-SY"), (0x2809, "en-BZ"), (0x280A, "es-PE"), (0x280C, "fr-SN"),
+    (0x281A, "sr-Cyrl-RS"), (0x2C01, "ar-JO"), (0x2C09, "en-TT"), (0x2C0A, "es-AR"),
+    (0x2C0C, "fr-CM"), (0x2C1A, "sr-Latn-ME"), (0x3001, "ar-LB"), (0x3009, "en-ZW"),
+    (0x300A, "es-EC"), (0x300C, "fr-CI"), (0x301A, "sr-Cyrl-ME"), (0x3401, "ar-KW"),
+    (0x3409, "en-PH"), (0x340A, "es-CL"), (0x340C, "fr-ML"), (0x3801, "ar-AE"), (0x3809, "en-ID"),
+    (0x380A, "es-UY"), (0x380C, "fr-MA"), (0x3C01, "ar-BH"), (0x3C09, "en-HK"), (0x3C0A, "es-PY"),
+    (0x3C0C, "fr-HT"), (0x4001, "ar-QA"), (0x4009, "en-IN"), (0x400A, "es-BO"), (0x4409, "en-MY"),
+    (0x440A, "es-SV"), (0x4809, "en-SG"), (0x480A, "es-HN"), (0x4C0A, "es-NI"), (0x500A, "es-PR"),
+    (0x540A, "es-US"), (0x580A, "es-419"), (0x5C0A, "es-CU"), (0x641A, "bs-Cyrl"),
+    (0x681A, "bs-Latn"), (0x6C1A, "sr-Cyrl"), (0x701A, "sr-Latn"), (0x703B, "smn"),
+    (0x742C, "az-Cyrl"), (0x743B, "sms"), (0x7804, "zh"), (0x7814, "nn"), (0x781A, "bs"),
+    (0x782C, "az-Latn"), (0x783B, "sma"), (0x7843, "uz-Cyrl"), (0x7850, "mn-Cyrl"),
+    (0x785D, "iu-Cans"), (0x7C04, "zh-Hant"), (0x7C14, "nb"), (0x7C1A, "sr"), (0x7C28, "tg-Cyrl"),
+    (0x7C2E, "dsb"), (0x7C3B, "smj"), (0x7C43, "uz-Latn"), (0x7C50, "mn-Mong"), (0x7C59, "sd-Arab"),
+    (0x7C5C, "chr-Cher"), (0x7C5D, "iu-Latn"), (0x7C5F, "tzm-Latn"), (0x7C67, "ff-Latn"),
+    (0x7C68, "ha-Latn"), (0x7C92, "ckb-Arab"), (0xE40C, "fr-015"),
+];
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::testing::{name, Writer};
+    use std::string::{String, ToString};
+    use std::vec::Vec;
+
+    fn ltag(tags: &[&str]) -> Vec<u8> {
+        let mut w = Writer::new();
+        w.u32(1).u32(0).u32(tags.len() as u32);
+        let mut offset = 12 + tags.len() * 4;
+        for tag in tags {
+            w.u16(offset as u16).u16(tag.len() as u16);
+            offset += tag.len();
+        }
+        for tag in tags {
+            w.bytes(tag.as_bytes());
+        }
+        w.finish()
+    }
+
+    fn find(name: &Name, name_id: NameId, languages: &[&str]) -> Option<String> {
+        Some(name.find(name_id, languages, None)?.decode().collect())
+    }
+
+    #[test]
+    fn language_matching() {
+        let data = name(
+            &[
+                (1, 0, 0, FAMILY_NAME, "Mac"),
+                (3, 1, 0x0409, FAMILY_NAME, "English"),
+                (3, 1, 0x0407, FAMILY_NAME, "Deutsch"),
+                (3, 1, 0x0807, FAMILY_NAME, "Schweiz"),
+                (1, 0, 2, SUBFAMILY_NAME, "Fett"),
+                (3, 1, 0x0409, SUBFAMILY_NAME, "Bold"),
+            ],
+            &[],
+        );
+        let name = Name::try_new(&data).unwrap();
+        assert_eq!(find(&name, FAMILY_NAME, &["de-CH"]).unwrap(), "Schweiz");
+        assert_eq!(find(&name, FAMILY_NAME, &["DE-at"]).unwrap(), "Deutsch");
+        assert_eq!(
+            find(&name, FAMILY_NAME, &["fr", "de-CH"]).unwrap(),
+            "Schweiz"
+        );
+        assert_eq!(find(&name, FAMILY_NAME, &["fr"]).unwrap(), "English");
+        assert_eq!(find(&name, FAMILY_NAME, &[]).unwrap(), "English");
+        assert_eq!(find(&name, SUBFAMILY_NAME, &["de"]).unwrap(), "Fett");
+        assert_eq!(find(&name, SUBFAMILY_NAME, &["ja"]).unwrap(), "Bold");
+        let tag = name
+            .language_tag(&name.records().get(0).unwrap(), None)
+            .unwrap();
+        assert_eq!(tag.as_str(), Some("en"));
+        let tag = name
+            .language_tag(&name.records().get(3).unwrap(), None)
+            .unwrap();
+        assert!(tag.matches("DE-ch"));
+        assert!(!tag.matches("de"));
+        assert!(!tag.matches("de-CH-x"));
+    }
+
+    #[test]
+    fn fallbacks() {
+        let data = name(
+            &[
+                (3, 1, 0x0409, FAMILY_NAME, "Family"),
+                (3, 1, 0x0409, SUBFAMILY_NAME, "Regular"),
+                (3, 1, 0x0409, TYPOGRAPHIC_SUBFAMILY_NAME, "Light"),
+                (3, 6, 0x0409, FULL_NAME, "Undecodable"),
+            ],
+            &[],
+        );
+        let name = Name::try_new(&data).unwrap();
+        assert_eq!(find(&name, TYPOGRAPHIC_FAMILY_NAME, &[]).unwrap(), "Family");
+        assert_eq!(find(&name, WWS_FAMILY_NAME, &[]).unwrap(), "Family");
+        assert_eq!(find(&name, WWS_SUBFAMILY_NAME, &[]).unwrap(), "Light");
+        assert_eq!(find(&name, FULL_NAME, &[]), None);
+        assert_eq!(find(&name, VERSION_STRING, &[]), None);
+    }
+
+    #[test]
+    fn language_tags() {
+        let data = name(
+            &[
+                (0, 3, 0, FAMILY_NAME, "Unicode"),
+                (0, 3, 0xFFFF, FAMILY_NAME, "Default"),
+                (3, 1, 0x8000, FAMILY_NAME, "Famille"),
+                (3, 1, 0x8001, FAMILY_NAME, "Missing"),
+            ],
+            &["fr-CA"],
+        );
+        let ltag_data = ltag(&["ja"]);
+        let ltag = Ltag::new(&ltag_data);
+        let name = Name::try_new(&data).unwrap();
+        let records = name.records();
+        let tag = |index| name.language_tag(&records.get(index).unwrap(), Some(&ltag));
+        assert_eq!(tag(0).unwrap().as_str(), Some("ja"));
+        assert!(tag(1).is_none());
+        let custom = tag(2).unwrap();
+        assert_eq!(custom.as_str(), None);
+        assert_eq!(custom.to_string(), "fr-CA");
+        assert!(custom.matches("FR-ca"));
+        assert!(tag(3).is_none());
+        assert!(name.language_tag(&records.get(0).unwrap(), None).is_none());
+        let find = |languages: &[&str]| -> String {
+            let entry = name.find(FAMILY_NAME, languages, Some(&ltag)).unwrap();
+            entry.decode().collect()
+        };
+        assert_eq!(find(&["ja-JP"]), "Unicode");
+        assert_eq!(find(&["fr"]), "Famille");
+    }
+}
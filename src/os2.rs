@@ -17,6 +17,21 @@ impl<'a> Os2<'a> {
         Self(Buffer::new(data))
     }
 
+    /// Creates a new OS/2 and Windows metrics table from a byte slice
+    /// containing the table data, returning an error if the table is
+    /// truncated for its version.
+    pub fn try_new(data: &'a [u8]) -> Result<Self, ReadError> {
+        let b = Buffer::new(data);
+        let len = match b.try_read::<u16>(0).map_err(|e| e.with_tag(OS2))? {
+            0 => 78,
+            1 => 86,
+            2..=4 => 96,
+            _ => 100,
+        };
+        b.try_ensure_range(0, len).map_err(|e| e.with_tag(OS2))?;
+        Ok(Self(b))
+    }
+
     /// Returns the version number for the OS/2 table: 0x0000 to 0x0005.
     pub fn version(&self) -> u16 {
         self.0.read(0).unwrap_or(0)
@@ -0,0 +1,263 @@
+use super::{GlyphInfo, MAX_CONTEXT_LENGTH};
+use crate::otl::{Covered, Layout, LookupFilter, NestedLookup, SubtableKind};
+use crate::parse_prelude::*;
+
+/// Positions of the input glyphs that were matched by a contextual rule.
+#[derive(Copy, Clone)]
+pub(crate) struct ContextMatch {
+    /// Indices of the matched input glyphs in the glyph buffer.
+    pub positions: [usize; MAX_CONTEXT_LENGTH],
+    /// Number of matched input glyphs.
+    pub len: usize,
+}
+
+impl ContextMatch {
+    pub fn new() -> Self {
+        Self {
+            positions: [0; MAX_CONTEXT_LENGTH],
+            len: 0,
+        }
+    }
+
+    /// Returns the index of the last matched input glyph.
+    pub fn end(&self) -> usize {
+        self.positions[self.len.saturating_sub(1)]
+    }
+}
+
+impl<'a> Layout<'a> {
+    /// Matches a sequence of `count` input glyphs following the glyph at
+    /// `index`. The matched positions, including `index`, are written to
+    /// `m`.
+    pub(crate) fn match_input(
+        &self,
+        glyphs: &[GlyphInfo],
+        index: usize,
+        filter: &LookupFilter,
+        count: usize,
+        mut f: impl FnMut(usize, GlyphId) -> bool,
+        m: &mut ContextMatch,
+    ) -> bool {
+        if count + 1 > MAX_CONTEXT_LENGTH {
+            return false;
+        }
+        m.positions[0] = index;
+        m.len = 1;
+        let mut pos = index;
+        for k in 0..count {
+            pos = match self.next_glyph(glyphs, pos, filter) {
+                Some(pos) => pos,
+                None => return false,
+            };
+            if !f(k, glyphs[pos].glyph_id) {
+                return false;
+            }
+            m.positions[m.len] = pos;
+            m.len += 1;
+        }
+        true
+    }
+
+    /// Matches a sequence of `count` backtrack glyphs preceding the glyph at
+    /// `index`.
+    pub(crate) fn match_backtrack(
+        &self,
+        glyphs: &[GlyphInfo],
+        index: usize,
+        filter: &LookupFilter,
+        count: usize,
+        mut f: impl FnMut(usize, GlyphId) -> bool,
+    ) -> bool {
+        let mut pos = index;
+        for k in 0..count {
+            pos = match self.prev_glyph(glyphs, pos, filter) {
+                Some(pos) => pos,
+                None => return false,
+            };
+            if !f(k, glyphs[pos].glyph_id) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Matches a sequence of `count` lookahead glyphs following the glyph at
+    /// `index`.
+    pub(crate) fn match_lookahead(
+        &self,
+        glyphs: &[GlyphInfo],
+        index: usize,
+        filter: &LookupFilter,
+        count: usize,
+        mut f: impl FnMut(usize, GlyphId) -> bool,
+    ) -> bool {
+        let mut pos = index;
+        for k in 0..count {
+            pos = match self.next_glyph(glyphs, pos, filter) {
+                Some(pos) => pos,
+                None => return false,
+            };
+            if !f(k, glyphs[pos].glyph_id) {
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Attempts to match the sequence and chained context subtable at the
+    /// glyph with the specified index. On success, returns the nested
+    /// lookups that should be applied to the positions recorded in `m`.
+    pub(crate) fn match_context<'b>(
+        &self,
+        subtable: &SubtableKind<'b>,
+        covered: Covered,
+        glyphs: &[GlyphInfo],
+        index: usize,
+        filter: &LookupFilter,
+        m: &mut ContextMatch,
+    ) -> Option<Slice<'b, NestedLookup>> {
+        use SubtableKind::*;
+        match subtable {
+            SeqContext1(s) => {
+                let set = s.get(covered)?;
+                for i in 0..set.len() {
+                    let rule = match set.get(i) {
+                        Some(rule) => rule,
+                        _ => continue,
+                    };
+                    let input = rule.input;
+                    if self.match_input(
+                        glyphs,
+                        index,
+                        filter,
+                        input.len(),
+                        |k, g| input.get(k) == Some(g),
+                        m,
+                    ) {
+                        return Some(rule.lookups);
+                    }
+                }
+                None
+            }
+            SeqContext2(s) => {
+                let classes = s.input();
+                let set = s.get(covered)?;
+                for i in 0..set.len() {
+                    let rule = match set.get(i) {
+                        Some(rule) => rule,
+                        _ => continue,
+                    };
+                    let input = rule.input;
+                    if self.match_input(
+                        glyphs,
+                        index,
+                        filter,
+                        input.len(),
+                        |k, g| input.get(k) == Some(classes.get(g)),
+                        m,
+                    ) {
+                        return Some(rule.lookups);
+                    }
+                }
+                None
+            }
+            SeqContext3(s) => {
+                let rule = s.get(covered)?;
+                let input = rule.input;
+                if self.match_input(
+                    glyphs,
+                    index,
+                    filter,
+                    input.len(),
+                    |k, g| input.get(k).and_then(|c| c.get(g)).is_some(),
+                    m,
+                ) {
+                    return Some(rule.lookups);
+                }
+                None
+            }
+            ChainContext1(s) => {
+                let set = s.get(covered)?;
+                for i in 0..set.len() {
+                    let rule = match set.get(i) {
+                        Some(rule) => rule,
+                        _ => continue,
+                    };
+                    let (backtrack, input, lookahead) =
+                        (rule.backtrack, rule.input, rule.lookahead);
+                    if self.match_input(
+                        glyphs,
+                        index,
+                        filter,
+                        input.len(),
+                        |k, g| input.get(k) == Some(g),
+                        m,
+                    ) && self.match_backtrack(glyphs, index, filter, backtrack.len(), |k, g| {
+                        backtrack.get(k) == Some(g)
+                    }) && self.match_lookahead(
+                        glyphs,
+                        m.end(),
+                        filter,
+                        lookahead.len(),
+                        |k, g| lookahead.get(k) == Some(g),
+                    ) {
+                        return Some(rule.lookups);
+                    }
+                }
+                None
+            }
+            ChainContext2(s) => {
+                let (backtrack_classes, input_classes, lookahead_classes) =
+                    (s.backtrack(), s.input(), s.lookahead());
+                let set = s.get(covered)?;
+                for i in 0..set.len() {
+                    let rule = match set.get(i) {
+                        Some(rule) => rule,
+                        _ => continue,
+                    };
+                    let (backtrack, input, lookahead) =
+                        (rule.backtrack, rule.input, rule.lookahead);
+                    if self.match_input(
+                        glyphs,
+                        index,
+                        filter,
+                        input.len(),
+                        |k, g| input.get(k) == Some(input_classes.get(g)),
+                        m,
+                    ) && self.match_backtrack(glyphs, index, filter, backtrack.len(), |k, g| {
+                        backtrack.get(k) == Some(backtrack_classes.get(g))
+                    }) && self.match_lookahead(
+                        glyphs,
+                        m.end(),
+                        filter,
+                        lookahead.len(),
+                        |k, g| lookahead.get(k) == Some(lookahead_classes.get(g)),
+                    ) {
+                        return Some(rule.lookups);
+                    }
+                }
+                None
+            }
+            ChainContext3(s) => {
+                let rule = s.get(covered)?;
+                let (backtrack, input, lookahead) = (rule.backtrack, rule.input, rule.lookahead);
+                if self.match_input(
+                    glyphs,
+                    index,
+                    filter,
+                    input.len(),
+                    |k, g| input.get(k).and_then(|c| c.get(g)).is_some(),
+                    m,
+                ) && self.match_backtrack(glyphs, index, filter, backtrack.len(), |k, g| {
+                    backtrack.get(k).and_then(|c| c.get(g)).is_some()
+                }) && self.match_lookahead(glyphs, m.end(), filter, lookahead.len(), |k, g| {
+                    lookahead.get(k).and_then(|c| c.get(g)).is_some()
+                }) {
+                    return Some(rule.lookups);
+                }
+                None
+            }
+            _ => None,
+        }
+    }
+}
@@ -0,0 +1,193 @@
+//! Application of layout lookups to a sequence of glyphs.
+
+mod context;
+mod pos;
+#[cfg(feature = "std")]
+mod sub;
+
+pub use pos::{GlyphPosition, Positioner};
+#[cfg(feature = "std")]
+pub use sub::Substitutor;
+
+use super::{Gdef, GlyphClass, Layout, LookupFilter, MarkAttachClass};
+use crate::parse_prelude::*;
+
+/// Maximum nesting depth for contextual lookups.
+pub(crate) const MAX_NESTING_DEPTH: usize = 64;
+
+/// Maximum length of an input sequence matched by a contextual lookup.
+pub(crate) const MAX_CONTEXT_LENGTH: usize = 64;
+
+/// Glyph class for a base glyph.
+pub const BASE_CLASS: GlyphClass = 1;
+
+/// Glyph class for a ligature glyph.
+pub const LIGATURE_CLASS: GlyphClass = 2;
+
+/// Glyph class for a mark glyph.
+pub const MARK_CLASS: GlyphClass = 3;
+
+/// Glyph class for a component glyph.
+pub const COMPONENT_CLASS: GlyphClass = 4;
+
+/// Direction of a run of glyphs.
+#[derive(Copy, Clone, PartialEq, Eq, Default, Debug)]
+pub enum Direction {
+    /// Horizontal text that is laid out from left to right.
+    #[default]
+    LeftToRight,
+    /// Horizontal text that is laid out from right to left.
+    RightToLeft,
+    /// Vertical text that is laid out from top to bottom.
+    TopToBottom,
+    /// Vertical text that is laid out from bottom to top.
+    BottomToTop,
+}
+
+impl Direction {
+    /// Returns true if the direction is horizontal.
+    pub fn is_horizontal(self) -> bool {
+        matches!(self, Self::LeftToRight | Self::RightToLeft)
+    }
+
+    /// Returns true if the direction matches the logical order of the
+    /// glyphs.
+    pub fn is_forward(self) -> bool {
+        matches!(self, Self::LeftToRight | Self::TopToBottom)
+    }
+}
+
+/// Glyph that is the target of layout lookups.
+#[derive(Copy, Clone, Default, Debug)]
+pub struct GlyphInfo {
+    /// Glyph identifier.
+    pub glyph_id: GlyphId,
+    /// Glyph class from the glyph definition table.
+    pub class: GlyphClass,
+    /// Mark attachment class from the glyph definition table.
+    pub mark_class: MarkAttachClass,
+    /// Index of the source cluster that produced the glyph.
+    pub cluster: u32,
+    /// Mask of features that are enabled for the glyph.
+    pub mask: u32,
+    /// Identifier of the ligature that contains the glyph, or zero if the
+    /// glyph is not part of a ligature.
+    pub ligature_id: u8,
+    /// One-based index of the ligature component to which the glyph belongs
+    /// or zero if the glyph is the ligature itself.
+    pub component: u8,
+    /// Number of components in the ligature if the glyph is the ligature
+    /// itself.
+    pub num_components: u8,
+    /// True if the glyph was replaced by a substitution. Shapers clear this
+    /// to detect the glyphs produced by specific features.
+    pub substituted: bool,
+    /// Additional data associated with the glyph by a shaper. This is
+    /// preserved by substitutions.
+    pub data: u32,
+}
+
+impl GlyphInfo {
+    /// Creates a new glyph with the specified identifier and cluster. All
+    /// features are enabled.
+    pub fn new(glyph_id: GlyphId, cluster: u32) -> Self {
+        Self {
+            glyph_id,
+            cluster,
+            mask: !0,
+            ..Default::default()
+        }
+    }
+
+    /// Updates the glyph and mark attachment classes from the specified
+    /// glyph definitions.
+    pub fn classify(&mut self, gdef: Option<&Gdef>) {
+        if let Some(gdef) = gdef {
+            self.class = gdef.class(self.glyph_id);
+            self.mark_class = gdef.mark_class(self.glyph_id);
+        } else {
+            self.class = 0;
+            self.mark_class = 0;
+        }
+    }
+
+    /// Returns true if the glyph is a mark.
+    pub fn is_mark(&self) -> bool {
+        self.class == MARK_CLASS
+    }
+
+    /// Returns true if the glyph is a ligature.
+    pub fn is_ligature(&self) -> bool {
+        self.class == LIGATURE_CLASS
+    }
+
+    /// Returns the number of components in the glyph. This is one unless
+    /// the glyph was formed by a ligature substitution.
+    pub fn component_count(&self) -> u8 {
+        if self.ligature_id != 0 && self.component == 0 {
+            self.num_components.max(1)
+        } else {
+            1
+        }
+    }
+}
+
+impl<'a> Layout<'a> {
+    /// Returns true if the glyph should be ignored by a lookup with the
+    /// specified filter.
+    pub(crate) fn is_skipped(&self, glyph: &GlyphInfo, filter: &LookupFilter) -> bool {
+        let class = glyph.class;
+        if class != 0 && class < 8 && filter.ignored_classes & (1 << class) != 0 {
+            return true;
+        }
+        if class == MARK_CLASS && filter.mark_check {
+            if filter.mark_set != 0 {
+                return self
+                    .gdef
+                    .as_ref()
+                    .and_then(|gdef| gdef._mark_set_coverage(filter.mark_set, glyph.glyph_id))
+                    .is_none();
+            }
+            if filter.mark_class != 0 {
+                return glyph.mark_class != filter.mark_class as u16;
+            }
+        }
+        false
+    }
+
+    /// Returns the index of the next glyph after `index` that is not skipped
+    /// by the filter.
+    pub(crate) fn next_glyph(
+        &self,
+        glyphs: &[GlyphInfo],
+        index: usize,
+        filter: &LookupFilter,
+    ) -> Option<usize> {
+        let mut i = index + 1;
+        while i < glyphs.len() {
+            if !self.is_skipped(&glyphs[i], filter) {
+                return Some(i);
+            }
+            i += 1;
+        }
+        None
+    }
+
+    /// Returns the index of the previous glyph before `index` that is not
+    /// skipped by the filter.
+    pub(crate) fn prev_glyph(
+        &self,
+        glyphs: &[GlyphInfo],
+        index: usize,
+        filter: &LookupFilter,
+    ) -> Option<usize> {
+        let mut i = index;
+        while i > 0 {
+            i -= 1;
+            if !self.is_skipped(&glyphs[i], filter) {
+                return Some(i);
+            }
+        }
+        None
+    }
+}
@@ -0,0 +1,657 @@
+use super::context::ContextMatch;
+use super::{Direction, GlyphInfo, MAX_NESTING_DEPTH};
+use crate::gpos::Gpos;
+use crate::otl::{Anchor, Component, Covered, Layout, Lookup, LookupFilter, Stage, Subtable};
+use crate::otl::{SubtableKind, Value};
+use crate::parse_prelude::*;
+use crate::var::item::ItemVariationStore;
+
+const ATTACH_NONE: u8 = 0;
+const ATTACH_MARK: u8 = 1;
+const ATTACH_CURSIVE: u8 = 2;
+
+/// Position of a glyph in font units.
+///
+/// Offsets are relative to the pen position of the glyph and advances are
+/// the amount by which the pen position should be moved after the glyph.
+/// Values follow the font coordinate system where the y-axis points up.
+#[derive(Copy, Clone, Default, Debug)]
+pub struct GlyphPosition {
+    /// Horizontal advance.
+    pub x_advance: i32,
+    /// Vertical advance.
+    pub y_advance: i32,
+    /// Horizontal offset.
+    pub x_offset: i32,
+    /// Vertical offset.
+    pub y_offset: i32,
+    attach_kind: u8,
+    attach_chain: i16,
+}
+
+impl GlyphPosition {
+    /// Creates a new position with the specified advances and zero offsets.
+    pub fn new(x_advance: i32, y_advance: i32) -> Self {
+        Self {
+            x_advance,
+            y_advance,
+            ..Default::default()
+        }
+    }
+
+    /// Returns the relative index of the glyph to which this glyph is
+    /// attached, if any. The attachment is resolved by
+    /// [`Positioner::finish`].
+    pub fn attachment(&self) -> Option<isize> {
+        if self.attach_chain != 0 {
+            Some(self.attach_chain as isize)
+        } else {
+            None
+        }
+    }
+
+    fn minor_offset(&self, direction: Direction) -> i32 {
+        if direction.is_horizontal() {
+            self.y_offset
+        } else {
+            self.x_offset
+        }
+    }
+
+    fn set_minor_offset(&mut self, direction: Direction, value: i32) {
+        if direction.is_horizontal() {
+            self.y_offset = value;
+        } else {
+            self.x_offset = value;
+        }
+    }
+}
+
+/// Engine that applies glyph positioning lookups to a sequence of glyphs.
+#[derive(Copy, Clone)]
+pub struct Positioner<'a> {
+    layout: &'a Layout<'a>,
+    ivs: Option<ItemVariationStore<'a>>,
+    coords: &'a [NormalizedCoord],
+    direction: Direction,
+}
+
+impl<'a> Positioner<'a> {
+    /// Creates a new positioner for the specified glyph positioning table,
+    /// normalized variation coordinates and direction.
+    pub fn new(gpos: &'a Gpos<'a>, coords: &'a [NormalizedCoord], direction: Direction) -> Self {
+        let layout = &gpos.0;
+        let ivs = if coords.iter().any(|coord| *coord != 0) {
+            layout.gdef().and_then(|gdef| gdef.ivs())
+        } else {
+            None
+        };
+        Self {
+            layout,
+            ivs,
+            coords,
+            direction,
+        }
+    }
+
+    /// Returns the direction of the glyph sequence.
+    pub fn direction(&self) -> Direction {
+        self.direction
+    }
+
+    /// Applies the lookup at the specified index to all glyphs that have
+    /// any of the bits in `mask` enabled. Returns true if any adjustments
+    /// were made.
+    pub fn apply_lookup(
+        &self,
+        index: u16,
+        mask: u32,
+        glyphs: &[GlyphInfo],
+        positions: &mut [GlyphPosition],
+    ) -> bool {
+        let lookup = match self.layout.lookup(index) {
+            Some(lookup) if lookup.record.stage == Stage::PositionAdjustment => lookup,
+            _ => return false,
+        };
+        let len = glyphs.len().min(positions.len());
+        let glyphs = &glyphs[..len];
+        let filter = lookup.record.filter;
+        let mut applied = false;
+        let mut i = 0;
+        while i < len {
+            let glyph = &glyphs[i];
+            if glyph.mask & mask != 0 && !self.layout.is_skipped(glyph, &filter) {
+                if let Some(next) = self.apply_at(&lookup, glyphs, positions, i, 0) {
+                    applied = true;
+                    i = next.max(i + 1);
+                    continue;
+                }
+            }
+            i += 1;
+        }
+        applied
+    }
+
+    /// Resolves cursive and mark attachments by accumulating the offsets
+    /// of each glyph along its attachment chain. This should be called once
+    /// after all lookups have been applied.
+    pub fn finish(&self, positions: &mut [GlyphPosition]) {
+        if !positions.iter().any(|pos| pos.attach_chain != 0) {
+            return;
+        }
+        for i in 0..positions.len() {
+            propagate_attachment(positions, i, self.direction, MAX_NESTING_DEPTH);
+        }
+    }
+
+    /// Applies the first matching subtable of the lookup to the glyph at
+    /// the specified index. Returns the index at which processing should
+    /// continue.
+    fn apply_at(
+        &self,
+        lookup: &Lookup,
+        glyphs: &[GlyphInfo],
+        positions: &mut [GlyphPosition],
+        index: usize,
+        depth: usize,
+    ) -> Option<usize> {
+        let glyph_id = glyphs[index].glyph_id;
+        for subtable in lookup.subtables() {
+            let covered = match subtable.covered(glyph_id) {
+                Some(covered) => covered,
+                _ => continue,
+            };
+            if let Some(next) =
+                self.apply_subtable(&subtable, covered, glyphs, positions, index, depth)
+            {
+                return Some(next);
+            }
+        }
+        None
+    }
+
+    fn apply_subtable(
+        &self,
+        subtable: &Subtable,
+        covered: Covered,
+        glyphs: &[GlyphInfo],
+        positions: &mut [GlyphPosition],
+        index: usize,
+        depth: usize,
+    ) -> Option<usize> {
+        let filter = subtable.lookup.record.filter;
+        let kind = subtable.kind();
+        use SubtableKind::*;
+        match kind {
+            SinglePos1(s) => {
+                let value = s.get(covered)?;
+                self.apply_value(&value, &mut positions[index]);
+                Some(index + 1)
+            }
+            SinglePos2(s) => {
+                let value = s.get(covered)?;
+                self.apply_value(&value, &mut positions[index]);
+                Some(index + 1)
+            }
+            PairPos1(_) | PairPos2(_) => {
+                let next = self.layout.next_glyph(glyphs, index, &filter)?;
+                let next_id = glyphs[next].glyph_id;
+                let (values, has_second) = match kind {
+                    PairPos1(s) => (s.get(covered, next_id)?, s.value_formats()[1].0 != 0),
+                    PairPos2(s) => (s.get(covered, next_id)?, s.value_formats()[1].0 != 0),
+                    _ => return None,
+                };
+                if let Some(value) = &values[0] {
+                    self.apply_value(value, &mut positions[index]);
+                }
+                if let Some(value) = &values[1] {
+                    self.apply_value(value, &mut positions[next]);
+                }
+                Some(if has_second { next + 1 } else { next })
+            }
+            CursivePos1(s) => {
+                let entry = s.entry(covered)?;
+                let prev = self.layout.prev_glyph(glyphs, index, &filter)?;
+                let exit = s.exit(subtable.covered(glyphs[prev].glyph_id)?)?;
+                self.attach_cursive(subtable, positions, prev, index, exit, entry);
+                Some(index + 1)
+            }
+            MarkPos1(s) => {
+                let base = self.find_mark_base(glyphs, index)?;
+                let (mark_class, mark_anchor) = s.mark_anchor(covered)?;
+                let base_anchor = s.base_anchor(glyphs[base].glyph_id, mark_class)?;
+                self.attach_mark(positions, base, index, &base_anchor, &mark_anchor);
+                Some(index + 1)
+            }
+            MarkLigaturePos1(s) => {
+                let base = self.find_mark_base(glyphs, index)?;
+                let ligature_id = glyphs[base].glyph_id;
+                let (mark_class, mark_anchor) = s.mark_anchor(covered)?;
+                let count = s.num_ligature_components(ligature_id);
+                if count == 0 {
+                    return None;
+                }
+                let mark = &glyphs[index];
+                let component = if glyphs[base].ligature_id != 0
+                    && glyphs[base].ligature_id == mark.ligature_id
+                    && mark.component > 0
+                {
+                    count.min(mark.component as u16) - 1
+                } else {
+                    count - 1
+                };
+                let base_anchor = s.component_anchor(ligature_id, component, mark_class)?;
+                self.attach_mark(positions, base, index, &base_anchor, &mark_anchor);
+                Some(index + 1)
+            }
+            MarkMarkPos1(s) => {
+                let mark_filter = LookupFilter {
+                    ignored_classes: filter.ignored_classes & !0b1110,
+                    ..filter
+                };
+                let base = self.layout.prev_glyph(glyphs, index, &mark_filter)?;
+                let (mark1, mark2) = (&glyphs[index], &glyphs[base]);
+                if !mark2.is_mark() {
+                    return None;
+                }
+                let (id1, id2) = (mark1.ligature_id, mark2.ligature_id);
+                let (comp1, comp2) = (mark1.component, mark2.component);
+                let same_base = if id1 == id2 {
+                    id1 == 0 || comp1 == comp2
+                } else {
+                    (id1 > 0 && comp1 == 0) || (id2 > 0 && comp2 == 0)
+                };
+                if !same_base {
+                    return None;
+                }
+                let (mark_class, mark_anchor) = s.mark_anchor(covered)?;
+                let base_anchor = s.base_anchor(mark2.glyph_id, mark_class)?;
+                self.attach_mark(positions, base, index, &base_anchor, &mark_anchor);
+                Some(index + 1)
+            }
+            SeqContext1(_) | SeqContext2(_) | SeqContext3(_) | ChainContext1(_)
+            | ChainContext2(_) | ChainContext3(_) => {
+                let mut m = ContextMatch::new();
+                let lookups = self
+                    .layout
+                    .match_context(&kind, covered, glyphs, index, &filter, &mut m)?;
+                if depth < MAX_NESTING_DEPTH {
+                    for nested in lookups.iter() {
+                        let seq = nested.sequence_index as usize;
+                        if seq >= m.len {
+                            continue;
+                        }
+                        let pos = m.positions[seq];
+                        if let Some(lookup) = self.layout.lookup(nested.lookup_index) {
+                            if self.layout.is_skipped(&glyphs[pos], &lookup.record.filter) {
+                                continue;
+                            }
+                            self.apply_at(&lookup, glyphs, positions, pos, depth + 1);
+                        }
+                    }
+                }
+                Some(m.end() + 1)
+            }
+            _ => None,
+        }
+    }
+
+    /// Returns the index of the nearest preceding glyph that is not a mark.
+    fn find_mark_base(&self, glyphs: &[GlyphInfo], index: usize) -> Option<usize> {
+        (0..index).rev().find(|&i| !glyphs[i].is_mark())
+    }
+
+    fn attach_mark(
+        &self,
+        positions: &mut [GlyphPosition],
+        base: usize,
+        mark: usize,
+        base_anchor: &Anchor,
+        mark_anchor: &Anchor,
+    ) {
+        let (base_x, base_y) = self.anchor(base_anchor);
+        let (mark_x, mark_y) = self.anchor(mark_anchor);
+        let pos = &mut positions[mark];
+        pos.x_offset = base_x - mark_x;
+        pos.y_offset = base_y - mark_y;
+        pos.attach_kind = ATTACH_MARK;
+        pos.attach_chain = (base as isize - mark as isize) as i16;
+    }
+
+    fn attach_cursive(
+        &self,
+        subtable: &Subtable,
+        positions: &mut [GlyphPosition],
+        i: usize,
+        j: usize,
+        exit: Anchor,
+        entry: Anchor,
+    ) {
+        let (exit_x, exit_y) = self.anchor(&exit);
+        let (entry_x, entry_y) = self.anchor(&entry);
+        // Main direction adjustment.
+        match self.direction {
+            Direction::LeftToRight => {
+                positions[i].x_advance = exit_x + positions[i].x_offset;
+                let d = entry_x + positions[j].x_offset;
+                positions[j].x_advance -= d;
+                positions[j].x_offset -= d;
+            }
+            Direction::RightToLeft => {
+                let d = exit_x + positions[i].x_offset;
+                positions[i].x_advance -= d;
+                positions[i].x_offset -= d;
+                positions[j].x_advance = entry_x + positions[j].x_offset;
+            }
+            Direction::TopToBottom => {
+                positions[i].y_advance = exit_y + positions[i].y_offset;
+                let d = entry_y + positions[j].y_offset;
+                positions[j].y_advance -= d;
+                positions[j].y_offset -= d;
+            }
+            Direction::BottomToTop => {
+                let d = exit_y + positions[i].y_offset;
+                positions[i].y_advance -= d;
+                positions[i].y_offset -= d;
+                positions[j].y_advance = entry_y;
+            }
+        }
+        // Cross direction adjustment. The lookup flag determines which
+        // glyph of the pair is attached to the other.
+        let (mut child, mut parent) = (i, j);
+        let (mut x_offset, mut y_offset) = (entry_x - exit_x, entry_y - exit_y);
+        if !subtable.lookup.record.flag.is_rtl() {
+            core::mem::swap(&mut child, &mut parent);
+            x_offset = -x_offset;
+            y_offset = -y_offset;
+        }
+        reverse_cursive_minor_offset(positions, child, self.direction, parent);
+        let pos = &mut positions[child];
+        pos.attach_kind = ATTACH_CURSIVE;
+        pos.attach_chain = (parent as isize - child as isize) as i16;
+        if self.direction.is_horizontal() {
+            pos.y_offset = y_offset;
+        } else {
+            pos.x_offset = x_offset;
+        }
+    }
+
+    fn apply_value(&self, value: &Value, pos: &mut GlyphPosition) {
+        let format = value.format;
+        if format.has_x() || format.has_x_delta() {
+            pos.x_offset += self.component(&value.x);
+        }
+        if format.has_y() || format.has_y_delta() {
+            pos.y_offset += self.component(&value.y);
+        }
+        if self.direction.is_horizontal() {
+            if format.has_x_advance() || format.has_x_advance_delta() {
+                pos.x_advance += self.component(&value.x_advance);
+            }
+        } else if format.has_y_advance() || format.has_y_advance_delta() {
+            // Vertical advances grow downward.
+            pos.y_advance -= self.component(&value.y_advance);
+        }
+    }
+
+    fn anchor(&self, anchor: &Anchor) -> (i32, i32) {
+        (self.component(&anchor.x), self.component(&anchor.y))
+    }
+
+    /// Returns the value of the component with the variation delta applied.
+    fn component(&self, component: &Component) -> i32 {
+        let value = component.value as i32;
+        match &self.ivs {
+            Some(ivs) => {
+                value
+                    + component
+                        .delta(ivs, self.coords)
+                        .map(|delta| delta.to_i32())
+                        .unwrap_or(0)
+            }
+            _ => value,
+        }
+    }
+}
+
+/// Reverses the direction of an existing cursive chain starting at the
+/// specified glyph so that it can be attached to a new parent.
+fn reverse_cursive_minor_offset(
+    positions: &mut [GlyphPosition],
+    start: usize,
+    direction: Direction,
+    new_parent: usize,
+) {
+    let mut i = start;
+    let mut chain = positions[i].attach_chain;
+    let mut kind = positions[i].attach_kind;
+    let mut minor = positions[i].minor_offset(direction);
+    positions[i].attach_chain = 0;
+    for _ in 0..positions.len() {
+        if chain == 0 || kind != ATTACH_CURSIVE {
+            return;
+        }
+        let j = i as isize + chain as isize;
+        if j < 0 || j as usize >= positions.len() || j as usize == new_parent {
+            return;
+        }
+        let j = j as usize;
+        let next = positions[j];
+        let pos = &mut positions[j];
+        pos.set_minor_offset(direction, -minor);
+        pos.attach_chain = -chain;
+        pos.attach_kind = kind;
+        i = j;
+        chain = next.attach_chain;
+        kind = next.attach_kind;
+        minor = next.minor_offset(direction);
+    }
+}
+
+/// Adds the offsets of the parent glyphs along the attachment chain to the
+/// glyph at the specified index.
+fn propagate_attachment(
+    positions: &mut [GlyphPosition],
+    i: usize,
+    direction: Direction,
+    depth: usize,
+) {
+    let chain = positions[i].attach_chain;
+    let kind = positions[i].attach_kind;
+    if chain == 0 || depth == 0 {
+        return;
+    }
+    positions[i].attach_chain = 0;
+    positions[i].attach_kind = ATTACH_NONE;
+    let j = i as isize + chain as isize;
+    if j < 0 || j as usize >= positions.len() {
+        return;
+    }
+    let j = j as usize;
+    propagate_attachment(positions, j, direction, depth - 1);
+    let parent = positions[j];
+    if kind == ATTACH_CURSIVE {
+        if direction.is_horizontal() {
+            positions[i].y_offset += parent.y_offset;
+        } else {
+            positions[i].x_offset += parent.x_offset;
+        }
+    } else if j < i {
+        let mut x_offset = positions[i].x_offset + parent.x_offset;
+        let mut y_offset = positions[i].y_offset + parent.y_offset;
+        if direction.is_forward() {
+            for pos in &positions[j..i] {
+                x_offset -= pos.x_advance;
+                y_offset -= pos.y_advance;
+            }
+        } else {
+            for pos in &positions[j + 1..=i] {
+                x_offset += pos.x_advance;
+                y_offset += pos.y_advance;
+            }
+        }
+        positions[i].x_offset = x_offset;
+        positions[i].y_offset = y_offset;
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::otl::{Gdef, MARK_CLASS};
+    use crate::testing::{coverage, cursive_pos, layout, lookup, mark_base_pos, pair_pos};
+    use crate::testing::{single_pos, Writer};
+    use std::vec::Vec;
+
+    fn glyphs(ids: &[GlyphId], marks: &[GlyphId]) -> Vec<GlyphInfo> {
+        ids.iter()
+            .enumerate()
+            .map(|(i, glyph_id)| {
+                let mut glyph = GlyphInfo::new(*glyph_id, i as u32);
+                if marks.contains(glyph_id) {
+                    glyph.class = MARK_CLASS;
+                }
+                glyph
+            })
+            .collect()
+    }
+
+    #[test]
+    fn adjustments_and_mark_attachment() {
+        let data = layout(
+            b"DFLT",
+            &[],
+            &[
+                lookup(2, 8, &[pair_pos(&[(1, 2, -50)])]),
+                lookup(1, 0, &[single_pos(&[(2, 20)])]),
+                lookup(4, 0, &[mark_base_pos(&[(3, 50, 0)], &[(1, 250, 600)])]),
+            ],
+        );
+        let gpos = Gpos::new(&data, None);
+        let positioner = Positioner::new(&gpos, &[], Direction::LeftToRight);
+        let glyphs = glyphs(&[1, 3, 2], &[3]);
+        let mut positions = [
+            GlyphPosition::new(500, 0),
+            GlyphPosition::new(0, 0),
+            GlyphPosition::new(600, 0),
+        ];
+        for index in 0..3 {
+            assert!(positioner.apply_lookup(index, !0, &glyphs, &mut positions));
+        }
+        assert!(!positioner.apply_lookup(3, !0, &glyphs, &mut positions));
+        assert_eq!(positions[1].attachment(), Some(-1));
+        positioner.finish(&mut positions);
+        assert_eq!(positions[1].attachment(), None);
+        let values = positions
+            .iter()
+            .map(|p| (p.x_advance, p.x_offset, p.y_offset))
+            .collect::<Vec<_>>();
+        assert_eq!(values, [(450, 0, 0), (0, -250, 600), (620, 0, 0)]);
+    }
+
+    #[test]
+    fn cursive_attachment() {
+        let data = layout(
+            b"DFLT",
+            &[],
+            &[lookup(
+                3,
+                0,
+                &[cursive_pos(&[
+                    (1, None, Some((400, 100))),
+                    (2, Some((0, 0)), Some((300, 50))),
+                    (3, Some((20, 0)), None),
+                ])],
+            )],
+        );
+        let gpos = Gpos::new(&data, None);
+        let positioner = Positioner::new(&gpos, &[], Direction::LeftToRight);
+        let glyphs = glyphs(&[1, 2, 3], &[]);
+        let mut positions = [GlyphPosition::new(500, 0); 3];
+        assert!(positioner.apply_lookup(0, !0, &glyphs, &mut positions));
+        positioner.finish(&mut positions);
+        let values = positions
+            .iter()
+            .map(|p| (p.x_advance, p.x_offset, p.y_offset))
+            .collect::<Vec<_>>();
+        // Each exit anchor meets the entry anchor of the next glyph.
+        assert_eq!(values, [(400, 0, 0), (300, 0, 100), (480, -20, 150)]);
+    }
+
+    #[test]
+    fn right_to_left_cursive_chain() {
+        let subtable = || {
+            cursive_pos(&[
+                (1, None, Some((400, 100))),
+                (2, Some((0, 0)), Some((300, 50))),
+                (3, Some((20, 0)), None),
+            ])
+        };
+        let data = layout(
+            b"DFLT",
+            &[],
+            &[lookup(3, 0, &[subtable()]), lookup(3, 1, &[subtable()])],
+        );
+        let gpos = Gpos::new(&data, None);
+        let positioner = Positioner::new(&gpos, &[], Direction::RightToLeft);
+        let glyphs = glyphs(&[1, 2, 3], &[]);
+        let apply = |index| {
+            let mut positions = [GlyphPosition::new(500, 0); 3];
+            assert!(positioner.apply_lookup(index, !0, &glyphs, &mut positions));
+            positioner.finish(&mut positions);
+            positions
+                .iter()
+                .map(|p| (p.x_advance, p.x_offset, p.y_offset))
+                .collect::<Vec<_>>()
+        };
+        // Without the flag, the last glyph of the chain is offset vertically.
+        assert_eq!(apply(0), [(100, -400, 0), (-300, -300, 100), (20, 0, 150)]);
+        // With the flag, the first glyph is.
+        assert_eq!(apply(1), [(100, -400, -150), (-300, -300, -50), (20, 0, 0)]);
+    }
+
+    #[test]
+    fn variation_deltas() {
+        // Single adjustment of glyph 1 with an x offset of 100 and an advance
+        // of 50, each with a variation index device table.
+        let mut subtable = Writer::new();
+        subtable
+            .u16(1)
+            .u16(26)
+            .u16(0x55)
+            .i16(100)
+            .i16(50)
+            .u16(14)
+            .u16(20);
+        subtable.u16(0).u16(0).u16(0x8000).u16(0).u16(1).u16(0x8000);
+        subtable.bytes(&coverage(&[1]));
+        let data = layout(b"DFLT", &[], &[lookup(1, 0, &[subtable.finish()])]);
+        // Version 1.3 header followed by a store with deltas of 10 and -20
+        // at the peak of a single axis.
+        let mut gdef = Writer::new();
+        gdef.u16(1)
+            .u16(3)
+            .u16(0)
+            .u16(0)
+            .u16(0)
+            .u16(0)
+            .u16(0)
+            .u32(18);
+        gdef.u16(1).u32(12).u16(1).u32(22);
+        gdef.u16(1).u16(1).u16(0).u16(0x4000).u16(0x4000);
+        gdef.u16(2).u16(0).u16(1).u16(0).u8(10).u8(0xEC);
+        let gdef = gdef.finish();
+        let gpos = Gpos::new(&data, Gdef::new(&gdef));
+        let glyphs = glyphs(&[1], &[]);
+        let apply = |coords| {
+            let positioner = Positioner::new(&gpos, coords, Direction::LeftToRight);
+            let mut positions = [GlyphPosition::new(500, 0)];
+            assert!(positioner.apply_lookup(0, !0, &glyphs, &mut positions));
+            (positions[0].x_advance, positions[0].x_offset)
+        };
+        assert_eq!(apply(&[]), (550, 100));
+        assert_eq!(apply(&[0]), (550, 100));
+        assert_eq!(apply(&[0x4000]), (530, 110));
+        assert_eq!(apply(&[0x2000]), (540, 105));
+    }
+}
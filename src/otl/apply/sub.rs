@@ -0,0 +1,494 @@
+use super::context::ContextMatch;
+use super::{GlyphInfo, MAX_CONTEXT_LENGTH, MAX_NESTING_DEPTH};
+use crate::gsub::Gsub;
+use crate::otl::{Covered, Layout, Lookup, LookupKind, Stage, Subtable, SubtableKind};
+use crate::parse_prelude::*;
+use std::vec::Vec;
+
+/// Engine that applies glyph substitution lookups to a sequence of glyphs.
+#[derive(Copy, Clone)]
+pub struct Substitutor<'a> {
+    layout: &'a Layout<'a>,
+}
+
+impl<'a> Substitutor<'a> {
+    /// Creates a new substitutor for the specified glyph substitution table.
+    pub fn new(gsub: &'a Gsub<'a>) -> Self {
+        Self { layout: &gsub.0 }
+    }
+
+    /// Applies the lookup at the specified index to all glyphs that have
+    /// any of the bits in `mask` enabled. The `alternate` parameter selects
+    /// the replacement for alternate substitutions. Returns true if any
+    /// substitutions were made.
+    pub fn apply_lookup(
+        &self,
+        index: u16,
+        mask: u32,
+        alternate: u16,
+        glyphs: &mut Vec<GlyphInfo>,
+    ) -> bool {
+        let lookup = match self.layout.lookup(index) {
+            Some(lookup) if lookup.record.stage == Stage::Substitution => lookup,
+            _ => return false,
+        };
+        let filter = lookup.record.filter;
+        let mut applied = false;
+        if lookup.record.kind == LookupKind::RevChainContext {
+            let mut i = glyphs.len();
+            while i > 0 {
+                i -= 1;
+                let glyph = &glyphs[i];
+                if glyph.mask & mask != 0
+                    && !self.layout.is_skipped(glyph, &filter)
+                    && self.apply_reverse(&lookup, glyphs, i)
+                {
+                    applied = true;
+                }
+            }
+            return applied;
+        }
+        let mut i = 0;
+        while i < glyphs.len() {
+            let glyph = &glyphs[i];
+            if glyph.mask & mask != 0 && !self.layout.is_skipped(glyph, &filter) {
+                let len = glyphs.len();
+                if let Some(next) = self.apply_at(&lookup, alternate, glyphs, i, 0) {
+                    applied = true;
+                    // Always make progress unless the glyph was deleted.
+                    i = if next > i || glyphs.len() < len {
+                        next
+                    } else {
+                        i + 1
+                    };
+                    continue;
+                }
+            }
+            i += 1;
+        }
+        applied
+    }
+
+    /// Returns true if the lookup at the specified index would substitute
+    /// exactly the specified sequence of glyphs without any surrounding
+    /// context.
+    pub fn would_apply(&self, index: u16, glyph_ids: &[GlyphId]) -> bool {
+        let lookup = match self.layout.lookup(index) {
+            Some(lookup) if lookup.record.stage == Stage::Substitution => lookup,
+            _ => return false,
+        };
+        let first = match glyph_ids.first() {
+            Some(first) => *first,
+            _ => return false,
+        };
+        let mut glyphs = Vec::new();
+        for subtable in lookup.subtables() {
+            let covered = match subtable.covered(first) {
+                Some(covered) => covered,
+                _ => continue,
+            };
+            let kind = subtable.kind();
+            use SubtableKind::*;
+            let applies = match kind {
+                SingleSubst1(_) | SingleSubst2(_) | MultipleSubst1(_) | AlternateSubst1(_) => {
+                    glyph_ids.len() == 1
+                }
+                LigatureSubst1(s) => s.get(covered).is_some_and(|mut ligatures| {
+                    ligatures.any(|ligature| {
+                        let components = ligature.trailing_components;
+                        components.len() + 1 == glyph_ids.len()
+                            && components.iter().eq(glyph_ids[1..].iter().copied())
+                    })
+                }),
+                SeqContext1(_) | SeqContext2(_) | SeqContext3(_) | ChainContext1(_)
+                | ChainContext2(_) | ChainContext3(_) => {
+                    if glyphs.is_empty() {
+                        glyphs.extend(glyph_ids.iter().enumerate().map(|(i, glyph_id)| {
+                            let mut glyph = GlyphInfo::new(*glyph_id, i as u32);
+                            glyph.classify(self.layout.gdef());
+                            glyph
+                        }));
+                    }
+                    let mut m = ContextMatch::new();
+                    self.layout
+                        .match_context(&kind, covered, &glyphs, 0, &Default::default(), &mut m)
+                        .is_some()
+                        && m.len == glyph_ids.len()
+                }
+                _ => false,
+            };
+            if applies {
+                return true;
+            }
+        }
+        false
+    }
+
+    /// Applies the first matching subtable of the lookup to the glyph at
+    /// the specified index. Returns the index at which processing should
+    /// continue.
+    fn apply_at(
+        &self,
+        lookup: &Lookup,
+        alternate: u16,
+        glyphs: &mut Vec<GlyphInfo>,
+        index: usize,
+        depth: usize,
+    ) -> Option<usize> {
+        let glyph_id = glyphs[index].glyph_id;
+        for subtable in lookup.subtables() {
+            let covered = match subtable.covered(glyph_id) {
+                Some(covered) => covered,
+                _ => continue,
+            };
+            if let Some(next) =
+                self.apply_subtable(&subtable, covered, alternate, glyphs, index, depth)
+            {
+                return Some(next);
+            }
+        }
+        None
+    }
+
+    fn apply_subtable(
+        &self,
+        subtable: &Subtable,
+        covered: Covered,
+        alternate: u16,
+        glyphs: &mut Vec<GlyphInfo>,
+        index: usize,
+        depth: usize,
+    ) -> Option<usize> {
+        let filter = subtable.lookup.record.filter;
+        let kind = subtable.kind();
+        use SubtableKind::*;
+        match kind {
+            SingleSubst1(s) => {
+                let glyph_id = s.get(covered)?;
+                self.set_glyph(&mut glyphs[index], glyph_id);
+                Some(index + 1)
+            }
+            SingleSubst2(s) => {
+                let glyph_id = s.get(covered)?;
+                self.set_glyph(&mut glyphs[index], glyph_id);
+                Some(index + 1)
+            }
+            MultipleSubst1(s) => {
+                let seq = s.get(covered)?;
+                let len = seq.len();
+                if len == 1 {
+                    self.set_glyph(&mut glyphs[index], seq.get(0)?);
+                    return Some(index + 1);
+                }
+                let template = glyphs[index];
+                glyphs.splice(
+                    index..index + 1,
+                    seq.iter().enumerate().map(|(i, glyph_id)| {
+                        let mut glyph = template;
+                        self.set_glyph(&mut glyph, glyph_id);
+                        glyph.ligature_id = 0;
+                        glyph.component = (i + 1).min(u8::MAX as usize) as u8;
+                        glyph
+                    }),
+                );
+                Some(index + len)
+            }
+            AlternateSubst1(s) => {
+                let alternates = s.get(covered)?;
+                let glyph_id = alternates.get(alternate as usize)?;
+                self.set_glyph(&mut glyphs[index], glyph_id);
+                Some(index + 1)
+            }
+            LigatureSubst1(s) => {
+                let mut m = ContextMatch::new();
+                for ligature in s.get(covered)? {
+                    let components = ligature.trailing_components;
+                    if self.layout.match_input(
+                        glyphs,
+                        index,
+                        &filter,
+                        components.len(),
+                        |k, g| components.get(k) == Some(g),
+                        &mut m,
+                    ) {
+                        self.ligate(glyphs, &m, ligature.ligature);
+                        return Some(index + 1);
+                    }
+                }
+                None
+            }
+            SeqContext1(_) | SeqContext2(_) | SeqContext3(_) | ChainContext1(_)
+            | ChainContext2(_) | ChainContext3(_) => {
+                let mut m = ContextMatch::new();
+                let lookups = self
+                    .layout
+                    .match_context(&kind, covered, glyphs, index, &filter, &mut m)?;
+                let mut end = m.end() + 1;
+                if depth >= MAX_NESTING_DEPTH {
+                    return Some(end);
+                }
+                for nested in lookups.iter() {
+                    let seq = nested.sequence_index as usize;
+                    if seq >= m.len {
+                        continue;
+                    }
+                    let pos = m.positions[seq];
+                    let lookup = match self.layout.lookup(nested.lookup_index) {
+                        Some(lookup) => lookup,
+                        _ => continue,
+                    };
+                    if pos >= glyphs.len()
+                        || self.layout.is_skipped(&glyphs[pos], &lookup.record.filter)
+                    {
+                        continue;
+                    }
+                    let orig_len = glyphs.len();
+                    self.apply_at(&lookup, alternate, glyphs, pos, depth + 1);
+                    let delta = glyphs.len() as isize - orig_len as isize;
+                    if delta != 0 {
+                        end = adjust_match(&mut m, seq, delta, end);
+                    }
+                }
+                Some(end)
+            }
+            _ => None,
+        }
+    }
+
+    /// Applies a reverse chained contextual substitution to the glyph at the
+    /// specified index.
+    fn apply_reverse(&self, lookup: &Lookup, glyphs: &mut [GlyphInfo], index: usize) -> bool {
+        let filter = lookup.record.filter;
+        let glyph_id = glyphs[index].glyph_id;
+        for subtable in lookup.subtables() {
+            let covered = match subtable.covered(glyph_id) {
+                Some(covered) => covered,
+                _ => continue,
+            };
+            let s = match subtable.kind() {
+                SubtableKind::RevChainContext1(s) => s,
+                _ => continue,
+            };
+            let rule = match s.rule() {
+                Some(rule) => rule,
+                _ => continue,
+            };
+            let (backtrack, lookahead) = (rule.backtrack, rule.lookahead);
+            if self
+                .layout
+                .match_backtrack(glyphs, index, &filter, backtrack.len(), |k, g| {
+                    backtrack.get(k).and_then(|c| c.get(g)).is_some()
+                })
+                && self
+                    .layout
+                    .match_lookahead(glyphs, index, &filter, lookahead.len(), |k, g| {
+                        lookahead.get(k).and_then(|c| c.get(g)).is_some()
+                    })
+            {
+                if let Some(glyph_id) = rule.substitutions.get(covered.coverage_index() as usize) {
+                    self.set_glyph(&mut glyphs[index], glyph_id);
+                    return true;
+                }
+            }
+        }
+        false
+    }
+
+    /// Replaces the matched components with the specified ligature glyph.
+    fn ligate(&self, glyphs: &mut Vec<GlyphInfo>, m: &ContextMatch, ligature: GlyphId) {
+        let first = m.positions[0];
+        let last = m.end();
+        let is_mark_ligature = (0..m.len).all(|i| glyphs[m.positions[i]].is_mark());
+        let ligature_id = if is_mark_ligature {
+            0
+        } else {
+            self.next_ligature_id(glyphs)
+        };
+        let cluster = glyphs[first..=last]
+            .iter()
+            .map(|glyph| glyph.cluster)
+            .min()
+            .unwrap_or(0);
+        for glyph in &mut glyphs[first..=last] {
+            glyph.cluster = cluster;
+        }
+        // Assign ligature components to the marks that were skipped
+        // during matching.
+        let mut last_ligature_id = 0;
+        let mut last_count = 1u8;
+        let mut count = 0u8;
+        for i in 0..m.len {
+            let pos = m.positions[i];
+            if i > 0 && !is_mark_ligature {
+                for glyph in &mut glyphs[m.positions[i - 1] + 1..pos] {
+                    let component = match glyph.component {
+                        0 => last_count,
+                        component => component,
+                    };
+                    glyph.component = count - last_count + component.min(last_count);
+                    glyph.ligature_id = ligature_id;
+                }
+            }
+            last_ligature_id = glyphs[pos].ligature_id;
+            last_count = glyphs[pos].component_count();
+            count = count.saturating_add(last_count);
+        }
+        // Update the marks following the ligature that were attached to its
+        // last component.
+        if !is_mark_ligature && last_ligature_id != 0 {
+            for glyph in &mut glyphs[last + 1..] {
+                if glyph.ligature_id != last_ligature_id || !glyph.is_mark() {
+                    break;
+                }
+                if glyph.component == 0 {
+                    break;
+                }
+                glyph.component = count - last_count + glyph.component.min(last_count);
+                glyph.ligature_id = ligature_id;
+            }
+        }
+        let glyph = &mut glyphs[first];
+        self.set_glyph(glyph, ligature);
+        glyph.ligature_id = ligature_id;
+        glyph.component = 0;
+        glyph.num_components = count;
+        for i in (1..m.len).rev() {
+            glyphs.remove(m.positions[i]);
+        }
+    }
+
+    /// Returns an identifier for a new ligature that does not conflict with
+    /// the ligature of any glyph in the sequence.
+    fn next_ligature_id(&self, glyphs: &[GlyphInfo]) -> u8 {
+        let max = glyphs
+            .iter()
+            .map(|glyph| glyph.ligature_id)
+            .max()
+            .unwrap_or(0);
+        if max < u8::MAX {
+            max + 1
+        } else {
+            1
+        }
+    }
+
+    fn set_glyph(&self, glyph: &mut GlyphInfo, glyph_id: GlyphId) {
+        glyph.glyph_id = glyph_id;
+        glyph.substituted = true;
+        if let Some(gdef) = self.layout.gdef() {
+            if gdef.has_classes() {
+                glyph.classify(Some(gdef));
+            }
+        }
+    }
+}
+
+/// Updates the matched positions after a nested lookup changed the length
+/// of the glyph sequence by `delta` at the specified sequence index.
+/// Returns the new end of the match.
+fn adjust_match(m: &mut ContextMatch, seq: usize, delta: isize, end: usize) -> usize {
+    let pos = m.positions[seq] as isize;
+    let mut end = end as isize + delta;
+    let mut delta = delta;
+    if end < pos {
+        // Never rewind beyond the position of the nested lookup.
+        delta += pos - end;
+        end = pos;
+    }
+    let mut count = m.len as isize;
+    let mut next = seq as isize + 1;
+    if delta > 0 {
+        if delta + count > MAX_CONTEXT_LENGTH as isize {
+            m.len = seq + 1;
+            return end as usize;
+        }
+    } else {
+        delta = delta.max(next - count);
+        next -= delta;
+    }
+    m.positions
+        .copy_within(next as usize..count as usize, (next + delta) as usize);
+    next += delta;
+    count += delta;
+    for j in seq + 1..next as usize {
+        m.positions[j] = m.positions[j - 1] + 1;
+    }
+    for j in next as usize..count as usize {
+        m.positions[j] = (m.positions[j] as isize + delta) as usize;
+    }
+    m.len = count as usize;
+    end as usize
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::otl::MARK_CLASS;
+    use crate::testing::{coverage, layout, ligature_subst, lookup, Writer};
+
+    #[test]
+    fn ligature_of_ligature_components() {
+        // a b -> ab and ab c -> abc, both ignoring marks.
+        let data = layout(
+            b"DFLT",
+            &[],
+            &[
+                lookup(4, 8, &[ligature_subst(&[(&[1, 2], 5)])]),
+                lookup(4, 8, &[ligature_subst(&[(&[5, 3], 6)])]),
+            ],
+        );
+        let gsub = Gsub::new(&data, None);
+        let substitutor = Substitutor::new(&gsub);
+        let mut glyphs = [1, 4, 2, 4, 3, 4]
+            .iter()
+            .enumerate()
+            .map(|(i, glyph_id)| {
+                let mut glyph = GlyphInfo::new(*glyph_id, i as u32);
+                if *glyph_id == 4 {
+                    glyph.class = MARK_CLASS;
+                }
+                glyph
+            })
+            .collect::<Vec<_>>();
+        assert!(substitutor.apply_lookup(0, !0, 0, &mut glyphs));
+        assert_eq!(glyphs[0].component_count(), 2);
+        assert!(substitutor.apply_lookup(1, !0, 0, &mut glyphs));
+        let ids = glyphs.iter().map(|g| g.glyph_id).collect::<Vec<_>>();
+        assert_eq!(ids, [6, 4, 4, 4]);
+        let ligature = glyphs[0];
+        assert_eq!(ligature.component_count(), 3);
+        // The marks following a and b belong to the first and second
+        // components of the final ligature.
+        let components = glyphs[1..3]
+            .iter()
+            .map(|g| (g.ligature_id, g.component))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            components,
+            [(ligature.ligature_id, 1), (ligature.ligature_id, 2)]
+        );
+    }
+
+    #[test]
+    fn empty_coverage_contexts() {
+        // Format 3 sequence and chained contexts with no input coverage. The
+        // primary coverage offset is read from the following field.
+        let subtable = || {
+            let mut w = Writer::new();
+            w.u16(3).u16(0).u16(0).u16(8).bytes(&coverage(&[1]));
+            w.finish()
+        };
+        let data = layout(
+            b"DFLT",
+            &[],
+            &[lookup(5, 0, &[subtable()]), lookup(6, 0, &[subtable()])],
+        );
+        let gsub = Gsub::new(&data, None);
+        let substitutor = Substitutor::new(&gsub);
+        let mut glyphs = Vec::new();
+        glyphs.push(GlyphInfo::new(1, 0));
+        assert!(!substitutor.apply_lookup(0, !0, 0, &mut glyphs));
+        assert!(!substitutor.apply_lookup(1, !0, 0, &mut glyphs));
+        assert_eq!(glyphs[0].glyph_id, 1);
+    }
+}
@@ -0,0 +1,541 @@
+use super::apply::MAX_NESTING_DEPTH;
+use super::{ClassDef, Coverage, CoverageArray, Layout, NestedLookup, Stage, SubtableKind};
+use crate::parse_prelude::*;
+use core::iter::FromIterator;
+use std::vec::Vec;
+
+/// Number of lookup applications permitted per lookup in the layout table
+/// when computing a closure.
+const OPERATIONS_PER_LOOKUP: usize = 64;
+
+/// Bounds for the number of lookup applications permitted when computing a
+/// closure.
+const MIN_OPERATIONS: usize = 0x4000;
+const MAX_OPERATIONS: usize = 0x10_0000;
+
+/// Set of glyph identifiers.
+#[derive(Clone, Default, PartialEq, Eq, Debug)]
+pub struct GlyphSet {
+    bits: Vec<u64>,
+    len: usize,
+}
+
+impl GlyphSet {
+    /// Creates a new empty glyph set.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the number of glyphs in the set.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns true if the set is empty.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Returns true if the set contains the specified glyph.
+    pub fn contains(&self, glyph_id: GlyphId) -> bool {
+        self.bits
+            .get(glyph_id as usize / 64)
+            .is_some_and(|word| word & (1 << (glyph_id % 64)) != 0)
+    }
+
+    /// Adds a glyph to the set. Returns true if the glyph was not already
+    /// present.
+    pub fn insert(&mut self, glyph_id: GlyphId) -> bool {
+        let index = glyph_id as usize / 64;
+        if index >= self.bits.len() {
+            self.bits.resize(index + 1, 0);
+        }
+        let bit = 1 << (glyph_id % 64);
+        let word = &mut self.bits[index];
+        if *word & bit != 0 {
+            return false;
+        }
+        *word |= bit;
+        self.len += 1;
+        true
+    }
+
+    /// Removes a glyph from the set. Returns true if the glyph was present.
+    pub fn remove(&mut self, glyph_id: GlyphId) -> bool {
+        let bit = 1 << (glyph_id % 64);
+        match self.bits.get_mut(glyph_id as usize / 64) {
+            Some(word) if *word & bit != 0 => {
+                *word &= !bit;
+                self.len -= 1;
+                true
+            }
+            _ => false,
+        }
+    }
+
+    /// Removes all glyphs from the set.
+    pub fn clear(&mut self) {
+        self.bits.clear();
+        self.len = 0;
+    }
+
+    /// Returns an iterator over the glyphs in the set in ascending order.
+    pub fn iter(&self) -> impl Iterator<Item = GlyphId> + '_ + Clone {
+        self.bits.iter().enumerate().flat_map(|(index, word)| {
+            let word = *word;
+            (0..64u32)
+                .filter(move |bit| word & (1 << bit) != 0)
+                .map(move |bit| (index * 64) as GlyphId + bit as GlyphId)
+        })
+    }
+
+    fn is_subset(&self, other: &GlyphSet) -> bool {
+        self.bits
+            .iter()
+            .enumerate()
+            .all(|(index, word)| word & !other.bits.get(index).copied().unwrap_or(0) == 0)
+    }
+
+    fn union(&mut self, other: &GlyphSet) {
+        if other.bits.len() > self.bits.len() {
+            self.bits.resize(other.bits.len(), 0);
+        }
+        for (word, other) in self.bits.iter_mut().zip(&other.bits) {
+            *word |= other;
+        }
+        self.len = self
+            .bits
+            .iter()
+            .map(|word| word.count_ones() as usize)
+            .sum();
+    }
+
+    fn intersects(&self, coverage: &Coverage) -> bool {
+        let mut found = false;
+        coverage.indices_with(|glyph_id, _| {
+            found = self.contains(glyph_id);
+            !found
+        });
+        found
+    }
+
+    fn intersection(&self, coverage: &Coverage) -> GlyphSet {
+        let mut set = GlyphSet::new();
+        coverage.indices_with(|glyph_id, _| {
+            if self.contains(glyph_id) {
+                set.insert(glyph_id);
+            }
+            true
+        });
+        set
+    }
+}
+
+impl Extend<GlyphId> for GlyphSet {
+    fn extend<I: IntoIterator<Item = GlyphId>>(&mut self, iter: I) {
+        for glyph_id in iter {
+            self.insert(glyph_id);
+        }
+    }
+}
+
+impl FromIterator<GlyphId> for GlyphSet {
+    fn from_iter<I: IntoIterator<Item = GlyphId>>(iter: I) -> Self {
+        let mut set = Self::new();
+        set.extend(iter);
+        set
+    }
+}
+
+/// Expands the glyph set with all glyphs that can be produced by the
+/// specified substitution lookups until no new glyphs are found or the
+/// operation budget is exhausted.
+pub(crate) fn close_glyphs(layout: &Layout, lookups: &[u16], glyphs: &mut GlyphSet) {
+    let mut added = Vec::new();
+    let mut done = Vec::new();
+    let mut budget = (layout.num_lookups() as usize * OPERATIONS_PER_LOOKUP)
+        .clamp(MIN_OPERATIONS, MAX_OPERATIONS);
+    loop {
+        done.clear();
+        let closure = Closure {
+            layout,
+            glyphs,
+            added: &mut added,
+            done: &mut done,
+            budget: &mut budget,
+        };
+        closure.lookups(lookups);
+        let len = glyphs.len();
+        glyphs.extend(added.drain(..));
+        if glyphs.len() == len || budget == 0 {
+            break;
+        }
+    }
+}
+
+/// State for a single pass of the closure. Glyphs produced during the pass
+/// are collected in `added` so that contexts are always matched against
+/// the glyph set from the start of the pass.
+///
+/// The glyphs produced by a lookup only grow with its active glyphs, so
+/// `done` records the active glyphs each lookup has been applied to during
+/// the pass and a lookup is skipped when its active glyphs are a subset.
+/// Each lookup application consumes one unit of `budget`, which is shared
+/// by all passes.
+struct Closure<'a, 'b> {
+    layout: &'b Layout<'a>,
+    glyphs: &'b GlyphSet,
+    added: &'b mut Vec<GlyphId>,
+    done: &'b mut Vec<GlyphSet>,
+    budget: &'b mut usize,
+}
+
+impl<'a, 'b> Closure<'a, 'b> {
+    fn lookups(mut self, lookups: &[u16]) {
+        let glyphs = self.glyphs;
+        for index in lookups {
+            self.lookup(*index, glyphs, 0);
+        }
+    }
+
+    /// Applies the lookup to the glyphs in `active`, which are the
+    /// candidates for the first (or only) glyph in the input sequence.
+    fn lookup(&mut self, index: u16, active: &GlyphSet, depth: usize) {
+        let layout = self.layout;
+        let lookup = match layout.lookup(index) {
+            Some(lookup) if lookup.record.stage == Stage::Substitution => lookup,
+            _ => return,
+        };
+        if *self.budget == 0 {
+            return;
+        }
+        let index = index as usize;
+        if index >= self.done.len() {
+            self.done.resize(index + 1, GlyphSet::new());
+        }
+        if active.is_subset(&self.done[index]) {
+            return;
+        }
+        self.done[index].union(active);
+        *self.budget -= 1;
+        for subtable in lookup.subtables() {
+            use SubtableKind::*;
+            match subtable.kind() {
+                SingleSubst1(s) => {
+                    s.substs_with(|glyph_id, subst| {
+                        if active.contains(glyph_id) {
+                            self.added.push(subst);
+                        }
+                        true
+                    });
+                }
+                SingleSubst2(s) => {
+                    s.substs_with(|glyph_id, subst| {
+                        if active.contains(glyph_id) {
+                            self.added.push(subst);
+                        }
+                        true
+                    });
+                }
+                MultipleSubst1(s) => {
+                    s.substs_with(|glyph_id, seq| {
+                        if active.contains(glyph_id) {
+                            self.added.extend(seq.iter());
+                        }
+                        true
+                    });
+                }
+                AlternateSubst1(s) => {
+                    s.alternates_with(|glyph_id, alternates| {
+                        if active.contains(glyph_id) {
+                            self.added.extend(alternates.iter());
+                        }
+                        true
+                    });
+                }
+                LigatureSubst1(s) => {
+                    s.ligatures_with(|ligature| {
+                        if active.contains(ligature.first_component)
+                            && ligature
+                                .trailing_components
+                                .iter()
+                                .all(|glyph_id| self.glyphs.contains(glyph_id))
+                        {
+                            self.added.push(ligature.ligature);
+                        }
+                        true
+                    });
+                }
+                SeqContext1(s) => {
+                    for first in active.intersection(&subtable.coverage()).iter() {
+                        let rule_set = match subtable.covered(first).and_then(|c| s.get(c)) {
+                            Some(rule_set) => rule_set,
+                            _ => continue,
+                        };
+                        for rule in rule_set.iter() {
+                            let input = rule.input;
+                            if input.iter().all(|glyph_id| self.glyphs.contains(glyph_id)) {
+                                self.nested(rule.lookups, depth, |_, seq| {
+                                    glyph_at(first, &input, seq)
+                                });
+                            }
+                        }
+                    }
+                }
+                SeqContext2(s) => {
+                    let input_classes = s.input();
+                    let classes = self.classes(&input_classes);
+                    let firsts = active.intersection(&subtable.coverage());
+                    for (class, rule_set) in s.rule_sets().iter().enumerate() {
+                        if !firsts
+                            .iter()
+                            .any(|g| input_classes.get(g) as usize == class)
+                        {
+                            continue;
+                        }
+                        for rule in rule_set.iter() {
+                            let input = rule.input;
+                            if input.iter().all(|class| has_class(&classes, class)) {
+                                self.nested(rule.lookups, depth, |closure, seq| {
+                                    if seq == 0 {
+                                        firsts
+                                            .iter()
+                                            .filter(|g| input_classes.get(*g) as usize == class)
+                                            .collect()
+                                    } else {
+                                        let class = input.get(seq - 1).unwrap_or(0);
+                                        closure.with_class(&input_classes, class)
+                                    }
+                                });
+                            }
+                        }
+                    }
+                }
+                SeqContext3(s) => {
+                    let rule = match s.rule() {
+                        Some(rule) => rule,
+                        _ => continue,
+                    };
+                    let mut input = rule.input.iter();
+                    if input.next().is_some_and(|first| active.intersects(&first))
+                        && input.all(|coverage| self.glyphs.intersects(&coverage))
+                    {
+                        self.nested(rule.lookups, depth, |closure, seq| {
+                            covered_at(closure.glyphs, active, &rule.input, seq)
+                        });
+                    }
+                }
+                ChainContext1(s) => {
+                    for first in active.intersection(&subtable.coverage()).iter() {
+                        let rule_set = match subtable.covered(first).and_then(|c| s.get(c)) {
+                            Some(rule_set) => rule_set,
+                            _ => continue,
+                        };
+                        for rule in rule_set.iter() {
+                            let input = rule.input;
+                            if rule
+                                .backtrack
+                                .iter()
+                                .chain(input.iter())
+                                .chain(rule.lookahead.iter())
+                                .all(|glyph_id| self.glyphs.contains(glyph_id))
+                            {
+                                self.nested(rule.lookups, depth, |_, seq| {
+                                    glyph_at(first, &input, seq)
+                                });
+                            }
+                        }
+                    }
+                }
+                ChainContext2(s) => {
+                    let (backtrack_classes, input_classes, lookahead_classes) =
+                        (s.backtrack(), s.input(), s.lookahead());
+                    let backtrack = self.classes(&backtrack_classes);
+                    let classes = self.classes(&input_classes);
+                    let lookahead = self.classes(&lookahead_classes);
+                    let firsts = active.intersection(&subtable.coverage());
+                    for (class, rule_set) in s.rule_sets().iter().enumerate() {
+                        if !firsts
+                            .iter()
+                            .any(|g| input_classes.get(g) as usize == class)
+                        {
+                            continue;
+                        }
+                        for rule in rule_set.iter() {
+                            let input = rule.input;
+                            if rule.backtrack.iter().all(|c| has_class(&backtrack, c))
+                                && input.iter().all(|c| has_class(&classes, c))
+                                && rule.lookahead.iter().all(|c| has_class(&lookahead, c))
+                            {
+                                self.nested(rule.lookups, depth, |closure, seq| {
+                                    if seq == 0 {
+                                        firsts
+                                            .iter()
+                                            .filter(|g| input_classes.get(*g) as usize == class)
+                                            .collect()
+                                    } else {
+                                        let class = input.get(seq - 1).unwrap_or(0);
+                                        closure.with_class(&input_classes, class)
+                                    }
+                                });
+                            }
+                        }
+                    }
+                }
+                ChainContext3(s) => {
+                    let rule = match s.rule() {
+                        Some(rule) => rule,
+                        _ => continue,
+                    };
+                    let mut input = rule.input.iter();
+                    if input.next().is_some_and(|first| active.intersects(&first))
+                        && input.all(|coverage| self.glyphs.intersects(&coverage))
+                        && rule
+                            .backtrack
+                            .iter()
+                            .chain(rule.lookahead.iter())
+                            .all(|coverage| self.glyphs.intersects(&coverage))
+                    {
+                        self.nested(rule.lookups, depth, |closure, seq| {
+                            covered_at(closure.glyphs, active, &rule.input, seq)
+                        });
+                    }
+                }
+                RevChainContext1(s) => {
+                    let rule = match s.rule() {
+                        Some(rule) => rule,
+                        _ => continue,
+                    };
+                    if !rule
+                        .backtrack
+                        .iter()
+                        .chain(rule.lookahead.iter())
+                        .all(|coverage| self.glyphs.intersects(&coverage))
+                    {
+                        continue;
+                    }
+                    subtable
+                        .coverage()
+                        .indices_with(|glyph_id, coverage_index| {
+                            if active.contains(glyph_id) {
+                                if let Some(subst) = rule.substitutions.get(coverage_index as usize)
+                                {
+                                    self.added.push(subst);
+                                }
+                            }
+                            true
+                        });
+                }
+                _ => {}
+            }
+        }
+    }
+
+    /// Applies the nested lookups of a contextual rule. The `input`
+    /// function returns the glyphs that may occur at the specified index in
+    /// the input sequence.
+    fn nested(
+        &mut self,
+        lookups: Slice<NestedLookup>,
+        depth: usize,
+        input: impl Fn(&Self, usize) -> GlyphSet,
+    ) {
+        if depth >= MAX_NESTING_DEPTH {
+            return;
+        }
+        for nested in lookups.iter() {
+            let active = input(self, nested.sequence_index as usize);
+            if !active.is_empty() {
+                self.lookup(nested.lookup_index, &active, depth + 1);
+            }
+        }
+    }
+
+    /// Returns a bitmap of the classes assigned to the glyphs in the set.
+    fn classes(&self, class_def: &ClassDef) -> Vec<bool> {
+        let mut classes = Vec::new();
+        for glyph_id in self.glyphs.iter() {
+            let class = class_def.get(glyph_id) as usize;
+            if class >= classes.len() {
+                classes.resize(class + 1, false);
+            }
+            classes[class] = true;
+        }
+        classes
+    }
+
+    /// Returns the glyphs in the set that belong to the specified class.
+    fn with_class(&self, class_def: &ClassDef, class: u16) -> GlyphSet {
+        self.glyphs
+            .iter()
+            .filter(|glyph_id| class_def.get(*glyph_id) == class)
+            .collect()
+    }
+}
+
+fn has_class(classes: &[bool], class: u16) -> bool {
+    classes.get(class as usize).copied().unwrap_or(false)
+}
+
+/// Returns the glyph at the specified index of an input sequence that is
+/// matched by glyph identifiers.
+fn glyph_at(first: GlyphId, input: &Slice<GlyphId>, seq: usize) -> GlyphSet {
+    let glyph_id = if seq == 0 {
+        Some(first)
+    } else {
+        input.get(seq - 1)
+    };
+    glyph_id.into_iter().collect()
+}
+
+/// Returns the glyphs at the specified index of an input sequence that is
+/// matched by coverage.
+fn covered_at(glyphs: &GlyphSet, active: &GlyphSet, input: &CoverageArray, seq: usize) -> GlyphSet {
+    let glyphs = if seq == 0 { active } else { glyphs };
+    input
+        .get(seq)
+        .map(|coverage| glyphs.intersection(&coverage))
+        .unwrap_or_default()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gsub::Gsub;
+    use crate::testing::{context_subst, layout, ligature_subst, lookup, single_subst};
+
+    #[test]
+    fn closure() {
+        let data = layout(
+            b"latn",
+            &[],
+            &[
+                lookup(1, 0, &[single_subst(&[(1, 2)])]),
+                lookup(4, 0, &[ligature_subst(&[(&[2, 3], 4)])]),
+                lookup(5, 0, &[context_subst(&[4, 3], &[(1, 3)])]),
+                lookup(1, 0, &[single_subst(&[(3, 5)])]),
+            ],
+        );
+        let gsub = Gsub::new(&data, None);
+        let glyphs = [1, 3].iter().copied().collect::<GlyphSet>();
+        let closure = gsub.closure(&[0, 1, 2], &glyphs);
+        assert_eq!(closure.iter().collect::<Vec<_>>(), [1, 2, 3, 4, 5]);
+    }
+
+    #[test]
+    fn repeated_nested_lookups() {
+        // Each contextual lookup applies the next lookup twice, which would
+        // require an exponential number of lookup applications without the
+        // record of completed lookups.
+        let count = 48;
+        let mut lookups = (0..count)
+            .map(|i| lookup(5, 0, &[context_subst(&[1], &[(0, i + 1), (0, i + 1)])]))
+            .collect::<Vec<_>>();
+        lookups.push(lookup(1, 0, &[single_subst(&[(1, 2)])]));
+        let data = layout(b"latn", &[], &lookups);
+        let gsub = Gsub::new(&data, None);
+        let glyphs = [1].iter().copied().collect::<GlyphSet>();
+        let closure = gsub.closure(&[0], &glyphs);
+        assert_eq!(closure.iter().collect::<Vec<_>>(), [1, 2]);
+    }
+}
@@ -68,7 +68,7 @@ impl<'a> SeqContext3<'a> {
         let mut c = data.cursor_at(base as usize + 2)?;
         let mut input_count = c.read::<u16>()? as usize;
         if !include_first {
-            input_count -= 1;
+            input_count = input_count.checked_sub(1)?;
             c.skip(2)?;
         }
         let lookup_count = c.read::<u16>()? as usize;
@@ -121,7 +121,8 @@ impl<'a> ChainContext2<'a> {
 
     /// Returns the rule set for the specified covered glyph.
     pub fn get(&self, covered: Covered) -> Option<RuleSet<'a, ClassChainRule<'a>>> {
-        self.rule_sets().get(covered.coverage_index() as usize)
+        let index = self.input().get(covered.glyph_id()) as usize;
+        self.rule_sets().get(index)
     }
 
     /// Returns the list of chained contextual rule sets. The rule set is indexed by the
@@ -155,7 +156,7 @@ impl<'a> ChainContext3<'a> {
         let backtrack = CoverageArray::new(data, base, s.read_slice16::<u16>()?);
         let mut input_count = s.read::<u16>()? as usize;
         if !include_first {
-            input_count -= 1;
+            input_count = input_count.checked_sub(1)?;
             s.skip(2)?;
         }
         let input = CoverageArray::new(data, base, s.read_slice::<u16>(input_count)?);
@@ -0,0 +1,206 @@
+use super::Layout;
+use crate::parse_prelude::*;
+use core::ops::Range;
+use std::vec::Vec;
+
+const DFLT: Tag = Tag::new(b"DFLT");
+const DFLT_LOWER: Tag = Tag::new(b"dflt");
+const LATN: Tag = Tag::new(b"latn");
+
+/// Features of a layout table resolved for a script, language and location
+/// in variation space.
+///
+/// The script falls back to the default and Latin scripts when not present
+/// in the table and the language falls back to the default language of the
+/// script. Features are substituted based on the feature variations that
+/// match the location.
+#[derive(Clone, Default, Debug)]
+pub struct FeatureSet {
+    script: Option<Tag>,
+    language: Option<Tag>,
+    required: Option<(Tag, Range<usize>)>,
+    features: Vec<(Tag, Range<usize>)>,
+    lookups: Vec<u16>,
+}
+
+impl FeatureSet {
+    /// Resolves the features for the specified script and language at the
+    /// location in variation space defined by the normalized coordinates.
+    pub fn new(
+        layout: &Layout,
+        script: Tag,
+        language: Option<Tag>,
+        coords: &[NormalizedCoord],
+    ) -> Self {
+        let mut set = Self::default();
+        let script = match [script, DFLT, DFLT_LOWER, LATN]
+            .iter()
+            .find_map(|tag| layout.find_script(*tag))
+        {
+            Some(script) => script,
+            _ => return set,
+        };
+        set.script = Some(script.record.tag);
+        let language = match language
+            .and_then(|tag| script.languages().find(|lang| lang.tag() == tag))
+            .or_else(|| script.default_language())
+        {
+            Some(language) => language,
+            _ => return set,
+        };
+        set.language = Some(language.tag());
+        let variations = layout.feature_variations();
+        let subst = variations
+            .as_ref()
+            .and_then(|variations| variations.find(coords))
+            .map(|condition_set| condition_set.features());
+        let lookup_indices = |index: u16| match subst.and_then(|subst| subst.find(index)) {
+            Some(feature) => Some(feature.lookup_indices()),
+            _ => layout
+                .feature(index)
+                .map(|feature| feature.lookup_indices()),
+        };
+        if let Some(index) = language.required_feature_index() {
+            if let (Some(feature), Some(indices)) = (layout.feature(index), lookup_indices(index)) {
+                let range = set.push_lookups(indices.iter());
+                set.required = Some((feature.record.tag, range));
+            }
+        }
+        let features = language
+            .feature_indices()
+            .iter()
+            .filter_map(|index| Some((layout.feature(index)?.record.tag, index)))
+            .collect::<Vec<_>>();
+        for (i, (tag, _)) in features.iter().enumerate() {
+            if features[..i].iter().any(|(prev, _)| prev == tag) {
+                continue;
+            }
+            // Merge the lookups of all features with the same tag.
+            let indices = features[i..]
+                .iter()
+                .filter(|(other, _)| other == tag)
+                .filter_map(|(_, index)| lookup_indices(*index))
+                .flat_map(|indices| indices.iter());
+            let range = set.push_lookups(indices);
+            set.features.push((*tag, range));
+        }
+        set
+    }
+
+    /// Returns the tag of the selected script.
+    pub fn script(&self) -> Option<Tag> {
+        self.script
+    }
+
+    /// Returns the tag of the selected language.
+    pub fn language(&self) -> Option<Tag> {
+        self.language
+    }
+
+    /// Returns the tag and lookup indices of the feature that is required by
+    /// the language.
+    pub fn required_feature(&self) -> Option<(Tag, &[u16])> {
+        let (tag, range) = self.required.clone()?;
+        Some((tag, &self.lookups[range]))
+    }
+
+    /// Returns the ordered lookup indices for the feature with the
+    /// specified tag. This excludes the required feature.
+    pub fn lookups(&self, tag: Tag) -> Option<&[u16]> {
+        self.features
+            .iter()
+            .find(|(feature, _)| *feature == tag)
+            .map(|(_, range)| &self.lookups[range.clone()])
+    }
+
+    /// Returns an iterator over the tags and ordered lookup indices of the
+    /// features associated with the language. This excludes the required
+    /// feature.
+    pub fn features(&self) -> impl Iterator<Item = (Tag, &[u16])> + '_ + Clone {
+        self.features
+            .iter()
+            .map(move |(tag, range)| (*tag, &self.lookups[range.clone()]))
+    }
+
+    /// Appends the lookup indices in sorted order without duplicates and
+    /// returns their range.
+    fn push_lookups(&mut self, indices: impl Iterator<Item = u16>) -> Range<usize> {
+        let start = self.lookups.len();
+        let mut indices = indices.collect::<Vec<_>>();
+        indices.sort_unstable();
+        indices.dedup();
+        self.lookups.extend(indices);
+        start..self.lookups.len()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::gsub::Gsub;
+    use crate::testing::{layout, lookup, single_subst, Writer};
+    use std::vec;
+
+    /// Returns the layout table with feature variations that replace the
+    /// lookups of the feature at the specified index when the first axis is
+    /// between 0.5 and 1.0.
+    fn with_variations(mut data: Vec<u8>, feature_index: u16, lookups: &[u16]) -> Vec<u8> {
+        data[3] = 1;
+        data.splice(10..10, [0; 4].iter().copied());
+        for offset in [4, 6, 8] {
+            let value = u16::from_be_bytes([data[offset], data[offset + 1]]) + 4;
+            data[offset..offset + 2].copy_from_slice(&value.to_be_bytes());
+        }
+        let base = data.len() as u32;
+        data[10..14].copy_from_slice(&base.to_be_bytes());
+        let mut w = Writer::new();
+        w.u16(1).u16(0).u32(1).u32(16).u32(30);
+        w.u16(1).u32(6).u16(1).u16(0).i16(0x2000).i16(0x4000);
+        w.u16(1).u16(0).u16(1).u16(feature_index).u32(12);
+        w.u16(0).u16(lookups.len() as u16);
+        for index in lookups {
+            w.u16(*index);
+        }
+        data.extend_from_slice(&w.0);
+        data
+    }
+
+    fn gsub_data() -> Vec<u8> {
+        let lookups = (0..4)
+            .map(|i| lookup(1, 0, &[single_subst(&[(1, 2 + i)])]))
+            .collect::<Vec<_>>();
+        layout(
+            b"DFLT",
+            &[(b"liga", &[1, 0]), (b"smcp", &[3]), (b"liga", &[2, 1])],
+            &lookups,
+        )
+    }
+
+    #[test]
+    fn merged_features() {
+        let data = gsub_data();
+        let gsub = Gsub::new(&data, None);
+        let set = FeatureSet::new(&gsub.0, Tag::new(b"arab"), Some(Tag::new(b"URD ")), &[]);
+        assert_eq!(set.script(), Some(DFLT));
+        assert!(set.required_feature().is_none());
+        assert_eq!(set.lookups(Tag::new(b"liga")), Some(&[0, 1, 2][..]));
+        assert_eq!(set.lookups(Tag::new(b"kern")), None);
+        let tags = set.features().map(|(tag, _)| tag).collect::<Vec<_>>();
+        assert_eq!(tags, [Tag::new(b"liga"), Tag::new(b"smcp")]);
+    }
+
+    #[test]
+    fn feature_variations() {
+        let data = with_variations(gsub_data(), 1, &[0, 2]);
+        let gsub = Gsub::new(&data, None);
+        let smcp = Tag::new(b"smcp");
+        let lookups = |coord| {
+            let set = FeatureSet::new(&gsub.0, DFLT, None, &[coord]);
+            set.lookups(smcp).map(|lookups| lookups.to_vec())
+        };
+        assert_eq!(lookups(0), Some(vec![3]));
+        assert_eq!(lookups(0x3000), Some(vec![0, 2]));
+        assert_eq!(lookups(0x4000), Some(vec![0, 2]));
+        assert_eq!(lookups(-0x4000), Some(vec![3]));
+    }
+}
@@ -1,15 +1,34 @@
 //! OpenType layout common types.
 
+mod apply;
+#[cfg(feature = "std")]
+mod closure;
 mod context;
 mod def;
+#[cfg(feature = "std")]
+mod feature_set;
 mod lookup;
 mod pos;
 mod shared;
 mod sub;
 mod table;
 
+#[cfg(feature = "std")]
+pub use apply::Substitutor;
+#[cfg(feature = "std")]
+pub(crate) use apply::MAX_NESTING_DEPTH;
+pub use apply::{
+    Direction, GlyphInfo, GlyphPosition, Positioner, BASE_CLASS, COMPONENT_CLASS, LIGATURE_CLASS,
+    MARK_CLASS,
+};
+#[cfg(feature = "std")]
+pub(crate) use closure::close_glyphs;
+#[cfg(feature = "std")]
+pub use closure::GlyphSet;
 pub use context::*;
 pub use def::Gdef;
+#[cfg(feature = "std")]
+pub use feature_set::FeatureSet;
 pub use lookup::{
     Lookup, LookupFilter, LookupFlag, LookupKind, LookupRecord, Subtable, SubtableKind,
     SubtableRecord,
@@ -221,7 +221,7 @@ impl<'a> PairPos2<'a> {
     /// Returns the position adjustments for the specified covered glyph
     /// and the following glyph.
     pub fn get(&self, glyph_id: Covered, next_id: GlyphId) -> Option<[Option<Value>; 2]> {
-        self.get_impl(glyph_id.coverage_index(), next_id)
+        self.get_impl(glyph_id.glyph_id(), next_id)
     }
 
     /// Returns the position adjustments for the specified glyph class pair.
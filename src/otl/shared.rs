@@ -17,11 +17,23 @@ impl<'a> Coverage<'a> {
         Self { data, offset }
     }
 
+    /// Returns the offset of the coverage table from the beginning of the
+    /// associated layout table.
+    pub fn offset(&self) -> u32 {
+        self.offset
+    }
+
     /// Returns true if the coverage table is valid.
     pub fn is_valid(&self) -> bool {
         validate_coverage(&self.data, self.offset).is_some()
     }
 
+    /// Returns true if the coverage table is valid and the glyphs or ranges
+    /// it contains are in increasing order, as required for binary search.
+    pub fn is_sorted(&self) -> bool {
+        validate_coverage_order(&self.data, self.offset).is_some()
+    }
+
     /// Returns the coverage index for the specified glyph.
     pub fn get(&self, glyph_id: GlyphId) -> Option<u16> {
         get_coverage(&self.data, self.offset, glyph_id)
@@ -113,7 +125,7 @@ pub struct ClassDef<'a> {
 }
 
 impl<'a> ClassDef<'a> {
-    pub(super) fn new(data: Buffer<'a>, offset: u32) -> Self {
+    pub(crate) fn new(data: Buffer<'a>, offset: u32) -> Self {
         Self { data, offset }
     }
 
@@ -156,6 +168,34 @@ pub fn validate_coverage(b: &Buffer, coverage_offset: u32) -> Option<()> {
     }
 }
 
+pub fn validate_coverage_order(b: &Buffer, coverage_offset: u32) -> Option<()> {
+    validate_coverage(b, coverage_offset)?;
+    let base = coverage_offset as usize;
+    let fmt = b.read::<u16>(base)?;
+    let len = b.read::<u16>(base + 2)? as usize;
+    let arr = base + 4;
+    let mut prev: Option<u16> = None;
+    if fmt == 1 {
+        for glyph_id in b.read_slice::<u16>(arr, len)?.iter() {
+            if prev.is_some_and(|prev| glyph_id <= prev) {
+                return None;
+            }
+            prev = Some(glyph_id);
+        }
+    } else {
+        for i in 0..len {
+            let rec = arr + i * 6;
+            let start = b.read::<u16>(rec)?;
+            let end = b.read::<u16>(rec + 2)?;
+            if end < start || prev.is_some_and(|prev| start <= prev) {
+                return None;
+            }
+            prev = Some(end);
+        }
+    }
+    Some(())
+}
+
 pub unsafe fn _get_coverage_unchecked(
     b: &Buffer,
     coverage_offset: u32,
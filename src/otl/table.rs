@@ -66,6 +66,25 @@ impl<'a> Layout<'a> {
         (0..self.num_scripts()).filter_map(move |index| self.script(index))
     }
 
+    /// Returns the script with the specified tag.
+    ///
+    /// Indic scripts have both old style tags such as `deva` and new style
+    /// tags such as `dev2`. For these scripts, either tag may be specified
+    /// and the script with the new style tag is preferred when both are
+    /// present.
+    pub fn find_script(&'a self, tag: Tag) -> Option<Script<'a>> {
+        if let Some(&(new_tag, old_tag)) = INDIC_SCRIPT_TAGS
+            .iter()
+            .find(|(new_tag, old_tag)| *new_tag == tag || *old_tag == tag)
+        {
+            self.scripts()
+                .find(|script| script.record.tag == new_tag)
+                .or_else(|| self.scripts().find(|script| script.record.tag == old_tag))
+        } else {
+            self.scripts().find(|script| script.record.tag == tag)
+        }
+    }
+
     /// Returns the number of available features.
     pub fn num_features(&self) -> u16 {
         if let Some(base) = self.data.read_u16(6) {
@@ -219,6 +238,20 @@ impl<'a> Layout<'a> {
     }
 }
 
+/// Pairs of new and old style tags for Indic scripts.
+const INDIC_SCRIPT_TAGS: [(Tag, Tag); 10] = [
+    (Tag::new(b"dev2"), Tag::new(b"deva")),
+    (Tag::new(b"bng2"), Tag::new(b"beng")),
+    (Tag::new(b"gur2"), Tag::new(b"guru")),
+    (Tag::new(b"gjr2"), Tag::new(b"gujr")),
+    (Tag::new(b"ory2"), Tag::new(b"orya")),
+    (Tag::new(b"tml2"), Tag::new(b"taml")),
+    (Tag::new(b"tel2"), Tag::new(b"telu")),
+    (Tag::new(b"knd2"), Tag::new(b"knda")),
+    (Tag::new(b"mlm2"), Tag::new(b"mlym")),
+    (Tag::new(b"mym2"), Tag::new(b"mymr")),
+];
+
 /// Information about a script.
 #[derive(Copy, Clone)]
 pub struct ScriptRecord {
@@ -232,6 +265,14 @@ pub struct ScriptRecord {
 }
 
 impl ScriptRecord {
+    /// Returns true if the script is identified by an old style Indic
+    /// script tag such as `deva`.
+    pub fn is_old_indic(&self) -> bool {
+        INDIC_SCRIPT_TAGS
+            .iter()
+            .any(|(_, old_tag)| *old_tag == self.tag)
+    }
+
     /// Creates a new bound script for the specified layout context. The script
     /// must belong to the associated layout table.
     pub fn materialize<'a>(&self, layout: &'a Layout<'a>) -> Script<'a> {
@@ -372,6 +413,20 @@ impl<'a> Language<'a> {
         self.record.code()
     }
 
+    /// Returns the index of the feature that is required by the language.
+    pub fn required_feature_index(&self) -> Option<u16> {
+        let data = &self.layout().data;
+        match data.read_u16(self.record.offset as usize + 2)? {
+            0xFFFF => None,
+            index => Some(index),
+        }
+    }
+
+    /// Returns the feature that is required by the language.
+    pub fn required_feature(&'a self) -> Option<Feature<'a>> {
+        self.layout().feature(self.required_feature_index()?)
+    }
+
     /// Returns the indices of the features associated with the language.
     pub fn feature_indices(&self) -> Slice<'a, u16> {
         let data = &self.layout().data;
@@ -0,0 +1,109 @@
+//! Errors that occur while reading font data.
+
+use crate::types::Tag;
+use core::fmt;
+
+/// Kind of error that occurred while reading font data.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum ReadErrorKind {
+    /// The requested table is not present in the font.
+    MissingTable,
+    /// A read extended beyond the end of the data.
+    OutOfBounds,
+    /// A table or subtable has a format or version that is not supported.
+    UnsupportedFormat(u32),
+    /// A value is invalid in the context in which it appears.
+    InvalidData,
+}
+
+/// Error that occurred while reading font data.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct ReadError {
+    /// Kind of the error.
+    pub kind: ReadErrorKind,
+    /// Tag of the table that contains the corrupt structure, if known.
+    pub tag: Option<Tag>,
+    /// Byte offset of the corrupt structure from the beginning of the
+    /// table, or of the data being read if the table is not known.
+    pub offset: usize,
+}
+
+impl ReadError {
+    /// Creates a new error with the specified kind and offset.
+    pub fn new(kind: ReadErrorKind, offset: usize) -> Self {
+        Self {
+            kind,
+            tag: None,
+            offset,
+        }
+    }
+
+    /// Creates a new error for a table that is not present in the font.
+    pub fn missing_table(tag: Tag) -> Self {
+        Self {
+            kind: ReadErrorKind::MissingTable,
+            tag: Some(tag),
+            offset: 0,
+        }
+    }
+
+    /// Creates a new error for a read that extended beyond the end of the
+    /// data.
+    pub fn out_of_bounds(offset: usize) -> Self {
+        Self::new(ReadErrorKind::OutOfBounds, offset)
+    }
+
+    /// Creates a new error for an unsupported format or version.
+    pub fn unsupported_format(format: u32, offset: usize) -> Self {
+        Self::new(ReadErrorKind::UnsupportedFormat(format), offset)
+    }
+
+    /// Creates a new error for an invalid value.
+    pub fn invalid_data(offset: usize) -> Self {
+        Self::new(ReadErrorKind::InvalidData, offset)
+    }
+
+    /// Returns the error with the specified table tag if it does not already
+    /// identify a table.
+    pub fn with_tag(mut self, tag: Tag) -> Self {
+        if self.tag.is_none() {
+            self.tag = Some(tag);
+        }
+        self
+    }
+
+    /// Returns the error with the offset adjusted by the specified base
+    /// offset. This is used to convert an offset relative to a subtable
+    /// into one relative to the containing table.
+    pub fn with_base(mut self, base: usize) -> Self {
+        self.offset = self.offset.saturating_add(base);
+        self
+    }
+}
+
+impl fmt::Display for ReadError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self.kind {
+            ReadErrorKind::MissingTable => {
+                return match self.tag {
+                    Some(tag) => write!(f, "missing '{}' table", tag),
+                    None => write!(f, "missing table"),
+                };
+            }
+            ReadErrorKind::OutOfBounds => {
+                write!(f, "out of bounds read at offset {}", self.offset)?
+            }
+            ReadErrorKind::UnsupportedFormat(format) => {
+                write!(f, "unsupported format {} at offset {}", format, self.offset)?
+            }
+            ReadErrorKind::InvalidData => write!(f, "invalid data at offset {}", self.offset)?,
+        }
+        if let Some(tag) = self.tag {
+            write!(f, " in '{}' table", tag)?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for ReadError {}
@@ -5,8 +5,10 @@
 use core::ops::Range;
 
 pub mod array;
+pub mod error;
 pub mod slice;
 
+pub use error::{ReadError, ReadErrorKind};
 pub use slice::Slice;
 
 use super::types::Tag;
@@ -47,10 +49,10 @@ impl<'a> Buffer<'a> {
     }
 
     /// Returns true if the specified range is within the bounds of the
-    /// underlying data.
+    /// underlying data. An empty range may begin at the end of the data.
     pub fn check_range(&self, offset: usize, len: usize) -> bool {
         let end = self.0.len();
-        (offset < end) && (end - offset >= len)
+        (offset <= end) && (end - offset >= len)
     }
 
     /// Returns an error if the specified range is not within the bounds of
@@ -187,6 +189,40 @@ impl<'a> Buffer<'a> {
         Some(&self.0[offset..offset + len])
     }
 
+    /// Reads a value of the specified type at the specified offset. Returns
+    /// an error if the read would be out of bounds.
+    pub fn try_read<T: ReadData>(&self, offset: usize) -> Result<T, ReadError> {
+        T::read_data(self.0, offset).ok_or(ReadError::out_of_bounds(offset))
+    }
+
+    /// Reads a slice of values of the specified type and length at some
+    /// offset. Returns an error if the read would be out of bounds.
+    pub fn try_read_slice<T: ReadData>(
+        &self,
+        offset: usize,
+        len: usize,
+    ) -> Result<Slice<'a, T>, ReadError> {
+        self.read_slice(offset, len)
+            .ok_or(ReadError::out_of_bounds(offset))
+    }
+
+    /// Reads a sequence of bytes at the specified offset and length. Returns
+    /// an error if the read would be out of bounds.
+    pub fn try_read_bytes(&self, offset: usize, len: usize) -> Result<&'a [u8], ReadError> {
+        self.read_bytes(offset, len)
+            .ok_or(ReadError::out_of_bounds(offset))
+    }
+
+    /// Returns an error if the specified range is not within the bounds of
+    /// the underlying data.
+    pub fn try_ensure_range(&self, offset: usize, len: usize) -> Result<(), ReadError> {
+        if self.check_range(offset, len) {
+            Ok(())
+        } else {
+            Err(ReadError::out_of_bounds(offset))
+        }
+    }
+
     /// Creates a new cursor at the specified offset.
     pub fn cursor_at(&self, offset: usize) -> Option<Cursor<'a>> {
         Cursor::with_offset(self.0, offset)
@@ -396,6 +432,35 @@ impl<'a> Cursor<'a> {
         self.offset += len;
         Some(bytes)
     }
+
+    /// Reads a value of the specified type. Returns an error containing the
+    /// current offset if the read would be out of bounds.
+    pub fn try_read<T: ReadData>(&mut self) -> Result<T, ReadError> {
+        let offset = self.offset;
+        self.read().ok_or(ReadError::out_of_bounds(offset))
+    }
+
+    /// Reads a slice of values of the specified type and length. Returns an
+    /// error containing the current offset if the read would be out of
+    /// bounds.
+    pub fn try_read_slice<T: ReadData>(&mut self, len: usize) -> Result<Slice<'a, T>, ReadError> {
+        let offset = self.offset;
+        self.read_slice(len).ok_or(ReadError::out_of_bounds(offset))
+    }
+
+    /// Reads a sequence of bytes of the specified length. Returns an error
+    /// containing the current offset if the read would be out of bounds.
+    pub fn try_read_bytes(&mut self, len: usize) -> Result<&'a [u8], ReadError> {
+        let offset = self.offset;
+        self.read_bytes(len).ok_or(ReadError::out_of_bounds(offset))
+    }
+
+    /// Skips the specified number of bytes. Returns an error containing the
+    /// current offset if this would move beyond the end of the data.
+    pub fn try_skip(&mut self, bytes: usize) -> Result<(), ReadError> {
+        let offset = self.offset;
+        self.skip(bytes).ok_or(ReadError::out_of_bounds(offset))
+    }
 }
 
 impl Default for Cursor<'_> {
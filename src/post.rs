@@ -1,6 +1,11 @@
 //! PostScript table.
 
 use crate::parse_prelude::*;
+#[cfg(feature = "std")]
+use crate::{cff::Cff, TableProvider};
+use core::convert::TryFrom;
+#[cfg(feature = "std")]
+use std::{borrow::Cow, vec::Vec};
 
 /// Tag for the `post` table.
 pub const POST: Tag = Tag::new(b"post");
@@ -18,6 +23,19 @@ impl<'a> Post<'a> {
         Self(Buffer::new(data))
     }
 
+    /// Creates a new PostScript table from a byte slice containing the table
+    /// data, returning an error if the version is not supported or the table
+    /// is truncated.
+    pub fn try_new(data: &'a [u8]) -> Result<Self, ReadError> {
+        let b = Buffer::new(data);
+        match b.try_read::<u32>(0).map_err(|e| e.with_tag(POST))? {
+            0x00010000 | 0x00020000 | 0x00025000 | 0x00030000 | 0x00040000 => {}
+            version => return Err(ReadError::unsupported_format(version, 0).with_tag(POST)),
+        }
+        b.try_ensure_range(0, 32).map_err(|e| e.with_tag(POST))?;
+        Ok(Self(b))
+    }
+
     /// Returns the version of the PostScript table.
     pub fn version(&self) -> Fixed {
         self.0.read(0).unwrap_or(Fixed::ZERO)
@@ -45,10 +63,20 @@ impl<'a> Post<'a> {
     }
 
     /// Returns true if the table can provide glyph names. Only versions 1.0
-    /// (0x00010000) and 2.0 (0x00020000).
+    /// (0x00010000), 2.0 (0x00020000) and 2.5 (0x00025000).
     pub fn has_glyph_names(&self) -> bool {
-        let v = self.version().0;
-        v == 0x10000 || v == 0x20000
+        matches!(self.version().0, 0x10000 | 0x20000 | 0x25000)
+    }
+
+    /// Returns the number of glyphs described by the table. For version 3.0,
+    /// this is zero.
+    pub fn num_glyphs(&self) -> u16 {
+        match self.version().0 {
+            0x10000 => NUM_STANDARD_NAMES,
+            0x20000 | 0x25000 => self.0.read_u16(32).unwrap_or(0),
+            0x40000 => (self.0.len().saturating_sub(32) / 2).min(u16::MAX as usize) as u16,
+            _ => 0,
+        }
     }
 
     /// Returns the name of the specified glyph if available.
@@ -58,7 +86,7 @@ impl<'a> Post<'a> {
         }
         let v = self.version().0;
         if v == 0x10000 {
-            if glyph_id >= 258 {
+            if glyph_id >= NUM_STANDARD_NAMES {
                 return None;
             }
             return Some(DEFAULT_GLYPH_NAMES[glyph_id as usize]);
@@ -82,16 +110,39 @@ impl<'a> Post<'a> {
             base += 1;
             let bytes = b.read_bytes(base, len)?;
             return core::str::from_utf8(bytes).ok();
+        } else if v == 0x25000 {
+            let b = &self.0;
+            if glyph_id >= b.read_u16(32)? {
+                return None;
+            }
+            let offset = b.read_i8(34 + glyph_id as usize)?;
+            let index = glyph_id as i32 + offset as i32;
+            return DEFAULT_GLYPH_NAMES
+                .get(usize::try_from(index).ok()?)
+                .copied();
         }
         None
     }
 
+    /// Returns the character code for the specified glyph in a version 4.0
+    /// table. Version 4.0 tables map glyphs to character codes rather than
+    /// names.
+    pub fn char_code(&self, glyph_id: GlyphId) -> Option<u16> {
+        if self.version().0 != 0x40000 {
+            return None;
+        }
+        match self.0.read_u16(32 + glyph_id as usize * 2)? {
+            0xFFFF => None,
+            code => Some(code),
+        }
+    }
+
     /// Returns an iterator over the available glyph names.
     pub fn glyph_names(&self) -> impl Iterator<Item = Option<&'a str>> + 'a + Clone {
-        let len = match self.version().0 {
-            0x10000 => 258,
-            0x20000 => self.0.read_u16(32).unwrap_or(0),
-            _ => 0,
+        let len = if self.has_glyph_names() {
+            self.num_glyphs()
+        } else {
+            0
         };
         Names {
             post: *self,
@@ -122,6 +173,131 @@ impl<'a> Iterator for Names<'a> {
     }
 }
 
+/// Glyph names indexed for constant time lookup by glyph identifier and
+/// logarithmic lookup by name.
+///
+/// Names are read from the PostScript table or, for version 3.0 tables,
+/// from the charset of the compact font format table. Glyphs in version 4.0
+/// tables are named after their character codes using the Adobe Glyph List.
+#[cfg(feature = "std")]
+#[derive(Clone, Default)]
+pub struct GlyphNames<'a> {
+    names: Vec<Option<Cow<'a, str>>>,
+    /// Glyph identifiers sorted by name.
+    by_name: Vec<GlyphId>,
+}
+
+#[cfg(feature = "std")]
+impl<'a> GlyphNames<'a> {
+    /// Creates glyph names for the specified font.
+    pub fn new(font: &impl TableProvider<'a>) -> Self {
+        let post = font.post();
+        match post {
+            Some(post) if post.has_glyph_names() || post.version().0 == 0x40000 => {
+                Self::from_post(&post)
+            }
+            _ => match font.cff() {
+                Some(cff) => Self::from_cff(&cff),
+                _ => Self::default(),
+            },
+        }
+    }
+
+    /// Creates glyph names from the specified PostScript table.
+    pub fn from_post(post: &Post<'a>) -> Self {
+        let b = &post.0;
+        let count = post.num_glyphs();
+        let names = match post.version().0 {
+            0x20000 => {
+                // Collect the offsets of the Pascal strings in a single pass.
+                let mut strings = Vec::new();
+                let mut base = 34 + count as usize * 2;
+                while let Some(len) = b.read_u8(base) {
+                    strings.push(b.read_bytes(base + 1, len as usize));
+                    base += len as usize + 1;
+                }
+                (0..count)
+                    .map(|glyph_id| {
+                        let index = b.read_u16(34 + glyph_id as usize * 2)? as usize;
+                        let name = match index.checked_sub(NUM_STANDARD_NAMES as usize) {
+                            Some(index) => core::str::from_utf8((*strings.get(index)?)?).ok()?,
+                            None => DEFAULT_GLYPH_NAMES[index],
+                        };
+                        Some(Cow::Borrowed(name))
+                    })
+                    .collect()
+            }
+            0x40000 => (0..count)
+                .map(|glyph_id| Some(crate::agl::glyph_name(post.char_code(glyph_id)? as u32)))
+                .collect(),
+            _ => post
+                .glyph_names()
+                .map(|name| name.map(Cow::Borrowed))
+                .collect(),
+        };
+        Self::from_names(names)
+    }
+
+    /// Creates glyph names from the charset of the specified compact font
+    /// format table.
+    pub fn from_cff(cff: &Cff<'a>) -> Self {
+        let names = (0..cff.num_glyphs())
+            .map(|glyph_id| cff.glyph_name(glyph_id).map(Cow::Borrowed))
+            .collect();
+        Self::from_names(names)
+    }
+
+    pub(crate) fn from_names(names: Vec<Option<Cow<'a, str>>>) -> Self {
+        let mut by_name = (0..names.len() as GlyphId)
+            .filter(|&glyph_id| names[glyph_id as usize].is_some())
+            .collect::<Vec<_>>();
+        // The sort is stable so the first glyph with a duplicate name is
+        // found by lookup.
+        by_name.sort_by(|a, b| names[*a as usize].cmp(&names[*b as usize]));
+        Self { names, by_name }
+    }
+
+    pub(crate) fn into_names(self) -> Vec<Option<Cow<'a, str>>> {
+        self.names
+    }
+
+    /// Returns the number of glyphs.
+    pub fn len(&self) -> usize {
+        self.names.len()
+    }
+
+    /// Returns true if there are no glyphs.
+    pub fn is_empty(&self) -> bool {
+        self.names.is_empty()
+    }
+
+    /// Returns the name of the specified glyph.
+    pub fn get(&self, glyph_id: GlyphId) -> Option<&str> {
+        self.names.get(glyph_id as usize)?.as_deref()
+    }
+
+    /// Returns the identifier of the first glyph with the specified name.
+    pub fn glyph_id(&self, name: &str) -> Option<GlyphId> {
+        let index = self
+            .by_name
+            .partition_point(|&glyph_id| self.get(glyph_id) < Some(name));
+        let glyph_id = *self.by_name.get(index)?;
+        if self.get(glyph_id) == Some(name) {
+            Some(glyph_id)
+        } else {
+            None
+        }
+    }
+
+    /// Returns an iterator over the glyph names, indexed by glyph identifier.
+    pub fn iter(&self) -> impl Iterator<Item = Option<&str>> + '_ + Clone {
+        self.names.iter().map(|name| name.as_deref())
+    }
+}
+
+/// Number of glyphs in the standard Macintosh glyph order.
+const NUM_STANDARD_NAMES: u16 = 258;
+
 #[rustfmt::skip]
 const DEFAULT_GLYPH_NAMES: [&str; 258] = [
     ".notdef", ".null", "nonmarkingreturn", "space", "exclam", "quotedbl", "numbersign", "dollar", 
@@ -154,3 +330,91 @@ const DEFAULT_GLYPH_NAMES: [&str; 258] = [
     "onequarter", "threequarters", "franc", "Gbreve", "gbreve", "Idotaccent", "Scedilla", 
     "scedilla", "Cacute", "cacute", "Ccaron", "ccaron", "dcroat",     
 ];
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::testing::{TestFont, Writer};
+    use crate::FontRef;
+    use std::vec;
+
+    fn post(version: u32, glyphs: &[u16], extra: &[u8]) -> Vec<u8> {
+        let mut w = Writer::new();
+        w.u32(version);
+        for _ in 0..7 {
+            w.u32(0);
+        }
+        if version != 0x40000 {
+            w.u16(glyphs.len() as u16);
+        }
+        for glyph in glyphs {
+            w.u16(*glyph);
+        }
+        w.bytes(extra);
+        w.finish()
+    }
+
+    /// Returns a compact font format table with three glyphs named by a
+    /// format 0 charset.
+    fn cff() -> Vec<u8> {
+        let mut w = Writer::new();
+        w.bytes(&[1, 0, 4, 1]);
+        // Name index.
+        w.u16(1).bytes(&[1, 1, 2]).bytes(b"A");
+        // Top dict index with the charset and charstrings offsets.
+        w.u16(1).bytes(&[1, 1, 9]);
+        w.u8(28).u16(36).u8(15).u8(28).u16(41).u8(17);
+        // String and global subroutine indices.
+        w.u16(1).bytes(&[1, 1, 7]).bytes(b"custom");
+        w.u16(0);
+        // Charset and charstrings.
+        w.u8(0).u16(34).u16(391);
+        w.u16(3).bytes(&[1, 1, 2, 3, 4, 14, 14, 14]);
+        w.finish()
+    }
+
+    #[test]
+    fn versions() {
+        let mut strings = vec![6];
+        strings.extend_from_slice(b"custom");
+        let data = post(0x20000, &[0, 36, 258, 258], &strings);
+        let names = GlyphNames::from_post(&Post::new(&data));
+        assert_eq!(
+            names.iter().collect::<Vec<_>>(),
+            [Some(".notdef"), Some("A"), Some("custom"), Some("custom")]
+        );
+        assert_eq!(names.glyph_id("custom"), Some(2));
+        assert_eq!(names.glyph_id("B"), None);
+        let mut data = post(0x25000, &[], &[0, 35]);
+        data[33] = 2;
+        let post25 = Post::new(&data);
+        assert_eq!(post25.glyph_name(1), Some("A"));
+        assert_eq!(post25.glyph_name(2), None);
+        let data = post(0x40000, &[0xFFFF, 0x41, 0xE9], &[]);
+        let post4 = Post::new(&data);
+        assert_eq!(post4.num_glyphs(), 3);
+        assert_eq!(post4.char_code(1), Some(0x41));
+        let names = GlyphNames::from_post(&post4);
+        assert_eq!(
+            names.iter().collect::<Vec<_>>(),
+            [None, Some("A"), Some("eacute")]
+        );
+    }
+
+    #[test]
+    fn cff_charset() {
+        let data = cff();
+        let cff = Cff::new(&data);
+        assert_eq!(cff.num_glyphs(), 3);
+        assert!(!cff.is_cid());
+        assert_eq!(cff.glyph_sid(2), Some(391));
+        assert_eq!(cff.glyph_name(3), None);
+        let font = TestFont::new(3).table(b"CFF ", data).build();
+        let names = GlyphNames::new(&FontRef::from_index(&font, 0).unwrap());
+        assert_eq!(
+            names.iter().collect::<Vec<_>>(),
+            [Some(".notdef"), Some("A"), Some("custom")]
+        );
+        assert_eq!(names.get(1), Some("A"));
+    }
+}
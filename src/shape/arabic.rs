@@ -0,0 +1,367 @@
+//! Shaping for scripts with cursive joining behavior.
+//!
+//! This covers Arabic, Syriac, N'Ko, Mongolian and Phags-pa. Each character is
+//! assigned a joining form based on the joining types of its neighbors
+//! and the corresponding `isol`, `fina`, `fin2`, `fin3`, `medi`, `med2` and
+//! `init` features are applied only to glyphs in that form.
+
+use super::plan::FeatureCollector;
+use super::unicode;
+use crate::otl::GlyphInfo;
+use crate::parse_prelude::*;
+use std::vec::Vec;
+
+/// Joining type of a character.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum JoiningType {
+    /// Non-joining.
+    U,
+    /// Left joining.
+    L,
+    /// Right joining.
+    R,
+    /// Dual joining.
+    D,
+    /// Syriac alaph.
+    Alaph,
+    /// Syriac dalath and rish.
+    DalathRish,
+    /// Transparent.
+    T,
+}
+
+use JoiningType::*;
+
+/// Joining types for the supported scripts as sorted, inclusive ranges.
+/// Join causing characters are stored as dual joining.
+#[rustfmt::skip]
+const JOINING_TYPES: &[(u32, u32, JoiningType)] = &[
+    (0x0600, 0x0605, U), (0x0608, 0x0608, U), (0x060B, 0x060B, U),
+    (0x0620, 0x0620, D), (0x0621, 0x0621, U), (0x0622, 0x0625, R),
+    (0x0626, 0x0626, D), (0x0627, 0x0627, R), (0x0628, 0x0628, D),
+    (0x0629, 0x0629, R), (0x062A, 0x062E, D), (0x062F, 0x0632, R),
+    (0x0633, 0x063F, D), (0x0640, 0x0640, D), (0x0641, 0x0647, D),
+    (0x0648, 0x0648, R), (0x0649, 0x064A, D), (0x066E, 0x066F, D),
+    (0x0671, 0x0673, R), (0x0674, 0x0674, U), (0x0675, 0x0677, R),
+    (0x0678, 0x0687, D), (0x0688, 0x0699, R), (0x069A, 0x06BF, D),
+    (0x06C0, 0x06C0, R), (0x06C1, 0x06C2, D), (0x06C3, 0x06CB, R),
+    (0x06CC, 0x06CC, D), (0x06CD, 0x06CD, R), (0x06CE, 0x06CE, D),
+    (0x06CF, 0x06CF, R), (0x06D0, 0x06D1, D), (0x06D2, 0x06D3, R),
+    (0x06D5, 0x06D5, R), (0x06DD, 0x06DD, U), (0x06EE, 0x06EF, R),
+    (0x06FA, 0x06FC, D), (0x06FF, 0x06FF, D),
+    // Syriac
+    (0x0710, 0x0710, Alaph), (0x0712, 0x0714, D), (0x0715, 0x0716, DalathRish),
+    (0x0717, 0x0719, R), (0x071A, 0x071D, D), (0x071E, 0x071E, R),
+    (0x071F, 0x0727, D), (0x0728, 0x0728, R), (0x0729, 0x0729, D),
+    (0x072A, 0x072A, DalathRish), (0x072B, 0x072B, D), (0x072C, 0x072C, R),
+    (0x072D, 0x072E, D), (0x072F, 0x072F, DalathRish), (0x074D, 0x074D, R),
+    (0x074E, 0x074F, D),
+    // Arabic Supplement
+    (0x0750, 0x0758, D), (0x0759, 0x075B, R), (0x075C, 0x076A, D),
+    (0x076B, 0x076C, R), (0x076D, 0x0770, D), (0x0771, 0x0771, R),
+    (0x0772, 0x0772, D), (0x0773, 0x0774, R), (0x0775, 0x0777, D),
+    (0x0778, 0x0779, R), (0x077A, 0x077F, D),
+    // N'Ko
+    (0x07CA, 0x07EA, D), (0x07FA, 0x07FA, D),
+    // Syriac Supplement
+    (0x0860, 0x0860, D), (0x0861, 0x0861, U), (0x0862, 0x0865, D),
+    (0x0866, 0x0866, U), (0x0867, 0x0867, R), (0x0868, 0x0868, D),
+    (0x0869, 0x086A, R),
+    // Arabic Extended-A
+    (0x08A0, 0x08A9, D), (0x08AA, 0x08AC, R), (0x08AD, 0x08AD, U),
+    (0x08AE, 0x08AE, R), (0x08AF, 0x08B0, D), (0x08B1, 0x08B2, R),
+    (0x08B3, 0x08B8, D), (0x08B9, 0x08B9, R), (0x08BA, 0x08C8, D),
+    (0x08E2, 0x08E2, U),
+    // Mongolian
+    (0x1807, 0x1807, D), (0x180A, 0x180A, D), (0x180E, 0x180E, U),
+    (0x1820, 0x1878, D), (0x1880, 0x1884, U), (0x1887, 0x18A8, D),
+    (0x18AA, 0x18AA, D),
+    // Phags-pa
+    (0xA840, 0xA871, D), (0xA872, 0xA872, L),
+    // Zero width non-joiner and joiner
+    (0x200C, 0x200C, U), (0x200D, 0x200D, D),
+    (0x202F, 0x202F, U),
+];
+
+/// Canonical combining classes for Arabic marks.
+#[rustfmt::skip]
+const COMBINING_CLASSES: &[(u32, u32, u8)] = &[
+    (0x0610, 0x0617, 230), (0x0618, 0x0618, 30), (0x0619, 0x0619, 31),
+    (0x061A, 0x061A, 32), (0x064B, 0x064B, 27), (0x064C, 0x064C, 28),
+    (0x064D, 0x064D, 29), (0x064E, 0x064E, 30), (0x064F, 0x064F, 31),
+    (0x0650, 0x0650, 32), (0x0651, 0x0651, 33), (0x0652, 0x0652, 34),
+    (0x0653, 0x0654, 230), (0x0655, 0x0656, 220), (0x0657, 0x065B, 230),
+    (0x065C, 0x065C, 220), (0x065D, 0x065E, 230), (0x065F, 0x065F, 220),
+    (0x0670, 0x0670, 35), (0x06D6, 0x06DC, 230), (0x06DF, 0x06E2, 230),
+    (0x06E3, 0x06E3, 220), (0x06E4, 0x06E4, 230), (0x06E7, 0x06E8, 230),
+    (0x06EA, 0x06EA, 220), (0x06EB, 0x06EC, 230), (0x06ED, 0x06ED, 220),
+    (0x0711, 0x0711, 36), (0x08D3, 0x08D3, 220), (0x08F3, 0x08F3, 230),
+];
+
+/// Modifier combining marks that are moved before other marks.
+///
+/// <https://www.unicode.org/reports/tr53/>
+const MODIFIER_MARKS: &[u32] = &[
+    0x0654, 0x0655, 0x0658, 0x06DC, 0x06E3, 0x06E7, 0x06ED, 0x08D3, 0x08F3,
+];
+
+/// Joining forms in the order of their features.
+const FORMS: [&[u8; 4]; 7] = [
+    b"isol", b"fina", b"fin2", b"fin3", b"medi", b"med2", b"init",
+];
+
+const ISOL: u8 = 0;
+const FINA: u8 = 1;
+const FIN2: u8 = 2;
+const FIN3: u8 = 3;
+const MEDI: u8 = 4;
+const MED2: u8 = 5;
+const INIT: u8 = 6;
+const NONE: u8 = 7;
+
+/// Bit of the first joining form feature in the glyph mask.
+const FORM_SHIFT: u32 = 1;
+
+/// Transitions of the joining state machine indexed by state and joining
+/// type. Each entry contains the form of the previous character, the form
+/// of the current character and the next state.
+#[rustfmt::skip]
+const STATE_TABLE: [[(u8, u8, u8); 6]; 7] = [
+    // State 0: previous character was non-joining.
+    [(NONE, NONE, 0), (NONE, ISOL, 2), (NONE, ISOL, 1), (NONE, ISOL, 2), (NONE, ISOL, 1), (NONE, ISOL, 6)],
+    // State 1: previous character was right joining or an isolated alaph.
+    [(NONE, NONE, 0), (NONE, ISOL, 2), (NONE, ISOL, 1), (NONE, ISOL, 2), (NONE, FIN2, 5), (NONE, ISOL, 6)],
+    // State 2: previous character was dual or left joining in isolated form.
+    [(NONE, NONE, 0), (NONE, ISOL, 2), (INIT, FINA, 1), (INIT, FINA, 3), (INIT, FINA, 4), (INIT, FINA, 6)],
+    // State 3: previous character was dual joining in final form.
+    [(NONE, NONE, 0), (NONE, ISOL, 2), (MEDI, FINA, 1), (MEDI, FINA, 3), (MEDI, FINA, 4), (MEDI, FINA, 6)],
+    // State 4: previous character was alaph in final form.
+    [(NONE, NONE, 0), (NONE, ISOL, 2), (MED2, ISOL, 1), (MED2, ISOL, 2), (MED2, FIN2, 5), (MED2, ISOL, 6)],
+    // State 5: previous character was alaph in fin2 or fin3 form.
+    [(NONE, NONE, 0), (NONE, ISOL, 2), (ISOL, ISOL, 1), (ISOL, ISOL, 2), (ISOL, FIN2, 5), (ISOL, ISOL, 6)],
+    // State 6: previous character was dalath or rish.
+    [(NONE, NONE, 0), (NONE, ISOL, 2), (NONE, ISOL, 1), (NONE, ISOL, 2), (NONE, FIN3, 5), (NONE, ISOL, 6)],
+];
+
+/// Returns true if the script is shaped with cursive joining.
+pub fn is_joining_script(script: Tag) -> bool {
+    [b"arab", b"syrc", b"nko ", b"mong", b"phag"]
+        .iter()
+        .any(|tag| Tag::new(tag) == script)
+}
+
+/// Adds the features for joining scripts.
+pub fn collect_features(script: Tag, features: &mut FeatureCollector) {
+    features.add(Tag::new(b"stch"), super::GLOBAL_MASK);
+    features.pause();
+    features.add(Tag::new(b"ccmp"), super::GLOBAL_MASK);
+    features.add(Tag::new(b"locl"), super::GLOBAL_MASK);
+    features.pause();
+    let is_syriac = script == Tag::new(b"syrc");
+    for (i, tag) in FORMS.iter().enumerate() {
+        let form = i as u8;
+        if !is_syriac && matches!(form, FIN2 | FIN3 | MED2) {
+            continue;
+        }
+        features.add(Tag::new(tag), 1 << (FORM_SHIFT + form as u32));
+        features.pause();
+    }
+    features.add(Tag::new(b"rlig"), super::GLOBAL_MASK);
+    features.pause();
+    features.add(Tag::new(b"calt"), super::GLOBAL_MASK);
+    features.pause();
+    features.add(Tag::new(b"mset"), super::GLOBAL_MASK);
+}
+
+/// Reorders the marks following each base character so that they are in
+/// canonical order with modifier combining marks moved to the front.
+pub fn reorder_marks(chars: &mut [(char, u32)]) {
+    let mut i = 0;
+    while i < chars.len() {
+        if combining_class(chars[i].0) == 0 {
+            i += 1;
+            continue;
+        }
+        let start = i;
+        while i < chars.len() && combining_class(chars[i].0) != 0 {
+            i += 1;
+        }
+        let marks = &mut chars[start..i];
+        // Insertion sort is stable and the runs are short.
+        for j in 1..marks.len() {
+            let mut k = j;
+            while k > 0 && combining_class(marks[k - 1].0) > combining_class(marks[k].0) {
+                marks.swap(k - 1, k);
+                k -= 1;
+            }
+        }
+        reorder_modifier_marks(marks);
+    }
+}
+
+fn reorder_modifier_marks(marks: &mut [(char, u32)]) {
+    let mut start = 0;
+    let mut i = 0;
+    for class in [220u8, 230] {
+        while i < marks.len() && combining_class(marks[i].0) < class {
+            i += 1;
+        }
+        if i == marks.len() {
+            break;
+        }
+        if combining_class(marks[i].0) > class {
+            continue;
+        }
+        let mut j = i;
+        while j < marks.len()
+            && combining_class(marks[j].0) == class
+            && MODIFIER_MARKS.contains(&(marks[j].0 as u32))
+        {
+            j += 1;
+        }
+        if i == j {
+            continue;
+        }
+        // Move the run after any modifier marks that were already moved.
+        marks[start..j].rotate_right(j - i);
+        start += j - i;
+        i = j;
+    }
+}
+
+/// Assigns the joining form masks to the glyphs. Glyphs and characters
+/// must correspond one to one.
+pub fn setup_masks(chars: &[(char, u32)], glyphs: &mut [GlyphInfo]) {
+    let mut forms: Vec<u8> = Vec::with_capacity(chars.len());
+    let mut prev: Option<usize> = None;
+    let mut state = 0;
+    for (i, &(ch, _)) in chars.iter().enumerate() {
+        let column = match joining_type(ch) {
+            T => {
+                forms.push(NONE);
+                continue;
+            }
+            U => 0,
+            L => 1,
+            R => 2,
+            D => 3,
+            Alaph => 4,
+            DalathRish => 5,
+        };
+        let (prev_form, form, next_state) = STATE_TABLE[state][column];
+        if prev_form != NONE {
+            if let Some(prev) = prev {
+                forms[prev] = prev_form;
+            }
+        }
+        forms.push(form);
+        prev = Some(i);
+        state = next_state as usize;
+    }
+    // Mongolian free variation selectors take the form of the preceding
+    // character.
+    for i in 1..chars.len() {
+        if matches!(chars[i].0 as u32, 0x180B..=0x180D | 0x180F) {
+            forms[i] = forms[i - 1];
+        }
+    }
+    for (glyph, form) in glyphs.iter_mut().zip(&forms) {
+        if *form != NONE {
+            glyph.mask |= 1 << (FORM_SHIFT + *form as u32);
+        }
+    }
+}
+
+fn joining_type(ch: char) -> JoiningType {
+    let c = ch as u32;
+    if let Some(ty) = lookup(JOINING_TYPES, c) {
+        return ty;
+    }
+    if unicode::is_mark(ch) || unicode::is_default_ignorable(ch) {
+        T
+    } else {
+        U
+    }
+}
+
+fn combining_class(ch: char) -> u8 {
+    lookup(COMBINING_CLASSES, ch as u32).unwrap_or(0)
+}
+
+fn lookup<T: Copy>(ranges: &[(u32, u32, T)], c: u32) -> Option<T> {
+    ranges
+        .binary_search_by(|&(start, end, _)| {
+            use core::cmp::Ordering::*;
+            if c < start {
+                Greater
+            } else if c > end {
+                Less
+            } else {
+                Equal
+            }
+        })
+        .ok()
+        .map(|index| ranges[index].2)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::{ShapeOptions, Shaper};
+    use crate::testing::{layout, lookup, single_subst, TestFont};
+    use crate::FontRef;
+    use std::vec::Vec;
+
+    fn reordered(text: &str) -> Vec<u32> {
+        let mut chars = text.chars().map(|ch| (ch, 0)).collect::<Vec<_>>();
+        reorder_marks(&mut chars);
+        chars.iter().map(|(ch, _)| *ch as u32).collect()
+    }
+
+    #[test]
+    fn modifier_combining_marks() {
+        // Kasra, hamza below and hamza above. Both hamzas move to the front
+        // in their canonical order.
+        assert_eq!(
+            reordered("\u{628}\u{650}\u{655}\u{654}"),
+            [0x628, 0x655, 0x654, 0x650]
+        );
+        assert_eq!(
+            reordered("\u{628}\u{654}\u{651}\u{650}"),
+            [0x628, 0x654, 0x650, 0x651]
+        );
+    }
+
+    #[test]
+    fn joining_forms() {
+        let gsub = layout(
+            b"arab",
+            &[(b"fina", &[0]), (b"init", &[1]), (b"medi", &[2])],
+            &[
+                lookup(1, 0, &[single_subst(&[(1, 3)])]),
+                lookup(1, 0, &[single_subst(&[(1, 4)])]),
+                lookup(1, 0, &[single_subst(&[(1, 5)])]),
+            ],
+        );
+        let data = TestFont::new(6)
+            .chars("\u{628}\u{627}")
+            .table(b"GSUB", gsub)
+            .build();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        let options = ShapeOptions {
+            script: Tag::new(b"arab"),
+            ..Default::default()
+        };
+        let shape = |text: &str| {
+            Shaper::new(&font)
+                .shape(text, &options)
+                .iter()
+                .map(|glyph| glyph.glyph_id)
+                .collect::<Vec<_>>()
+        };
+        // Glyphs are returned in visual order.
+        assert_eq!(shape("\u{628}\u{628}\u{628}"), [3, 5, 4]);
+        assert_eq!(shape("\u{628}\u{627}\u{628}"), [1, 2, 4]);
+        assert_eq!(shape("\u{628}"), [1]);
+    }
+}
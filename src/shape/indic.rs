@@ -0,0 +1,1047 @@
+//! Shaping for the Indic scripts.
+//!
+//! Text is segmented into syllables which are reordered into the order
+//! expected by the font before the basic shaping features are applied and
+//! adjusted again afterward. Fonts that use the old style script tags such
+//! as `deva` and those that use the new style tags such as `dev2` are both
+//! supported.
+
+use super::plan::{FeatureCollector, Pause};
+use super::syllable::{self, category, position, set_category, set_position};
+use super::GLOBAL_MASK;
+use crate::otl::{GlyphInfo, Substitutor};
+use crate::parse_prelude::*;
+use std::vec::Vec;
+
+// Character categories.
+const X: u8 = 0;
+const C: u8 = 1;
+const V: u8 = 2;
+const N: u8 = 3;
+const H: u8 = 4;
+const ZWNJ: u8 = 5;
+const ZWJ: u8 = 6;
+const M: u8 = 7;
+const SM: u8 = 8;
+const A: u8 = 9;
+const PLACEHOLDER: u8 = 10;
+const DOTTED_CIRCLE: u8 = 11;
+const RA: u8 = 12;
+const REPHA: u8 = 13;
+const CM: u8 = 14;
+
+// Reordering positions.
+const POS_START: u8 = 0;
+const POS_RA_TO_BECOME_REPH: u8 = 1;
+const POS_PRE_M: u8 = 2;
+const POS_PRE_C: u8 = 3;
+const POS_BASE_C: u8 = 4;
+const POS_AFTER_MAIN: u8 = 5;
+const POS_BEFORE_SUB: u8 = 7;
+const POS_BELOW_C: u8 = 8;
+const POS_AFTER_SUB: u8 = 9;
+const POS_BEFORE_POST: u8 = 10;
+const POS_POST_C: u8 = 11;
+const POS_AFTER_POST: u8 = 12;
+const POS_SMVD: u8 = 13;
+const POS_END: u8 = 14;
+
+// Syllable kinds.
+const CONSONANT_SYLLABLE: u8 = 0;
+const VOWEL_SYLLABLE: u8 = 1;
+const STANDALONE_CLUSTER: u8 = 2;
+const BROKEN_CLUSTER: u8 = 3;
+const NON_INDIC_CLUSTER: u8 = 4;
+
+// Masks for the features that apply to specific glyphs in a syllable.
+const RPHF_MASK: u32 = 1 << 1;
+const PREF_MASK: u32 = 1 << 2;
+const BLWF_MASK: u32 = 1 << 3;
+const ABVF_MASK: u32 = 1 << 4;
+const HALF_MASK: u32 = 1 << 5;
+const PSTF_MASK: u32 = 1 << 6;
+const INIT_MASK: u32 = 1 << 7;
+
+/// Features applied between the initial and final reordering, each in a
+/// separate stage.
+const BASIC_FEATURES: &[(&[u8; 4], u32)] = &[
+    (b"nukt", GLOBAL_MASK),
+    (b"akhn", GLOBAL_MASK),
+    (b"rphf", RPHF_MASK),
+    (b"rkrf", GLOBAL_MASK),
+    (b"pref", PREF_MASK),
+    (b"blwf", BLWF_MASK),
+    (b"abvf", ABVF_MASK),
+    (b"half", HALF_MASK),
+    (b"pstf", PSTF_MASK),
+    (b"vatu", GLOBAL_MASK),
+    (b"cjct", GLOBAL_MASK),
+];
+
+/// Features applied after the final reordering.
+const OTHER_FEATURES: &[(&[u8; 4], u32)] = &[
+    (b"init", INIT_MASK),
+    (b"pres", GLOBAL_MASK),
+    (b"abvs", GLOBAL_MASK),
+    (b"blws", GLOBAL_MASK),
+    (b"psts", GLOBAL_MASK),
+    (b"haln", GLOBAL_MASK),
+];
+
+// Indices of the scripts in the Unicode blocks starting at U+0900.
+const DEVA: u8 = 0;
+const BENG: u8 = 1;
+const GURU: u8 = 2;
+const GUJR: u8 = 3;
+const ORYA: u8 = 4;
+const TAML: u8 = 5;
+const TELU: u8 = 6;
+const KNDA: u8 = 7;
+const MLYM: u8 = 8;
+
+/// Encoding of the reph in a script.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum RephMode {
+    /// Reph is formed by Ra followed by a halant.
+    Implicit,
+    /// Reph is formed by Ra, halant and zero width joiner.
+    Explicit,
+    /// Reph is encoded as a separate character.
+    Logical,
+}
+
+/// Shaping configuration for an Indic script.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Config {
+    /// Old style script tag.
+    tag: Tag,
+    /// New style script tag.
+    new_tag: Tag,
+    /// Index of the script in the Unicode blocks starting at U+0900.
+    script: u8,
+    /// Virama character.
+    virama: char,
+    /// Final position of the reph.
+    reph_position: u8,
+    /// Encoding of the reph.
+    reph_mode: RephMode,
+    /// True if below-base forms only apply after the base consonant.
+    blwf_post_only: bool,
+}
+
+const CONFIGS: [Config; 9] = [
+    Config {
+        tag: Tag::new(b"deva"),
+        new_tag: Tag::new(b"dev2"),
+        script: DEVA,
+        virama: '\u{094D}',
+        reph_position: POS_BEFORE_POST,
+        reph_mode: RephMode::Implicit,
+        blwf_post_only: false,
+    },
+    Config {
+        tag: Tag::new(b"beng"),
+        new_tag: Tag::new(b"bng2"),
+        script: BENG,
+        virama: '\u{09CD}',
+        reph_position: POS_AFTER_SUB,
+        reph_mode: RephMode::Implicit,
+        blwf_post_only: false,
+    },
+    Config {
+        tag: Tag::new(b"guru"),
+        new_tag: Tag::new(b"gur2"),
+        script: GURU,
+        virama: '\u{0A4D}',
+        reph_position: POS_BEFORE_SUB,
+        reph_mode: RephMode::Implicit,
+        blwf_post_only: false,
+    },
+    Config {
+        tag: Tag::new(b"gujr"),
+        new_tag: Tag::new(b"gjr2"),
+        script: GUJR,
+        virama: '\u{0ACD}',
+        reph_position: POS_BEFORE_POST,
+        reph_mode: RephMode::Implicit,
+        blwf_post_only: false,
+    },
+    Config {
+        tag: Tag::new(b"orya"),
+        new_tag: Tag::new(b"ory2"),
+        script: ORYA,
+        virama: '\u{0B4D}',
+        reph_position: POS_AFTER_MAIN,
+        reph_mode: RephMode::Implicit,
+        blwf_post_only: false,
+    },
+    Config {
+        tag: Tag::new(b"taml"),
+        new_tag: Tag::new(b"tml2"),
+        script: TAML,
+        virama: '\u{0BCD}',
+        reph_position: POS_AFTER_POST,
+        reph_mode: RephMode::Implicit,
+        blwf_post_only: false,
+    },
+    Config {
+        tag: Tag::new(b"telu"),
+        new_tag: Tag::new(b"tel2"),
+        script: TELU,
+        virama: '\u{0C4D}',
+        reph_position: POS_AFTER_POST,
+        reph_mode: RephMode::Explicit,
+        blwf_post_only: true,
+    },
+    Config {
+        tag: Tag::new(b"knda"),
+        new_tag: Tag::new(b"knd2"),
+        script: KNDA,
+        virama: '\u{0CCD}',
+        reph_position: POS_AFTER_POST,
+        reph_mode: RephMode::Implicit,
+        blwf_post_only: true,
+    },
+    Config {
+        tag: Tag::new(b"mlym"),
+        new_tag: Tag::new(b"mlm2"),
+        script: MLYM,
+        virama: '\u{0D4D}',
+        reph_position: POS_AFTER_MAIN,
+        reph_mode: RephMode::Logical,
+        blwf_post_only: false,
+    },
+];
+
+/// Returns the configuration for the script with the specified old or new
+/// style tag.
+pub fn config(script: Tag) -> Option<&'static Config> {
+    CONFIGS
+        .iter()
+        .find(|config| config.tag == script || config.new_tag == script)
+}
+
+impl Config {
+    /// Returns the virama character for the script.
+    pub fn virama(&self) -> char {
+        self.virama
+    }
+}
+
+/// Returns the category and initial position of a character.
+fn properties(ch: char) -> (u8, u8) {
+    let c = ch as u32;
+    let category = match c {
+        0x00A0 | 0x00D7 | 0x2010..=0x2014 => PLACEHOLDER,
+        0x25CC => DOTTED_CIRCLE,
+        0x200C => ZWNJ,
+        0x200D => ZWJ,
+        0x0900..=0x0D7F => block_category(c),
+        _ => X,
+    };
+    let position = match category {
+        C | V | RA | PLACEHOLDER | DOTTED_CIRCLE => POS_BASE_C,
+        M => matra_position(c),
+        SM | A => POS_SMVD,
+        REPHA => POS_RA_TO_BECOME_REPH,
+        _ => POS_END,
+    };
+    (category, position)
+}
+
+/// Returns the category of a character in one of the Indic blocks. The
+/// blocks share a common layout derived from ISCII.
+fn block_category(c: u32) -> u8 {
+    match c {
+        0x0951..=0x0954 | 0x09FE | 0x0A51 | 0x0AFA..=0x0AFC => return A,
+        0x0970..=0x0971 | 0x0B83 | 0x0D4F | 0x0D58..=0x0D5E => return X,
+        0x0972..=0x0977 | 0x0A72..=0x0A73 | 0x0D5F => return V,
+        0x0978..=0x097F | 0x09CE | 0x09F1 | 0x0AF9 | 0x0B71 | 0x0D54..=0x0D56 => return C,
+        0x0D7A..=0x0D7F => return C,
+        0x09F0 => return RA,
+        0x0A70..=0x0A71 | 0x0C04 => return SM,
+        0x0A75 => return CM,
+        0x0AFD..=0x0AFF => return N,
+        0x0D3B..=0x0D3C => return H,
+        0x0D4E => return REPHA,
+        _ => {}
+    }
+    match c & 0x7F {
+        0x00..=0x03 => SM,
+        0x04..=0x14 | 0x60..=0x61 => V,
+        0x30 => RA,
+        0x15..=0x39 | 0x58..=0x5F => C,
+        0x3A..=0x3B | 0x3E..=0x4C | 0x4E..=0x4F | 0x55..=0x57 | 0x62..=0x63 => M,
+        0x3C => N,
+        0x4D => H,
+        _ => X,
+    }
+}
+
+/// Side of the base consonant on which a dependent vowel sign is written.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum MatraSide {
+    Left,
+    Right,
+    Top,
+    Bottom,
+}
+
+/// Returns the reordering position of a dependent vowel sign.
+fn matra_position(c: u32) -> u8 {
+    let script = ((c - 0x0900) >> 7) as u8;
+    let side = match (script, c & 0x7F) {
+        (BENG, 0x47..=0x48) | (ORYA, 0x47) | (TAML, 0x46..=0x48) | (MLYM, 0x46..=0x48) => {
+            MatraSide::Left
+        }
+        (GURU, 0x4B..=0x4C)
+        | (ORYA, 0x3F)
+        | (ORYA, 0x56)
+        | (TAML, 0x40)
+        | (TELU, 0x3E..=0x40)
+        | (TELU, 0x46..=0x4C)
+        | (KNDA, 0x3F)
+        | (KNDA, 0x46)
+        | (KNDA, 0x4C) => MatraSide::Top,
+        (BENG, 0x57)
+        | (ORYA, 0x57)
+        | (TAML, 0x3F)
+        | (TAML, 0x41..=0x42)
+        | (TAML, 0x57)
+        | (TELU, 0x41..=0x44)
+        | (KNDA, 0x41..=0x44)
+        | (KNDA, 0x55)
+        | (KNDA, 0x56)
+        | (MLYM, 0x3F)
+        | (MLYM, 0x57) => MatraSide::Right,
+        (_, 0x3F) | (_, 0x4E) => MatraSide::Left,
+        (_, 0x3A) | (_, 0x45..=0x48) | (_, 0x55) => MatraSide::Top,
+        (_, 0x41..=0x44) | (_, 0x56..=0x57) | (_, 0x62..=0x63) => MatraSide::Bottom,
+        _ => MatraSide::Right,
+    };
+    match side {
+        MatraSide::Left => POS_PRE_M,
+        MatraSide::Right => match script {
+            DEVA => POS_AFTER_SUB,
+            TELU if c <= 0x0C42 => POS_BEFORE_SUB,
+            TELU => POS_AFTER_SUB,
+            KNDA if !(0x0CC3..=0x0CD6).contains(&c) => POS_BEFORE_SUB,
+            KNDA => POS_AFTER_SUB,
+            _ => POS_AFTER_POST,
+        },
+        MatraSide::Top => match script {
+            GURU => POS_AFTER_POST,
+            ORYA => POS_AFTER_MAIN,
+            TELU | KNDA => POS_BEFORE_SUB,
+            _ => POS_AFTER_SUB,
+        },
+        MatraSide::Bottom => match script {
+            GURU | GUJR | TAML | MLYM => POS_AFTER_POST,
+            TELU | KNDA => POS_BEFORE_SUB,
+            _ => POS_AFTER_SUB,
+        },
+    }
+}
+
+fn is_consonant(glyph: &GlyphInfo) -> bool {
+    matches!(
+        category(glyph),
+        C | V | RA | CM | PLACEHOLDER | DOTTED_CIRCLE
+    )
+}
+
+fn is_joiner(category: u8) -> bool {
+    category == ZWJ || category == ZWNJ
+}
+
+/// Returns the end and kind of the syllable beginning at the specified
+/// index.
+fn scan_syllable(glyphs: &[GlyphInfo], start: usize) -> (usize, u8) {
+    let cat = |i: usize| glyphs.get(i).map(category).unwrap_or(X);
+    let mut i = start;
+    if cat(i) == REPHA {
+        i += 1;
+    }
+    let kind = match cat(i) {
+        C | RA => {
+            i = scan_consonants(glyphs, i);
+            CONSONANT_SYLLABLE
+        }
+        V | PLACEHOLDER | DOTTED_CIRCLE => {
+            let kind = if cat(i) == V {
+                VOWEL_SYLLABLE
+            } else {
+                STANDALONE_CLUSTER
+            };
+            i += 1;
+            while cat(i) == N {
+                i += 1;
+            }
+            loop {
+                if cat(i) == ZWJ {
+                    i += 1;
+                } else if let Some(next) = halant_then_consonant(glyphs, i) {
+                    i = scan_consonants(glyphs, next);
+                } else {
+                    break;
+                }
+            }
+            kind
+        }
+        M | H | N | SM | A | CM => BROKEN_CLUSTER,
+        _ if i > start => BROKEN_CLUSTER,
+        _ => return (start + 1, NON_INDIC_CLUSTER),
+    };
+    while matches!(cat(i), M | N | H | ZWJ | ZWNJ | SM | A | CM) {
+        i += 1;
+    }
+    (i, kind)
+}
+
+/// Returns the end of a sequence of consonants joined by halants, beginning
+/// with the consonant at the specified index.
+fn scan_consonants(glyphs: &[GlyphInfo], mut i: usize) -> usize {
+    loop {
+        i += 1;
+        while glyphs.get(i).map(category) == Some(N) {
+            i += 1;
+        }
+        match halant_then_consonant(glyphs, i) {
+            Some(next) => i = next,
+            _ => return i,
+        }
+    }
+}
+
+/// Returns the index of the consonant if the glyph at the specified index
+/// begins a halant followed by a consonant, optionally separated by joiners.
+fn halant_then_consonant(glyphs: &[GlyphInfo], mut i: usize) -> Option<usize> {
+    let cat = |i: usize| glyphs.get(i).map(category).unwrap_or(X);
+    if is_joiner(cat(i)) && cat(i + 1) == H {
+        i += 1;
+    }
+    if cat(i) != H {
+        return None;
+    }
+    i += 1;
+    if is_joiner(cat(i)) {
+        i += 1;
+    }
+    if matches!(cat(i), C | RA) {
+        Some(i)
+    } else {
+        None
+    }
+}
+
+/// Assigns character categories to the glyphs and segments them into
+/// syllables, inserting a dotted circle into each broken cluster.
+pub fn setup(chars: &[(char, u32)], glyphs: &mut Vec<GlyphInfo>, dotted_circle: GlyphId) {
+    for (glyph, (ch, _)) in glyphs.iter_mut().zip(chars) {
+        let (category, position) = properties(*ch);
+        set_category(glyph, category);
+        set_position(glyph, position);
+    }
+    syllable::find_syllables(glyphs, scan_syllable);
+    let data = DOTTED_CIRCLE as u32 | (POS_BASE_C as u32) << 8;
+    syllable::insert_dotted_circles(glyphs, dotted_circle, BROKEN_CLUSTER, data, |glyphs| {
+        (category(&glyphs[0]) == REPHA) as usize
+    });
+}
+
+/// Adds the features for the Indic scripts.
+pub fn collect_features(features: &mut FeatureCollector) {
+    features.add(Tag::new(b"locl"), GLOBAL_MASK);
+    features.add(Tag::new(b"ccmp"), GLOBAL_MASK);
+    features.pause_with(Pause::IndicInitialReordering);
+    for (i, (tag, mask)) in BASIC_FEATURES.iter().enumerate() {
+        features.add(Tag::new(tag), *mask);
+        if i + 1 < BASIC_FEATURES.len() {
+            features.pause();
+        }
+    }
+    features.pause_with(Pause::IndicFinalReordering);
+    for (tag, mask) in OTHER_FEATURES {
+        features.add(Tag::new(tag), *mask);
+    }
+}
+
+/// Font specific state for reordering Indic syllables.
+pub struct IndicPlan<'a> {
+    config: &'static Config,
+    /// True if the font uses the old style script tag.
+    old_spec: bool,
+    substitutor: Option<Substitutor<'a>>,
+    virama: GlyphId,
+    rphf: Vec<u16>,
+    pref: Vec<u16>,
+    blwf: Vec<u16>,
+    pstf: Vec<u16>,
+    vatu: Vec<u16>,
+}
+
+impl<'a> IndicPlan<'a> {
+    /// Creates a new plan. The `lookups` function returns the lookup
+    /// indices for a feature.
+    pub fn new(
+        config: &'static Config,
+        old_spec: bool,
+        substitutor: Option<Substitutor<'a>>,
+        virama: GlyphId,
+        lookups: impl Fn(Tag) -> Vec<u16>,
+    ) -> Self {
+        Self {
+            config,
+            old_spec,
+            substitutor,
+            virama,
+            rphf: lookups(Tag::new(b"rphf")),
+            pref: lookups(Tag::new(b"pref")),
+            blwf: lookups(Tag::new(b"blwf")),
+            pstf: lookups(Tag::new(b"pstf")),
+            vatu: lookups(Tag::new(b"vatu")),
+        }
+    }
+
+    fn would_substitute(&self, lookups: &[u16], glyph_ids: &[GlyphId]) -> bool {
+        match &self.substitutor {
+            Some(substitutor) => lookups
+                .iter()
+                .any(|index| substitutor.would_apply(*index, glyph_ids)),
+            _ => false,
+        }
+    }
+
+    /// Returns the position of a consonant based on the forms provided by
+    /// the font.
+    fn consonant_position(&self, glyph_id: GlyphId) -> u8 {
+        if self.virama == 0 {
+            return POS_BASE_C;
+        }
+        let glyph_ids = [self.virama, glyph_id, self.virama];
+        let (pre, post) = (&glyph_ids[..2], &glyph_ids[1..]);
+        let would_substitute = |lookups: &[u16]| {
+            self.would_substitute(lookups, pre) || self.would_substitute(lookups, post)
+        };
+        if would_substitute(&self.blwf) || would_substitute(&self.vatu) {
+            POS_BELOW_C
+        } else if would_substitute(&self.pstf) || would_substitute(&self.pref) {
+            POS_POST_C
+        } else {
+            POS_BASE_C
+        }
+    }
+
+    /// Reorders each syllable into the order expected by the font and sets
+    /// the masks for the basic features.
+    pub fn initial_reordering(&self, glyphs: &mut [GlyphInfo]) {
+        for glyph in glyphs.iter_mut() {
+            if position(glyph) == POS_BASE_C {
+                let position = self.consonant_position(glyph.glyph_id);
+                set_position(glyph, position);
+            }
+        }
+        let mut start = 0;
+        while start < glyphs.len() {
+            let end = syllable::syllable_end(glyphs, start);
+            if syllable::kind(&glyphs[start]) != NON_INDIC_CLUSTER {
+                self.reorder_syllable(&mut glyphs[start..end]);
+            }
+            start = end;
+        }
+    }
+
+    fn reorder_syllable(&self, glyphs: &mut [GlyphInfo]) {
+        let end = glyphs.len();
+        let mut base = end;
+        let mut limit = 0;
+        let mut has_reph = false;
+        if !self.rphf.is_empty() && end >= 3 {
+            let third = category(&glyphs[2]);
+            let candidate = match self.config.reph_mode {
+                RephMode::Implicit => !is_joiner(third),
+                RephMode::Explicit => third == ZWJ,
+                RephMode::Logical => false,
+            };
+            let glyph_ids = [glyphs[0].glyph_id, glyphs[1].glyph_id, glyphs[2].glyph_id];
+            if candidate
+                && (self.would_substitute(&self.rphf, &glyph_ids[..2])
+                    || (self.config.reph_mode == RephMode::Explicit
+                        && self.would_substitute(&self.rphf, &glyph_ids)))
+            {
+                limit = 2;
+                has_reph = true;
+            }
+        }
+        if !has_reph && self.config.reph_mode == RephMode::Logical && category(&glyphs[0]) == REPHA
+        {
+            limit = 1;
+            has_reph = true;
+        }
+        if has_reph {
+            while limit < end && is_joiner(category(&glyphs[limit])) {
+                limit += 1;
+            }
+            base = 0;
+        }
+        // Find the base consonant, starting from the end of the syllable
+        // and skipping consonants that have below-base or post-base forms.
+        let mut i = end;
+        let mut seen_below = false;
+        loop {
+            i -= 1;
+            let glyph = &glyphs[i];
+            if is_consonant(glyph) {
+                let pos = position(glyph);
+                if pos != POS_BELOW_C && (pos != POS_POST_C || seen_below) {
+                    base = i;
+                    break;
+                }
+                if pos == POS_BELOW_C {
+                    seen_below = true;
+                }
+                base = i;
+            } else if i > 0 && category(glyph) == ZWJ && category(&glyphs[i - 1]) == H {
+                // A zero width joiner after a halant requests an explicit
+                // half form.
+                break;
+            }
+            if i <= limit {
+                break;
+            }
+        }
+        if has_reph && base == 0 && limit <= 2 {
+            // There is no other consonant so Ra becomes the base.
+            has_reph = false;
+        }
+        for glyph in &mut glyphs[..base] {
+            let pos = position(glyph).min(POS_PRE_C);
+            set_position(glyph, pos);
+        }
+        if base < end {
+            set_position(&mut glyphs[base], POS_BASE_C);
+        }
+        if has_reph {
+            set_position(&mut glyphs[0], POS_RA_TO_BECOME_REPH);
+        }
+        if self.old_spec {
+            // Move the first post-base halant after the last consonant.
+            let double_halants = self.config.script == KNDA;
+            if let Some(i) = (base + 1..end).find(|i| category(&glyphs[*i]) == H) {
+                let mut j = end - 1;
+                while j > i
+                    && !is_consonant(&glyphs[j])
+                    && !(double_halants && category(&glyphs[j]) == H)
+                {
+                    j -= 1;
+                }
+                if category(&glyphs[j]) != H && j > i {
+                    syllable::move_glyph(glyphs, i, j);
+                }
+            }
+        }
+        // Attach joiners, nuktas and halants to the previous character so
+        // that they move together.
+        let mut last_pos = POS_START;
+        for i in 0..end {
+            let cat = category(&glyphs[i]);
+            if matches!(cat, ZWJ | ZWNJ | N | H | CM) {
+                let mut pos = last_pos;
+                if cat == H && pos == POS_PRE_M {
+                    if let Some(prev) = glyphs[..i]
+                        .iter()
+                        .rev()
+                        .map(position)
+                        .find(|pos| *pos != POS_PRE_M)
+                    {
+                        pos = prev;
+                    }
+                }
+                set_position(&mut glyphs[i], pos);
+            } else if position(&glyphs[i]) != POS_SMVD {
+                last_pos = position(&glyphs[i]);
+            }
+        }
+        // Post-base consonants own everything before them since the last
+        // consonant or vowel sign.
+        let mut last = base;
+        for i in base + 1..end {
+            if is_consonant(&glyphs[i]) {
+                let pos = position(&glyphs[i]);
+                for glyph in &mut glyphs[last + 1..i] {
+                    if position(glyph) < POS_SMVD {
+                        set_position(glyph, pos);
+                    }
+                }
+                last = i;
+            } else if category(&glyphs[i]) == M {
+                last = i;
+            }
+        }
+        if glyphs.windows(2).any(|w| position(&w[0]) > position(&w[1])) {
+            glyphs.sort_by_key(position);
+            syllable::merge_clusters(glyphs);
+        }
+        // Find the base again and flip any sequence of left vowel signs.
+        base = end;
+        let mut first_left = end;
+        let mut last_left = end;
+        for (i, glyph) in glyphs.iter().enumerate() {
+            match position(glyph) {
+                POS_BASE_C => {
+                    base = i;
+                    break;
+                }
+                POS_PRE_M => {
+                    if first_left == end {
+                        first_left = i;
+                    }
+                    last_left = i;
+                }
+                _ => {}
+            }
+        }
+        if first_left < last_left {
+            glyphs[first_left..=last_left].reverse();
+            let mut i = first_left;
+            for j in first_left..=last_left {
+                if category(&glyphs[j]) == M {
+                    glyphs[i..=j].reverse();
+                    i = j + 1;
+                }
+            }
+        }
+        // Set the masks for the basic features.
+        for glyph in glyphs
+            .iter_mut()
+            .take_while(|glyph| position(glyph) == POS_RA_TO_BECOME_REPH)
+        {
+            glyph.mask |= RPHF_MASK;
+        }
+        let mut pre_mask = HALF_MASK;
+        if !self.old_spec && !self.config.blwf_post_only {
+            pre_mask |= BLWF_MASK;
+        }
+        for glyph in &mut glyphs[..base] {
+            glyph.mask |= pre_mask;
+        }
+        if base < end {
+            for glyph in &mut glyphs[base + 1..] {
+                glyph.mask |= BLWF_MASK | ABVF_MASK | PSTF_MASK;
+            }
+        }
+        if self.old_spec && self.config.script == DEVA {
+            // Old style fonts apply below-base forms to Ra before half forms.
+            for i in 0..base.saturating_sub(1) {
+                if category(&glyphs[i]) == RA
+                    && category(&glyphs[i + 1]) == H
+                    && (i + 2 == base || category(&glyphs[i + 2]) != ZWJ)
+                {
+                    glyphs[i].mask |= BLWF_MASK;
+                    glyphs[i + 1].mask |= BLWF_MASK;
+                }
+            }
+        }
+        if !self.pref.is_empty() && base + 2 < end {
+            for i in base + 1..end - 1 {
+                let glyph_ids = [glyphs[i].glyph_id, glyphs[i + 1].glyph_id];
+                if self.would_substitute(&self.pref, &glyph_ids) {
+                    glyphs[i].mask |= PREF_MASK;
+                    glyphs[i + 1].mask |= PREF_MASK;
+                    break;
+                }
+            }
+        }
+        // A zero width non-joiner prevents half forms of the preceding
+        // consonant.
+        for i in 1..end {
+            if category(&glyphs[i]) == ZWNJ {
+                let mut j = i;
+                loop {
+                    j -= 1;
+                    glyphs[j].mask &= !HALF_MASK;
+                    if j == 0 || is_consonant(&glyphs[j]) {
+                        break;
+                    }
+                }
+            }
+        }
+    }
+
+    /// Moves the reph and pre-base vowel signs to their final positions
+    /// after the basic features have been applied and sets the masks for
+    /// the remaining features.
+    pub fn final_reordering(&self, glyphs: &mut [GlyphInfo]) {
+        let mut start = 0;
+        while start < glyphs.len() {
+            let end = syllable::syllable_end(glyphs, start);
+            if syllable::kind(&glyphs[start]) != NON_INDIC_CLUSTER {
+                let word_start = start == 0 || category(&glyphs[start - 1]) == X;
+                self.finish_syllable(&mut glyphs[start..end], word_start);
+            }
+            start = end;
+        }
+    }
+
+    fn finish_syllable(&self, glyphs: &mut [GlyphInfo], word_start: bool) {
+        let end = glyphs.len();
+        let mut base = glyphs
+            .iter()
+            .position(|glyph| position(glyph) >= POS_BASE_C)
+            .unwrap_or(end);
+        if base < end && base > 0 && position(&glyphs[base]) > POS_BASE_C {
+            base -= 1;
+        }
+        if base == end && base > 0 && category(&glyphs[base - 1]) == ZWJ {
+            base -= 1;
+        }
+        if base < end {
+            while base > 0 && matches!(category(&glyphs[base]), N | H) {
+                base -= 1;
+            }
+        }
+        // Move pre-base vowel signs after the last explicit halant before
+        // the base.
+        if end > 1 && base > 0 {
+            let mut new_pos = if base == end { base - 2 } else { base - 1 };
+            if self.config.script != MLYM && self.config.script != TAML {
+                loop {
+                    while new_pos > 0 && !matches!(category(&glyphs[new_pos]), M | H) {
+                        new_pos -= 1;
+                    }
+                    if category(&glyphs[new_pos]) == H && position(&glyphs[new_pos]) != POS_PRE_M {
+                        // A zero width joiner after the halant keeps the
+                        // vowel sign before it.
+                        if new_pos + 1 < end && category(&glyphs[new_pos + 1]) == ZWJ && new_pos > 0
+                        {
+                            new_pos -= 1;
+                            continue;
+                        }
+                    } else {
+                        new_pos = 0;
+                    }
+                    break;
+                }
+            }
+            if new_pos > 0 && position(&glyphs[new_pos]) != POS_PRE_M {
+                let mut i = new_pos;
+                while i > 0 {
+                    if position(&glyphs[i - 1]) == POS_PRE_M {
+                        let old_pos = i - 1;
+                        if old_pos < base && base <= new_pos {
+                            base -= 1;
+                        }
+                        syllable::move_glyph(glyphs, old_pos, new_pos);
+                        new_pos -= 1;
+                    }
+                    i -= 1;
+                }
+            }
+        }
+        // Move the reph if it was formed.
+        if end > 1
+            && position(&glyphs[0]) == POS_RA_TO_BECOME_REPH
+            && ((category(&glyphs[0]) == REPHA) ^ syllable::is_ligated(&glyphs[0]))
+        {
+            let new_pos = self.reph_position(glyphs, base);
+            syllable::move_glyph(glyphs, 0, new_pos);
+        }
+        if word_start && position(&glyphs[0]) == POS_PRE_M {
+            glyphs[0].mask |= INIT_MASK;
+        }
+    }
+
+    /// Returns the final position of the reph in the syllable.
+    fn reph_position(&self, glyphs: &[GlyphInfo], base: usize) -> usize {
+        let end = glyphs.len();
+        // Position after the first explicit halant before the base.
+        let after_halant = || {
+            let mut pos = 1;
+            while pos < base && category(&glyphs[pos]) != H {
+                pos += 1;
+            }
+            if pos < base && category(&glyphs[pos]) == H {
+                if pos + 1 < base && is_joiner(category(&glyphs[pos + 1])) {
+                    pos += 1;
+                }
+                Some(pos)
+            } else {
+                None
+            }
+        };
+        let reph_pos = self.config.reph_position;
+        if reph_pos != POS_AFTER_POST {
+            if let Some(pos) = after_halant() {
+                return pos;
+            }
+            if reph_pos == POS_AFTER_MAIN {
+                let mut pos = base;
+                while pos + 1 < end && position(&glyphs[pos + 1]) <= POS_AFTER_MAIN {
+                    pos += 1;
+                }
+                if pos < end {
+                    return pos;
+                }
+            }
+            if reph_pos == POS_AFTER_SUB {
+                let mut pos = base;
+                while pos + 1 < end
+                    && !matches!(
+                        position(&glyphs[pos + 1]),
+                        POS_POST_C | POS_AFTER_POST | POS_SMVD
+                    )
+                {
+                    pos += 1;
+                }
+                if pos < end {
+                    return pos;
+                }
+            }
+        }
+        if let Some(pos) = after_halant() {
+            return pos;
+        }
+        // Otherwise, move the reph to the end of the syllable before any
+        // syllable modifiers.
+        let mut pos = end - 1;
+        while pos > 0 && position(&glyphs[pos]) == POS_SMVD {
+            pos -= 1;
+        }
+        if category(&glyphs[pos]) == H && base + 1 < pos {
+            // Keep a vowel sign and halant sequence together.
+            let matras = glyphs[base + 1..pos]
+                .iter()
+                .filter(|glyph| category(glyph) == M)
+                .count();
+            pos -= matras;
+        }
+        pos
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::{ShapeOptions, Shaper};
+    use crate::testing::{layout, ligature_subst, lookup, scripts_layout, TestFont};
+    use crate::{FontRef, TableProvider};
+
+    fn shape(font: &[u8], script: &[u8; 4], text: &str) -> Vec<GlyphId> {
+        let font = FontRef::from_index(font, 0).unwrap();
+        let options = ShapeOptions {
+            script: Tag::new(script),
+            ..Default::default()
+        };
+        Shaper::new(&font)
+            .shape(text, &options)
+            .iter()
+            .map(|glyph| glyph.glyph_id)
+            .collect()
+    }
+
+    // Devanagari ka, ra, virama, vowel sign i, ssa and ya followed by the
+    // joiners and a dotted circle.
+    const CHARS: &str = "\u{915}\u{930}\u{94D}\u{93F}\u{937}\u{92F}\u{200D}\u{200C}\u{25CC}";
+
+    fn font(features: &[(&[u8; 4], &[u16])], lookups: &[Vec<u8>]) -> Vec<u8> {
+        let gsub = layout(b"dev2", features, lookups);
+        TestFont::new(15).chars(CHARS).table(b"GSUB", gsub).build()
+    }
+
+    #[test]
+    fn reph() {
+        let data = font(
+            &[(b"rphf", &[0])],
+            &[lookup(4, 0, &[ligature_subst(&[(&[2, 3], 10)])])],
+        );
+        // The reph moves to the end of the syllable.
+        assert_eq!(shape(&data, b"deva", "\u{930}\u{94D}\u{915}"), [1, 10]);
+        assert_eq!(
+            shape(&data, b"deva", "\u{930}\u{94D}\u{915}\u{93F}"),
+            [4, 1, 10]
+        );
+        // Ra is the base when there is no other consonant.
+        assert_eq!(shape(&data, b"deva", "\u{930}\u{94D}"), [2, 3]);
+        // No reph is formed when the font lacks the feature.
+        let data = font(&[], &[]);
+        assert_eq!(shape(&data, b"deva", "\u{930}\u{94D}\u{915}"), [2, 3, 1]);
+    }
+
+    #[test]
+    fn pre_base_matras() {
+        let data = font(
+            &[(b"half", &[0])],
+            &[lookup(4, 0, &[ligature_subst(&[(&[1, 3], 11)])])],
+        );
+        // The vowel sign is written before the base consonant.
+        assert_eq!(shape(&data, b"deva", "\u{915}\u{93F}"), [4, 1]);
+        // And before the half forms of a conjunct.
+        assert_eq!(
+            shape(&data, b"deva", "\u{915}\u{94D}\u{937}\u{93F}"),
+            [4, 11, 5]
+        );
+        // But after a halant that remains explicit.
+        let data = font(&[], &[]);
+        assert_eq!(
+            shape(&data, b"deva", "\u{915}\u{94D}\u{937}\u{93F}"),
+            [1, 3, 4, 5]
+        );
+    }
+
+    #[test]
+    fn joiners() {
+        let data = font(
+            &[(b"half", &[0])],
+            &[lookup(4, 0, &[ligature_subst(&[(&[1, 3], 11)])])],
+        );
+        assert_eq!(shape(&data, b"deva", "\u{915}\u{94D}\u{937}"), [11, 5]);
+        // A final halant does not form a half form on its own.
+        assert_eq!(shape(&data, b"deva", "\u{915}\u{94D}"), [1, 3]);
+        // A zero width joiner requests the half form.
+        assert_eq!(shape(&data, b"deva", "\u{915}\u{94D}\u{200D}"), [11, 0]);
+        // A zero width non-joiner prevents it.
+        assert_eq!(
+            shape(&data, b"deva", "\u{915}\u{94D}\u{200C}\u{937}"),
+            [1, 3, 0, 5]
+        );
+    }
+
+    #[test]
+    fn script_tags() {
+        // The new style script is preferred when a font has both.
+        let gsub = scripts_layout(
+            &[(b"dev2", &[0]), (b"deva", &[1])],
+            &[(b"rphf", &[0]), (b"rphf", &[1])],
+            &[
+                lookup(4, 0, &[ligature_subst(&[(&[2, 3], 10)])]),
+                lookup(4, 0, &[ligature_subst(&[(&[2, 3], 12)])]),
+            ],
+        );
+        let data = TestFont::new(15)
+            .chars(CHARS)
+            .table(b"GSUB", gsub.clone())
+            .build();
+        for tag in [b"deva", b"dev2"] {
+            assert_eq!(shape(&data, tag, "\u{930}\u{94D}\u{915}"), [1, 10]);
+        }
+        let font = FontRef::from_index(&data, 0).unwrap();
+        let gsub = font.gsub().unwrap();
+        let script = gsub.0.find_script(Tag::new(b"deva")).unwrap();
+        assert_eq!(script.record.tag, Tag::new(b"dev2"));
+        assert!(!script.record.is_old_indic());
+        // Fonts with only the old style script move a post-base halant
+        // after the following consonant.
+        let pstf = |tag: &[u8; 4], components: &[GlyphId]| {
+            let gsub = layout(
+                tag,
+                &[(b"pstf", &[0])],
+                &[lookup(4, 0, &[ligature_subst(&[(components, 13)])])],
+            );
+            TestFont::new(15).chars(CHARS).table(b"GSUB", gsub).build()
+        };
+        let data = pstf(b"deva", &[6, 3]);
+        assert_eq!(shape(&data, b"dev2", "\u{915}\u{94D}\u{92F}"), [1, 13]);
+        let data = pstf(b"dev2", &[3, 6]);
+        assert_eq!(shape(&data, b"deva", "\u{915}\u{94D}\u{92F}"), [1, 13]);
+    }
+}
@@ -0,0 +1,650 @@
+//! Text shaping.
+//!
+//! This module provides a minimal shaper that maps characters to glyphs and
+//! applies the default typographic features from the `GSUB` and `GPOS`
+//! tables. It supports scripts that do not require complex reordering or
+//! contextual shaping, such as Latin, Cyrillic, Greek, Hebrew and the CJK
+//! scripts, along with the joining scripts such as Arabic and Syriac, the
+//! Indic scripts and the scripts covered by the Universal Shaping Engine.
+//!
+//! Khmer and Myanmar require dedicated shaping models that are not
+//! implemented. Text in these scripts is shaped with the default features
+//! only, so syllables are not reordered.
+
+mod arabic;
+mod indic;
+mod plan;
+mod syllable;
+mod unicode;
+mod universal;
+
+use crate::cmap::{Charmap, Cmap, MapVariant};
+use crate::gdef::Gdef;
+use crate::gpos::Gpos;
+use crate::gsub::Gsub;
+use crate::hmtx::Hmtx;
+use crate::hvar::Hvar;
+use crate::otl::{
+    Direction, FeatureSet, GlyphInfo, GlyphPosition, Positioner, Substitutor, BASE_CLASS,
+    MARK_CLASS,
+};
+use crate::parse_prelude::*;
+use crate::var::item::Index;
+use crate::vmtx::Vmtx;
+use crate::TableProvider;
+use plan::{FeatureCollector, Pause, PlanLookup};
+use std::vec::Vec;
+
+/// Mask for features that apply to all glyphs.
+const GLOBAL_MASK: u32 = 1;
+
+/// Mask for glyphs produced by default ignorable characters. These glyphs
+/// are hidden after shaping.
+const IGNORABLE_MASK: u32 = 1 << 31;
+
+/// Shaping model for a script.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+enum ShaperKind {
+    /// Scripts that only require the default features.
+    Default,
+    /// Scripts with cursive joining.
+    Arabic,
+    /// Indic scripts that require syllable reordering.
+    Indic(&'static indic::Config),
+    /// Scripts covered by the Universal Shaping Engine.
+    Universal,
+}
+
+impl ShaperKind {
+    fn new(script: Tag) -> Self {
+        if arabic::is_joining_script(script) {
+            Self::Arabic
+        } else if let Some(config) = indic::config(script) {
+            Self::Indic(config)
+        } else if universal::is_universal_script(script) {
+            Self::Universal
+        } else {
+            Self::Default
+        }
+    }
+}
+
+/// Features that are applied by default in the order of application.
+const DEFAULT_FEATURES: &[&[u8; 4]] = &[
+    b"ccmp", b"locl", b"rlig", b"calt", b"clig", b"liga", b"rclt", b"abvm", b"blwm", b"mark",
+    b"mkmk", b"curs", b"dist", b"kern",
+];
+
+/// Scripts that are written from right to left.
+const RTL_SCRIPTS: &[&[u8; 4]] = &[
+    b"adlm", b"arab", b"hebr", b"mand", b"mani", b"nko ", b"rohg", b"samr", b"syrc", b"thaa",
+    b"yezi",
+];
+
+/// Setting for a typographic feature.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct FeatureSetting {
+    /// Tag that identifies the feature.
+    pub tag: Tag,
+    /// Value of the feature. Zero disables the feature, one enables it and
+    /// larger values select an alternate glyph for features such as `salt`.
+    pub value: u16,
+}
+
+impl FeatureSetting {
+    /// Creates a new feature setting.
+    pub fn new(tag: Tag, value: u16) -> Self {
+        Self { tag, value }
+    }
+}
+
+/// Options for shaping a run of text.
+#[derive(Copy, Clone, Default, Debug)]
+pub struct ShapeOptions<'a> {
+    /// OpenType script tag such as `latn` or `hebr`.
+    pub script: Tag,
+    /// OpenType language system tag. The default language of the script
+    /// is used if not specified.
+    pub language: Option<Tag>,
+    /// Direction of the run. If not specified, the direction is inferred from
+    /// the script.
+    pub direction: Option<Direction>,
+    /// Settings that enable, disable or adjust features.
+    pub features: &'a [FeatureSetting],
+    /// Normalized variation coordinates.
+    pub coords: &'a [NormalizedCoord],
+}
+
+impl<'a> ShapeOptions<'a> {
+    /// Returns the direction of the run, inferring it from the script if
+    /// necessary.
+    pub fn direction(&self) -> Direction {
+        self.direction.unwrap_or_else(|| {
+            if RTL_SCRIPTS.iter().any(|tag| Tag::new(tag) == self.script) {
+                Direction::RightToLeft
+            } else {
+                Direction::LeftToRight
+            }
+        })
+    }
+}
+
+/// Glyph produced by shaping.
+#[derive(Copy, Clone, Default, Debug)]
+pub struct ShapedGlyph {
+    /// Glyph identifier.
+    pub glyph_id: GlyphId,
+    /// Byte offset of the first character in the cluster that produced the
+    /// glyph.
+    pub cluster: u32,
+    /// Horizontal advance in font units.
+    pub x_advance: i32,
+    /// Vertical advance in font units.
+    pub y_advance: i32,
+    /// Horizontal offset in font units.
+    pub x_offset: i32,
+    /// Vertical offset in font units.
+    pub y_offset: i32,
+}
+
+/// Text shaper for a single font.
+#[derive(Copy, Clone)]
+pub struct Shaper<'a> {
+    cmap: Option<Cmap<'a>>,
+    charmap: Charmap<'a>,
+    gdef: Option<Gdef<'a>>,
+    gsub: Option<Gsub<'a>>,
+    gpos: Option<Gpos<'a>>,
+    hmtx: Option<Hmtx<'a>>,
+    hvar: Option<Hvar<'a>>,
+    vmtx: Option<Vmtx<'a>>,
+    upem: u16,
+}
+
+impl<'a> Shaper<'a> {
+    /// Creates a new shaper for the specified font.
+    pub fn new(font: &impl TableProvider<'a>) -> Self {
+        Self {
+            cmap: font.cmap(),
+            charmap: font.charmap().unwrap_or_default(),
+            gdef: font.gdef(),
+            gsub: font.gsub(),
+            gpos: font.gpos(),
+            hmtx: font.hmtx(),
+            hvar: font.hvar(),
+            vmtx: font.vmtx(),
+            upem: font.head().map(|head| head.units_per_em()).unwrap_or(1000),
+        }
+    }
+
+    /// Shapes the text with the specified options and returns the resulting
+    /// glyphs in visual order.
+    pub fn shape(&self, text: &str, options: &ShapeOptions) -> Vec<ShapedGlyph> {
+        let direction = options.direction();
+        let kind = ShaperKind::new(options.script);
+        let mut chars = text
+            .char_indices()
+            .map(|(offset, ch)| (ch, offset as u32))
+            .collect::<Vec<_>>();
+        match kind {
+            ShaperKind::Arabic => arabic::reorder_marks(&mut chars),
+            ShaperKind::Indic(_) | ShaperKind::Universal => {
+                unicode::decompose_split_vowels(&mut chars)
+            }
+            ShaperKind::Default => {}
+        }
+        let mut glyphs = self.map(&mut chars, direction);
+        let dotted_circle = self.map_char('\u{25CC}', direction);
+        match kind {
+            ShaperKind::Arabic => arabic::setup_masks(&chars, &mut glyphs),
+            ShaperKind::Indic(_) => indic::setup(&chars, &mut glyphs, dotted_circle),
+            ShaperKind::Universal => universal::setup(&chars, &mut glyphs, dotted_circle),
+            ShaperKind::Default => {}
+        }
+        let features = self.collect_features(kind, options, direction);
+        let mut lookups = Vec::new();
+        self.substitute(kind, options, &features, &mut lookups, &mut glyphs);
+        let mut positions = self.advances(&glyphs, options.coords, direction);
+        if let Some(gpos) = &self.gpos {
+            let feature_set =
+                FeatureSet::new(&gpos.0, options.script, options.language, options.coords);
+            plan::collect_lookups(&feature_set, &features.requests, &mut lookups);
+            let positioner = Positioner::new(gpos, options.coords, direction);
+            for lookup in &lookups {
+                positioner.apply_lookup(lookup.index, lookup.mask, &glyphs, &mut positions);
+            }
+            zero_mark_advances(&glyphs, &mut positions);
+            positioner.finish(&mut positions);
+        } else {
+            zero_mark_advances(&glyphs, &mut positions);
+        }
+        let space = self.map_char(' ', direction);
+        let mut shaped = glyphs
+            .iter()
+            .zip(&positions)
+            .map(|(glyph, pos)| {
+                if glyph.mask & IGNORABLE_MASK != 0 {
+                    ShapedGlyph {
+                        glyph_id: space,
+                        cluster: glyph.cluster,
+                        ..Default::default()
+                    }
+                } else {
+                    ShapedGlyph {
+                        glyph_id: glyph.glyph_id,
+                        cluster: glyph.cluster,
+                        x_advance: pos.x_advance,
+                        y_advance: pos.y_advance,
+                        x_offset: pos.x_offset,
+                        y_offset: pos.y_offset,
+                    }
+                }
+            })
+            .collect::<Vec<_>>();
+        if !direction.is_forward() {
+            shaped.reverse();
+        }
+        shaped
+    }
+
+    /// Maps the characters to nominal glyphs. Variation selectors that
+    /// select a glyph from the character map are merged with the preceding
+    /// character and removed from `chars`.
+    fn map(&self, chars: &mut Vec<(char, u32)>, direction: Direction) -> Vec<GlyphInfo> {
+        let mut glyphs: Vec<GlyphInfo> = Vec::with_capacity(chars.len());
+        let mut i = 0;
+        while i < chars.len() {
+            let (ch, cluster) = chars[i];
+            let mut glyph_id = self.map_char(ch, direction);
+            if let Some(&(vs, _)) = chars.get(i + 1) {
+                if unicode::is_variation_selector(vs) {
+                    let variant = self
+                        .cmap
+                        .as_ref()
+                        .and_then(|cmap| cmap.map_variant(ch as u32, vs as u32));
+                    if let Some(variant) = variant {
+                        if let MapVariant::Variant(variant) = variant {
+                            glyph_id = variant;
+                        }
+                        chars.remove(i + 1);
+                    }
+                }
+            }
+            let mut glyph = GlyphInfo::new(glyph_id, cluster);
+            glyph.mask = GLOBAL_MASK;
+            if unicode::is_default_ignorable(ch) {
+                glyph.mask |= IGNORABLE_MASK;
+            }
+            match &self.gdef {
+                Some(gdef) if gdef.has_classes() => glyph.classify(Some(gdef)),
+                _ => {
+                    glyph.class = if unicode::is_mark(ch) {
+                        MARK_CLASS
+                    } else {
+                        BASE_CLASS
+                    }
+                }
+            }
+            glyphs.push(glyph);
+            i += 1;
+        }
+        glyphs
+    }
+
+    fn map_char(&self, ch: char, direction: Direction) -> GlyphId {
+        let charmap = &self.charmap;
+        if direction == Direction::RightToLeft {
+            if let Some(glyph_id) = unicode::mirror(ch).and_then(|ch| charmap.map(ch as u32)) {
+                return glyph_id;
+            }
+        }
+        charmap.map(ch as u32).unwrap_or(0)
+    }
+
+    /// Applies the substitution lookups stage by stage, performing the
+    /// operations required by the shaping model between stages.
+    fn substitute(
+        &self,
+        kind: ShaperKind,
+        options: &ShapeOptions,
+        features: &FeatureCollector,
+        lookups: &mut Vec<PlanLookup>,
+        glyphs: &mut Vec<GlyphInfo>,
+    ) {
+        let substitutor = self.gsub.as_ref().map(Substitutor::new);
+        let feature_set = self
+            .gsub
+            .as_ref()
+            .map(|gsub| FeatureSet::new(&gsub.0, options.script, options.language, options.coords))
+            .unwrap_or_default();
+        plan::collect_lookups(&feature_set, &features.requests, lookups);
+        let indic = match kind {
+            ShaperKind::Indic(config) => {
+                let old_spec = self
+                    .gsub
+                    .as_ref()
+                    .and_then(|gsub| gsub.0.find_script(options.script))
+                    .is_some_and(|script| script.record.is_old_indic());
+                let virama = self.map_char(config.virama(), Direction::LeftToRight);
+                Some(indic::IndicPlan::new(
+                    config,
+                    old_spec,
+                    substitutor,
+                    virama,
+                    |tag| feature_set.lookups(tag).unwrap_or_default().to_vec(),
+                ))
+            }
+            _ => None,
+        };
+        let last_stage = lookups
+            .iter()
+            .map(|lookup| lookup.stage)
+            .chain(features.pauses.iter().map(|(stage, _)| *stage))
+            .max();
+        let last_stage = match last_stage {
+            Some(stage) => stage,
+            _ => return,
+        };
+        let mut next = 0;
+        for stage in 0..=last_stage {
+            while let Some(lookup) = lookups.get(next).filter(|lookup| lookup.stage == stage) {
+                if let Some(substitutor) = &substitutor {
+                    substitutor.apply_lookup(lookup.index, lookup.mask, lookup.alternate, glyphs);
+                }
+                next += 1;
+            }
+            for (_, pause) in features.pauses.iter().filter(|(s, _)| *s == stage) {
+                match pause {
+                    Pause::IndicInitialReordering => {
+                        if let Some(indic) = &indic {
+                            indic.initial_reordering(glyphs);
+                        }
+                    }
+                    Pause::IndicFinalReordering => {
+                        if let Some(indic) = &indic {
+                            indic.final_reordering(glyphs);
+                        }
+                    }
+                    Pause::UniversalClearSubstituted => universal::clear_substituted(glyphs),
+                    Pause::UniversalRecordRepha => universal::record_repha(glyphs),
+                    Pause::UniversalRecordPref => universal::record_pref(glyphs),
+                    Pause::UniversalReordering => universal::reorder(glyphs),
+                }
+            }
+        }
+    }
+
+    /// Returns the features to apply based on the shaping model, the
+    /// direction and the user settings.
+    fn collect_features(
+        &self,
+        kind: ShaperKind,
+        options: &ShapeOptions,
+        direction: Direction,
+    ) -> FeatureCollector {
+        let mut features = FeatureCollector::default();
+        features.add(Tag::new(b"rvrn"), GLOBAL_MASK);
+        features.pause();
+        let directional: &[&[u8; 4]] = match direction {
+            Direction::LeftToRight => &[b"ltra", b"ltrm"],
+            Direction::RightToLeft => &[b"rtla", b"rtlm"],
+            _ => &[b"vert"],
+        };
+        for tag in directional {
+            features.add(Tag::new(tag), GLOBAL_MASK);
+        }
+        match kind {
+            ShaperKind::Arabic => arabic::collect_features(options.script, &mut features),
+            ShaperKind::Indic(_) => indic::collect_features(&mut features),
+            ShaperKind::Universal => universal::collect_features(&mut features),
+            ShaperKind::Default => {}
+        }
+        for tag in DEFAULT_FEATURES {
+            features.add(Tag::new(tag), GLOBAL_MASK);
+        }
+        features.apply_settings(options.features, GLOBAL_MASK);
+        features
+    }
+
+    /// Returns the initial positions containing the advances for each glyph.
+    fn advances(
+        &self,
+        glyphs: &[GlyphInfo],
+        coords: &[NormalizedCoord],
+        direction: Direction,
+    ) -> Vec<GlyphPosition> {
+        if direction.is_horizontal() {
+            let hmtx = match &self.hmtx {
+                Some(hmtx) => hmtx,
+                _ => return glyphs.iter().map(|_| GlyphPosition::default()).collect(),
+            };
+            let metrics = hmtx.hmetrics();
+            let last = metrics.len().checked_sub(1);
+            let ivs = self
+                .hvar
+                .as_ref()
+                .filter(|_| !coords.is_empty())
+                .and_then(|hvar| Some((hvar.ivs()?, hvar.advance_mapping())));
+            glyphs
+                .iter()
+                .map(|glyph| {
+                    let gid = glyph.glyph_id as usize;
+                    let mut advance = last
+                        .and_then(|last| metrics.get(gid.min(last)))
+                        .map(|metric| metric.advance_width as i32)
+                        .unwrap_or(0);
+                    if let Some((ivs, mapping)) = &ivs {
+                        let index = match mapping {
+                            Some(mapping) => mapping.get(gid as u32),
+                            _ => Some(Index::new(0, glyph.glyph_id)),
+                        };
+                        if let Some(index) = index {
+                            advance += ivs.delta(index, coords).to_i32();
+                        }
+                    }
+                    GlyphPosition::new(advance, 0)
+                })
+                .collect()
+        } else {
+            let metrics = self.vmtx.as_ref().map(|vmtx| vmtx.vmetrics());
+            let last = metrics.and_then(|metrics| metrics.len().checked_sub(1));
+            glyphs
+                .iter()
+                .map(|glyph| {
+                    let gid = glyph.glyph_id as usize;
+                    let advance = metrics
+                        .zip(last)
+                        .and_then(|(metrics, last)| metrics.get(gid.min(last)))
+                        .map(|metric| metric.advance_height as i32)
+                        .unwrap_or(self.upem as i32);
+                    GlyphPosition::new(0, -advance)
+                })
+                .collect()
+        }
+    }
+}
+
+/// Sets the advances of all mark glyphs to zero.
+fn zero_mark_advances(glyphs: &[GlyphInfo], positions: &mut [GlyphPosition]) {
+    for (glyph, pos) in glyphs.iter().zip(positions.iter_mut()) {
+        if glyph.is_mark() {
+            pos.x_advance = 0;
+            pos.y_advance = 0;
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::Writer;
+    use crate::testing::{cmap4, layout, ligature_subst, lookup, pair_pos, single_subst, TestFont};
+    use crate::FontRef;
+
+    /// Shapes the text and returns (glyph, cluster, advance) triples.
+    fn shape(font: &FontRef, text: &str, options: &ShapeOptions) -> Vec<(GlyphId, u32, i32)> {
+        Shaper::new(font)
+            .shape(text, options)
+            .iter()
+            .map(|glyph| (glyph.glyph_id, glyph.cluster, glyph.x_advance))
+            .collect()
+    }
+
+    fn script(tag: &[u8; 4]) -> ShapeOptions<'static> {
+        ShapeOptions {
+            script: Tag::new(tag),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn simple_scripts() {
+        let data = TestFont::new(13).chars("abвгδεאב中文()").build();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        assert_eq!(
+            shape(&font, "ab", &script(b"latn")),
+            [(1, 0, 510), (2, 1, 520)]
+        );
+        assert_eq!(
+            shape(&font, "вг", &script(b"cyrl")),
+            [(3, 0, 530), (4, 2, 540)]
+        );
+        assert_eq!(
+            shape(&font, "δε", &script(b"grek")),
+            [(5, 0, 550), (6, 2, 560)]
+        );
+        assert_eq!(
+            shape(&font, "中文", &script(b"hani")),
+            [(9, 0, 590), (10, 3, 600)]
+        );
+        // Right to left runs are returned in visual order with mirrored
+        // punctuation.
+        assert_eq!(
+            shape(&font, "אב", &script(b"hebr")),
+            [(8, 2, 580), (7, 0, 570)]
+        );
+        assert_eq!(
+            shape(&font, "א(", &script(b"hebr")),
+            [(12, 2, 620), (7, 0, 570)]
+        );
+        assert_eq!(
+            shape(&font, "a(", &script(b"latn")),
+            [(1, 0, 510), (11, 1, 610)]
+        );
+        // Unmapped characters produce .notdef.
+        assert_eq!(shape(&font, "z", &script(b"latn")), [(0, 0, 500)]);
+    }
+
+    #[test]
+    fn feature_settings() {
+        // f i -> fi (4) by default and a -> a.sc (5) with smcp. The pair f a
+        // is kerned.
+        let gsub = layout(
+            b"latn",
+            &[(b"liga", &[0]), (b"smcp", &[1])],
+            &[
+                lookup(4, 0, &[ligature_subst(&[(&[1, 2], 4)])]),
+                lookup(1, 0, &[single_subst(&[(3, 5)])]),
+            ],
+        );
+        let gpos = layout(
+            b"latn",
+            &[(b"kern", &[0])],
+            &[lookup(2, 0, &[pair_pos(&[(1, 3, -30)])])],
+        );
+        let data = TestFont::new(6)
+            .chars("fia")
+            .table(b"GSUB", gsub)
+            .table(b"GPOS", gpos)
+            .build();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        let with = |features: &[FeatureSetting]| {
+            let options = ShapeOptions {
+                script: Tag::new(b"latn"),
+                features,
+                ..Default::default()
+            };
+            shape(&font, "fia", &options)
+        };
+        let setting = |tag: &[u8; 4], value| FeatureSetting::new(Tag::new(tag), value);
+        assert_eq!(with(&[]), [(4, 0, 540), (3, 2, 530)]);
+        assert_eq!(
+            with(&[setting(b"liga", 0)]),
+            [(1, 0, 510), (2, 1, 520), (3, 2, 530)]
+        );
+        assert_eq!(
+            with(&[setting(b"liga", 0), setting(b"smcp", 1)]),
+            [(1, 0, 510), (2, 1, 520), (5, 2, 550)]
+        );
+        assert_eq!(
+            shape(&font, "fa", &script(b"latn")),
+            [(1, 0, 480), (3, 1, 530)]
+        );
+        let options = ShapeOptions {
+            script: Tag::new(b"latn"),
+            features: &[setting(b"kern", 0)],
+            ..Default::default()
+        };
+        assert_eq!(shape(&font, "fa", &options), [(1, 0, 510), (3, 1, 530)]);
+    }
+
+    #[test]
+    fn variation_selectors() {
+        // FE00 selects glyph 4 for a and the default glyph for b.
+        let mut cmap = Writer::new();
+        cmap.u16(0)
+            .u16(2)
+            .u16(0)
+            .u16(5)
+            .u32(20)
+            .u16(3)
+            .u16(1)
+            .u32(58);
+        cmap.u16(14)
+            .u32(38)
+            .u32(1)
+            .u8(0)
+            .u16(0xFE00)
+            .u32(21)
+            .u32(29);
+        cmap.u32(1).u8(0).u16(0x62).u8(0);
+        cmap.u32(1).u8(0).u16(0x61).u16(4);
+        cmap.bytes(&cmap4(&[(0x61, 1), (0x62, 2), (0x63, 3)])[12..]);
+        let data = TestFont::new(5).table(b"cmap", cmap.finish()).build();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        assert_eq!(
+            shape(&font, "a\u{FE00}b\u{FE00}c\u{FE01}", &script(b"latn")),
+            [(4, 0, 540), (2, 4, 520), (3, 8, 530), (0, 9, 0)]
+        );
+    }
+
+    #[test]
+    fn legacy_and_symbol_character_maps() {
+        // A Mac Roman subtable that maps every byte to .notdef precedes the
+        // Unicode subtable.
+        let mut cmap = Writer::new();
+        cmap.u16(0)
+            .u16(2)
+            .u16(1)
+            .u16(0)
+            .u32(20)
+            .u16(3)
+            .u16(1)
+            .u32(282);
+        cmap.u16(0).u16(262).u16(0).bytes(&[0; 256]);
+        cmap.bytes(&cmap4(&[(0x42, 2), (0xE9, 3)])[12..]);
+        let data = TestFont::new(4).table(b"cmap", cmap.finish()).build();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        assert_eq!(
+            shape(&font, "B\u{E9}", &script(b"latn")),
+            [(2, 0, 520), (3, 1, 530)]
+        );
+        // Symbol fonts map the byte range through the private use area.
+        let mut cmap = Writer::new();
+        cmap.u16(0).u16(1).u16(3).u16(0).u32(12);
+        cmap.bytes(&cmap4(&[(0xF041, 2)])[12..]);
+        let data = TestFont::new(4).table(b"cmap", cmap.finish()).build();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        assert_eq!(shape(&font, "A", &script(b"latn")), [(2, 0, 520)]);
+    }
+}
@@ -0,0 +1,152 @@
+use super::FeatureSetting;
+use crate::otl::FeatureSet;
+use crate::parse_prelude::*;
+use std::vec::Vec;
+
+/// Request for a feature to be applied during shaping.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct FeatureRequest {
+    /// Tag of the feature.
+    pub tag: Tag,
+    /// Mask of the glyphs to which the feature applies.
+    pub mask: u32,
+    /// Index of the alternate glyph selected by the feature.
+    pub alternate: u16,
+    /// Stage in which the feature is applied.
+    pub stage: u8,
+}
+
+impl FeatureRequest {
+    pub fn new(tag: Tag, mask: u32, stage: u8) -> Self {
+        Self {
+            tag,
+            mask,
+            alternate: 0,
+            stage,
+        }
+    }
+}
+
+/// Operation performed on the glyph sequence after the substitutions of a
+/// stage have been applied.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub(crate) enum Pause {
+    /// Reorders Indic syllables before the basic features.
+    IndicInitialReordering,
+    /// Reorders Indic syllables after the basic features.
+    IndicFinalReordering,
+    /// Clears the substituted flag before the `rphf` feature.
+    UniversalClearSubstituted,
+    /// Records the repha glyphs formed by the `rphf` feature.
+    UniversalRecordRepha,
+    /// Records the pre-base glyphs formed by the `pref` feature.
+    UniversalRecordPref,
+    /// Reorders Universal Shaping Engine clusters.
+    UniversalReordering,
+}
+
+/// Collects the features to apply during shaping, grouped into stages.
+#[derive(Clone, Default, Debug)]
+pub(crate) struct FeatureCollector {
+    pub requests: Vec<FeatureRequest>,
+    /// Operations performed after each stage.
+    pub pauses: Vec<(u8, Pause)>,
+    stage: u8,
+}
+
+impl FeatureCollector {
+    /// Adds a feature that applies to the glyphs with the specified mask in
+    /// the current stage. Features that were already added are merged.
+    pub fn add(&mut self, tag: Tag, mask: u32) {
+        if let Some(request) = self.requests.iter_mut().find(|request| request.tag == tag) {
+            request.mask |= mask;
+        } else {
+            self.requests
+                .push(FeatureRequest::new(tag, mask, self.stage));
+        }
+    }
+
+    /// Starts a new stage. Lookups for features in earlier stages are
+    /// applied to the entire sequence before those in later stages.
+    pub fn pause(&mut self) {
+        self.stage += 1;
+    }
+
+    /// Starts a new stage, performing the specified operation after the
+    /// substitutions for the current stage have been applied.
+    pub fn pause_with(&mut self, pause: Pause) {
+        self.pauses.push((self.stage, pause));
+        self.stage += 1;
+    }
+
+    /// Applies the user settings, disabling, enabling or adjusting features
+    /// in the current stage.
+    pub fn apply_settings(&mut self, settings: &[FeatureSetting], mask: u32) {
+        for setting in settings {
+            let existing = self
+                .requests
+                .iter()
+                .position(|request| request.tag == setting.tag);
+            match (existing, setting.value) {
+                (Some(index), 0) => {
+                    self.requests.remove(index);
+                }
+                (Some(index), value) => self.requests[index].alternate = value - 1,
+                (None, 0) => {}
+                (None, value) => {
+                    let mut request = FeatureRequest::new(setting.tag, mask, self.stage);
+                    request.alternate = value - 1;
+                    self.requests.push(request);
+                }
+            }
+        }
+    }
+}
+
+/// Lookup scheduled for application during shaping.
+#[derive(Copy, Clone, Debug)]
+pub(crate) struct PlanLookup {
+    /// Stage in which the lookup is applied.
+    pub stage: u8,
+    /// Index of the lookup.
+    pub index: u16,
+    /// Mask of the glyphs to which the lookup applies.
+    pub mask: u32,
+    /// Index of the alternate glyph selected by the lookup.
+    pub alternate: u16,
+}
+
+/// Collects the lookups for the requested features, appending them to
+/// `lookups` ordered by stage and lookup index.
+pub(crate) fn collect_lookups(
+    features: &FeatureSet,
+    requests: &[FeatureRequest],
+    lookups: &mut Vec<PlanLookup>,
+) {
+    lookups.clear();
+    let mut add_lookups = |indices: &[u16], mask: u32, alternate: u16, stage: u8| {
+        lookups.extend(indices.iter().map(|index| PlanLookup {
+            stage,
+            index: *index,
+            mask,
+            alternate,
+        }));
+    };
+    if let Some((_, indices)) = features.required_feature() {
+        add_lookups(indices, !0, 0, 0);
+    }
+    for request in requests {
+        if let Some(indices) = features.lookups(request.tag) {
+            add_lookups(indices, request.mask, request.alternate, request.stage);
+        }
+    }
+    lookups.sort_by_key(|lookup| (lookup.stage, lookup.index));
+    lookups.dedup_by(|b, a| {
+        if a.stage == b.stage && a.index == b.index {
+            a.mask |= b.mask;
+            true
+        } else {
+            false
+        }
+    });
+}
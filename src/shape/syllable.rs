@@ -0,0 +1,125 @@
+//! Syllable bookkeeping shared by the Indic and Universal shapers.
+//!
+//! These shapers store per glyph state in [`GlyphInfo::data`] so that it
+//! survives substitutions. The character category occupies the low byte,
+//! followed by the reordering position, the syllable serial number and
+//! the syllable kind.
+
+use crate::otl::GlyphInfo;
+use crate::parse_prelude::*;
+use std::vec::Vec;
+
+/// Returns the shaper specific category of the glyph.
+pub fn category(glyph: &GlyphInfo) -> u8 {
+    glyph.data as u8
+}
+
+/// Sets the shaper specific category of the glyph.
+pub fn set_category(glyph: &mut GlyphInfo, category: u8) {
+    glyph.data = (glyph.data & !0xFF) | category as u32;
+}
+
+/// Returns the reordering position of the glyph.
+pub fn position(glyph: &GlyphInfo) -> u8 {
+    (glyph.data >> 8) as u8
+}
+
+/// Sets the reordering position of the glyph.
+pub fn set_position(glyph: &mut GlyphInfo, position: u8) {
+    glyph.data = (glyph.data & !0xFF00) | (position as u32) << 8;
+}
+
+/// Returns the serial number of the syllable containing the glyph.
+pub fn serial(glyph: &GlyphInfo) -> u8 {
+    (glyph.data >> 16) as u8
+}
+
+/// Returns the kind of the syllable containing the glyph.
+pub fn kind(glyph: &GlyphInfo) -> u8 {
+    (glyph.data >> 24) as u8
+}
+
+/// Returns true if the glyph is the result of a ligature substitution.
+pub fn is_ligated(glyph: &GlyphInfo) -> bool {
+    glyph.ligature_id != 0 && glyph.component == 0
+}
+
+/// Segments the glyphs into syllables. The `scan` function returns the end
+/// and kind of the syllable beginning at the specified index.
+pub fn find_syllables(glyphs: &mut [GlyphInfo], scan: impl Fn(&[GlyphInfo], usize) -> (usize, u8)) {
+    let mut start = 0;
+    let mut serial = 1u32;
+    while start < glyphs.len() {
+        let (end, kind) = scan(glyphs, start);
+        let end = end.clamp(start + 1, glyphs.len());
+        for glyph in &mut glyphs[start..end] {
+            glyph.data = (glyph.data & 0xFFFF) | serial << 16 | (kind as u32) << 24;
+        }
+        serial = if serial == 0xFF { 1 } else { serial + 1 };
+        start = end;
+    }
+}
+
+/// Returns the end of the syllable beginning at the specified index.
+pub fn syllable_end(glyphs: &[GlyphInfo], start: usize) -> usize {
+    let serial = serial(&glyphs[start]);
+    glyphs[start..]
+        .iter()
+        .position(|glyph| self::serial(glyph) != serial)
+        .map(|len| start + len)
+        .unwrap_or(glyphs.len())
+}
+
+/// Inserts a dotted circle glyph into each syllable of the specified kind.
+/// The `offset` function returns the index relative to the start of the
+/// syllable at which the dotted circle is inserted.
+pub fn insert_dotted_circles(
+    glyphs: &mut Vec<GlyphInfo>,
+    dotted_circle: GlyphId,
+    broken: u8,
+    data: u32,
+    offset: impl Fn(&[GlyphInfo]) -> usize,
+) {
+    if dotted_circle == 0 || !glyphs.iter().any(|glyph| kind(glyph) == broken) {
+        return;
+    }
+    let mut start = 0;
+    while start < glyphs.len() {
+        let mut end = syllable_end(glyphs, start);
+        if kind(&glyphs[start]) == broken {
+            let index = start + offset(&glyphs[start..end]).min(end - start - 1);
+            let mut glyph = glyphs[index];
+            glyph.glyph_id = dotted_circle;
+            glyph.class = crate::otl::BASE_CLASS;
+            glyph.mark_class = 0;
+            glyph.data = (glyph.data & 0xFFFF_0000) | data;
+            glyphs.insert(index, glyph);
+            end += 1;
+        }
+        start = end;
+    }
+}
+
+/// Moves the glyph at index `from` to index `to`, shifting the glyphs in
+/// between and merging their clusters.
+pub fn move_glyph(glyphs: &mut [GlyphInfo], from: usize, to: usize) {
+    if from == to {
+        return;
+    }
+    let (start, end) = if from < to { (from, to) } else { (to, from) };
+    merge_clusters(&mut glyphs[start..=end]);
+    if from < to {
+        glyphs[from..=to].rotate_left(1);
+    } else {
+        glyphs[to..=from].rotate_right(1);
+    }
+}
+
+/// Merges the clusters of the glyphs into a single cluster.
+pub fn merge_clusters(glyphs: &mut [GlyphInfo]) {
+    if let Some(cluster) = glyphs.iter().map(|glyph| glyph.cluster).min() {
+        for glyph in glyphs {
+            glyph.cluster = cluster;
+        }
+    }
+}
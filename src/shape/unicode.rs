@@ -0,0 +1,169 @@
+//! Minimal character properties used for shaping.
+
+use std::vec::Vec;
+
+/// Ranges of nonspacing and enclosing combining marks for the scripts
+/// supported by the shaper. Used to synthesize glyph classes for fonts
+/// that lack glyph definitions.
+const MARK_RANGES: &[(u32, u32)] = &[
+    (0x0300, 0x036F),
+    (0x0483, 0x0489),
+    (0x0591, 0x05BD),
+    (0x05BF, 0x05BF),
+    (0x05C1, 0x05C2),
+    (0x05C4, 0x05C5),
+    (0x05C7, 0x05C7),
+    (0x0610, 0x061A),
+    (0x064B, 0x065F),
+    (0x0670, 0x0670),
+    (0x06D6, 0x06DC),
+    (0x06DF, 0x06E4),
+    (0x06E7, 0x06E8),
+    (0x06EA, 0x06ED),
+    (0x0711, 0x0711),
+    (0x0730, 0x074A),
+    (0x07EB, 0x07F3),
+    (0x07FD, 0x07FD),
+    (0x08D3, 0x08E1),
+    (0x08E3, 0x08FF),
+    (0x1885, 0x1886),
+    (0x18A9, 0x18A9),
+    (0x1AB0, 0x1AFF),
+    (0x1DC0, 0x1DFF),
+    (0x20D0, 0x20F0),
+    (0x302A, 0x302F),
+    (0x3099, 0x309A),
+    (0xFB1E, 0xFB1E),
+    (0xFE20, 0xFE2F),
+];
+
+/// Pairs of characters with the bidi mirrored property.
+const MIRRORS: &[(char, char)] = &[
+    ('(', ')'),
+    ('<', '>'),
+    ('[', ']'),
+    ('{', '}'),
+    ('«', '»'),
+    ('‹', '›'),
+    ('⁅', '⁆'),
+    ('⁽', '⁾'),
+    ('₍', '₎'),
+    ('≤', '≥'),
+    ('〈', '〉'),
+    ('《', '》'),
+    ('「', '」'),
+    ('『', '』'),
+    ('【', '】'),
+    ('〔', '〕'),
+    ('（', '）'),
+    ('［', '］'),
+    ('｛', '｝'),
+];
+
+/// Returns true if the character is a combining mark.
+pub fn is_mark(ch: char) -> bool {
+    let c = ch as u32;
+    if c < 0x300 {
+        return false;
+    }
+    in_ranges(MARK_RANGES, c)
+}
+
+fn in_ranges(ranges: &[(u32, u32)], c: u32) -> bool {
+    ranges
+        .binary_search_by(|&(start, end)| {
+            use core::cmp::Ordering::*;
+            if c < start {
+                Greater
+            } else if c > end {
+                Less
+            } else {
+                Equal
+            }
+        })
+        .is_ok()
+}
+
+/// Returns the mirrored form of a character for right-to-left text.
+pub fn mirror(ch: char) -> Option<char> {
+    MIRRORS.iter().find_map(|&(a, b)| {
+        if a == ch {
+            Some(b)
+        } else if b == ch {
+            Some(a)
+        } else {
+            None
+        }
+    })
+}
+
+/// Ranges of default ignorable characters.
+const DEFAULT_IGNORABLE_RANGES: &[(u32, u32)] = &[
+    (0x00AD, 0x00AD),
+    (0x034F, 0x034F),
+    (0x061C, 0x061C),
+    (0x115F, 0x1160),
+    (0x17B4, 0x17B5),
+    (0x180B, 0x180F),
+    (0x200B, 0x200F),
+    (0x202A, 0x202E),
+    (0x2060, 0x206F),
+    (0x3164, 0x3164),
+    (0xFE00, 0xFE0F),
+    (0xFEFF, 0xFEFF),
+    (0xFFA0, 0xFFA0),
+    (0xFFF0, 0xFFF8),
+    (0x1BCA0, 0x1BCA3),
+    (0x1D173, 0x1D17A),
+    (0xE0000, 0xE0FFF),
+];
+
+/// Returns true if the character is a default ignorable code point.
+pub fn is_default_ignorable(ch: char) -> bool {
+    let c = ch as u32;
+    if c < 0xAD {
+        return false;
+    }
+    in_ranges(DEFAULT_IGNORABLE_RANGES, c)
+}
+
+/// Returns true if the character is a variation selector.
+pub fn is_variation_selector(ch: char) -> bool {
+    matches!(ch as u32, 0x180B..=0x180D | 0xFE00..=0xFE0F | 0xE0100..=0xE01EF)
+}
+
+/// Decompositions of the split vowel signs that contain a part written
+/// before the base consonant.
+const SPLIT_VOWELS: &[(char, &[char])] = &[
+    ('\u{09CB}', &['\u{09C7}', '\u{09BE}']),
+    ('\u{09CC}', &['\u{09C7}', '\u{09D7}']),
+    ('\u{0B48}', &['\u{0B47}', '\u{0B56}']),
+    ('\u{0B4B}', &['\u{0B47}', '\u{0B3E}']),
+    ('\u{0B4C}', &['\u{0B47}', '\u{0B57}']),
+    ('\u{0BCA}', &['\u{0BC6}', '\u{0BBE}']),
+    ('\u{0BCB}', &['\u{0BC7}', '\u{0BBE}']),
+    ('\u{0BCC}', &['\u{0BC6}', '\u{0BD7}']),
+    ('\u{0D4A}', &['\u{0D46}', '\u{0D3E}']),
+    ('\u{0D4B}', &['\u{0D47}', '\u{0D3E}']),
+    ('\u{0D4C}', &['\u{0D46}', '\u{0D57}']),
+    ('\u{0DDA}', &['\u{0DD9}', '\u{0DCA}']),
+    ('\u{0DDC}', &['\u{0DD9}', '\u{0DCF}']),
+    ('\u{0DDD}', &['\u{0DD9}', '\u{0DCF}', '\u{0DCA}']),
+    ('\u{0DDE}', &['\u{0DD9}', '\u{0DDF}']),
+];
+
+/// Replaces the split vowel signs with their decompositions so that the
+/// parts written before the base consonant can be reordered.
+pub fn decompose_split_vowels(chars: &mut Vec<(char, u32)>) {
+    let mut i = 0;
+    while i < chars.len() {
+        let (ch, cluster) = chars[i];
+        if let Ok(index) = SPLIT_VOWELS.binary_search_by_key(&ch, |(ch, _)| *ch) {
+            let parts = SPLIT_VOWELS[index].1;
+            chars.splice(i..=i, parts.iter().map(|part| (*part, cluster)));
+            i += parts.len();
+        } else {
+            i += 1;
+        }
+    }
+}
@@ -0,0 +1,839 @@
+//! Shaping based on the Universal Shaping Engine model.
+//!
+//! Characters are assigned the categories defined by the Universal Shaping
+//! Engine and segmented into clusters using its cluster grammar. The
+//! shaping features are applied to each cluster as a whole. The repha and
+//! the pre-base glyphs are reordered after the basic features have been
+//! applied.
+
+use super::plan::{FeatureCollector, Pause};
+use super::syllable::{self, category, set_category};
+use super::GLOBAL_MASK;
+use crate::otl::GlyphInfo;
+use crate::parse_prelude::*;
+use std::vec::Vec;
+
+// Character categories.
+const O: u8 = 0;
+const B: u8 = 1;
+const N: u8 = 2;
+const GB: u8 = 3;
+const CGJ: u8 = 4;
+const SUB: u8 = 5;
+const H: u8 = 6;
+const HN: u8 = 7;
+const ZWNJ: u8 = 8;
+const WJ: u8 = 9;
+const R: u8 = 10;
+const CS: u8 = 11;
+const IS: u8 = 12;
+const SK: u8 = 13;
+const G: u8 = 14;
+const J: u8 = 15;
+const SB: u8 = 16;
+const SE: u8 = 17;
+const HVM: u8 = 18;
+const HM: u8 = 19;
+const HR: u8 = 20;
+const FABV: u8 = 21;
+const FBLW: u8 = 22;
+const FPST: u8 = 23;
+const MABV: u8 = 24;
+const MBLW: u8 = 25;
+const MPST: u8 = 26;
+const MPRE: u8 = 27;
+const CMABV: u8 = 28;
+const CMBLW: u8 = 29;
+const VABV: u8 = 30;
+const VBLW: u8 = 31;
+const VPST: u8 = 32;
+const VPRE: u8 = 33;
+const VMABV: u8 = 34;
+const VMBLW: u8 = 35;
+const VMPST: u8 = 36;
+const VMPRE: u8 = 37;
+const SMABV: u8 = 38;
+const SMBLW: u8 = 39;
+const FMABV: u8 = 40;
+const FMBLW: u8 = 41;
+const FMPST: u8 = 42;
+
+/// Category used past the end of the glyph sequence.
+const END: u8 = 0xFF;
+
+// Cluster kinds.
+const VIRAMA_TERMINATED_CLUSTER: u8 = 0;
+const SAKOT_TERMINATED_CLUSTER: u8 = 1;
+const STANDARD_CLUSTER: u8 = 2;
+const NUMBER_JOINER_TERMINATED_CLUSTER: u8 = 3;
+const NUMERAL_CLUSTER: u8 = 4;
+const SYMBOL_CLUSTER: u8 = 5;
+const HIEROGLYPH_CLUSTER: u8 = 6;
+const BROKEN_CLUSTER: u8 = 7;
+const NON_CLUSTER: u8 = 8;
+
+/// Mask for the `rphf` feature.
+const RPHF_MASK: u32 = 1 << 1;
+
+/// Masks for the topographical features in the order `isol`, `init`,
+/// `medi` and `fina`.
+const TOPOGRAPHICAL_MASKS: [u32; 4] = [1 << 2, 1 << 3, 1 << 4, 1 << 5];
+
+const ISOL: usize = 0;
+const INIT: usize = 1;
+const MEDI: usize = 2;
+const FINA: usize = 3;
+
+/// Features applied to each cluster after the repha has been formed.
+const BASIC_FEATURES: &[&[u8; 4]] = &[
+    b"rkrf", b"abvf", b"blwf", b"half", b"pstf", b"vatu", b"cjct",
+];
+
+/// Topographical features applied after reordering.
+const TOPOGRAPHICAL_FEATURES: [&[u8; 4]; 4] = [b"isol", b"init", b"medi", b"fina"];
+
+/// Features applied after the topographical features.
+const OTHER_FEATURES: &[&[u8; 4]] = &[b"abvs", b"blws", b"haln", b"pres", b"psts"];
+
+/// Scripts shaped with the Universal Shaping Engine.
+const SCRIPTS: &[&[u8; 4]] = &[
+    b"ahom", b"bali", b"batk", b"bhks", b"brah", b"bugi", b"buhd", b"cakm", b"cham", b"cpmn",
+    b"diak", b"dogr", b"dupl", b"egyp", b"elym", b"gong", b"gonm", b"gran", b"hano", b"hmng",
+    b"hmnp", b"java", b"kali", b"kawi", b"khar", b"khoj", b"kits", b"kthi", b"lana", b"lepc",
+    b"limb", b"mahj", b"maka", b"marc", b"medf", b"modi", b"mtei", b"mult", b"nagm", b"nand",
+    b"newa", b"rjng", b"saur", b"shrd", b"sidd", b"sind", b"sinh", b"soyo", b"sund", b"sylo",
+    b"tagb", b"takr", b"tale", b"tavt", b"tfng", b"tglg", b"tibt", b"tirh", b"tnsa", b"toto",
+    b"vith", b"wcho", b"zanb",
+];
+
+/// Returns true if the script is shaped with the Universal Shaping Engine.
+pub fn is_universal_script(script: Tag) -> bool {
+    SCRIPTS.iter().any(|tag| Tag::new(tag) == script)
+}
+
+/// Returns the category of a character.
+fn properties(ch: char) -> u8 {
+    let c = ch as u32;
+    CATEGORIES
+        .binary_search_by(|&(start, end, _)| {
+            use core::cmp::Ordering::*;
+            if c < start {
+                Greater
+            } else if c > end {
+                Less
+            } else {
+                Equal
+            }
+        })
+        .map(|index| CATEGORIES[index].2)
+        .unwrap_or(O)
+}
+
+/// Returns true if characters in the category are combining marks.
+fn is_mark(category: u8) -> bool {
+    !matches!(
+        category,
+        O | B | N | GB | CGJ | ZWNJ | WJ | R | CS | G | J | SB | SE | FMPST | END
+    )
+}
+
+fn is_halant(glyph: &GlyphInfo) -> bool {
+    matches!(category(glyph), H | HVM | IS) && !syllable::is_ligated(glyph)
+}
+
+/// Matcher for the cluster grammar of the Universal Shaping Engine over a
+/// sequence of categories. Each method returns the end of the longest match
+/// of a rule beginning at the specified index.
+struct Grammar<'a>(&'a [u8]);
+
+impl Grammar<'_> {
+    fn cat(&self, i: usize) -> u8 {
+        self.0.get(i).copied().unwrap_or(END)
+    }
+
+    fn many(&self, mut i: usize, category: u8) -> usize {
+        while self.cat(i) == category {
+            i += 1;
+        }
+        i
+    }
+
+    fn optional(&self, i: usize, category: u8) -> usize {
+        i + (self.cat(i) == category) as usize
+    }
+
+    fn is_halant(&self, i: usize) -> bool {
+        matches!(self.cat(i), H | HVM | IS | SK)
+    }
+
+    /// `CMAbv* CMBlw* ((h B | SUB) CMAbv* CMBlw*)*`
+    fn consonant_modifiers(&self, i: usize) -> usize {
+        let mut i = self.many(self.many(i, CMABV), CMBLW);
+        loop {
+            let next = if self.is_halant(i) && self.cat(i + 1) == B {
+                i + 2
+            } else if self.cat(i) == SUB {
+                i + 1
+            } else {
+                return i;
+            };
+            i = self.many(self.many(next, CMABV), CMBLW);
+        }
+    }
+
+    /// `MPre? MAbv? MBlw? MPst?`
+    fn medial_consonants(&self, i: usize) -> usize {
+        [MPRE, MABV, MBLW, MPST]
+            .iter()
+            .fold(i, |i, category| self.optional(i, *category))
+    }
+
+    /// `VPre* VAbv* VBlw* VPst* | H`
+    fn dependent_vowels(&self, i: usize) -> usize {
+        if self.cat(i) == H {
+            return i + 1;
+        }
+        [VPRE, VABV, VBLW, VPST]
+            .iter()
+            .fold(i, |i, category| self.many(i, *category))
+    }
+
+    /// `HVM? VMPre* VMAbv* VMBlw* VMPst*`
+    fn vowel_modifiers(&self, i: usize) -> usize {
+        [VMPRE, VMABV, VMBLW, VMPST]
+            .iter()
+            .fold(self.optional(i, HVM), |i, category| self.many(i, *category))
+    }
+
+    /// `(R | CS)? (B | GB)`
+    fn syllable_start(&self, i: usize) -> Option<usize> {
+        let i = i + matches!(self.cat(i), R | CS) as usize;
+        matches!(self.cat(i), B | GB).then_some(i + 1)
+    }
+
+    /// Consonant modifiers, medial consonants, dependent vowels and vowel
+    /// modifiers followed by `(Sk B)*`.
+    fn syllable_middle(&self, i: usize) -> usize {
+        let mut i = self.consonant_modifiers(i);
+        i = self.medial_consonants(i);
+        i = self.dependent_vowels(i);
+        i = self.vowel_modifiers(i);
+        while self.cat(i) == SK && self.cat(i + 1) == B {
+            i += 2;
+        }
+        i
+    }
+
+    /// Syllable middle followed by `FAbv* FBlw* FPst*` and
+    /// `FMAbv* FMBlw* | FMPst?`.
+    fn syllable_tail(&self, i: usize) -> usize {
+        let i = [FABV, FBLW, FPST]
+            .iter()
+            .fold(self.syllable_middle(i), |i, category| {
+                self.many(i, *category)
+            });
+        if self.cat(i) == FMPST {
+            i + 1
+        } else {
+            self.many(self.many(i, FMABV), FMBLW)
+        }
+    }
+
+    /// Consonant modifiers followed by `IS`.
+    fn virama_terminated_tail(&self, i: usize) -> Option<usize> {
+        let i = self.consonant_modifiers(i);
+        (self.cat(i) == IS).then_some(i + 1)
+    }
+
+    /// Syllable middle followed by `Sk`.
+    fn sakot_terminated_tail(&self, i: usize) -> Option<usize> {
+        let i = self.syllable_middle(i);
+        (self.cat(i) == SK).then_some(i + 1)
+    }
+
+    /// `SMAbv+ SMBlw* | SMBlw+`
+    fn symbol_tail(&self, i: usize) -> Option<usize> {
+        match self.cat(i) {
+            SMABV | SMBLW => Some(self.many(self.many(i, SMABV), SMBLW)),
+            _ => None,
+        }
+    }
+
+    /// `(HN N)* HN`
+    fn number_joiner_terminated_tail(&self, mut i: usize) -> Option<usize> {
+        while self.cat(i) == HN && self.cat(i + 1) == N {
+            i += 2;
+        }
+        (self.cat(i) == HN).then_some(i + 1)
+    }
+
+    /// `(HN N)+`
+    fn numeral_tail(&self, start: usize) -> Option<usize> {
+        let mut i = start;
+        while self.cat(i) == HN && self.cat(i + 1) == N {
+            i += 2;
+        }
+        (i > start).then_some(i)
+    }
+
+    /// `SB* G HR? HM? SE* (J SB* (G HR? HM? SE*)?)*`
+    fn hieroglyph_cluster(&self, i: usize) -> Option<usize> {
+        let glyph = |i: usize| self.many(self.optional(self.optional(i, HR), HM), SE);
+        let i = self.many(i, SB);
+        if self.cat(i) != G {
+            return None;
+        }
+        let mut i = glyph(i + 1);
+        while self.cat(i) == J {
+            i = self.many(i + 1, SB);
+            if self.cat(i) == G {
+                i = glyph(i + 1);
+            }
+        }
+        Some(i)
+    }
+
+    /// Returns the end and kind of the longest cluster beginning at the
+    /// specified index. Earlier rules take precedence over later rules
+    /// with matches of the same length.
+    fn cluster(&self, i: usize) -> (usize, u8) {
+        let start = self.syllable_start(i);
+        let number = (self.cat(i) == N).then_some(i + 1);
+        let broken = {
+            let i = self.optional(i, R);
+            [
+                Some(self.syllable_tail(i)),
+                self.sakot_terminated_tail(i),
+                self.symbol_tail(i),
+                self.virama_terminated_tail(i),
+                self.number_joiner_terminated_tail(i),
+                self.numeral_tail(i),
+            ]
+            .iter()
+            .flatten()
+            .copied()
+            .max()
+        };
+        let candidates = [
+            (
+                start.and_then(|i| self.virama_terminated_tail(i)),
+                VIRAMA_TERMINATED_CLUSTER,
+            ),
+            (
+                start.and_then(|i| self.sakot_terminated_tail(i)),
+                SAKOT_TERMINATED_CLUSTER,
+            ),
+            (start.map(|i| self.syllable_tail(i)), STANDARD_CLUSTER),
+            (
+                number.and_then(|i| self.number_joiner_terminated_tail(i)),
+                NUMBER_JOINER_TERMINATED_CLUSTER,
+            ),
+            (
+                number.map(|i| self.numeral_tail(i).unwrap_or(i)),
+                NUMERAL_CLUSTER,
+            ),
+            (
+                matches!(self.cat(i), O | GB | SB)
+                    .then(|| self.symbol_tail(i + 1).unwrap_or(i + 1)),
+                SYMBOL_CLUSTER,
+            ),
+            (self.hieroglyph_cluster(i), HIEROGLYPH_CLUSTER),
+            (broken.filter(|end| *end > i), BROKEN_CLUSTER),
+        ];
+        let mut best = (i + 1, NON_CLUSTER);
+        let mut best_len = 0;
+        for (end, kind) in candidates.iter() {
+            if let Some(end) = end {
+                let end = self.optional(*end, ZWNJ);
+                if end - i > best_len {
+                    best = (end, *kind);
+                    best_len = end - i;
+                }
+            }
+        }
+        if self.cat(i) == FMPST && best_len <= 1 {
+            return (i + 1, NON_CLUSTER);
+        }
+        best
+    }
+}
+
+/// Returns true if the glyph is ignored by the cluster grammar. Combining
+/// grapheme joiners are always ignored and a zero width non-joiner is
+/// ignored if it is followed by a combining mark.
+fn is_ignored(glyphs: &[GlyphInfo], index: usize) -> bool {
+    match category(&glyphs[index]) {
+        CGJ => true,
+        ZWNJ => glyphs[index + 1..]
+            .iter()
+            .map(category)
+            .find(|category| *category != CGJ)
+            .is_some_and(is_mark),
+        _ => false,
+    }
+}
+
+/// Assigns character categories to the glyphs and segments them into
+/// clusters, inserting a dotted circle into each broken cluster.
+pub fn setup(chars: &[(char, u32)], glyphs: &mut Vec<GlyphInfo>, dotted_circle: GlyphId) {
+    for (glyph, (ch, _)) in glyphs.iter_mut().zip(chars) {
+        set_category(glyph, properties(*ch));
+    }
+    // The grammar operates on the glyphs that are not ignored. Ignored
+    // glyphs belong to the preceding cluster, or the following cluster at
+    // the start of the sequence.
+    let indices = (0..glyphs.len())
+        .filter(|i| !is_ignored(glyphs, *i))
+        .collect::<Vec<_>>();
+    let categories = indices
+        .iter()
+        .map(|i| category(&glyphs[*i]))
+        .collect::<Vec<_>>();
+    let grammar = Grammar(&categories);
+    syllable::find_syllables(glyphs, |glyphs, start| {
+        let i = indices.partition_point(|index| *index < start);
+        if i == indices.len() {
+            return (glyphs.len(), NON_CLUSTER);
+        }
+        let (end, kind) = grammar.cluster(i);
+        (indices.get(end).copied().unwrap_or(glyphs.len()), kind)
+    });
+    syllable::insert_dotted_circles(glyphs, dotted_circle, BROKEN_CLUSTER, B as u32, |glyphs| {
+        (category(&glyphs[0]) == R) as usize
+    });
+    let mut start = 0;
+    let mut last_start = 0;
+    let mut last_form = None;
+    while start < glyphs.len() {
+        let end = syllable::syllable_end(glyphs, start);
+        let kind = syllable::kind(&glyphs[start]);
+        if kind == NON_CLUSTER || kind == HIEROGLYPH_CLUSTER {
+            last_form = None;
+            last_start = start;
+            start = end;
+            continue;
+        }
+        // The repha may be formed from the first three glyphs of a cluster
+        // or is the first glyph if encoded separately.
+        let limit = if category(&glyphs[start]) == R {
+            1
+        } else {
+            (end - start).min(3)
+        };
+        for glyph in &mut glyphs[start..start + limit] {
+            glyph.mask |= RPHF_MASK;
+        }
+        // Clusters that follow each other join with the topographical
+        // features.
+        let join = matches!(last_form, Some(FINA) | Some(ISOL));
+        if join {
+            let form = if last_form == Some(FINA) { MEDI } else { INIT };
+            set_form(&mut glyphs[last_start..start], form);
+        }
+        let form = if join { FINA } else { ISOL };
+        set_form(&mut glyphs[start..end], form);
+        last_form = Some(form);
+        last_start = start;
+        start = end;
+    }
+}
+
+fn set_form(glyphs: &mut [GlyphInfo], form: usize) {
+    let all = TOPOGRAPHICAL_MASKS.iter().fold(0, |all, mask| all | mask);
+    for glyph in glyphs {
+        glyph.mask = (glyph.mask & !all) | TOPOGRAPHICAL_MASKS[form];
+    }
+}
+
+/// Adds the features for the Universal Shaping Engine.
+pub fn collect_features(features: &mut FeatureCollector) {
+    for tag in &[b"locl", b"ccmp", b"nukt", b"akhn"] {
+        features.add(Tag::new(tag), GLOBAL_MASK);
+    }
+    features.pause_with(Pause::UniversalClearSubstituted);
+    features.add(Tag::new(b"rphf"), RPHF_MASK);
+    features.pause_with(Pause::UniversalRecordRepha);
+    features.add(Tag::new(b"pref"), GLOBAL_MASK);
+    features.pause_with(Pause::UniversalRecordPref);
+    for tag in BASIC_FEATURES {
+        features.add(Tag::new(tag), GLOBAL_MASK);
+    }
+    features.pause_with(Pause::UniversalReordering);
+    for (tag, mask) in TOPOGRAPHICAL_FEATURES.iter().zip(TOPOGRAPHICAL_MASKS) {
+        features.add(Tag::new(tag), mask);
+    }
+    features.pause();
+    for tag in OTHER_FEATURES {
+        features.add(Tag::new(tag), GLOBAL_MASK);
+    }
+}
+
+/// Marks the first glyph of each cluster that was substituted by the
+/// `rphf` feature as a repha.
+pub fn record_repha(glyphs: &mut [GlyphInfo]) {
+    let mut start = 0;
+    while start < glyphs.len() {
+        let end = syllable::syllable_end(glyphs, start);
+        if let Some(glyph) = glyphs[start..end]
+            .iter_mut()
+            .take_while(|glyph| glyph.mask & RPHF_MASK != 0)
+            .find(|glyph| glyph.substituted)
+        {
+            set_category(glyph, R);
+        }
+        start = end;
+    }
+    clear_substituted(glyphs);
+}
+
+/// Marks the first glyph of each cluster that was substituted by the
+/// `pref` feature as a pre-base vowel sign so that it is reordered.
+pub fn record_pref(glyphs: &mut [GlyphInfo]) {
+    let mut start = 0;
+    while start < glyphs.len() {
+        let end = syllable::syllable_end(glyphs, start);
+        if let Some(glyph) = glyphs[start..end]
+            .iter_mut()
+            .find(|glyph| glyph.substituted)
+        {
+            set_category(glyph, VPRE);
+        }
+        start = end;
+    }
+}
+
+/// Clears the substituted flag of the glyphs.
+pub fn clear_substituted(glyphs: &mut [GlyphInfo]) {
+    for glyph in glyphs {
+        glyph.substituted = false;
+    }
+}
+
+/// Moves the repha toward the end of each cluster and the pre-base glyphs
+/// toward the start.
+pub fn reorder(glyphs: &mut [GlyphInfo]) {
+    let mut start = 0;
+    while start < glyphs.len() {
+        let end = syllable::syllable_end(glyphs, start);
+        if matches!(
+            syllable::kind(&glyphs[start]),
+            VIRAMA_TERMINATED_CLUSTER
+                | SAKOT_TERMINATED_CLUSTER
+                | STANDARD_CLUSTER
+                | SYMBOL_CLUSTER
+                | BROKEN_CLUSTER
+        ) {
+            reorder_cluster(&mut glyphs[start..end]);
+        }
+        start = end;
+    }
+}
+
+fn reorder_cluster(glyphs: &mut [GlyphInfo]) {
+    let end = glyphs.len();
+    if category(&glyphs[0]) == R && end > 1 {
+        // Move the repha before the first post-base glyph or to the end
+        // of the cluster.
+        for i in 1..end {
+            let is_post_base = matches!(
+                category(&glyphs[i]),
+                FABV | FBLW
+                    | FPST
+                    | MABV
+                    | MBLW
+                    | MPST
+                    | MPRE
+                    | VABV
+                    | VBLW
+                    | VPST
+                    | VPRE
+                    | VMABV
+                    | VMBLW
+                    | VMPST
+                    | VMPRE
+            ) || is_halant(&glyphs[i]);
+            if is_post_base || i == end - 1 {
+                let to = if is_post_base { i - 1 } else { i };
+                syllable::move_glyph(glyphs, 0, to);
+                break;
+            }
+        }
+    }
+    // Move pre-base glyphs to the start of the cluster or after the last
+    // halant. Only the first component of a multiple substitution moves.
+    let mut j = 0;
+    for i in 0..end {
+        if is_halant(&glyphs[i]) {
+            j = i + 1;
+        } else if matches!(category(&glyphs[i]), VPRE | VMPRE) && glyphs[i].component == 0 && j < i
+        {
+            syllable::move_glyph(glyphs, i, j);
+        }
+    }
+}
+
+/// Categories of the characters in the scripts shaped by the Universal
+/// Shaping Engine as sorted, inclusive ranges. Characters that are not
+/// listed are in the other category.
+#[rustfmt::skip]
+const CATEGORIES: &[(u32, u32, u8)] = &[
+    (0x002D, 0x002D, GB), (0x0030, 0x0039, B), (0x00A0, 0x00A0, GB), (0x00B2, 0x00B3, FMPST),
+    (0x00D7, 0x00D7, GB), (0x034F, 0x034F, CGJ), (0x0951, 0x0951, VMABV), (0x0952, 0x0952, VMBLW),
+    (0x0D81, 0x0D81, VMABV), (0x0D82, 0x0D83, VMPST), (0x0D85, 0x0D96, B), (0x0D9A, 0x0DB1, B),
+    (0x0DB3, 0x0DBB, B), (0x0DBD, 0x0DBD, B), (0x0DC0, 0x0DC6, B), (0x0DCA, 0x0DCA, HVM),
+    (0x0DCF, 0x0DD1, VPST), (0x0DD2, 0x0DD3, VABV), (0x0DD4, 0x0DD4, VBLW), (0x0DD6, 0x0DD6, VBLW),
+    (0x0DD8, 0x0DD8, VPST), (0x0DD9, 0x0DDE, VPRE), (0x0DDF, 0x0DDF, VPST), (0x0DE6, 0x0DEF, B),
+    (0x0DF2, 0x0DF3, VPST), (0x0F18, 0x0F19, VBLW), (0x0F20, 0x0F33, B), (0x0F35, 0x0F35, FMBLW),
+    (0x0F37, 0x0F37, FMBLW), (0x0F39, 0x0F39, CMABV), (0x0F3E, 0x0F3E, VPST),
+    (0x0F3F, 0x0F3F, VPRE), (0x0F40, 0x0F47, B), (0x0F49, 0x0F6C, B), (0x0F71, 0x0F71, VBLW),
+    (0x0F72, 0x0F73, VABV), (0x0F74, 0x0F75, VBLW), (0x0F76, 0x0F7D, VABV), (0x0F7E, 0x0F7E, VMABV),
+    (0x0F7F, 0x0F7F, VMPST), (0x0F80, 0x0F81, VABV), (0x0F82, 0x0F83, VMABV),
+    (0x0F84, 0x0F84, VBLW), (0x0F88, 0x0F8C, B), (0x0F8D, 0x0F97, SUB), (0x0F99, 0x0FBC, SUB),
+    (0x0FC6, 0x0FC6, FMBLW), (0x1700, 0x1711, B), (0x1712, 0x1712, VABV), (0x1713, 0x1714, VBLW),
+    (0x1715, 0x1715, VPST), (0x171F, 0x1731, B), (0x1732, 0x1732, VABV), (0x1733, 0x1733, VBLW),
+    (0x1734, 0x1734, VPST), (0x1740, 0x1751, B), (0x1752, 0x1752, VABV), (0x1753, 0x1753, VBLW),
+    (0x1760, 0x176C, B), (0x176E, 0x1770, B), (0x1772, 0x1772, VABV), (0x1773, 0x1773, VBLW),
+    (0x1900, 0x1900, GB), (0x1901, 0x191E, B), (0x1920, 0x1921, VABV), (0x1922, 0x1922, VBLW),
+    (0x1923, 0x1924, VPST), (0x1925, 0x1928, VABV), (0x1929, 0x192B, SUB), (0x1930, 0x1931, FPST),
+    (0x1932, 0x1932, VMBLW), (0x1933, 0x1938, FPST), (0x1939, 0x1939, FBLW), (0x193A, 0x193A, VABV),
+    (0x193B, 0x193B, FMBLW), (0x1946, 0x196D, B), (0x1970, 0x1974, B), (0x1A00, 0x1A16, B),
+    (0x1A17, 0x1A17, VABV), (0x1A18, 0x1A18, VBLW), (0x1A19, 0x1A19, VPRE), (0x1A1A, 0x1A1A, VPST),
+    (0x1A1B, 0x1A1B, VABV), (0x1A20, 0x1A54, B), (0x1A55, 0x1A55, MPRE), (0x1A56, 0x1A56, MBLW),
+    (0x1A57, 0x1A57, SUB), (0x1A58, 0x1A59, FABV), (0x1A5A, 0x1A5A, MABV), (0x1A5B, 0x1A5E, SUB),
+    (0x1A60, 0x1A60, SK), (0x1A61, 0x1A61, VPST), (0x1A62, 0x1A62, VABV), (0x1A63, 0x1A64, VPST),
+    (0x1A65, 0x1A68, VABV), (0x1A69, 0x1A6A, VBLW), (0x1A6B, 0x1A6B, VABV), (0x1A6C, 0x1A6C, VBLW),
+    (0x1A6D, 0x1A6D, VPST), (0x1A6E, 0x1A72, VPRE), (0x1A73, 0x1A73, VABV), (0x1A74, 0x1A79, VMABV),
+    (0x1A7A, 0x1A7A, VABV), (0x1A7B, 0x1A7C, FMABV), (0x1A7F, 0x1A7F, FMBLW), (0x1A80, 0x1A89, B),
+    (0x1A90, 0x1A99, B), (0x1B00, 0x1B02, VMABV), (0x1B03, 0x1B03, FABV), (0x1B04, 0x1B04, VMPST),
+    (0x1B05, 0x1B33, B), (0x1B34, 0x1B34, CMABV), (0x1B35, 0x1B35, VPST), (0x1B36, 0x1B37, VABV),
+    (0x1B38, 0x1B3B, VBLW), (0x1B3C, 0x1B3D, VABV), (0x1B3E, 0x1B41, VPRE), (0x1B42, 0x1B43, VABV),
+    (0x1B44, 0x1B44, H), (0x1B45, 0x1B4C, B), (0x1B50, 0x1B59, B), (0x1B6B, 0x1B6B, SMABV),
+    (0x1B6C, 0x1B6C, SMBLW), (0x1B6D, 0x1B73, SMABV), (0x1B80, 0x1B80, VMABV),
+    (0x1B81, 0x1B81, FABV), (0x1B82, 0x1B82, VMPST), (0x1B83, 0x1BA0, B), (0x1BA1, 0x1BA3, SUB),
+    (0x1BA4, 0x1BA4, VABV), (0x1BA5, 0x1BA5, VBLW), (0x1BA6, 0x1BA6, VPRE), (0x1BA7, 0x1BA7, VPST),
+    (0x1BA8, 0x1BA9, VABV), (0x1BAA, 0x1BAA, VPST), (0x1BAB, 0x1BAB, IS), (0x1BAC, 0x1BAD, SUB),
+    (0x1BAE, 0x1BE5, B), (0x1BE6, 0x1BE6, CMABV), (0x1BE7, 0x1BE7, VPST), (0x1BE8, 0x1BE9, VABV),
+    (0x1BEA, 0x1BEC, VPST), (0x1BED, 0x1BED, VABV), (0x1BEE, 0x1BEE, VPST), (0x1BEF, 0x1BEF, VABV),
+    (0x1BF0, 0x1BF1, FABV), (0x1BF2, 0x1BF3, VPST), (0x1C00, 0x1C23, B), (0x1C24, 0x1C25, SUB),
+    (0x1C26, 0x1C26, VPST), (0x1C27, 0x1C29, VPRE), (0x1C2A, 0x1C2B, VPST), (0x1C2C, 0x1C2C, VBLW),
+    (0x1C2D, 0x1C33, FABV), (0x1C34, 0x1C35, VMPRE), (0x1C36, 0x1C36, FMABV),
+    (0x1C37, 0x1C37, CMBLW), (0x1C40, 0x1C49, B), (0x1C4D, 0x1C4F, B), (0x1CD0, 0x1CD2, VMABV),
+    (0x1CD4, 0x1CD9, VMBLW), (0x1CDA, 0x1CDB, VMABV), (0x1CDC, 0x1CDF, VMBLW),
+    (0x1CE0, 0x1CE0, VMABV), (0x1CE1, 0x1CE1, VMPST), (0x1CE2, 0x1CE8, VMBLW),
+    (0x1CED, 0x1CED, VMBLW), (0x1CF4, 0x1CF4, VMABV), (0x1CF5, 0x1CF6, CS), (0x1CF7, 0x1CF7, VMPST),
+    (0x1CF8, 0x1CF9, VMABV), (0x1CFA, 0x1CFA, GB), (0x1DFB, 0x1DFB, FMABV), (0x200C, 0x200C, ZWNJ),
+    (0x200D, 0x200D, CGJ), (0x2010, 0x2015, GB), (0x2022, 0x2022, GB), (0x2060, 0x2060, WJ),
+    (0x2074, 0x2074, FMPST), (0x2082, 0x2084, FMPST), (0x20F0, 0x20F0, VMABV), (0x25CC, 0x25CC, GB),
+    (0x25FB, 0x25FE, GB), (0xA800, 0xA801, B), (0xA802, 0xA802, VABV), (0xA803, 0xA805, B),
+    (0xA806, 0xA806, H), (0xA807, 0xA80A, B), (0xA80B, 0xA80B, VMABV), (0xA80C, 0xA822, B),
+    (0xA823, 0xA824, VPST), (0xA825, 0xA825, VBLW), (0xA826, 0xA826, VABV), (0xA827, 0xA827, VPST),
+    (0xA82C, 0xA82C, VBLW), (0xA880, 0xA881, VMPST), (0xA882, 0xA8B3, B), (0xA8B4, 0xA8B4, MPST),
+    (0xA8B5, 0xA8C3, VPST), (0xA8C4, 0xA8C4, H), (0xA8C5, 0xA8C5, VMABV), (0xA8D0, 0xA8D9, B),
+    (0xA900, 0xA925, B), (0xA926, 0xA92A, VABV), (0xA92B, 0xA92D, VMBLW), (0xA930, 0xA946, B),
+    (0xA947, 0xA949, VBLW), (0xA94A, 0xA94A, VABV), (0xA94B, 0xA94E, VBLW), (0xA94F, 0xA951, FABV),
+    (0xA952, 0xA952, FPST), (0xA953, 0xA953, VPST), (0xA980, 0xA981, VMABV), (0xA982, 0xA982, FABV),
+    (0xA983, 0xA983, VMPST), (0xA984, 0xA9B2, B), (0xA9B3, 0xA9B3, CMABV), (0xA9B4, 0xA9B5, VPST),
+    (0xA9B6, 0xA9B7, VABV), (0xA9B8, 0xA9B9, VBLW), (0xA9BA, 0xA9BB, VPRE), (0xA9BC, 0xA9BC, VABV),
+    (0xA9BD, 0xA9BF, MBLW), (0xA9C0, 0xA9C0, H), (0xA9D0, 0xA9D9, B), (0xAA00, 0xAA28, B),
+    (0xAA29, 0xAA2C, VABV), (0xAA2D, 0xAA2D, VBLW), (0xAA2E, 0xAA2E, VABV), (0xAA2F, 0xAA30, VPRE),
+    (0xAA31, 0xAA31, VABV), (0xAA32, 0xAA32, VBLW), (0xAA33, 0xAA33, MPST), (0xAA34, 0xAA34, MPRE),
+    (0xAA35, 0xAA36, MBLW), (0xAA40, 0xAA42, B), (0xAA43, 0xAA43, FABV), (0xAA44, 0xAA4B, B),
+    (0xAA4C, 0xAA4C, FABV), (0xAA4D, 0xAA4D, FPST), (0xAA50, 0xAA59, B), (0xAA80, 0xAAAF, B),
+    (0xAAB0, 0xAAB0, VABV), (0xAAB1, 0xAAB1, B), (0xAAB2, 0xAAB3, VABV), (0xAAB4, 0xAAB4, VBLW),
+    (0xAAB5, 0xAAB6, B), (0xAAB7, 0xAAB8, VABV), (0xAAB9, 0xAABD, B), (0xAABE, 0xAABE, VABV),
+    (0xAABF, 0xAABF, VMABV), (0xAAC0, 0xAAC0, B), (0xAAC1, 0xAAC1, VMABV), (0xAAC2, 0xAAC2, B),
+    (0xAAE0, 0xAAEA, B), (0xAAEB, 0xAAEB, VPRE), (0xAAEC, 0xAAEC, VBLW), (0xAAED, 0xAAED, VABV),
+    (0xAAEE, 0xAAEE, VPRE), (0xAAEF, 0xAAEF, VPST), (0xAAF5, 0xAAF5, VMPST), (0xAAF6, 0xAAF6, IS),
+    (0xABC0, 0xABE2, B), (0xABE3, 0xABE4, VPST), (0xABE5, 0xABE5, VABV), (0xABE6, 0xABE7, VPST),
+    (0xABE8, 0xABE8, VBLW), (0xABE9, 0xABEA, VPST), (0xABEC, 0xABEC, VMPST), (0xABED, 0xABED, VBLW),
+    (0xABF0, 0xABF9, B), (0xFE00, 0xFE0F, CGJ), (0x10A00, 0x10A00, B), (0x10A01, 0x10A03, VBLW),
+    (0x10A05, 0x10A05, VABV), (0x10A06, 0x10A06, VBLW), (0x10A0C, 0x10A0D, VBLW),
+    (0x10A0E, 0x10A0E, VMBLW), (0x10A0F, 0x10A0F, VMABV), (0x10A10, 0x10A13, B),
+    (0x10A15, 0x10A17, B), (0x10A19, 0x10A35, B), (0x10A38, 0x10A38, CMABV),
+    (0x10A39, 0x10A3A, CMBLW), (0x10A3F, 0x10A3F, IS), (0x10A40, 0x10A48, B),
+    (0x11000, 0x11000, VMPST), (0x11001, 0x11001, VMABV), (0x11002, 0x11002, VMPST),
+    (0x11003, 0x11004, CS), (0x11005, 0x11037, B), (0x11038, 0x1103B, VABV),
+    (0x1103C, 0x11041, VBLW), (0x11042, 0x11045, VABV), (0x11046, 0x11046, H),
+    (0x11052, 0x11065, N), (0x11066, 0x1106F, B), (0x11070, 0x11070, VABV), (0x11071, 0x11072, B),
+    (0x11073, 0x11074, VABV), (0x11075, 0x11075, B), (0x1107F, 0x1107F, HN),
+    (0x11080, 0x11081, VMABV), (0x11082, 0x11082, VMPST), (0x11083, 0x110AF, B),
+    (0x110B0, 0x110B0, VPST), (0x110B1, 0x110B1, VPRE), (0x110B2, 0x110B2, VPST),
+    (0x110B3, 0x110B4, VBLW), (0x110B5, 0x110B6, VABV), (0x110B7, 0x110B8, VPST),
+    (0x110B9, 0x110B9, H), (0x110BA, 0x110BA, CMBLW), (0x110C2, 0x110C2, VBLW),
+    (0x11100, 0x11102, VMABV), (0x11103, 0x11126, B), (0x11127, 0x11129, VABV),
+    (0x1112A, 0x1112B, VBLW), (0x1112C, 0x1112C, VPRE), (0x1112D, 0x11130, VABV),
+    (0x11131, 0x11132, VBLW), (0x11133, 0x11133, IS), (0x11134, 0x11134, VABV),
+    (0x11136, 0x1113F, B), (0x11144, 0x11144, B), (0x11145, 0x11146, VPST), (0x11147, 0x11147, B),
+    (0x11150, 0x11172, B), (0x11173, 0x11173, CMBLW), (0x11180, 0x11181, VMABV),
+    (0x11182, 0x11182, VMPST), (0x11183, 0x111B2, B), (0x111B3, 0x111B3, VPST),
+    (0x111B4, 0x111B4, VPRE), (0x111B5, 0x111B5, VPST), (0x111B6, 0x111BB, VBLW),
+    (0x111BC, 0x111BF, VABV), (0x111C0, 0x111C0, H), (0x111C1, 0x111C1, B), (0x111C2, 0x111C3, R),
+    (0x111C9, 0x111C9, FMBLW), (0x111CA, 0x111CA, CMBLW), (0x111CB, 0x111CB, VABV),
+    (0x111CC, 0x111CC, VBLW), (0x111CE, 0x111CE, VPRE), (0x111CF, 0x111CF, VMABV),
+    (0x111D0, 0x111D9, B), (0x111E1, 0x111F4, B), (0x11200, 0x11211, B), (0x11213, 0x1122B, B),
+    (0x1122C, 0x1122E, VPST), (0x1122F, 0x1122F, VBLW), (0x11230, 0x11233, VABV),
+    (0x11234, 0x11234, VMABV), (0x11235, 0x11235, H), (0x11236, 0x11237, CMABV),
+    (0x1123E, 0x1123E, VMABV), (0x1123F, 0x11240, B), (0x11241, 0x11241, VBLW),
+    (0x11280, 0x11286, B), (0x11288, 0x11288, B), (0x1128A, 0x1128D, B), (0x1128F, 0x1129D, B),
+    (0x1129F, 0x112A8, B), (0x112B0, 0x112DE, B), (0x112DF, 0x112DF, VMABV),
+    (0x112E0, 0x112E0, VPST), (0x112E1, 0x112E1, VPRE), (0x112E2, 0x112E2, VPST),
+    (0x112E3, 0x112E4, VBLW), (0x112E5, 0x112E8, VABV), (0x112E9, 0x112E9, CMBLW),
+    (0x112EA, 0x112EA, VBLW), (0x112F0, 0x112F9, B), (0x11300, 0x11303, VMABV),
+    (0x11305, 0x1130C, B), (0x1130F, 0x11310, B), (0x11313, 0x11328, B), (0x1132A, 0x11330, B),
+    (0x11332, 0x11333, B), (0x11335, 0x11339, B), (0x1133B, 0x1133C, CMBLW), (0x1133D, 0x1133D, B),
+    (0x1133E, 0x1133F, VPST), (0x11340, 0x11340, VABV), (0x11341, 0x11344, VPST),
+    (0x11347, 0x11348, VPRE), (0x1134B, 0x1134C, VPRE), (0x1134D, 0x1134D, H),
+    (0x11357, 0x11357, VPST), (0x1135E, 0x11361, B), (0x11362, 0x11363, VPST),
+    (0x11366, 0x1136C, VMABV), (0x11370, 0x11374, VMABV), (0x11400, 0x11434, B),
+    (0x11435, 0x11435, VPST), (0x11436, 0x11436, VPRE), (0x11437, 0x11437, VPST),
+    (0x11438, 0x1143D, VBLW), (0x1143E, 0x1143F, VABV), (0x11440, 0x11441, VPST),
+    (0x11442, 0x11442, H), (0x11443, 0x11444, VMABV), (0x11445, 0x11445, VMPST),
+    (0x11446, 0x11446, CMBLW), (0x11447, 0x11447, B), (0x11450, 0x11459, B),
+    (0x1145E, 0x1145E, FMABV), (0x1145F, 0x1145F, B), (0x11460, 0x11461, CS), (0x11481, 0x114AF, B),
+    (0x114B0, 0x114B0, VPST), (0x114B1, 0x114B1, VPRE), (0x114B2, 0x114B2, VPST),
+    (0x114B3, 0x114B8, VBLW), (0x114B9, 0x114B9, VPRE), (0x114BA, 0x114BA, VABV),
+    (0x114BB, 0x114BC, VPRE), (0x114BD, 0x114BD, VPST), (0x114BE, 0x114BE, VPRE),
+    (0x114BF, 0x114C1, VMABV), (0x114C2, 0x114C2, H), (0x114C3, 0x114C3, CMBLW),
+    (0x114C4, 0x114C4, B), (0x114D0, 0x114D9, B), (0x11580, 0x115AE, B), (0x115AF, 0x115AF, VPST),
+    (0x115B0, 0x115B0, VPRE), (0x115B1, 0x115B1, VPST), (0x115B2, 0x115B5, VBLW),
+    (0x115B8, 0x115BB, VPRE), (0x115BC, 0x115BD, VMABV), (0x115BE, 0x115BE, VMPST),
+    (0x115BF, 0x115BF, H), (0x115C0, 0x115C0, CMBLW), (0x115D8, 0x115DB, B),
+    (0x115DC, 0x115DD, VBLW), (0x11600, 0x1162F, B), (0x11630, 0x11632, VPST),
+    (0x11633, 0x11638, VBLW), (0x11639, 0x1163A, VABV), (0x1163B, 0x1163C, VPST),
+    (0x1163D, 0x1163D, VMABV), (0x1163E, 0x1163E, VMPST), (0x1163F, 0x1163F, H),
+    (0x11640, 0x11640, VABV), (0x11650, 0x11659, B), (0x11680, 0x116AA, B),
+    (0x116AB, 0x116AB, VMABV), (0x116AC, 0x116AC, VMPST), (0x116AD, 0x116AD, VABV),
+    (0x116AE, 0x116AE, VPRE), (0x116AF, 0x116AF, VPST), (0x116B0, 0x116B1, VBLW),
+    (0x116B2, 0x116B5, VABV), (0x116B6, 0x116B6, H), (0x116B7, 0x116B7, CMBLW),
+    (0x116B8, 0x116B8, B), (0x116C0, 0x116C9, B), (0x11700, 0x1171A, B), (0x1171D, 0x1171D, MBLW),
+    (0x1171E, 0x1171E, MPRE), (0x1171F, 0x1171F, MABV), (0x11720, 0x11721, VPST),
+    (0x11722, 0x11723, VABV), (0x11724, 0x11725, VBLW), (0x11726, 0x11726, VPRE),
+    (0x11727, 0x11727, VABV), (0x11728, 0x11728, VBLW), (0x11729, 0x1172B, VABV),
+    (0x11730, 0x1173B, B), (0x11740, 0x11746, B), (0x11800, 0x1182B, B), (0x1182C, 0x1182C, VPST),
+    (0x1182D, 0x1182D, VPRE), (0x1182E, 0x1182E, VPST), (0x1182F, 0x11832, VBLW),
+    (0x11833, 0x11836, VABV), (0x11837, 0x11837, VMABV), (0x11838, 0x11838, VMPST),
+    (0x11839, 0x11839, H), (0x1183A, 0x1183A, CMBLW), (0x11900, 0x11906, B), (0x11909, 0x11909, B),
+    (0x1190C, 0x11913, B), (0x11915, 0x11916, B), (0x11918, 0x1192F, B), (0x11930, 0x11934, VPST),
+    (0x11935, 0x11935, VPRE), (0x11937, 0x11938, VPRE), (0x1193B, 0x1193C, VMABV),
+    (0x1193D, 0x1193D, VPST), (0x1193E, 0x1193E, IS), (0x1193F, 0x1193F, R),
+    (0x11940, 0x11940, MPST), (0x11941, 0x11941, R), (0x11942, 0x11942, MBLW),
+    (0x11943, 0x11943, CMBLW), (0x11950, 0x11959, B), (0x119A0, 0x119A7, B), (0x119AA, 0x119D0, B),
+    (0x119D1, 0x119D1, VPST), (0x119D2, 0x119D2, VPRE), (0x119D3, 0x119D3, VPST),
+    (0x119D4, 0x119D7, VBLW), (0x119DA, 0x119DB, VABV), (0x119DC, 0x119DD, VPST),
+    (0x119DE, 0x119DF, VMPST), (0x119E0, 0x119E0, H), (0x119E1, 0x119E1, B),
+    (0x119E4, 0x119E4, VPRE), (0x11A00, 0x11A00, B), (0x11A01, 0x11A01, VABV),
+    (0x11A02, 0x11A03, VBLW), (0x11A04, 0x11A09, VABV), (0x11A0A, 0x11A0A, VBLW),
+    (0x11A0B, 0x11A32, B), (0x11A33, 0x11A33, FMBLW), (0x11A34, 0x11A34, VBLW),
+    (0x11A35, 0x11A38, VMABV), (0x11A39, 0x11A39, VMPST), (0x11A3A, 0x11A3A, R),
+    (0x11A3B, 0x11A3E, MBLW), (0x11A3F, 0x11A3F, GB), (0x11A45, 0x11A45, GB),
+    (0x11A47, 0x11A47, IS), (0x11A50, 0x11A50, B), (0x11A51, 0x11A51, VABV),
+    (0x11A52, 0x11A53, VBLW), (0x11A54, 0x11A56, VABV), (0x11A57, 0x11A58, VPST),
+    (0x11A59, 0x11A5B, VBLW), (0x11A5C, 0x11A83, B), (0x11A84, 0x11A89, R),
+    (0x11A8A, 0x11A95, FBLW), (0x11A96, 0x11A96, VMABV), (0x11A97, 0x11A97, VMPST),
+    (0x11A98, 0x11A98, CMABV), (0x11A99, 0x11A99, IS), (0x11A9D, 0x11A9D, B), (0x11C00, 0x11C08, B),
+    (0x11C0A, 0x11C2E, B), (0x11C2F, 0x11C2F, VPST), (0x11C30, 0x11C31, VABV),
+    (0x11C32, 0x11C36, VBLW), (0x11C38, 0x11C3B, VABV), (0x11C3C, 0x11C3D, VMABV),
+    (0x11C3E, 0x11C3E, VMPST), (0x11C3F, 0x11C3F, H), (0x11C40, 0x11C40, B), (0x11C50, 0x11C6C, B),
+    (0x11C72, 0x11C8F, B), (0x11C92, 0x11CA7, SUB), (0x11CA9, 0x11CAF, SUB),
+    (0x11CB0, 0x11CB0, VBLW), (0x11CB1, 0x11CB1, VPRE), (0x11CB2, 0x11CB2, VBLW),
+    (0x11CB3, 0x11CB3, VABV), (0x11CB4, 0x11CB4, VPST), (0x11CB5, 0x11CB6, VMABV),
+    (0x11D00, 0x11D06, B), (0x11D08, 0x11D09, B), (0x11D0B, 0x11D30, B), (0x11D31, 0x11D35, VABV),
+    (0x11D36, 0x11D36, VBLW), (0x11D3A, 0x11D3A, VABV), (0x11D3C, 0x11D3D, VABV),
+    (0x11D3F, 0x11D3F, VABV), (0x11D40, 0x11D41, VMABV), (0x11D42, 0x11D42, CMBLW),
+    (0x11D43, 0x11D43, VABV), (0x11D44, 0x11D44, VBLW), (0x11D45, 0x11D45, IS),
+    (0x11D46, 0x11D46, R), (0x11D47, 0x11D47, MBLW), (0x11D50, 0x11D59, B), (0x11D60, 0x11D65, B),
+    (0x11D67, 0x11D68, B), (0x11D6A, 0x11D89, B), (0x11D8A, 0x11D8E, VPST),
+    (0x11D90, 0x11D91, VABV), (0x11D93, 0x11D94, VPST), (0x11D95, 0x11D95, VMABV),
+    (0x11D96, 0x11D96, VMPST), (0x11D97, 0x11D97, IS), (0x11DA0, 0x11DA9, B), (0x11EE0, 0x11EF1, B),
+    (0x11EF2, 0x11EF2, GB), (0x11EF3, 0x11EF3, VABV), (0x11EF4, 0x11EF4, VBLW),
+    (0x11EF5, 0x11EF5, VPRE), (0x11EF6, 0x11EF6, VPST), (0x11F00, 0x11F01, VMABV),
+    (0x11F02, 0x11F02, R), (0x11F03, 0x11F03, VMPST), (0x11F04, 0x11F10, B), (0x11F12, 0x11F33, B),
+    (0x11F34, 0x11F35, VPST), (0x11F36, 0x11F37, VABV), (0x11F38, 0x11F3A, VBLW),
+    (0x11F3E, 0x11F3F, VPRE), (0x11F40, 0x11F40, VABV), (0x11F41, 0x11F41, VPST),
+    (0x11F42, 0x11F42, IS), (0x11F50, 0x11F59, B), (0x13000, 0x1342F, G), (0x13430, 0x13436, J),
+    (0x13437, 0x13437, SB), (0x13438, 0x13438, SE), (0x13439, 0x1343B, J), (0x1343C, 0x1343C, SB),
+    (0x1343D, 0x1343D, SE), (0x1343E, 0x1343E, SB), (0x1343F, 0x1343F, SE), (0x13440, 0x13440, HR),
+    (0x13441, 0x13446, G), (0x13447, 0x13455, HM), (0xE0100, 0xE01EF, CGJ),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::shape::{ShapeOptions, Shaper};
+    use crate::testing::{layout, ligature_subst, lookup, single_subst, TestFont};
+    use crate::FontRef;
+
+    #[test]
+    fn cluster_grammar() {
+        let cluster = |categories: &[u8]| Grammar(categories).cluster(0);
+        assert_eq!(
+            cluster(&[B, H, B, VPRE, VMPST, ZWNJ, B]),
+            (6, STANDARD_CLUSTER)
+        );
+        assert_eq!(cluster(&[B, IS, B]), (3, STANDARD_CLUSTER));
+        assert_eq!(cluster(&[B, IS, O]), (2, VIRAMA_TERMINATED_CLUSTER));
+        assert_eq!(cluster(&[N, HN, N, B]), (3, NUMERAL_CLUSTER));
+        assert_eq!(cluster(&[SB, G, J, G, SE]), (5, HIEROGLYPH_CLUSTER));
+        assert_eq!(cluster(&[R, VPST]), (2, BROKEN_CLUSTER));
+        assert_eq!(cluster(&[FMPST, B]), (1, NON_CLUSTER));
+    }
+
+    fn shape(font: &[u8], text: &str) -> Vec<GlyphId> {
+        let font = FontRef::from_index(font, 0).unwrap();
+        let options = ShapeOptions {
+            script: Tag::new(b"java"),
+            ..Default::default()
+        };
+        Shaper::new(&font)
+            .shape(text, &options)
+            .iter()
+            .map(|glyph| glyph.glyph_id)
+            .collect()
+    }
+
+    // Javanese ka, taling, pangkon, ra and tarung followed by a dotted
+    // circle.
+    const CHARS: &str = "\u{A98F}\u{A9BA}\u{A9C0}\u{A9AB}\u{A9B4}\u{25CC}";
+
+    #[test]
+    fn pre_base_vowels() {
+        let data = TestFont::new(7).chars(CHARS).build();
+        assert_eq!(shape(&data, "\u{A98F}\u{A9BA}"), [2, 1]);
+        // The vowel sign moves after the last halant.
+        assert_eq!(
+            shape(&data, "\u{A98F}\u{A9C0}\u{A98F}\u{A9BA}"),
+            [1, 3, 2, 1]
+        );
+        // A dotted circle is inserted into a broken cluster.
+        assert_eq!(shape(&data, "\u{A9BA}"), [2, 6]);
+    }
+
+    #[test]
+    fn repha() {
+        let gsub = layout(
+            b"java",
+            &[(b"rphf", &[0])],
+            &[lookup(4, 0, &[ligature_subst(&[(&[4, 3], 7)])])],
+        );
+        let data = TestFont::new(8).chars(CHARS).table(b"GSUB", gsub).build();
+        // The repha moves before the first post-base glyph.
+        assert_eq!(shape(&data, "\u{A9AB}\u{A9C0}\u{A98F}\u{A9B4}"), [1, 7, 5]);
+        // Or to the end of the cluster.
+        assert_eq!(shape(&data, "\u{A9AB}\u{A9C0}\u{A98F}"), [1, 7]);
+    }
+
+    #[test]
+    fn topographical_forms() {
+        let gsub = layout(
+            b"java",
+            &[(b"init", &[0]), (b"fina", &[1])],
+            &[
+                lookup(1, 0, &[single_subst(&[(1, 7)])]),
+                lookup(1, 0, &[single_subst(&[(1, 8)])]),
+            ],
+        );
+        let data = TestFont::new(9).chars(CHARS).table(b"GSUB", gsub).build();
+        assert_eq!(shape(&data, "\u{A98F}\u{A98F}"), [7, 8]);
+        assert_eq!(shape(&data, "\u{A98F}"), [1]);
+    }
+}
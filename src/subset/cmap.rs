@@ -0,0 +1,292 @@
+//! Subsetting for the character to glyph mapping table.
+
+use super::Plan;
+use crate::cmap::Cmap;
+use crate::otl::GlyphSet;
+use crate::parse_prelude::*;
+use core::convert::TryFrom;
+use std::vec::Vec;
+
+/// Returns the offset of the format 14 subtable.
+fn variations_offset(data: &[u8]) -> Option<usize> {
+    Cmap::new(data)
+        .records()
+        .iter()
+        .find(|record| {
+            (record.platform_id, record.encoding_id) == (0, 5)
+                && Buffer::new(data).read_u16(record.offset as usize) == Some(14)
+        })
+        .map(|record| record.offset as usize)
+}
+
+/// Invokes the specified closure for each variation selector record in a
+/// format 14 subtable with the selector and the offsets of the default and
+/// non-default tables.
+fn variation_records(b: &Buffer, mut f: impl FnMut(u32, usize, usize)) -> Option<()> {
+    let count = b.read_u32(6)? as usize;
+    for i in 0..count {
+        let mut c = b.cursor_at(10 + i * 11)?;
+        let selector = c.read_u24()?;
+        let default_offset = c.read_u32()? as usize;
+        let non_default_offset = c.read_u32()? as usize;
+        f(selector, default_offset, non_default_offset);
+    }
+    Some(())
+}
+
+/// Invokes the specified closure for each mapping in a non-default
+/// variation table.
+fn non_default_mappings(b: &Buffer, offset: usize, mut f: impl FnMut(u32, GlyphId)) -> Option<()> {
+    if offset == 0 {
+        return Some(());
+    }
+    let count = b.read_u32(offset)? as usize;
+    let mut c = b.cursor_at(offset + 4)?;
+    for _ in 0..count {
+        let codepoint = c.read_u24()?;
+        f(codepoint, c.read_u16()?);
+    }
+    Some(())
+}
+
+/// Adds the glyphs for the non-default variation sequences of the specified
+/// codepoints to the glyph set.
+pub fn close_variants(data: &[u8], codepoints: &[u32], glyphs: &mut GlyphSet) {
+    let b = match variations_offset(data).and_then(|offset| Buffer::with_offset(data, offset)) {
+        Some(b) => b,
+        None => return,
+    };
+    variation_records(&b, |_, _, non_default_offset| {
+        non_default_mappings(&b, non_default_offset, |codepoint, glyph_id| {
+            if codepoints.binary_search(&codepoint).is_ok() {
+                glyphs.insert(glyph_id);
+            }
+        });
+    });
+}
+
+/// Returns the character to glyph mapping table for the subset.
+pub fn subset(data: &[u8], plan: &Plan) -> Option<Vec<u8>> {
+    let is_symbol = Cmap::new(data)
+        .records()
+        .iter()
+        .any(|record| (record.platform_id, record.encoding_id) == (3, 0));
+    let mapping = plan.mapping().collect::<Vec<_>>();
+    let format4 = format4(&mapping);
+    let format12 = if mapping.iter().any(|m| m.0 > 0xFFFF) || format4.is_none() {
+        Some(format12(&mapping))
+    } else {
+        None
+    };
+    let format14 = match variations_offset(data) {
+        Some(offset) => format14(Buffer::with_offset(data, offset)?, plan)?,
+        None => None,
+    };
+    let mut records = Vec::new();
+    if let Some(format4) = &format4 {
+        if !is_symbol {
+            records.push((0, 3, format4));
+        }
+    }
+    if let Some(format12) = &format12 {
+        records.push((0, 4, format12));
+    }
+    if let Some(format14) = &format14 {
+        records.push((0, 5, format14));
+    }
+    if let Some(format4) = &format4 {
+        records.push((3, if is_symbol { 0 } else { 1 }, format4));
+    }
+    if let Some(format12) = &format12 {
+        records.push((3, 10, format12));
+    }
+    let mut new_data = Vec::new();
+    new_data.extend_from_slice(&0u16.to_be_bytes());
+    new_data.extend_from_slice(&(records.len() as u16).to_be_bytes());
+    let mut offset = 4 + records.len() * 8;
+    let mut subtables: Vec<&Vec<u8>> = Vec::new();
+    let mut subtable_offsets = Vec::new();
+    for (platform_id, encoding_id, subtable) in &records {
+        let subtable_offset = match subtables.iter().position(|s| core::ptr::eq(*s, *subtable)) {
+            Some(index) => subtable_offsets[index],
+            None => {
+                subtables.push(subtable);
+                subtable_offsets.push(offset);
+                offset += subtable.len();
+                offset - subtable.len()
+            }
+        };
+        new_data.extend_from_slice(&(*platform_id as u16).to_be_bytes());
+        new_data.extend_from_slice(&(*encoding_id as u16).to_be_bytes());
+        new_data.extend_from_slice(&(subtable_offset as u32).to_be_bytes());
+    }
+    for subtable in subtables {
+        new_data.extend_from_slice(subtable);
+    }
+    Some(new_data)
+}
+
+/// Returns runs of consecutive codepoints that map to consecutive glyphs as
+/// (first codepoint, last codepoint, first glyph) triples.
+fn runs(mapping: &[(u32, GlyphId)]) -> Vec<(u32, u32, GlyphId)> {
+    let mut runs: Vec<(u32, u32, GlyphId)> = Vec::new();
+    for &(codepoint, glyph_id) in mapping {
+        if let Some(run) = runs.last_mut() {
+            if codepoint == run.1 + 1 && glyph_id as u32 == run.2 as u32 + (codepoint - run.0) {
+                run.1 = codepoint;
+                continue;
+            }
+        }
+        runs.push((codepoint, codepoint, glyph_id));
+    }
+    runs
+}
+
+/// Returns a format 4 subtable for the mappings in the basic multilingual
+/// plane or `None` if the subtable would be too large.
+fn format4(mapping: &[(u32, GlyphId)]) -> Option<Vec<u8>> {
+    let end = mapping.partition_point(|m| m.0 < 0xFFFF);
+    let mut segments = runs(&mapping[..end]);
+    segments.push((0xFFFF, 0xFFFF, 0));
+    let seg_count = segments.len();
+    let len = u16::try_from(16 + seg_count * 8).ok()?;
+    let entry_selector = 15 - (seg_count as u16).leading_zeros() as u16;
+    let search_range = 2u16 << entry_selector;
+    let mut data = Vec::with_capacity(len as usize);
+    for value in [
+        4,
+        len,
+        0,
+        seg_count as u16 * 2,
+        search_range,
+        entry_selector,
+        seg_count as u16 * 2 - search_range,
+    ] {
+        data.extend_from_slice(&value.to_be_bytes());
+    }
+    for segment in &segments {
+        data.extend_from_slice(&(segment.1 as u16).to_be_bytes());
+    }
+    data.extend_from_slice(&0u16.to_be_bytes());
+    for segment in &segments {
+        data.extend_from_slice(&(segment.0 as u16).to_be_bytes());
+    }
+    for segment in &segments {
+        let delta = if segment.0 == 0xFFFF {
+            1
+        } else {
+            segment.2.wrapping_sub(segment.0 as u16)
+        };
+        data.extend_from_slice(&delta.to_be_bytes());
+    }
+    for _ in &segments {
+        data.extend_from_slice(&0u16.to_be_bytes());
+    }
+    Some(data)
+}
+
+/// Returns a format 12 subtable for all mappings.
+fn format12(mapping: &[(u32, GlyphId)]) -> Vec<u8> {
+    let groups = runs(mapping);
+    let len = 16 + groups.len() * 12;
+    let mut data = Vec::with_capacity(len);
+    data.extend_from_slice(&12u16.to_be_bytes());
+    data.extend_from_slice(&0u16.to_be_bytes());
+    data.extend_from_slice(&(len as u32).to_be_bytes());
+    data.extend_from_slice(&0u32.to_be_bytes());
+    data.extend_from_slice(&(groups.len() as u32).to_be_bytes());
+    for (first, last, glyph_id) in groups {
+        data.extend_from_slice(&first.to_be_bytes());
+        data.extend_from_slice(&last.to_be_bytes());
+        data.extend_from_slice(&(glyph_id as u32).to_be_bytes());
+    }
+    data
+}
+
+/// Returns a format 14 subtable with the variation sequences for the
+/// codepoints in the subset or `None` if no sequences remain.
+fn format14(b: Buffer, plan: &Plan) -> Option<Option<Vec<u8>>> {
+    let mut records = Vec::new();
+    let mut result = Some(());
+    variation_records(&b, |selector, default_offset, non_default_offset| {
+        let mut defaults = Vec::new();
+        if default_offset != 0 {
+            let count = b.read_u32(default_offset).unwrap_or(0) as usize;
+            for i in 0..count {
+                let range = default_offset + 4 + i * 4;
+                let (start, additional) = match (b.read_u24(range), b.read_u8(range + 3)) {
+                    (Some(start), Some(additional)) => (start, additional as u32),
+                    _ => {
+                        result = None;
+                        return;
+                    }
+                };
+                let first = plan.codepoints.partition_point(|&c| c < start);
+                let last = plan
+                    .codepoints
+                    .partition_point(|&c| c <= start + additional);
+                defaults.extend_from_slice(&plan.codepoints[first..last]);
+            }
+        }
+        let mut non_defaults = Vec::new();
+        let found = non_default_mappings(&b, non_default_offset, |codepoint, glyph_id| {
+            if plan.codepoints.binary_search(&codepoint).is_ok() {
+                if let Some(glyph_id) = plan.map(glyph_id) {
+                    non_defaults.push((codepoint, glyph_id));
+                }
+            }
+        });
+        if found.is_none() {
+            result = None;
+        }
+        if !defaults.is_empty() || !non_defaults.is_empty() {
+            records.push((selector, defaults, non_defaults));
+        }
+    })?;
+    result?;
+    if records.is_empty() {
+        return Some(None);
+    }
+    let mut data = Vec::new();
+    let mut tables = Vec::new();
+    let base = 10 + records.len() * 11;
+    data.extend_from_slice(&14u16.to_be_bytes());
+    data.extend_from_slice(&0u32.to_be_bytes());
+    data.extend_from_slice(&(records.len() as u32).to_be_bytes());
+    for (selector, defaults, non_defaults) in &records {
+        data.extend_from_slice(&selector.to_be_bytes()[1..]);
+        if defaults.is_empty() {
+            data.extend_from_slice(&0u32.to_be_bytes());
+        } else {
+            data.extend_from_slice(&((base + tables.len()) as u32).to_be_bytes());
+            let mut ranges: Vec<(u32, u8)> = Vec::new();
+            for &codepoint in defaults {
+                match ranges.last_mut() {
+                    Some(range) if range.1 < 255 && codepoint == range.0 + range.1 as u32 + 1 => {
+                        range.1 += 1
+                    }
+                    _ => ranges.push((codepoint, 0)),
+                }
+            }
+            tables.extend_from_slice(&(ranges.len() as u32).to_be_bytes());
+            for (start, additional) in ranges {
+                tables.extend_from_slice(&start.to_be_bytes()[1..]);
+                tables.push(additional);
+            }
+        }
+        if non_defaults.is_empty() {
+            data.extend_from_slice(&0u32.to_be_bytes());
+        } else {
+            data.extend_from_slice(&((base + tables.len()) as u32).to_be_bytes());
+            tables.extend_from_slice(&(non_defaults.len() as u32).to_be_bytes());
+            for (codepoint, glyph_id) in non_defaults {
+                tables.extend_from_slice(&codepoint.to_be_bytes()[1..]);
+                tables.extend_from_slice(&glyph_id.to_be_bytes());
+            }
+        }
+    }
+    data.extend_from_slice(&tables);
+    let len = data.len() as u32;
+    data[2..6].copy_from_slice(&len.to_be_bytes());
+    Some(Some(data))
+}
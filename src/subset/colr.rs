@@ -0,0 +1,562 @@
+//! Subsetting for the color and color palette tables.
+
+use super::layout::{delta_set_index_map, item_variation_store};
+use super::serialize::{Object, ObjectId, Serializer};
+use super::Plan;
+use crate::otl::GlyphSet;
+use crate::parse_prelude::*;
+use std::collections::{HashMap, HashSet};
+use std::vec::Vec;
+
+/// Subset color table and the mapping from original to subset palette
+/// entry indices.
+pub struct ColrSubset {
+    pub colr: Vec<u8>,
+    pub palette_map: Vec<Option<u16>>,
+}
+
+/// Child of a paint table.
+#[derive(Copy, Clone)]
+enum Child {
+    Paint,
+    ColorLine { is_var: bool },
+    Affine { is_var: bool },
+}
+
+/// Returns the size and the 24-bit child offsets of a paint table with the
+/// specified format.
+fn paint_layout(format: u8) -> Option<(usize, &'static [(usize, Child)])> {
+    use Child::*;
+    const PAINT: &[(usize, Child)] = &[(1, Paint)];
+    Some(match format {
+        1 => (6, &[]),
+        2 => (5, &[]),
+        3 => (9, &[]),
+        4 | 6 => (16, &[(1, ColorLine { is_var: false })]),
+        5 | 7 => (20, &[(1, ColorLine { is_var: true })]),
+        8 => (12, &[(1, ColorLine { is_var: false })]),
+        9 => (16, &[(1, ColorLine { is_var: true })]),
+        10 => (6, PAINT),
+        11 => (3, &[]),
+        12 => (7, &[(1, Paint), (4, Affine { is_var: false })]),
+        13 => (7, &[(1, Paint), (4, Affine { is_var: true })]),
+        20 | 24 => (6, PAINT),
+        14 | 16 | 28 => (8, PAINT),
+        21 | 22 | 25 | 26 => (10, PAINT),
+        15 | 17 | 18 | 29 | 30 => (12, PAINT),
+        23 | 27 => (14, PAINT),
+        19 | 31 => (16, PAINT),
+        32 => (8, &[(1, Paint), (5, Paint)]),
+        _ => return None,
+    })
+}
+
+/// Returns the size of a color stop.
+fn color_stop_size(is_var: bool) -> usize {
+    if is_var {
+        10
+    } else {
+        6
+    }
+}
+
+/// Item reachable from a paint graph.
+#[derive(Copy, Clone)]
+enum Visit {
+    Glyph(GlyphId),
+    Palette(u16),
+}
+
+#[derive(Copy, Clone)]
+struct Colr<'a> {
+    b: Buffer<'a>,
+    version: u16,
+}
+
+impl<'a> Colr<'a> {
+    fn new(data: &'a [u8]) -> Option<Self> {
+        let b = Buffer::new(data);
+        let version = b.read_u16(0)?;
+        Some(Self { b, version })
+    }
+
+    fn offset32(&self, offset: usize) -> Option<usize> {
+        if self.version < 1 {
+            return None;
+        }
+        match self.b.read_u32(offset)? {
+            0 => None,
+            offset => Some(offset as usize),
+        }
+    }
+
+    /// Returns an iterator over the base glyph records as (glyph, first
+    /// layer, layer count) triples.
+    fn base_glyphs(&self) -> impl Iterator<Item = (GlyphId, usize, usize)> + 'a {
+        let b = self.b;
+        let count = b.read_u16(2).unwrap_or(0) as usize;
+        let offset = b.read_u32(4).unwrap_or(0) as usize;
+        (0..count).filter_map(move |i| {
+            let record = offset + i * 6;
+            Some((
+                b.read_u16(record)?,
+                b.read_u16(record + 2)? as usize,
+                b.read_u16(record + 4)? as usize,
+            ))
+        })
+    }
+
+    /// Returns the layer record at the specified index as a (glyph, palette
+    /// index) pair.
+    fn layer(&self, index: usize) -> Option<(GlyphId, u16)> {
+        let offset = self.b.read_u32(8)? as usize + index * 4;
+        Some((self.b.read_u16(offset)?, self.b.read_u16(offset + 2)?))
+    }
+
+    /// Returns an iterator over the base glyph paint records as (glyph,
+    /// paint offset) pairs.
+    fn base_paints(&self) -> impl Iterator<Item = (GlyphId, usize)> + 'a {
+        let b = self.b;
+        let base = self.offset32(14).unwrap_or(0);
+        let count = if base != 0 {
+            b.read_u32(base).unwrap_or(0) as usize
+        } else {
+            0
+        };
+        (0..count).filter_map(move |i| {
+            let record = base + 4 + i * 6;
+            Some((b.read_u16(record)?, base + b.read_u32(record + 2)? as usize))
+        })
+    }
+
+    /// Returns the offset of the paint in the layer list at the specified
+    /// index.
+    fn paint_layer(&self, index: usize) -> Option<usize> {
+        let base = self.offset32(18)?;
+        Some(base + self.b.read_u32(base + 4 + index * 4)? as usize)
+    }
+
+    /// Visits the glyphs and palette indices reachable from the paint at
+    /// the specified offset.
+    fn visit(&self, offset: usize, visited: &mut HashSet<usize>, f: &mut impl FnMut(Visit)) {
+        if !visited.insert(offset) {
+            return;
+        }
+        let b = &self.b;
+        let format = match b.read_u8(offset) {
+            Some(format) => format,
+            None => return,
+        };
+        match format {
+            1 => {
+                let count = b.read_u8(offset + 1).unwrap_or(0) as usize;
+                let first = b.read_u32(offset + 2).unwrap_or(0) as usize;
+                for index in first..first + count {
+                    if let Some(layer) = self.paint_layer(index) {
+                        self.visit(layer, visited, f);
+                    }
+                }
+            }
+            2 | 3 => {
+                if let Some(index) = b.read_u16(offset + 1) {
+                    f(Visit::Palette(index));
+                }
+            }
+            10 => {
+                if let Some(glyph_id) = b.read_u16(offset + 4) {
+                    f(Visit::Glyph(glyph_id));
+                }
+            }
+            11 => {
+                if let Some(glyph_id) = b.read_u16(offset + 1) {
+                    f(Visit::Glyph(glyph_id));
+                }
+            }
+            _ => {}
+        }
+        let children = match paint_layout(format) {
+            Some((_, children)) => children,
+            None => return,
+        };
+        for &(pos, child) in children {
+            let child_offset = match b.read_u24(offset + pos) {
+                Some(0) | None => continue,
+                Some(child_offset) => offset + child_offset as usize,
+            };
+            match child {
+                Child::Paint => self.visit(child_offset, visited, f),
+                Child::ColorLine { is_var } => {
+                    let count = b.read_u16(child_offset + 1).unwrap_or(0) as usize;
+                    for i in 0..count {
+                        let stop = child_offset + 3 + i * color_stop_size(is_var);
+                        if let Some(index) = b.read_u16(stop + 2) {
+                            f(Visit::Palette(index));
+                        }
+                    }
+                }
+                Child::Affine { .. } => {}
+            }
+        }
+    }
+}
+
+/// Adds the glyphs that are referenced by the color outlines of the glyphs
+/// in the set.
+pub fn close_glyphs(data: &[u8], glyphs: &mut GlyphSet) {
+    let colr = match Colr::new(data) {
+        Some(colr) => colr,
+        None => return,
+    };
+    for (glyph_id, first, count) in colr.base_glyphs() {
+        if glyphs.contains(glyph_id) {
+            for index in first..first + count {
+                if let Some((layer_glyph_id, _)) = colr.layer(index) {
+                    glyphs.insert(layer_glyph_id);
+                }
+            }
+        }
+    }
+    let mut visited = HashSet::new();
+    loop {
+        let mut added = Vec::new();
+        for (glyph_id, offset) in colr.base_paints() {
+            if glyphs.contains(glyph_id) {
+                colr.visit(offset, &mut visited, &mut |visit| {
+                    if let Visit::Glyph(glyph_id) = visit {
+                        added.push(glyph_id);
+                    }
+                });
+            }
+        }
+        let mut changed = false;
+        for glyph_id in added {
+            changed |= glyphs.insert(glyph_id);
+        }
+        if !changed {
+            break;
+        }
+    }
+}
+
+/// Returns the color table for the subset.
+pub fn subset(data: &[u8], plan: &Plan) -> Option<ColrSubset> {
+    let colr = Colr::new(data)?;
+    let mut palette_indices = Vec::new();
+    let base_glyphs = colr
+        .base_glyphs()
+        .filter(|base| plan.map(base.0).is_some())
+        .collect::<Vec<_>>();
+    for &(_, first, count) in &base_glyphs {
+        for index in first..first + count {
+            palette_indices.push(colr.layer(index)?.1);
+        }
+    }
+    let base_paints = colr
+        .base_paints()
+        .filter(|base| plan.map(base.0).is_some())
+        .collect::<Vec<_>>();
+    let mut visited = HashSet::new();
+    for &(_, offset) in &base_paints {
+        colr.visit(offset, &mut visited, &mut |visit| {
+            if let Visit::Palette(index) = visit {
+                palette_indices.push(index);
+            }
+        });
+    }
+    palette_indices.retain(|&index| index != 0xFFFF);
+    palette_indices.sort_unstable();
+    palette_indices.dedup();
+    let mut palette_map = std::vec![None; palette_indices.last().map_or(0, |&i| i as usize + 1)];
+    for (new_index, &index) in palette_indices.iter().enumerate() {
+        palette_map[index as usize] = Some(new_index as u16);
+    }
+    let mut writer = Writer {
+        colr,
+        plan,
+        palette_map: &palette_map,
+        s: Serializer::new(true),
+        paints: HashMap::new(),
+        active: HashSet::new(),
+        layers: Vec::new(),
+        layer_ranges: HashMap::new(),
+    };
+    let mut header = Object::new();
+    header.u16(colr.version.min(1));
+    header.u16(base_glyphs.len() as u16);
+    if base_glyphs.is_empty() {
+        header.offset32(None);
+        header.offset32(None);
+        header.u16(0);
+    } else {
+        let mut records = Object::new();
+        let mut layers = Object::new();
+        let mut num_layers = 0;
+        for &(glyph_id, first, count) in &base_glyphs {
+            records.u16(plan.map(glyph_id)?);
+            records.u16(num_layers);
+            records.u16(count as u16);
+            for index in first..first + count {
+                let (glyph_id, palette_index) = colr.layer(index)?;
+                layers.u16(plan.map(glyph_id).unwrap_or(0));
+                layers.u16(writer.palette_index(palette_index));
+            }
+            num_layers = num_layers.checked_add(count as u16)?;
+        }
+        let records = writer.s.add(records);
+        let layers = writer.s.add(layers);
+        header.offset32(Some(records));
+        header.offset32(Some(layers));
+        header.u16(num_layers);
+    }
+    if colr.version >= 1 {
+        let mut list = Object::new();
+        list.u32(base_paints.len() as u32);
+        for &(glyph_id, offset) in &base_paints {
+            list.u16(plan.map(glyph_id)?);
+            let paint = writer.paint(offset)?;
+            list.offset32(Some(paint));
+        }
+        let base_glyph_list = writer.s.add(list);
+        let layer_list = if writer.layers.is_empty() {
+            None
+        } else {
+            let mut list = Object::new();
+            list.u32(writer.layers.len() as u32);
+            for &paint in &writer.layers {
+                list.offset32(Some(paint));
+            }
+            Some(writer.s.add(list))
+        };
+        let clip_list = match colr.offset32(22) {
+            Some(offset) => writer.clip_list(offset),
+            None => None,
+        };
+        let var_index_map = match colr.offset32(26) {
+            Some(offset) => Some(delta_set_index_map(&mut writer.s, &colr.b, offset)?),
+            None => None,
+        };
+        let ivs = match colr.offset32(30) {
+            Some(offset) => Some(item_variation_store(&mut writer.s, &colr.b, offset)?),
+            None => None,
+        };
+        header.offset32(Some(base_glyph_list));
+        header.offset32(layer_list);
+        header.offset32(clip_list);
+        header.offset32(var_index_map);
+        header.offset32(ivs);
+    }
+    let root = writer.s.add(header);
+    Some(ColrSubset {
+        colr: writer.s.pack(root)?,
+        palette_map,
+    })
+}
+
+struct Writer<'a> {
+    colr: Colr<'a>,
+    plan: &'a Plan,
+    palette_map: &'a [Option<u16>],
+    s: Serializer,
+    paints: HashMap<usize, ObjectId>,
+    active: HashSet<usize>,
+    layers: Vec<ObjectId>,
+    layer_ranges: HashMap<(usize, usize), u32>,
+}
+
+impl Writer<'_> {
+    fn palette_index(&self, index: u16) -> u16 {
+        self.palette_map
+            .get(index as usize)
+            .copied()
+            .flatten()
+            .unwrap_or(0xFFFF)
+    }
+
+    fn paint(&mut self, offset: usize) -> Option<ObjectId> {
+        if let Some(id) = self.paints.get(&offset) {
+            return Some(*id);
+        }
+        // Paint graphs must be acyclic.
+        if !self.active.insert(offset) {
+            return None;
+        }
+        let b = self.colr.b;
+        let format = b.read_u8(offset)?;
+        let (size, children) = paint_layout(format)?;
+        let mut data = b.read_bytes(offset, size)?.to_vec();
+        match format {
+            1 => {
+                let count = data[1] as usize;
+                let first = b.read_u32(offset + 2)? as usize;
+                let new_first = match self.layer_ranges.get(&(first, count)) {
+                    Some(new_first) => *new_first,
+                    None => {
+                        let mut paints = Vec::with_capacity(count);
+                        for index in first..first + count {
+                            let layer = self.colr.paint_layer(index)?;
+                            paints.push(self.paint(layer)?);
+                        }
+                        let new_first = self.layers.len() as u32;
+                        self.layers.extend_from_slice(&paints);
+                        self.layer_ranges.insert((first, count), new_first);
+                        new_first
+                    }
+                };
+                data[2..6].copy_from_slice(&new_first.to_be_bytes());
+            }
+            2 | 3 => {
+                let index = self.palette_index(b.read_u16(offset + 1)?);
+                data[1..3].copy_from_slice(&index.to_be_bytes());
+            }
+            10 => {
+                let glyph_id = self.plan.map(b.read_u16(offset + 4)?).unwrap_or(0);
+                data[4..6].copy_from_slice(&glyph_id.to_be_bytes());
+            }
+            11 => {
+                let glyph_id = self.plan.map(b.read_u16(offset + 1)?).unwrap_or(0);
+                data[1..3].copy_from_slice(&glyph_id.to_be_bytes());
+            }
+            _ => {}
+        }
+        let mut links = Vec::new();
+        for &(pos, child) in children {
+            let child_offset = b.read_u24(offset + pos)? as usize;
+            data[pos..pos + 3].copy_from_slice(&[0; 3]);
+            if child_offset == 0 {
+                continue;
+            }
+            let child_offset = offset + child_offset;
+            let id = match child {
+                Child::Paint => self.paint(child_offset)?,
+                Child::ColorLine { is_var } => self.color_line(child_offset, is_var)?,
+                Child::Affine { is_var } => {
+                    let size = if is_var { 28 } else { 24 };
+                    self.s.add_bytes(b.read_bytes(child_offset, size)?)
+                }
+            };
+            links.push((pos, id));
+        }
+        let mut object = Object::new();
+        object.bytes(&data);
+        for (pos, id) in links {
+            object.set_offset(pos, 3, id);
+        }
+        let id = self.s.add(object);
+        self.active.remove(&offset);
+        self.paints.insert(offset, id);
+        Some(id)
+    }
+
+    fn color_line(&mut self, offset: usize, is_var: bool) -> Option<ObjectId> {
+        let b = self.colr.b;
+        let count = b.read_u16(offset + 1)? as usize;
+        let stop_size = color_stop_size(is_var);
+        let mut data = b.read_bytes(offset, 3 + count * stop_size)?.to_vec();
+        for i in 0..count {
+            let pos = 3 + i * stop_size + 2;
+            let index = u16::from_be_bytes([data[pos], data[pos + 1]]);
+            data[pos..pos + 2].copy_from_slice(&self.palette_index(index).to_be_bytes());
+        }
+        Some(self.s.add_bytes(&data))
+    }
+
+    /// Rebuilds the clip list, merging the clip ranges of consecutive
+    /// glyphs in the subset that share a clip box.
+    fn clip_list(&mut self, offset: usize) -> Option<ObjectId> {
+        let b = self.colr.b;
+        let count = b.read_u32(offset + 1)? as usize;
+        let mut clips = HashMap::new();
+        for i in 0..count {
+            let record = offset + 5 + i * 7;
+            let start = b.read_u16(record)?;
+            let end = b.read_u16(record + 2)?;
+            let clip_box = offset + b.read_u24(record + 4)? as usize;
+            for glyph_id in start..=end {
+                if let Some(glyph_id) = self.plan.map(glyph_id) {
+                    clips.insert(glyph_id, clip_box);
+                }
+            }
+        }
+        let mut glyph_ids = clips.keys().copied().collect::<Vec<_>>();
+        glyph_ids.sort_unstable();
+        let mut ranges: Vec<(GlyphId, GlyphId, usize)> = Vec::new();
+        for glyph_id in glyph_ids {
+            let clip_box = clips[&glyph_id];
+            match ranges.last_mut() {
+                Some(range) if range.1 + 1 == glyph_id && range.2 == clip_box => range.1 = glyph_id,
+                _ => ranges.push((glyph_id, glyph_id, clip_box)),
+            }
+        }
+        let mut list = Object::new();
+        list.u8(1);
+        list.u32(ranges.len() as u32);
+        for (start, end, clip_box) in ranges {
+            let size = if b.read_u8(clip_box)? == 2 { 13 } else { 9 };
+            let clip_box = self.s.add_bytes(b.read_bytes(clip_box, size)?);
+            list.u16(start);
+            list.u16(end);
+            list.offset24(Some(clip_box));
+        }
+        Some(self.s.add(list))
+    }
+}
+
+/// Returns the color palette table with the palette entries that are used
+/// by the subset color table.
+pub fn subset_cpal(data: &[u8], palette_map: &[Option<u16>]) -> Option<Vec<u8>> {
+    let b = Buffer::new(data);
+    let version = b.read_u16(0)?;
+    let num_entries = b.read_u16(2)? as usize;
+    let num_palettes = b.read_u16(4)? as usize;
+    let records_offset = b.read_u32(8)? as usize;
+    let entries = palette_map
+        .iter()
+        .enumerate()
+        .filter(|(index, new_index)| new_index.is_some() && *index < num_entries)
+        .map(|(index, _)| index)
+        .collect::<Vec<_>>();
+    let header_len = 12 + num_palettes * 2 + if version >= 1 { 12 } else { 0 };
+    let mut records = Vec::new();
+    let mut new_data = Vec::new();
+    new_data.extend_from_slice(&version.to_be_bytes());
+    new_data.extend_from_slice(&(entries.len() as u16).to_be_bytes());
+    new_data.extend_from_slice(&(num_palettes as u16).to_be_bytes());
+    new_data.extend_from_slice(&((entries.len() * num_palettes) as u16).to_be_bytes());
+    new_data.extend_from_slice(&(header_len as u32).to_be_bytes());
+    for palette in 0..num_palettes {
+        let first = b.read_u16(12 + palette * 2)? as usize;
+        new_data.extend_from_slice(&((palette * entries.len()) as u16).to_be_bytes());
+        for &entry in &entries {
+            records.extend_from_slice(b.read_bytes(records_offset + (first + entry) * 4, 4)?);
+        }
+    }
+    if version >= 1 {
+        let base = 12 + num_palettes * 2;
+        let mut arrays = Vec::new();
+        for (i, len) in [num_palettes * 4, num_palettes * 2].iter().enumerate() {
+            let array_offset = b.read_u32(base + i * 4)? as usize;
+            if array_offset == 0 {
+                new_data.extend_from_slice(&0u32.to_be_bytes());
+                continue;
+            }
+            let offset = header_len + records.len() + arrays.len();
+            new_data.extend_from_slice(&(offset as u32).to_be_bytes());
+            arrays.extend_from_slice(b.read_bytes(array_offset, *len)?);
+        }
+        let entry_labels = b.read_u32(base + 8)? as usize;
+        if entry_labels == 0 {
+            new_data.extend_from_slice(&0u32.to_be_bytes());
+        } else {
+            let offset = header_len + records.len() + arrays.len();
+            new_data.extend_from_slice(&(offset as u32).to_be_bytes());
+            for &entry in &entries {
+                arrays.extend_from_slice(b.read_bytes(entry_labels + entry * 2, 2)?);
+            }
+        }
+        new_data.extend_from_slice(&records);
+        new_data.extend_from_slice(&arrays);
+    } else {
+        new_data.extend_from_slice(&records);
+    }
+    Some(new_data)
+}
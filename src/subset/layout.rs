@@ -0,0 +1,1507 @@
+//! Subsetting for the glyph substitution, positioning and definition
+//! tables.
+
+use super::serialize::{Object, ObjectId, Serializer};
+use super::Plan;
+use crate::otl::{ClassDef, Coverage};
+use crate::parse_prelude::*;
+use std::vec::Vec;
+
+/// Packing modes for layout tables in the order they are attempted as
+/// (extension lookups, shared subtables) pairs.
+const MODES: [(bool, bool); 3] = [(false, true), (true, true), (true, false)];
+
+/// Returns the glyph substitution or positioning table for the subset.
+pub fn subset(data: &[u8], is_gpos: bool, plan: &Plan) -> Option<Vec<u8>> {
+    let table = Table::new(data, is_gpos, plan)?;
+    for (use_extensions, dedup) in MODES {
+        let mut writer = Writer {
+            table: &table,
+            b: table.b,
+            plan,
+            s: Serializer::new(dedup),
+            use_extensions,
+        };
+        let root = writer.table()?;
+        if let Some(data) = writer.s.pack(root) {
+            return Some(data);
+        }
+    }
+    None
+}
+
+/// Returns the glyph definition table for the subset.
+pub fn subset_gdef(data: &[u8], plan: &Plan) -> Option<Vec<u8>> {
+    let b = Buffer::new(data);
+    let minor_version = b.read_u16(2)?;
+    for (_, dedup) in MODES {
+        let mut writer = GdefWriter {
+            b,
+            plan,
+            s: Serializer::new(dedup),
+        };
+        let glyph_classes = writer.class_def(b.read_u16(4)? as usize);
+        let attach_list = writer.attach_list(b.read_u16(6)? as usize);
+        let lig_caret_list = writer.lig_caret_list(b.read_u16(8)? as usize);
+        let mark_classes = writer.class_def(b.read_u16(10)? as usize);
+        let mut header = Object::new();
+        header.u16(1);
+        header.u16(minor_version.min(3));
+        header.offset16(glyph_classes);
+        header.offset16(attach_list);
+        header.offset16(lig_caret_list);
+        header.offset16(mark_classes);
+        if minor_version >= 2 {
+            let mark_sets = writer.mark_sets(b.read_u16(12)? as usize);
+            header.offset16(mark_sets);
+        }
+        if minor_version >= 3 {
+            let ivs = match b.read_u32(14)? as usize {
+                0 => None,
+                offset => Some(item_variation_store(&mut writer.s, &b, offset)?),
+            };
+            header.offset32(ivs);
+        }
+        let root = writer.s.add(header);
+        if let Some(data) = writer.s.pack(root) {
+            return Some(data);
+        }
+    }
+    None
+}
+
+/// Copies the item variation store at the specified offset.
+pub fn item_variation_store(s: &mut Serializer, b: &Buffer, offset: usize) -> Option<ObjectId> {
+    let region_list = offset + b.read_u32(offset + 2)? as usize;
+    let axis_count = b.read_u16(region_list)? as usize;
+    let region_count = b.read_u16(region_list + 2)? as usize;
+    let region_list = s.add_bytes(b.read_bytes(region_list, 4 + region_count * axis_count * 6)?);
+    let count = b.read_u16(offset + 6)? as usize;
+    let mut store = Object::new();
+    store.u16(b.read_u16(offset)?);
+    store.offset32(Some(region_list));
+    store.u16(count as u16);
+    for i in 0..count {
+        let data = offset + b.read_u32(offset + 8 + i * 4)? as usize;
+        let item_count = b.read_u16(data)? as usize;
+        let word_delta_count = b.read_u16(data + 2)? as usize;
+        let region_index_count = b.read_u16(data + 4)? as usize;
+        let words = word_delta_count & 0x7FFF;
+        let row_size = if word_delta_count & 0x8000 != 0 {
+            words * 4 + region_index_count.checked_sub(words)? * 2
+        } else {
+            words * 2 + region_index_count.checked_sub(words)?
+        };
+        let size = 6 + region_index_count * 2 + item_count * row_size;
+        let data = s.add_bytes(b.read_bytes(data, size)?);
+        store.offset32(Some(data));
+    }
+    Some(s.add(store))
+}
+
+/// Copies the device or variation index table at the specified offset.
+fn device(s: &mut Serializer, b: &Buffer, offset: usize) -> Option<ObjectId> {
+    let start = b.read_u16(offset)? as usize;
+    let end = b.read_u16(offset + 2)? as usize;
+    let format = b.read_u16(offset + 4)?;
+    let size = match format {
+        1..=3 if end >= start => 6 + ((end - start + 1) << format).div_ceil(16) * 2,
+        _ => 6,
+    };
+    Some(s.add_bytes(b.read_bytes(offset, size)?))
+}
+
+/// Copies the delta set index map at the specified offset.
+pub fn delta_set_index_map(s: &mut Serializer, b: &Buffer, offset: usize) -> Option<ObjectId> {
+    let format = b.read_u8(offset)?;
+    let entry_format = b.read_u8(offset + 1)?;
+    let (header_size, count) = if format == 0 {
+        (4, b.read_u16(offset + 2)? as usize)
+    } else {
+        (6, b.read_u32(offset + 2)? as usize)
+    };
+    let entry_size = ((entry_format >> 4) & 3) as usize + 1;
+    Some(s.add_bytes(b.read_bytes(offset, header_size + count * entry_size)?))
+}
+
+/// Returns the retained (glyph, coverage index) pairs of the coverage table
+/// at the specified offset with glyph identifiers in the subset.
+fn covered(b: &Buffer, offset: usize, plan: &Plan) -> Vec<(GlyphId, u16)> {
+    let mut glyphs = Vec::new();
+    if offset != 0 {
+        Coverage::new(*b, offset as u32).indices_with(|glyph_id, index| {
+            if let Some(glyph_id) = plan.map(glyph_id) {
+                glyphs.push((glyph_id, index));
+            }
+            true
+        });
+    }
+    glyphs.sort_unstable();
+    glyphs
+}
+
+/// Adds a coverage table for the specified sorted glyphs, choosing the
+/// smaller format.
+fn coverage(s: &mut Serializer, glyphs: impl Iterator<Item = GlyphId> + Clone) -> ObjectId {
+    let mut ranges: Vec<(GlyphId, GlyphId)> = Vec::new();
+    let mut count = 0;
+    for glyph_id in glyphs.clone() {
+        count += 1;
+        match ranges.last_mut() {
+            Some(range) if range.1 + 1 == glyph_id => range.1 = glyph_id,
+            _ => ranges.push((glyph_id, glyph_id)),
+        }
+    }
+    let mut object = Object::new();
+    if count <= ranges.len() * 3 {
+        object.u16(1);
+        object.u16(count as u16);
+        for glyph_id in glyphs {
+            object.u16(glyph_id);
+        }
+    } else {
+        object.u16(2);
+        object.u16(ranges.len() as u16);
+        let mut index = 0;
+        for (start, end) in ranges {
+            object.u16(start);
+            object.u16(end);
+            object.u16(index);
+            index += end - start + 1;
+        }
+    }
+    s.add(object)
+}
+
+/// Adds a subset of the coverage table at the specified offset. Returns
+/// `None` if no glyphs remain.
+fn remap_coverage(s: &mut Serializer, b: &Buffer, offset: usize, plan: &Plan) -> Option<ObjectId> {
+    let glyphs = covered(b, offset, plan);
+    if glyphs.is_empty() {
+        return None;
+    }
+    Some(coverage(s, glyphs.iter().map(|g| g.0)))
+}
+
+/// Adds a subset of the class definition table at the specified offset,
+/// choosing the smaller format. Returns `None` for a null offset.
+fn remap_class_def(s: &mut Serializer, b: &Buffer, offset: usize, plan: &Plan) -> Option<ObjectId> {
+    if offset == 0 {
+        return None;
+    }
+    let mut classes = Vec::new();
+    ClassDef::new(*b, offset as u32).classes_with(|glyph_id, class| {
+        if let (Some(glyph_id), true) = (plan.map(glyph_id), class != 0) {
+            classes.push((glyph_id, class));
+        }
+        true
+    });
+    classes.sort_unstable();
+    let mut ranges: Vec<(GlyphId, GlyphId, u16)> = Vec::new();
+    for &(glyph_id, class) in &classes {
+        match ranges.last_mut() {
+            Some(range) if range.1 + 1 == glyph_id && range.2 == class => range.1 = glyph_id,
+            _ => ranges.push((glyph_id, glyph_id, class)),
+        }
+    }
+    let mut object = Object::new();
+    let span = match (classes.first(), classes.last()) {
+        (Some(first), Some(last)) => (last.0 - first.0) as usize + 1,
+        _ => 0,
+    };
+    if span != 0 && span * 2 + 6 <= ranges.len() * 6 + 4 {
+        let first = classes[0].0;
+        object.u16(1);
+        object.u16(first);
+        object.u16(span as u16);
+        let mut next = first;
+        for (glyph_id, class) in classes {
+            while next < glyph_id {
+                object.u16(0);
+                next += 1;
+            }
+            object.u16(class);
+            next += 1;
+        }
+    } else {
+        object.u16(2);
+        object.u16(ranges.len() as u16);
+        for (start, end, class) in ranges {
+            object.u16(start);
+            object.u16(end);
+            object.u16(class);
+        }
+    }
+    Some(s.add(object))
+}
+
+/// Lookup table with extension subtables resolved.
+struct LookupInfo {
+    kind: u16,
+    flag: u16,
+    mark_filtering_set: Option<u16>,
+    subtables: Vec<usize>,
+}
+
+/// Contextual rule with the lookups to apply.
+struct Rule {
+    backtrack: Vec<u16>,
+    input: Vec<u16>,
+    lookahead: Vec<u16>,
+    records: Vec<(u16, u16)>,
+}
+
+/// Source layout table with the retained features and lookups.
+struct Table<'a> {
+    b: Buffer<'a>,
+    is_gpos: bool,
+    features: Option<&'a [Tag]>,
+    script_list: usize,
+    feature_list: usize,
+    lookup_list: usize,
+    feature_variations: usize,
+    feature_map: Vec<Option<u16>>,
+    lookup_map: Vec<Option<u16>>,
+    lookups: Vec<u16>,
+}
+
+impl<'a> Table<'a> {
+    fn new(data: &'a [u8], is_gpos: bool, plan: &'a Plan) -> Option<Self> {
+        let b = Buffer::new(data);
+        let minor_version = b.read_u16(2)?;
+        let feature_variations = if minor_version >= 1 {
+            b.read_u32(10)? as usize
+        } else {
+            0
+        };
+        let mut table = Self {
+            b,
+            is_gpos,
+            features: plan.features.as_deref(),
+            script_list: b.read_u16(4)? as usize,
+            feature_list: b.read_u16(6)? as usize,
+            lookup_list: b.read_u16(8)? as usize,
+            feature_variations,
+            feature_map: Vec::new(),
+            lookup_map: Vec::new(),
+            lookups: Vec::new(),
+        };
+        table.build_maps()?;
+        Some(table)
+    }
+
+    /// Computes the retained features and lookups, including the lookups
+    /// that are referenced by contextual lookups.
+    fn build_maps(&mut self) -> Option<()> {
+        let b = self.b;
+        let num_features = b.read_u16(self.feature_list)? as usize;
+        let num_lookups = b.read_u16(self.lookup_list)? as usize;
+        let mut retained = std::vec![false; num_lookups];
+        let mut stack = Vec::new();
+        let mut retain = |indices: Slice<u16>, stack: &mut Vec<u16>| {
+            for index in indices.iter() {
+                if let Some(is_retained @ false) = retained.get_mut(index as usize) {
+                    *is_retained = true;
+                    stack.push(index);
+                }
+            }
+        };
+        let mut count = 0;
+        for i in 0..num_features {
+            let record = self.feature_list + 2 + i * 6;
+            let tag = Tag(b.read_u32(record)?);
+            if self
+                .features
+                .is_some_and(|features| !features.contains(&tag))
+            {
+                self.feature_map.push(None);
+                continue;
+            }
+            self.feature_map.push(Some(count));
+            count += 1;
+            let feature = self.feature_list + b.read_u16(record + 4)? as usize;
+            retain(b.read_slice16(feature + 2)?, &mut stack);
+        }
+        if self.feature_variations != 0 {
+            self.variation_features(|table, feature_index, feature| {
+                if table.map_feature(feature_index).is_some() {
+                    retain(table.b.read_slice16(feature + 2)?, &mut stack);
+                }
+                Some(())
+            })?;
+        }
+        while let Some(index) = stack.pop() {
+            let lookup = match self.lookup(index) {
+                Some(lookup) => lookup,
+                None => continue,
+            };
+            for &subtable in &lookup.subtables {
+                let mut nested = Vec::new();
+                self.nested_lookups(lookup.kind, subtable, &mut nested);
+                for index in nested {
+                    if (index as usize) < num_lookups && !retained[index as usize] {
+                        retained[index as usize] = true;
+                        stack.push(index);
+                    }
+                }
+            }
+        }
+        self.lookup_map = std::vec![None; num_lookups];
+        for (index, _) in retained.iter().enumerate().filter(|r| *r.1) {
+            self.lookup_map[index] = Some(self.lookups.len() as u16);
+            self.lookups.push(index as u16);
+        }
+        Some(())
+    }
+
+    fn map_feature(&self, index: u16) -> Option<u16> {
+        self.feature_map.get(index as usize).copied().flatten()
+    }
+
+    fn map_lookup(&self, index: u16) -> Option<u16> {
+        self.lookup_map.get(index as usize).copied().flatten()
+    }
+
+    /// Invokes the specified closure for each feature substitution in the
+    /// feature variations table with the feature index and the offset of
+    /// the alternate feature table.
+    fn variation_features(&self, mut f: impl FnMut(&Self, u16, usize) -> Option<()>) -> Option<()> {
+        let b = &self.b;
+        let base = self.feature_variations;
+        let count = b.read_u32(base + 4)? as usize;
+        for i in 0..count {
+            let substitutions = b.read_u32(base + 8 + i * 8 + 4)? as usize;
+            if substitutions == 0 {
+                continue;
+            }
+            let substitutions = base + substitutions;
+            let len = b.read_u16(substitutions + 4)? as usize;
+            for j in 0..len {
+                let record = substitutions + 6 + j * 6;
+                let feature_index = b.read_u16(record)?;
+                let feature = substitutions + b.read_u32(record + 2)? as usize;
+                f(self, feature_index, feature)?;
+            }
+        }
+        Some(())
+    }
+
+    fn extension_kind(&self) -> u16 {
+        if self.is_gpos {
+            9
+        } else {
+            7
+        }
+    }
+
+    /// Returns the context kind of the specified lookup type as a
+    /// (is context, is chained) pair.
+    fn context_kind(&self, kind: u16) -> (bool, bool) {
+        match (self.is_gpos, kind) {
+            (false, 5) | (true, 7) => (true, false),
+            (false, 6) | (true, 8) => (true, true),
+            _ => (false, false),
+        }
+    }
+
+    fn lookup(&self, index: u16) -> Option<LookupInfo> {
+        let b = &self.b;
+        let offset =
+            self.lookup_list + b.read_u16(self.lookup_list + 2 + index as usize * 2)? as usize;
+        let mut kind = b.read_u16(offset)?;
+        let flag = b.read_u16(offset + 2)?;
+        let count = b.read_u16(offset + 4)? as usize;
+        let mark_filtering_set = if flag & 0x10 != 0 {
+            Some(b.read_u16(offset + 6 + count * 2)?)
+        } else {
+            None
+        };
+        let mut subtables = Vec::with_capacity(count);
+        let is_extension = kind == self.extension_kind();
+        for i in 0..count {
+            let subtable = offset + b.read_u16(offset + 6 + i * 2)? as usize;
+            if is_extension {
+                kind = b.read_u16(subtable + 2)?;
+                subtables.push(subtable + b.read_u32(subtable + 4)? as usize);
+            } else {
+                subtables.push(subtable);
+            }
+        }
+        Some(LookupInfo {
+            kind,
+            flag,
+            mark_filtering_set,
+            subtables,
+        })
+    }
+
+    fn rule(&self, offset: usize, is_chain: bool) -> Option<Rule> {
+        let b = &self.b;
+        let mut c = b.cursor_at(offset)?;
+        let mut rule = Rule {
+            backtrack: Vec::new(),
+            input: Vec::new(),
+            lookahead: Vec::new(),
+            records: Vec::new(),
+        };
+        let num_records;
+        if is_chain {
+            let len = c.read_u16()? as usize;
+            rule.backtrack = c.read_slice::<u16>(len)?.iter().collect();
+            let len = c.read_u16()? as usize;
+            rule.input = c.read_slice::<u16>(len.checked_sub(1)?)?.iter().collect();
+            let len = c.read_u16()? as usize;
+            rule.lookahead = c.read_slice::<u16>(len)?.iter().collect();
+            num_records = c.read_u16()? as usize;
+        } else {
+            let len = c.read_u16()? as usize;
+            num_records = c.read_u16()? as usize;
+            rule.input = c.read_slice::<u16>(len.checked_sub(1)?)?.iter().collect();
+        }
+        for _ in 0..num_records {
+            rule.records.push((c.read_u16()?, c.read_u16()?));
+        }
+        Some(rule)
+    }
+
+    /// Returns the offsets of the coverage tables of a format 3 contextual
+    /// subtable as (backtrack, input, lookahead) along with the offset of
+    /// the lookup records and their count.
+    #[allow(clippy::type_complexity)]
+    fn coverage_rule(
+        &self,
+        offset: usize,
+        is_chain: bool,
+    ) -> Option<([Vec<usize>; 3], usize, usize)> {
+        let b = &self.b;
+        let mut c = b.cursor_at(offset + 2)?;
+        let mut coverages = [Vec::new(), Vec::new(), Vec::new()];
+        let num_records;
+        let read = |c: &mut Cursor, len: usize| -> Option<Vec<usize>> {
+            Some(
+                c.read_slice::<u16>(len)?
+                    .iter()
+                    .map(|o| offset + o as usize)
+                    .collect(),
+            )
+        };
+        if is_chain {
+            for coverage in &mut coverages {
+                let len = c.read_u16()? as usize;
+                *coverage = read(&mut c, len)?;
+            }
+            num_records = c.read_u16()? as usize;
+        } else {
+            let len = c.read_u16()? as usize;
+            num_records = c.read_u16()? as usize;
+            coverages[1] = read(&mut c, len)?;
+        }
+        Some((coverages, offset + 2 + c.offset(), num_records))
+    }
+
+    /// Returns the offsets of the rule sets of a format 1 or 2 contextual
+    /// subtable.
+    fn rule_sets(&self, offset: usize, format: u16, is_chain: bool) -> Option<Vec<usize>> {
+        let count_offset = match (format, is_chain) {
+            (1, _) => 4,
+            (2, false) => 6,
+            _ => 10,
+        };
+        let sets = self.b.read_slice16::<u16>(offset + count_offset)?;
+        Some(
+            sets.iter()
+                .map(|o| if o == 0 { 0 } else { offset + o as usize })
+                .collect(),
+        )
+    }
+
+    /// Returns the offsets of the rules in a rule set.
+    fn rules(&self, offset: usize) -> Option<Vec<usize>> {
+        let rules = self.b.read_slice16::<u16>(offset)?;
+        Some(rules.iter().map(|o| offset + o as usize).collect())
+    }
+
+    /// Collects the lookups that are referenced by a contextual subtable.
+    fn nested_lookups(&self, kind: u16, offset: usize, nested: &mut Vec<u16>) -> Option<()> {
+        let (is_context, is_chain) = self.context_kind(kind);
+        if !is_context {
+            return Some(());
+        }
+        let format = self.b.read_u16(offset)?;
+        if format == 3 {
+            let (_, records, count) = self.coverage_rule(offset, is_chain)?;
+            for i in 0..count {
+                nested.push(self.b.read_u16(records + i * 4 + 2)?);
+            }
+            return Some(());
+        }
+        for set in self.rule_sets(offset, format, is_chain)? {
+            if set == 0 {
+                continue;
+            }
+            for rule in self.rules(set)? {
+                let rule = self.rule(rule, is_chain)?;
+                nested.extend(rule.records.iter().map(|r| r.1));
+            }
+        }
+        Some(())
+    }
+}
+
+/// Writer for a glyph substitution or positioning table.
+struct Writer<'a> {
+    table: &'a Table<'a>,
+    b: Buffer<'a>,
+    plan: &'a Plan,
+    s: Serializer,
+    use_extensions: bool,
+}
+
+impl Writer<'_> {
+    fn u16(&self, offset: usize) -> Option<u16> {
+        self.b.read_u16(offset)
+    }
+
+    fn covered(&self, offset: usize) -> Vec<(GlyphId, u16)> {
+        covered(&self.b, offset, self.plan)
+    }
+
+    fn coverage(&mut self, glyphs: impl Iterator<Item = GlyphId> + Clone) -> ObjectId {
+        coverage(&mut self.s, glyphs)
+    }
+
+    fn remap_coverage(&mut self, offset: usize) -> Option<ObjectId> {
+        remap_coverage(&mut self.s, &self.b, offset, self.plan)
+    }
+
+    fn remap_class_def(&mut self, offset: usize) -> Option<ObjectId> {
+        remap_class_def(&mut self.s, &self.b, offset, self.plan)
+    }
+
+    fn table(&mut self) -> Option<ObjectId> {
+        let table = self.table;
+        let mut lookups = Vec::with_capacity(table.lookups.len());
+        for &index in &table.lookups {
+            lookups.push(self.lookup(index)?);
+        }
+        let mut lookup_list = Object::new();
+        lookup_list.u16(lookups.len() as u16);
+        for lookup in lookups {
+            lookup_list.offset16(Some(lookup));
+        }
+        let lookup_list = self.s.add(lookup_list);
+        let feature_list = self.feature_list()?;
+        let script_list = self.script_list()?;
+        let feature_variations = if table.feature_variations != 0 {
+            self.feature_variations()?
+        } else {
+            None
+        };
+        let mut header = Object::new();
+        header.u16(1);
+        header.u16(feature_variations.is_some() as u16);
+        header.offset16(Some(script_list));
+        header.offset16(Some(feature_list));
+        header.offset16(Some(lookup_list));
+        if feature_variations.is_some() {
+            header.offset32(feature_variations);
+        }
+        Some(self.s.add(header))
+    }
+
+    fn script_list(&mut self) -> Option<ObjectId> {
+        let base = self.table.script_list;
+        let count = self.u16(base)? as usize;
+        let mut list = Object::new();
+        list.u16(count as u16);
+        let mut scripts = Vec::with_capacity(count);
+        for i in 0..count {
+            let record = base + 2 + i * 6;
+            let script = base + self.u16(record + 4)? as usize;
+            let default_lang_sys = match self.u16(script)? {
+                0 => None,
+                offset => Some(self.lang_sys(script + offset as usize)?),
+            };
+            let num_lang_sys = self.u16(script + 2)? as usize;
+            let mut object = Object::new();
+            object.offset16(default_lang_sys);
+            object.u16(num_lang_sys as u16);
+            for j in 0..num_lang_sys {
+                let record = script + 4 + j * 6;
+                let lang_sys = self.lang_sys(script + self.u16(record + 4)? as usize)?;
+                object.u32(self.b.read_u32(record)?);
+                object.offset16(Some(lang_sys));
+            }
+            scripts.push((self.b.read_u32(record)?, self.s.add(object)));
+        }
+        for (tag, script) in scripts {
+            list.u32(tag);
+            list.offset16(Some(script));
+        }
+        Some(self.s.add(list))
+    }
+
+    fn lang_sys(&mut self, offset: usize) -> Option<ObjectId> {
+        let required = self.u16(offset + 2)?;
+        let required = self.table.map_feature(required).unwrap_or(0xFFFF);
+        let indices = self.b.read_slice16::<u16>(offset + 4)?;
+        let indices = indices
+            .iter()
+            .filter_map(|index| self.table.map_feature(index))
+            .collect::<Vec<_>>();
+        let mut object = Object::new();
+        object.u16(0);
+        object.u16(required);
+        object.u16(indices.len() as u16);
+        for index in indices {
+            object.u16(index);
+        }
+        Some(self.s.add(object))
+    }
+
+    fn feature_list(&mut self) -> Option<ObjectId> {
+        let base = self.table.feature_list;
+        let count = self.u16(base)? as usize;
+        let mut features = Vec::new();
+        for i in 0..count {
+            if self.table.feature_map[i].is_none() {
+                continue;
+            }
+            let record = base + 2 + i * 6;
+            let tag = Tag(self.b.read_u32(record)?);
+            let feature = self.feature(tag, base + self.u16(record + 4)? as usize)?;
+            features.push((tag, feature));
+        }
+        let mut list = Object::new();
+        list.u16(features.len() as u16);
+        for (tag, feature) in features {
+            list.u32(tag.0);
+            list.offset16(Some(feature));
+        }
+        Some(self.s.add(list))
+    }
+
+    fn feature(&mut self, tag: Tag, offset: usize) -> Option<ObjectId> {
+        let params = match self.u16(offset)? {
+            0 => None,
+            params => self.feature_params(tag, offset + params as usize),
+        };
+        let indices = self.b.read_slice16::<u16>(offset + 2)?;
+        let indices = indices
+            .iter()
+            .filter_map(|index| self.table.map_lookup(index))
+            .collect::<Vec<_>>();
+        let mut object = Object::new();
+        object.offset16(params);
+        object.u16(indices.len() as u16);
+        for index in indices {
+            object.u16(index);
+        }
+        Some(self.s.add(object))
+    }
+
+    fn feature_params(&mut self, tag: Tag, offset: usize) -> Option<ObjectId> {
+        let bytes = tag.0.to_be_bytes();
+        let size = match &bytes {
+            b"size" => 10,
+            [b's', b's', ..] => 4,
+            [b'c', b'v', ..] => 14 + self.u16(offset + 12)? as usize * 3,
+            _ => return None,
+        };
+        Some(self.s.add_bytes(self.b.read_bytes(offset, size)?))
+    }
+
+    fn feature_variations(&mut self) -> Option<Option<ObjectId>> {
+        let base = self.table.feature_variations;
+        let b = self.b;
+        let count = b.read_u32(base + 4)? as usize;
+        let mut records = Vec::new();
+        'records: for i in 0..count {
+            let record = base + 8 + i * 8;
+            let condition_set = b.read_u32(record)? as usize;
+            let mut set = Object::new();
+            if condition_set != 0 {
+                let condition_set = base + condition_set;
+                let conditions = b.read_u16(condition_set)? as usize;
+                set.u16(conditions as u16);
+                for j in 0..conditions {
+                    let condition = condition_set + b.read_u32(condition_set + 2 + j * 4)? as usize;
+                    if b.read_u16(condition)? != 1 {
+                        continue 'records;
+                    }
+                    let condition = self.s.add_bytes(b.read_bytes(condition, 8)?);
+                    set.offset32(Some(condition));
+                }
+            } else {
+                set.u16(0);
+            }
+            let substitutions = match b.read_u32(record + 4)? as usize {
+                0 => None,
+                offset => Some(base + offset),
+            };
+            let mut features = Vec::new();
+            if let Some(substitutions) = substitutions {
+                let len = b.read_u16(substitutions + 4)? as usize;
+                for j in 0..len {
+                    let record = substitutions + 6 + j * 6;
+                    let index = b.read_u16(record)?;
+                    if let Some(new_index) = self.table.map_feature(index) {
+                        let feature_list = self.table.feature_list;
+                        let tag = Tag(b.read_u32(feature_list + 2 + index as usize * 6)?);
+                        let feature = substitutions + b.read_u32(record + 2)? as usize;
+                        features.push((new_index, self.feature(tag, feature)?));
+                    }
+                }
+            }
+            let mut table = Object::new();
+            table.u16(1);
+            table.u16(0);
+            table.u16(features.len() as u16);
+            for (index, feature) in features {
+                table.u16(index);
+                table.offset32(Some(feature));
+            }
+            let set = self.s.add(set);
+            let table = self.s.add(table);
+            records.push((set, table));
+        }
+        if records.is_empty() {
+            return Some(None);
+        }
+        let mut variations = Object::new();
+        variations.u16(1);
+        variations.u16(0);
+        variations.u32(records.len() as u32);
+        for (set, table) in records {
+            variations.offset32(Some(set));
+            variations.offset32(Some(table));
+        }
+        Some(Some(self.s.add(variations)))
+    }
+
+    fn lookup(&mut self, index: u16) -> Option<ObjectId> {
+        let lookup = self.table.lookup(index)?;
+        let mut subtables = Vec::new();
+        for &offset in &lookup.subtables {
+            if let Some(subtable) = self.subtable(lookup.kind, offset) {
+                subtables.push(subtable);
+            }
+        }
+        let kind = if self.use_extensions {
+            for subtable in &mut subtables {
+                let mut extension = Object::new();
+                extension.u16(1);
+                extension.u16(lookup.kind);
+                extension.offset32(Some(*subtable));
+                *subtable = self.s.add(extension);
+            }
+            self.table.extension_kind()
+        } else {
+            lookup.kind
+        };
+        let mut object = Object::new();
+        object.u16(kind);
+        object.u16(lookup.flag);
+        object.u16(subtables.len() as u16);
+        for subtable in subtables {
+            object.offset16(Some(subtable));
+        }
+        if let Some(mark_filtering_set) = lookup.mark_filtering_set {
+            object.u16(mark_filtering_set);
+        }
+        Some(self.s.add(object))
+    }
+
+    /// Writes the subset of a lookup subtable. Returns `None` if the
+    /// subtable is empty in the subset or malformed.
+    fn subtable(&mut self, kind: u16, offset: usize) -> Option<ObjectId> {
+        let (is_context, is_chain) = self.table.context_kind(kind);
+        if is_context {
+            return self.context(offset, is_chain);
+        }
+        match (self.table.is_gpos, kind) {
+            (false, 1) => self.single_subst(offset),
+            (false, 2) | (false, 3) => self.sequence_subst(offset, kind == 3),
+            (false, 4) => self.ligature_subst(offset),
+            (false, 8) => self.reverse_chain_subst(offset),
+            (true, 1) => self.single_pos(offset),
+            (true, 2) => self.pair_pos(offset),
+            (true, 3) => self.cursive_pos(offset),
+            (true, 4) | (true, 5) | (true, 6) => self.mark_pos(offset, kind == 5),
+            _ => None,
+        }
+    }
+
+    fn single_subst(&mut self, offset: usize) -> Option<ObjectId> {
+        let format = self.u16(offset)?;
+        let mut pairs = Vec::new();
+        Coverage::new(self.b, offset as u32 + self.u16(offset + 2)? as u32).indices_with(
+            |glyph_id, index| {
+                let subst = if format == 1 {
+                    self.u16(offset + 4)
+                        .map(|delta| glyph_id.wrapping_add(delta))
+                } else {
+                    self.u16(offset + 6 + index as usize * 2)
+                };
+                if let (Some(glyph_id), Some(subst)) = (
+                    self.plan.map(glyph_id),
+                    subst.and_then(|g| self.plan.map(g)),
+                ) {
+                    pairs.push((glyph_id, subst));
+                }
+                true
+            },
+        );
+        if pairs.is_empty() {
+            return None;
+        }
+        pairs.sort_unstable();
+        let coverage = self.coverage(pairs.iter().map(|p| p.0));
+        let delta = pairs[0].1.wrapping_sub(pairs[0].0);
+        let mut object = Object::new();
+        if pairs.iter().all(|p| p.1.wrapping_sub(p.0) == delta) {
+            object.u16(1);
+            object.offset16(Some(coverage));
+            object.u16(delta);
+        } else {
+            object.u16(2);
+            object.offset16(Some(coverage));
+            object.u16(pairs.len() as u16);
+            for (_, subst) in pairs {
+                object.u16(subst);
+            }
+        }
+        Some(self.s.add(object))
+    }
+
+    /// Writes a multiple or alternate substitution subtable.
+    fn sequence_subst(&mut self, offset: usize, is_alternate: bool) -> Option<ObjectId> {
+        let sets = self.b.read_slice16::<u16>(offset + 4)?;
+        let mut entries = Vec::new();
+        for (glyph_id, index) in self.covered(offset + self.u16(offset + 2)? as usize) {
+            let set = offset + sets.get(index as usize)? as usize;
+            let glyphs = self.b.read_slice16::<u16>(set)?;
+            let mapped = glyphs
+                .iter()
+                .filter_map(|glyph_id| self.plan.map(glyph_id))
+                .collect::<Vec<_>>();
+            if is_alternate && mapped.is_empty() || !is_alternate && mapped.len() != glyphs.len() {
+                continue;
+            }
+            let mut object = Object::new();
+            object.u16(mapped.len() as u16);
+            for glyph_id in mapped {
+                object.u16(glyph_id);
+            }
+            entries.push((glyph_id, self.s.add(object)));
+        }
+        if entries.is_empty() {
+            return None;
+        }
+        let coverage = self.coverage(entries.iter().map(|e| e.0));
+        let mut object = Object::new();
+        object.u16(1);
+        object.offset16(Some(coverage));
+        object.u16(entries.len() as u16);
+        for (_, set) in entries {
+            object.offset16(Some(set));
+        }
+        Some(self.s.add(object))
+    }
+
+    fn ligature_subst(&mut self, offset: usize) -> Option<ObjectId> {
+        let sets = self.b.read_slice16::<u16>(offset + 4)?;
+        let mut entries = Vec::new();
+        for (glyph_id, index) in self.covered(offset + self.u16(offset + 2)? as usize) {
+            let set = offset + sets.get(index as usize)? as usize;
+            let mut ligatures = Vec::new();
+            for ligature in self.b.read_slice16::<u16>(set)?.iter() {
+                let ligature = set + ligature as usize;
+                let components = self.u16(ligature + 2)? as usize;
+                let components = self
+                    .b
+                    .read_slice::<u16>(ligature + 4, components.checked_sub(1)?)?;
+                let mut object = Object::new();
+                match self.plan.map(self.u16(ligature)?) {
+                    Some(glyph_id) => object.u16(glyph_id),
+                    None => continue,
+                }
+                object.u16(components.len() as u16 + 1);
+                let mut mapped = true;
+                for glyph_id in components.iter() {
+                    match self.plan.map(glyph_id) {
+                        Some(glyph_id) => object.u16(glyph_id),
+                        None => mapped = false,
+                    }
+                }
+                if mapped {
+                    ligatures.push(self.s.add(object));
+                }
+            }
+            if ligatures.is_empty() {
+                continue;
+            }
+            let mut object = Object::new();
+            object.u16(ligatures.len() as u16);
+            for ligature in ligatures {
+                object.offset16(Some(ligature));
+            }
+            entries.push((glyph_id, self.s.add(object)));
+        }
+        if entries.is_empty() {
+            return None;
+        }
+        let coverage = self.coverage(entries.iter().map(|e| e.0));
+        let mut object = Object::new();
+        object.u16(1);
+        object.offset16(Some(coverage));
+        object.u16(entries.len() as u16);
+        for (_, set) in entries {
+            object.offset16(Some(set));
+        }
+        Some(self.s.add(object))
+    }
+
+    fn reverse_chain_subst(&mut self, offset: usize) -> Option<ObjectId> {
+        let mut c = self.b.cursor_at(offset + 4)?;
+        let mut sequences = [Vec::new(), Vec::new()];
+        for sequence in &mut sequences {
+            for coverage in c.read_slice16::<u16>()?.iter() {
+                sequence.push(offset + coverage as usize);
+            }
+        }
+        let substs = c.read_slice16::<u16>()?;
+        let mut pairs = Vec::new();
+        for (glyph_id, index) in self.covered(offset + self.u16(offset + 2)? as usize) {
+            if let Some(subst) = substs.get(index as usize).and_then(|g| self.plan.map(g)) {
+                pairs.push((glyph_id, subst));
+            }
+        }
+        if pairs.is_empty() {
+            return None;
+        }
+        let mut coverages = [Vec::new(), Vec::new()];
+        for (coverages, sequence) in coverages.iter_mut().zip(&sequences) {
+            for &offset in sequence {
+                coverages.push(self.remap_coverage(offset)?);
+            }
+        }
+        let coverage = self.coverage(pairs.iter().map(|p| p.0));
+        let mut object = Object::new();
+        object.u16(1);
+        object.offset16(Some(coverage));
+        for coverages in coverages {
+            object.u16(coverages.len() as u16);
+            for coverage in coverages {
+                object.offset16(Some(coverage));
+            }
+        }
+        object.u16(pairs.len() as u16);
+        for (_, subst) in pairs {
+            object.u16(subst);
+        }
+        Some(self.s.add(object))
+    }
+
+    fn lookup_records(&self, records: &[(u16, u16)], object: &mut Object) {
+        let records = records
+            .iter()
+            .filter_map(|&(sequence_index, lookup_index)| {
+                Some((sequence_index, self.table.map_lookup(lookup_index)?))
+            })
+            .collect::<Vec<_>>();
+        object.u16(records.len() as u16);
+        for (sequence_index, lookup_index) in records {
+            object.u16(sequence_index);
+            object.u16(lookup_index);
+        }
+    }
+
+    /// Writes a contextual rule, remapping the glyphs of the sequences for
+    /// format 1 subtables. Returns `None` if a glyph is not in the subset.
+    fn rule(&mut self, rule: &Rule, is_chain: bool, is_glyphs: bool) -> Option<ObjectId> {
+        let map = |values: &[u16]| -> Option<Vec<u16>> {
+            if is_glyphs {
+                values.iter().map(|&g| self.plan.map(g)).collect()
+            } else {
+                Some(values.to_vec())
+            }
+        };
+        let backtrack = map(&rule.backtrack)?;
+        let input = map(&rule.input)?;
+        let lookahead = map(&rule.lookahead)?;
+        let mut object = Object::new();
+        if is_chain {
+            object.u16(backtrack.len() as u16);
+            backtrack.iter().for_each(|&v| object.u16(v));
+            object.u16(input.len() as u16 + 1);
+            input.iter().for_each(|&v| object.u16(v));
+            object.u16(lookahead.len() as u16);
+            lookahead.iter().for_each(|&v| object.u16(v));
+            self.lookup_records(&rule.records, &mut object);
+        } else {
+            let mut records = Object::new();
+            self.lookup_records(&rule.records, &mut records);
+            object.u16(input.len() as u16 + 1);
+            object.bytes(&records.data()[..2]);
+            input.iter().for_each(|&v| object.u16(v));
+            object.bytes(&records.data()[2..]);
+        }
+        Some(self.s.add(object))
+    }
+
+    /// Writes a rule set. Returns `None` if no rules remain.
+    fn rule_set(&mut self, offset: usize, is_chain: bool, is_glyphs: bool) -> Option<ObjectId> {
+        let mut rules = Vec::new();
+        for rule in self.table.rules(offset)? {
+            let rule = self.table.rule(rule, is_chain)?;
+            if let Some(rule) = self.rule(&rule, is_chain, is_glyphs) {
+                rules.push(rule);
+            }
+        }
+        if rules.is_empty() && is_glyphs {
+            return None;
+        }
+        let mut object = Object::new();
+        object.u16(rules.len() as u16);
+        for rule in rules {
+            object.offset16(Some(rule));
+        }
+        Some(self.s.add(object))
+    }
+
+    fn context(&mut self, offset: usize, is_chain: bool) -> Option<ObjectId> {
+        let format = self.u16(offset)?;
+        let mut object = Object::new();
+        match format {
+            1 => {
+                let sets = self.table.rule_sets(offset, format, is_chain)?;
+                let mut entries = Vec::new();
+                for (glyph_id, index) in self.covered(offset + self.u16(offset + 2)? as usize) {
+                    let set = match sets.get(index as usize) {
+                        Some(&set) if set != 0 => set,
+                        _ => continue,
+                    };
+                    if let Some(set) = self.rule_set(set, is_chain, true) {
+                        entries.push((glyph_id, set));
+                    }
+                }
+                if entries.is_empty() {
+                    return None;
+                }
+                let coverage = self.coverage(entries.iter().map(|e| e.0));
+                object.u16(1);
+                object.offset16(Some(coverage));
+                object.u16(entries.len() as u16);
+                for (_, set) in entries {
+                    object.offset16(Some(set));
+                }
+            }
+            2 => {
+                let coverage = self.remap_coverage(offset + self.u16(offset + 2)? as usize)?;
+                let num_class_defs = if is_chain { 3 } else { 1 };
+                let mut class_defs = Vec::new();
+                for i in 0..num_class_defs {
+                    let class_def = self.u16(offset + 4 + i * 2)? as usize;
+                    class_defs.push(if class_def == 0 {
+                        None
+                    } else {
+                        self.remap_class_def(offset + class_def)
+                    });
+                }
+                let mut sets = Vec::new();
+                for set in self.table.rule_sets(offset, format, is_chain)? {
+                    sets.push(if set == 0 {
+                        None
+                    } else {
+                        self.rule_set(set, is_chain, false)
+                    });
+                }
+                object.u16(2);
+                object.offset16(Some(coverage));
+                for class_def in class_defs {
+                    object.offset16(class_def);
+                }
+                object.u16(sets.len() as u16);
+                for set in sets {
+                    object.offset16(set);
+                }
+            }
+            3 => {
+                let (coverages, records, count) = self.table.coverage_rule(offset, is_chain)?;
+                let mut sequences = [Vec::new(), Vec::new(), Vec::new()];
+                for (sequence, offsets) in sequences.iter_mut().zip(&coverages) {
+                    for &offset in offsets {
+                        sequence.push(self.remap_coverage(offset)?);
+                    }
+                }
+                let mut lookup_records = Vec::with_capacity(count);
+                for i in 0..count {
+                    let record = records + i * 4;
+                    lookup_records.push((self.u16(record)?, self.u16(record + 2)?));
+                }
+                let mut records = Object::new();
+                self.lookup_records(&lookup_records, &mut records);
+                object.u16(3);
+                if is_chain {
+                    for sequence in sequences {
+                        object.u16(sequence.len() as u16);
+                        for coverage in sequence {
+                            object.offset16(Some(coverage));
+                        }
+                    }
+                    object.bytes(records.data());
+                } else {
+                    object.u16(sequences[1].len() as u16);
+                    object.bytes(&records.data()[..2]);
+                    for &coverage in &sequences[1] {
+                        object.offset16(Some(coverage));
+                    }
+                    object.bytes(&records.data()[2..]);
+                }
+            }
+            _ => return None,
+        }
+        Some(self.s.add(object))
+    }
+
+    fn device(&mut self, offset: usize) -> Option<ObjectId> {
+        device(&mut self.s, &self.b, offset)
+    }
+
+    /// Writes a value record at the specified offset with device offsets
+    /// relative to the specified base.
+    fn value_record(
+        &mut self,
+        object: &mut Object,
+        base: usize,
+        offset: usize,
+        format: u16,
+    ) -> Option<()> {
+        let mut offset = offset;
+        for bit in 0..8 {
+            if format & (1 << bit) == 0 {
+                continue;
+            }
+            let value = self.u16(offset)?;
+            if bit >= 4 {
+                let device = match value {
+                    0 => None,
+                    value => Some(self.device(base + value as usize)?),
+                };
+                object.offset16(device);
+            } else {
+                object.u16(value);
+            }
+            offset += 2;
+        }
+        Some(())
+    }
+
+    fn anchor(&mut self, offset: usize) -> Option<ObjectId> {
+        let format = self.u16(offset)?;
+        let mut object = Object::new();
+        match format {
+            1 | 2 => {
+                let size = if format == 1 { 6 } else { 8 };
+                object.bytes(self.b.read_bytes(offset, size)?);
+            }
+            3 => {
+                object.bytes(self.b.read_bytes(offset, 6)?);
+                for i in 0..2 {
+                    let device = match self.u16(offset + 6 + i * 2)? {
+                        0 => None,
+                        device => Some(self.device(offset + device as usize)?),
+                    };
+                    object.offset16(device);
+                }
+            }
+            _ => return None,
+        }
+        Some(self.s.add(object))
+    }
+
+    fn single_pos(&mut self, offset: usize) -> Option<ObjectId> {
+        let format = self.u16(offset)?;
+        let value_format = self.u16(offset + 4)?;
+        let value_size = value_size(value_format);
+        let glyphs = self.covered(offset + self.u16(offset + 2)? as usize);
+        if glyphs.is_empty() {
+            return None;
+        }
+        let coverage = self.coverage(glyphs.iter().map(|g| g.0));
+        let mut object = Object::new();
+        object.u16(format);
+        object.offset16(Some(coverage));
+        object.u16(value_format);
+        if format == 1 {
+            self.value_record(&mut object, offset, offset + 6, value_format)?;
+        } else {
+            object.u16(glyphs.len() as u16);
+            for (_, index) in glyphs {
+                let value = offset + 8 + index as usize * value_size;
+                self.value_record(&mut object, offset, value, value_format)?;
+            }
+        }
+        Some(self.s.add(object))
+    }
+
+    fn pair_pos(&mut self, offset: usize) -> Option<ObjectId> {
+        let format = self.u16(offset)?;
+        let value_formats = [self.u16(offset + 4)?, self.u16(offset + 6)?];
+        let value_sizes = [value_size(value_formats[0]), value_size(value_formats[1])];
+        let mut object = Object::new();
+        if format == 1 {
+            let sets = self.b.read_slice16::<u16>(offset + 8)?;
+            let record_size = 2 + value_sizes[0] + value_sizes[1];
+            let mut entries = Vec::new();
+            for (glyph_id, index) in self.covered(offset + self.u16(offset + 2)? as usize) {
+                let set = offset + sets.get(index as usize)? as usize;
+                let count = self.u16(set)? as usize;
+                let mut pairs = Object::new();
+                let mut num_pairs = 0u16;
+                pairs.u16(0);
+                for i in 0..count {
+                    let record = set + 2 + i * record_size;
+                    let second = match self.plan.map(self.u16(record)?) {
+                        Some(second) => second,
+                        None => continue,
+                    };
+                    pairs.u16(second);
+                    let value = record + 2;
+                    self.value_record(&mut pairs, set, value, value_formats[0])?;
+                    let value = value + value_sizes[0];
+                    self.value_record(&mut pairs, set, value, value_formats[1])?;
+                    num_pairs += 1;
+                }
+                if num_pairs != 0 {
+                    pairs.set_u16(0, num_pairs);
+                    entries.push((glyph_id, self.s.add(pairs)));
+                }
+            }
+            if entries.is_empty() {
+                return None;
+            }
+            let coverage = self.coverage(entries.iter().map(|e| e.0));
+            object.u16(1);
+            object.offset16(Some(coverage));
+            object.u16(value_formats[0]);
+            object.u16(value_formats[1]);
+            object.u16(entries.len() as u16);
+            for (_, set) in entries {
+                object.offset16(Some(set));
+            }
+        } else if format == 2 {
+            let coverage = self.remap_coverage(offset + self.u16(offset + 2)? as usize)?;
+            let class_def1 = self.remap_class_def(offset + self.u16(offset + 8)? as usize);
+            let class_def2 = self.remap_class_def(offset + self.u16(offset + 10)? as usize);
+            let class1_count = self.u16(offset + 12)? as usize;
+            let class2_count = self.u16(offset + 14)? as usize;
+            object.u16(2);
+            object.offset16(Some(coverage));
+            object.u16(value_formats[0]);
+            object.u16(value_formats[1]);
+            object.offset16(class_def1);
+            object.offset16(class_def2);
+            object.u16(class1_count as u16);
+            object.u16(class2_count as u16);
+            let mut value = offset + 16;
+            for _ in 0..class1_count * class2_count {
+                for (&format, &size) in value_formats.iter().zip(&value_sizes) {
+                    self.value_record(&mut object, offset, value, format)?;
+                    value += size;
+                }
+            }
+        } else {
+            return None;
+        }
+        Some(self.s.add(object))
+    }
+
+    fn cursive_pos(&mut self, offset: usize) -> Option<ObjectId> {
+        let glyphs = self.covered(offset + self.u16(offset + 2)? as usize);
+        if glyphs.is_empty() {
+            return None;
+        }
+        let mut anchors = Vec::with_capacity(glyphs.len());
+        for &(_, index) in &glyphs {
+            let record = offset + 6 + index as usize * 4;
+            let mut pair = [None; 2];
+            for (i, anchor) in pair.iter_mut().enumerate() {
+                *anchor = match self.u16(record + i * 2)? {
+                    0 => None,
+                    anchor => Some(self.anchor(offset + anchor as usize)?),
+                };
+            }
+            anchors.push(pair);
+        }
+        let coverage = self.coverage(glyphs.iter().map(|g| g.0));
+        let mut object = Object::new();
+        object.u16(1);
+        object.offset16(Some(coverage));
+        object.u16(anchors.len() as u16);
+        for [entry, exit] in anchors {
+            object.offset16(entry);
+            object.offset16(exit);
+        }
+        Some(self.s.add(object))
+    }
+
+    /// Writes a mark to base, mark to ligature or mark to mark positioning
+    /// subtable.
+    fn mark_pos(&mut self, offset: usize, is_ligature: bool) -> Option<ObjectId> {
+        let marks = self.covered(offset + self.u16(offset + 2)? as usize);
+        let bases = self.covered(offset + self.u16(offset + 4)? as usize);
+        if marks.is_empty() || bases.is_empty() {
+            return None;
+        }
+        let class_count = self.u16(offset + 6)? as usize;
+        let mark_array = offset + self.u16(offset + 8)? as usize;
+        let base_array = offset + self.u16(offset + 10)? as usize;
+        let mut mark_records = Object::new();
+        mark_records.u16(marks.len() as u16);
+        for &(_, index) in &marks {
+            let record = mark_array + 2 + index as usize * 4;
+            mark_records.u16(self.u16(record)?);
+            let anchor = self.anchor(mark_array + self.u16(record + 2)? as usize)?;
+            mark_records.offset16(Some(anchor));
+        }
+        let mut base_records = Object::new();
+        base_records.u16(bases.len() as u16);
+        for &(_, index) in &bases {
+            if is_ligature {
+                let attach = base_array + self.u16(base_array + 2 + index as usize * 2)? as usize;
+                let components = self.u16(attach)? as usize;
+                let mut object = Object::new();
+                object.u16(components as u16);
+                for i in 0..components * class_count {
+                    let anchor = match self.u16(attach + 2 + i * 2)? {
+                        0 => None,
+                        anchor => Some(self.anchor(attach + anchor as usize)?),
+                    };
+                    object.offset16(anchor);
+                }
+                base_records.offset16(Some(self.s.add(object)));
+            } else {
+                let record = base_array + 2 + index as usize * class_count * 2;
+                for i in 0..class_count {
+                    let anchor = match self.u16(record + i * 2)? {
+                        0 => None,
+                        anchor => Some(self.anchor(base_array + anchor as usize)?),
+                    };
+                    base_records.offset16(anchor);
+                }
+            }
+        }
+        let mark_coverage = self.coverage(marks.iter().map(|m| m.0));
+        let base_coverage = self.coverage(bases.iter().map(|b| b.0));
+        let mark_records = self.s.add(mark_records);
+        let base_records = self.s.add(base_records);
+        let mut object = Object::new();
+        object.u16(1);
+        object.offset16(Some(mark_coverage));
+        object.offset16(Some(base_coverage));
+        object.u16(class_count as u16);
+        object.offset16(Some(mark_records));
+        object.offset16(Some(base_records));
+        Some(self.s.add(object))
+    }
+}
+
+/// Returns the size of a value record with the specified format.
+fn value_size(format: u16) -> usize {
+    (format & 0xFF).count_ones() as usize * 2
+}
+
+/// Writer for a glyph definition table.
+struct GdefWriter<'a> {
+    b: Buffer<'a>,
+    plan: &'a Plan,
+    s: Serializer,
+}
+
+impl GdefWriter<'_> {
+    fn class_def(&mut self, offset: usize) -> Option<ObjectId> {
+        remap_class_def(&mut self.s, &self.b, offset, self.plan)
+    }
+
+    fn attach_list(&mut self, offset: usize) -> Option<ObjectId> {
+        if offset == 0 {
+            return None;
+        }
+        let b = self.b;
+        let glyphs = covered(&b, offset + b.read_u16(offset)? as usize, self.plan);
+        if glyphs.is_empty() {
+            return None;
+        }
+        let mut points = Vec::with_capacity(glyphs.len());
+        for &(_, index) in &glyphs {
+            let point = offset + b.read_u16(offset + 4 + index as usize * 2)? as usize;
+            let count = b.read_u16(point)? as usize;
+            points.push(self.s.add_bytes(b.read_bytes(point, 2 + count * 2)?));
+        }
+        let coverage = coverage(&mut self.s, glyphs.iter().map(|g| g.0));
+        let mut object = Object::new();
+        object.offset16(Some(coverage));
+        object.u16(points.len() as u16);
+        for point in points {
+            object.offset16(Some(point));
+        }
+        Some(self.s.add(object))
+    }
+
+    fn lig_caret_list(&mut self, offset: usize) -> Option<ObjectId> {
+        if offset == 0 {
+            return None;
+        }
+        let b = self.b;
+        let glyphs = covered(&b, offset + b.read_u16(offset)? as usize, self.plan);
+        if glyphs.is_empty() {
+            return None;
+        }
+        let mut ligatures = Vec::with_capacity(glyphs.len());
+        for &(_, index) in &glyphs {
+            let ligature = offset + b.read_u16(offset + 4 + index as usize * 2)? as usize;
+            let count = b.read_u16(ligature)? as usize;
+            let mut object = Object::new();
+            object.u16(count as u16);
+            for i in 0..count {
+                let caret = ligature + b.read_u16(ligature + 2 + i * 2)? as usize;
+                let mut value = Object::new();
+                value.bytes(b.read_bytes(caret, 4)?);
+                if b.read_u16(caret)? == 3 {
+                    let offset = caret + b.read_u16(caret + 4)? as usize;
+                    value.offset16(Some(device(&mut self.s, &b, offset)?));
+                }
+                object.offset16(Some(self.s.add(value)));
+            }
+            ligatures.push(self.s.add(object));
+        }
+        let coverage = coverage(&mut self.s, glyphs.iter().map(|g| g.0));
+        let mut object = Object::new();
+        object.offset16(Some(coverage));
+        object.u16(ligatures.len() as u16);
+        for ligature in ligatures {
+            object.offset16(Some(ligature));
+        }
+        Some(self.s.add(object))
+    }
+
+    fn mark_sets(&mut self, offset: usize) -> Option<ObjectId> {
+        if offset == 0 {
+            return None;
+        }
+        let b = self.b;
+        let count = b.read_u16(offset + 2)? as usize;
+        let mut object = Object::new();
+        object.u16(1);
+        object.u16(count as u16);
+        for i in 0..count {
+            let set = offset + b.read_u32(offset + 4 + i * 4)? as usize;
+            let glyphs = covered(&b, set, self.plan);
+            let coverage = coverage(&mut self.s, glyphs.iter().map(|g| g.0));
+            object.offset32(Some(coverage));
+        }
+        Some(self.s.add(object))
+    }
+}
@@ -0,0 +1,744 @@
+//! Font subsetting.
+//!
+//! A subset is described by a [`Plan`] that collects the glyphs required to
+//! render a set of codepoints and glyph identifiers. The glyph set is
+//! extended with the glyphs reachable through the retained `GSUB` features,
+//! color layers and composite glyphs. The plan then rewrites the glyph
+//! dependent tables of the font and emits a new font binary.
+//!
+//! The following tables are rewritten: `glyf`, `loca`, `hmtx`, `hhea`,
+//! `vmtx`, `vhea`, `maxp`, `head`, `post`, `name`, `OS/2`, `cmap`, `GSUB`,
+//! `GPOS`, `GDEF`, `COLR`, `CPAL`, `gvar`, `HVAR` and `VVAR`. Tables that
+//! are not glyph dependent are copied verbatim. Other glyph dependent tables
+//! are dropped when glyph identifiers are renumbered and copied otherwise.
+//!
+//! The `CFF` and `CFF2` tables are not rewritten, so fonts with these
+//! outlines can only be subset when glyph identifiers are retained.
+
+mod cmap;
+mod colr;
+mod layout;
+mod serialize;
+mod tables;
+
+use crate::build::FontBuilder;
+use crate::cff::CFF;
+use crate::cmap::{Charmap, Cmap, CMAP};
+use crate::colr::COLR;
+use crate::cpal::CPAL;
+use crate::font::{FontRef, TableProvider};
+use crate::gdef::GDEF;
+use crate::glyf::{GLYF, LOCA};
+use crate::gpos::GPOS;
+use crate::gsub::GSUB;
+use crate::head::HEAD;
+use crate::hhea::HHEA;
+use crate::hmtx::HMTX;
+use crate::hvar::HVAR;
+use crate::maxp::MAXP;
+use crate::name::{NameId, NAME};
+use crate::os2::OS2;
+use crate::otl::GlyphSet;
+use crate::parse_prelude::*;
+use crate::post::POST;
+use crate::vhea::VHEA;
+use crate::vmtx::VMTX;
+use crate::vvar::VVAR;
+use core::fmt;
+use std::vec::Vec;
+
+const CFF2: Tag = Tag::new(b"CFF2");
+const DSIG: Tag = Tag::new(b"DSIG");
+const GVAR: Tag = Tag::new(b"gvar");
+
+/// Glyph dependent tables that are dropped when glyph identifiers are
+/// renumbered.
+const UNSUPPORTED: [Tag; 17] = [
+    Tag::new(b"kern"),
+    Tag::new(b"hdmx"),
+    Tag::new(b"LTSH"),
+    Tag::new(b"VORG"),
+    Tag::new(b"EBDT"),
+    Tag::new(b"EBLC"),
+    Tag::new(b"EBSC"),
+    Tag::new(b"CBDT"),
+    Tag::new(b"CBLC"),
+    Tag::new(b"sbix"),
+    Tag::new(b"SVG "),
+    Tag::new(b"MATH"),
+    Tag::new(b"JSTF"),
+    Tag::new(b"BASE"),
+    Tag::new(b"morx"),
+    Tag::new(b"mort"),
+    Tag::new(b"kerx"),
+];
+
+/// Error that occurred while building a subset.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum SubsetError {
+    /// A table required to build the subset is missing or malformed.
+    InvalidTable(Tag),
+    /// The outlines in the specified table can only be subset when glyph
+    /// identifiers are retained.
+    UnsupportedOutlines(Tag),
+}
+
+impl fmt::Display for SubsetError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Self::InvalidTable(tag) => write!(f, "missing or malformed '{}' table", tag),
+            Self::UnsupportedOutlines(tag) => write!(
+                f,
+                "outlines in the '{}' table require retained glyph identifiers",
+                tag
+            ),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for SubsetError {}
+
+/// Options that control the content of a subset.
+#[derive(Copy, Clone, Default, Debug)]
+pub struct SubsetOptions<'a> {
+    /// Preserve the original glyph identifiers. Glyphs that are not in the
+    /// subset are replaced with empty glyphs. This is required for fonts
+    /// with `CFF` or `CFF2` outlines, which are copied unchanged.
+    pub retain_gids: bool,
+    /// Layout features to retain. All features are retained if `None`.
+    pub features: Option<&'a [Tag]>,
+    /// Name identifiers to retain. All names are retained if `None`.
+    pub name_ids: Option<&'a [NameId]>,
+}
+
+/// Set of glyphs and mappings that describe a subset of a font.
+#[derive(Clone, Debug)]
+pub struct Plan {
+    glyphs: GlyphSet,
+    old_to_new: Vec<Option<GlyphId>>,
+    new_to_old: Vec<Option<GlyphId>>,
+    mapping: Vec<(u32, GlyphId)>,
+    codepoints: Vec<u32>,
+    retain_gids: bool,
+    features: Option<Vec<Tag>>,
+    name_ids: Option<Vec<NameId>>,
+}
+
+impl Plan {
+    /// Creates a new subset plan for the specified font that retains the
+    /// glyphs for the specified codepoints and glyph identifiers along with
+    /// all glyphs that are reachable from them.
+    pub fn new<'a>(
+        font: &impl TableProvider<'a>,
+        codepoints: &[u32],
+        glyph_ids: &[GlyphId],
+        options: &SubsetOptions,
+    ) -> Option<Self> {
+        let num_glyphs = font.maxp()?.num_glyphs();
+        let mut codepoints = codepoints.to_vec();
+        codepoints.sort_unstable();
+        codepoints.dedup();
+        let mut glyphs = GlyphSet::new();
+        glyphs.insert(0);
+        glyphs.extend(glyph_ids.iter().copied());
+        let mut mapping = Vec::new();
+        if let Some(data) = font.table_data(CMAP) {
+            let charmap = Charmap::new(&Cmap::new(data));
+            let subtable = charmap.subtable();
+            for &codepoint in &codepoints {
+                let glyph_id = match charmap.map(codepoint).filter(|&id| id != 0) {
+                    Some(glyph_id) => glyph_id,
+                    None => continue,
+                };
+                // Symbol fonts map the byte range through the private use
+                // area, so keep the codepoint that is present in the font.
+                let is_remapped = charmap.is_symbol()
+                    && codepoint <= 0xFF
+                    && subtable.and_then(|s| s.map(codepoint)).unwrap_or(0) == 0;
+                let codepoint = if is_remapped {
+                    codepoint + 0xF000
+                } else {
+                    codepoint
+                };
+                mapping.push((codepoint, glyph_id));
+                glyphs.insert(glyph_id);
+            }
+            mapping.sort_unstable();
+            mapping.dedup();
+            cmap::close_variants(data, &codepoints, &mut glyphs);
+        }
+        if let Some(gsub) = font.gsub() {
+            glyphs = match options.features {
+                Some(features) => gsub.feature_closure(features, &glyphs),
+                None => {
+                    let features = gsub.features().map(|f| f.record.tag).collect::<Vec<_>>();
+                    gsub.feature_closure(&features, &glyphs)
+                }
+            };
+        }
+        if let Some(colr) = font.table_data(COLR) {
+            colr::close_glyphs(colr, &mut glyphs);
+        }
+        if let (Some(loca), Some(glyf)) = (font.loca(), font.glyf()) {
+            let mut stack = glyphs.iter().collect::<Vec<_>>();
+            while let Some(glyph_id) = stack.pop() {
+                let glyph = loca.get(glyph_id).and_then(|range| glyf.get(range));
+                for component in glyph.iter().flat_map(|glyph| glyph.components()) {
+                    if glyphs.insert(component.glyph_id) {
+                        stack.push(component.glyph_id);
+                    }
+                }
+            }
+        }
+        let out_of_range = glyphs
+            .iter()
+            .filter(|&id| id >= num_glyphs)
+            .collect::<Vec<_>>();
+        for glyph_id in out_of_range {
+            glyphs.remove(glyph_id);
+        }
+        let mut old_to_new = std::vec![None; num_glyphs as usize];
+        let mut new_to_old = Vec::new();
+        if options.retain_gids {
+            let len = if font.table_data(CFF).is_some() || font.table_data(CFF2).is_some() {
+                num_glyphs
+            } else {
+                glyphs.iter().last().unwrap_or(0) + 1
+            };
+            new_to_old.resize(len as usize, None);
+            for glyph_id in glyphs.iter() {
+                old_to_new[glyph_id as usize] = Some(glyph_id);
+                new_to_old[glyph_id as usize] = Some(glyph_id);
+            }
+        } else {
+            for glyph_id in glyphs.iter() {
+                old_to_new[glyph_id as usize] = Some(new_to_old.len() as GlyphId);
+                new_to_old.push(Some(glyph_id));
+            }
+        }
+        mapping.retain(|&(_, glyph_id)| glyphs.contains(glyph_id));
+        Some(Self {
+            glyphs,
+            old_to_new,
+            new_to_old,
+            mapping,
+            codepoints,
+            retain_gids: options.retain_gids,
+            features: options.features.map(|features| features.to_vec()),
+            name_ids: options.name_ids.map(|name_ids| name_ids.to_vec()),
+        })
+    }
+
+    /// Returns the set of original glyph identifiers that are retained in
+    /// the subset.
+    pub fn glyphs(&self) -> &GlyphSet {
+        &self.glyphs
+    }
+
+    /// Returns the number of glyphs in the subset.
+    pub fn num_glyphs(&self) -> u16 {
+        self.new_to_old.len() as u16
+    }
+
+    /// Returns the glyph identifier in the subset for the specified original
+    /// glyph identifier.
+    pub fn map(&self, glyph_id: GlyphId) -> Option<GlyphId> {
+        self.old_to_new.get(glyph_id as usize).copied().flatten()
+    }
+
+    /// Returns the original glyph identifier for the specified glyph
+    /// identifier in the subset.
+    pub fn original(&self, glyph_id: GlyphId) -> Option<GlyphId> {
+        self.new_to_old.get(glyph_id as usize).copied().flatten()
+    }
+
+    /// Returns the mapping from codepoints to glyph identifiers in the
+    /// subset, sorted by codepoint.
+    pub fn mapping(&self) -> impl Iterator<Item = (u32, GlyphId)> + '_ + Clone {
+        self.mapping
+            .iter()
+            .filter_map(move |&(codepoint, glyph_id)| Some((codepoint, self.map(glyph_id)?)))
+    }
+
+    /// Builds the subset of the specified font. Returns an error if a
+    /// required table is malformed or if the font contains `CFF` or `CFF2`
+    /// outlines and glyph identifiers are not retained.
+    pub fn subset(&self, font: &FontRef) -> Result<Vec<u8>, SubsetError> {
+        if !self.retain_gids {
+            for tag in [CFF, CFF2] {
+                if font.find_record(tag).is_some() {
+                    return Err(SubsetError::UnsupportedOutlines(tag));
+                }
+            }
+        }
+        let invalid = SubsetError::InvalidTable;
+        let mut tables: Vec<(Tag, Vec<u8>)> = Vec::new();
+        let mut index_to_location_format = None;
+        let colr = match font.table_data(COLR) {
+            Some(data) => Some(colr::subset(data, self).ok_or(invalid(COLR))?),
+            None => None,
+        };
+        for table in font.tables() {
+            let tag = table.record.tag;
+            let data = table.data;
+            let subset = match tag {
+                GLYF => {
+                    let loca = font.loca().ok_or(invalid(LOCA))?;
+                    let (glyf, loca, is_long) =
+                        tables::subset_glyf(data, loca, self).ok_or(invalid(tag))?;
+                    index_to_location_format = Some(is_long as i16);
+                    tables.push((LOCA, loca));
+                    Some(glyf)
+                }
+                LOCA | DSIG => continue,
+                HMTX => {
+                    let hhea = font.hhea().ok_or(invalid(HHEA))?;
+                    tables::subset_metrics(data, hhea.num_long_metrics(), self)
+                }
+                VMTX => {
+                    let vhea = font.vhea().ok_or(invalid(VHEA))?;
+                    tables::subset_metrics(data, vhea.num_long_metrics(), self)
+                }
+                MAXP => tables::subset_maxp(data, self),
+                POST => tables::subset_post(data, self),
+                NAME => tables::subset_name(data, self),
+                OS2 => Some(tables::subset_os2(data, self)),
+                CMAP => cmap::subset(data, self),
+                GSUB | GPOS => layout::subset(data, tag == GPOS, self),
+                GDEF => layout::subset_gdef(data, self),
+                COLR => match &colr {
+                    Some(colr) => Some(colr.colr.clone()),
+                    None => continue,
+                },
+                CPAL => match &colr {
+                    Some(colr) => colr::subset_cpal(data, &colr.palette_map),
+                    None => Some(data.to_vec()),
+                },
+                GVAR => tables::subset_gvar(data, self),
+                HVAR if !self.retain_gids => tables::subset_metrics_variations(data, 3, self),
+                VVAR if !self.retain_gids => tables::subset_metrics_variations(data, 4, self),
+                _ if !self.retain_gids && UNSUPPORTED.contains(&tag) => continue,
+                _ => Some(data.to_vec()),
+            };
+            tables.push((tag, subset.ok_or(invalid(tag))?));
+        }
+        for (header_tag, metrics_tag) in [(HHEA, HMTX), (VHEA, VMTX)] {
+            let num_long_metrics = match tables.iter().find(|(tag, _)| *tag == metrics_tag) {
+                Some((_, data)) => tables::num_long_metrics(data, self),
+                None => continue,
+            };
+            if let Some((_, header)) = tables.iter_mut().find(|(tag, _)| *tag == header_tag) {
+                if header.len() < 36 {
+                    return Err(invalid(header_tag));
+                }
+                tables::set_u16(header, 34, num_long_metrics);
+            }
+        }
+        if let Some((_, head)) = tables.iter_mut().find(|(tag, _)| *tag == HEAD) {
+            if head.len() < 54 {
+                return Err(invalid(HEAD));
+            }
+            if let Some(format) = index_to_location_format {
+                tables::set_u16(head, 50, format as u16);
+            }
+        }
+        let sfnt_version = Buffer::new(font.data)
+            .read_u32(font.offset as usize)
+            .ok_or(invalid(HEAD))?;
+        let mut builder = FontBuilder::new(sfnt_version);
+        for (tag, data) in tables {
+            builder.insert(tag, data);
+        }
+        builder.build().map_err(|_| invalid(HEAD))
+    }
+}
+
+/// Builds a subset of the specified font that retains the glyphs for the
+/// specified codepoints and glyph identifiers.
+pub fn subset(
+    font: &FontRef,
+    codepoints: &[u32],
+    glyph_ids: &[GlyphId],
+    options: &SubsetOptions,
+) -> Result<Vec<u8>, SubsetError> {
+    Plan::new(font, codepoints, glyph_ids, options)
+        .ok_or(SubsetError::InvalidTable(MAXP))?
+        .subset(font)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::colr::Paint;
+    use crate::otl::{Direction, GlyphInfo, GlyphPosition, Positioner, Substitutor, MARK_CLASS};
+    use crate::testing::{
+        cmap4, layout, ligature_subst, lookup, mark_base_pos, name, pair_pos, single_subst,
+        TestFont, Writer,
+    };
+    use crate::var::item::Index;
+
+    /// Returns a horizontal metrics variations table with an implicit
+    /// advance mapping and an advance delta of ten units per glyph
+    /// identifier at the peak of a single region.
+    fn hvar(num_glyphs: u16) -> Vec<u8> {
+        let mut w = Writer::new();
+        w.u32(0x00010000).u32(20).u32(0).u32(0).u32(0);
+        w.u16(1).u32(12).u16(1).u32(22);
+        w.u16(1).u16(1).i16(0).i16(0x4000).i16(0x4000);
+        w.u16(num_glyphs).u16(1).u16(1).u16(0);
+        for glyph_id in 0..num_glyphs {
+            w.i16(glyph_id as i16 * 10);
+        }
+        w.finish()
+    }
+
+    #[test]
+    fn renumbered_glyphs() {
+        let data = TestFont::new(5)
+            .chars("abcd")
+            .table(b"HVAR", hvar(5))
+            .build();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        let data = subset(&font, &['c' as u32], &[], &SubsetOptions::default()).unwrap();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        assert_eq!(font.maxp().unwrap().num_glyphs(), 2);
+        assert_eq!(font.cmap().unwrap().map('c' as u32), Some(1));
+        assert_eq!(font.cmap().unwrap().map('a' as u32), None);
+        assert_eq!(
+            font.hmtx()
+                .unwrap()
+                .hmetrics()
+                .get(1)
+                .unwrap()
+                .advance_width,
+            530
+        );
+        let hvar = font.hvar().unwrap();
+        let ivs = hvar.ivs().unwrap();
+        let index = hvar.advance_mapping().unwrap().get(1).unwrap();
+        assert_eq!((index.outer, index.inner), (0, 3));
+        assert_eq!(ivs.delta(index, &[0x4000]), Fixed::from_i32(30));
+        let index = Index { outer: 0, inner: 0 };
+        assert_eq!(ivs.delta(index, &[0x4000]), Fixed::ZERO);
+    }
+
+    #[test]
+    fn cff_outlines() {
+        let data = TestFont::new(3)
+            .chars("ab")
+            .table(b"CFF ", std::vec![1, 0, 4, 1])
+            .build();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        assert_eq!(
+            subset(&font, &['b' as u32], &[], &SubsetOptions::default()),
+            Err(SubsetError::UnsupportedOutlines(CFF))
+        );
+        let options = SubsetOptions {
+            retain_gids: true,
+            ..Default::default()
+        };
+        let data = subset(&font, &['b' as u32], &[], &options).unwrap();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        assert_eq!(font.cmap().unwrap().map('b' as u32), Some(2));
+    }
+
+    #[test]
+    fn legacy_and_symbol_character_maps() {
+        // A Mac Roman subtable that maps every byte to .notdef precedes the
+        // Unicode subtable.
+        let mut cmap = Writer::new();
+        cmap.u16(0)
+            .u16(2)
+            .u16(1)
+            .u16(0)
+            .u32(20)
+            .u16(3)
+            .u16(1)
+            .u32(282);
+        cmap.u16(0).u16(262).u16(0).bytes(&[0; 256]);
+        cmap.bytes(&cmap4(&[(0x42, 2), (0xE9, 3)])[12..]);
+        let data = TestFont::new(4).table(b"cmap", cmap.finish()).build();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        let data = subset(&font, &[0xE9], &[], &SubsetOptions::default()).unwrap();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        assert_eq!(font.maxp().unwrap().num_glyphs(), 2);
+        assert_eq!(font.charmap().unwrap().map(0xE9), Some(1));
+        // Symbol fonts map the byte range through the private use area.
+        let mut cmap = Writer::new();
+        cmap.u16(0).u16(1).u16(3).u16(0).u32(12);
+        cmap.bytes(&cmap4(&[(0xF041, 2), (0xF042, 3)])[12..]);
+        let data = TestFont::new(4).table(b"cmap", cmap.finish()).build();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        let data = subset(&font, &[0x41, 0xF041], &[], &SubsetOptions::default()).unwrap();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        assert_eq!(font.maxp().unwrap().num_glyphs(), 2);
+        let charmap = font.charmap().unwrap();
+        assert!(charmap.is_symbol());
+        assert_eq!(charmap.map(0x41), Some(1));
+        assert_eq!(charmap.map(0xF041), Some(1));
+        assert_eq!(charmap.map(0x42), None);
+    }
+
+    /// Returns a font with f, i, x and m mapped to glyphs 1 to 4 where
+    /// f i -> fi (5) and x -> x.alt (6). The pairs f i and x m are kerned
+    /// and m attaches as a mark to f and i.
+    fn layout_font() -> Vec<u8> {
+        let gsub = layout(
+            b"DFLT",
+            &[(b"liga", &[0]), (b"salt", &[1])],
+            &[
+                lookup(4, 0, &[ligature_subst(&[(&[1, 2], 5)])]),
+                lookup(1, 0, &[single_subst(&[(3, 6)])]),
+            ],
+        );
+        let gpos = layout(
+            b"DFLT",
+            &[(b"kern", &[0]), (b"mark", &[1])],
+            &[
+                lookup(2, 0, &[pair_pos(&[(1, 2, -50), (3, 4, -20)])]),
+                lookup(
+                    4,
+                    0,
+                    &[mark_base_pos(
+                        &[(4, 50, 0)],
+                        &[(1, 250, 600), (2, 300, 600)],
+                    )],
+                ),
+            ],
+        );
+        let mut gdef = Writer::new();
+        gdef.u32(0x00010000).u16(12).u16(0).u16(0).u16(0);
+        gdef.u16(2).u16(3);
+        gdef.u16(1)
+            .u16(3)
+            .u16(1)
+            .u16(4)
+            .u16(4)
+            .u16(3)
+            .u16(5)
+            .u16(6)
+            .u16(2);
+        TestFont::new(7)
+            .chars("fixm")
+            .table(b"GSUB", gsub)
+            .table(b"GPOS", gpos)
+            .table(b"GDEF", gdef.finish())
+            .build()
+    }
+
+    #[test]
+    fn layout_tables() {
+        let data = layout_font();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        let codepoints = ['f' as u32, 'i' as u32, 'm' as u32];
+        // The ligature is reachable through the closure of liga while the
+        // alternate of x is not.
+        let plan = Plan::new(&font, &codepoints, &[], &SubsetOptions::default()).unwrap();
+        assert_eq!(plan.glyphs().iter().collect::<Vec<_>>(), [0, 1, 2, 4, 5]);
+        let data = plan.subset(&font).unwrap();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        let gdef = font.gdef().unwrap();
+        let classes = (0..6).map(|id| gdef.class(id)).collect::<Vec<_>>();
+        assert_eq!(classes, [0, 1, 1, 3, 2, 0]);
+        let gsub = font.gsub().unwrap();
+        let substitutor = Substitutor::new(&gsub);
+        let mut glyphs = [1, 2, 3]
+            .iter()
+            .enumerate()
+            .map(|(i, glyph_id)| GlyphInfo::new(*glyph_id, i as u32))
+            .collect::<Vec<_>>();
+        assert!(substitutor.apply_lookup(0, !0, 0, &mut glyphs));
+        let ids = glyphs.iter().map(|g| g.glyph_id).collect::<Vec<_>>();
+        assert_eq!(ids, [4, 3]);
+        let gpos = font.gpos().unwrap();
+        let positioner = Positioner::new(&gpos, &[], Direction::LeftToRight);
+        glyphs = [1, 2, 3]
+            .iter()
+            .enumerate()
+            .map(|(i, glyph_id)| GlyphInfo::new(*glyph_id, i as u32))
+            .collect::<Vec<_>>();
+        glyphs[2].class = MARK_CLASS;
+        let mut positions = [GlyphPosition::new(510, 0); 3];
+        assert!(positioner.apply_lookup(0, !0, &glyphs, &mut positions));
+        assert!(positioner.apply_lookup(1, !0, &glyphs, &mut positions));
+        positioner.finish(&mut positions);
+        let values = positions
+            .iter()
+            .map(|p| (p.x_advance, p.x_offset, p.y_offset))
+            .collect::<Vec<_>>();
+        assert_eq!(values, [(460, 0, 0), (510, 0, 0), (510, -260, 600)]);
+    }
+
+    #[test]
+    fn retained_features() {
+        let data = layout_font();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        let features = [Tag::new(b"liga"), Tag::new(b"kern")];
+        let options = SubsetOptions {
+            features: Some(&features),
+            ..Default::default()
+        };
+        let data = subset(&font, &['f' as u32, 'i' as u32], &[], &options).unwrap();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        let gsub = font.gsub().unwrap();
+        let tags = gsub.features().map(|f| f.record.tag).collect::<Vec<_>>();
+        assert_eq!(tags, [Tag::new(b"liga")]);
+        assert_eq!(gsub.num_lookups(), 1);
+        let gpos = font.gpos().unwrap();
+        let tags = gpos.features().map(|f| f.record.tag).collect::<Vec<_>>();
+        assert_eq!(tags, [Tag::new(b"kern")]);
+        assert_eq!(gpos.num_lookups(), 1);
+    }
+
+    #[test]
+    fn composite_glyphs() {
+        // Replace the last glyph with a composite of glyphs 2 and 4.
+        let data = TestFont::new(6).chars("abcde").build();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        let loca = font.loca().unwrap();
+        let mut glyf = Writer::new();
+        let mut new_loca = Writer::new();
+        for glyph_id in 0..5 {
+            new_loca.u32(glyf.len() as u32);
+            let range = loca.get(glyph_id).unwrap();
+            glyf.bytes(&font.table_data(GLYF).unwrap()[range]);
+        }
+        new_loca.u32(glyf.len() as u32);
+        glyf.i16(-1).i16(0).i16(0).i16(400).i16(400);
+        glyf.u16(0x0022).u16(2).u8(0).u8(0);
+        glyf.u16(0x0002).u16(4).u8(10).u8(0);
+        new_loca.u32(glyf.len() as u32);
+        let data = TestFont::new(6)
+            .chars("abcde")
+            .table(b"glyf", glyf.finish())
+            .table(b"loca", new_loca.finish())
+            .build();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        let data = subset(&font, &['e' as u32], &[], &SubsetOptions::default()).unwrap();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        assert_eq!(font.maxp().unwrap().num_glyphs(), 4);
+        assert_eq!(font.cmap().unwrap().map('e' as u32), Some(3));
+        let glyf = font.glyf().unwrap();
+        let loca = font.loca().unwrap();
+        let glyph = glyf.get(loca.get(3).unwrap()).unwrap();
+        let components = glyph.components().map(|c| c.glyph_id).collect::<Vec<_>>();
+        assert_eq!(components, [1, 2]);
+        // The components keep their outlines.
+        let component = glyf.get(loca.get(2).unwrap()).unwrap();
+        assert_eq!(component.x_max(), 400);
+    }
+
+    #[test]
+    fn glyph_names_and_naming_table() {
+        let mut post = Writer::new();
+        post.u32(0x00020000);
+        for _ in 0..7 {
+            post.u32(0);
+        }
+        post.u16(4).u16(0).u16(68).u16(258).u16(259);
+        post.u8(10).bytes(b"alpha.ss01").u8(4).bytes(b"beta");
+        let names = name(
+            &[
+                (3, 1, 0x0409, 1, "Family"),
+                (3, 1, 0x0409, 2, "Regular"),
+                (3, 1, 0x0409, 4, "Family"),
+                (3, 1, 0x0409, 13, "License"),
+            ],
+            &[],
+        );
+        let data = TestFont::new(4)
+            .chars("abc")
+            .table(b"post", post.finish())
+            .table(b"name", names)
+            .build();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        let name_ids = [1, 2, 4];
+        let options = SubsetOptions {
+            name_ids: Some(&name_ids),
+            ..Default::default()
+        };
+        let data = subset(&font, &['c' as u32], &[1], &options).unwrap();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        let post = font.post().unwrap();
+        let glyph_names = post.glyph_names().collect::<Vec<_>>();
+        assert_eq!(glyph_names, [Some(".notdef"), Some("a"), Some("beta")]);
+        // Only the names of the retained glyphs are stored.
+        assert_eq!(font.table_data(POST).unwrap().len(), 32 + 2 + 6 + 5);
+        let name = font.name().unwrap();
+        assert_eq!(name.records().len(), 3);
+        let find = |name_id| {
+            let entry = name.find(name_id, &[], None)?;
+            Some(entry.decode().collect::<std::string::String>())
+        };
+        assert_eq!(find(4).as_deref(), Some("Family"));
+        assert_eq!(find(2).as_deref(), Some("Regular"));
+        assert_eq!(find(13), None);
+        // Equal strings share storage.
+        let records = name.records();
+        assert_eq!(
+            records.get(0).unwrap().offset,
+            records.get(2).unwrap().offset
+        );
+    }
+
+    #[test]
+    fn color_glyphs() {
+        // Glyph 1 has layers 4 and 5 and glyph 2 paints glyph 6 with a solid
+        // color. Glyph 7 is painted with a color that is not retained.
+        let mut colr = Writer::new();
+        colr.u16(1).u16(1).u32(34).u32(40).u16(2);
+        colr.u32(48).u32(0).u32(0).u32(0).u32(0);
+        colr.u16(1).u16(0).u16(2);
+        colr.u16(4).u16(2).u16(5).u16(0xFFFF);
+        colr.u32(2).u16(2).u32(16).u16(7).u32(27);
+        colr.u8(10).u8(0).u16(6).u16(6);
+        colr.u8(2).u16(1).u16(0x4000);
+        colr.u8(2).u16(3).u16(0x4000);
+        let mut cpal = Writer::new();
+        cpal.u16(0).u16(4).u16(1).u16(4).u32(14).u16(0);
+        for index in 0..4 {
+            cpal.u8(index).u8(0).u8(0).u8(255);
+        }
+        let data = TestFont::new(8)
+            .chars("ab")
+            .table(b"COLR", colr.finish())
+            .table(b"CPAL", cpal.finish())
+            .build();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        let data = subset(
+            &font,
+            &['a' as u32, 'b' as u32],
+            &[],
+            &SubsetOptions::default(),
+        )
+        .unwrap();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        assert_eq!(font.maxp().unwrap().num_glyphs(), 6);
+        let colr = font.colr().unwrap();
+        let layers = colr
+            .find_glyph(1)
+            .unwrap()
+            .layers
+            .iter()
+            .map(|layer| (layer.gid, layer.palette_index))
+            .collect::<Vec<_>>();
+        assert_eq!(layers, [(3, Some(1)), (4, None)]);
+        assert_eq!(colr.num_base_paints(), 1);
+        let (id, paint) = match colr.find_base_paint(2) {
+            Some(Paint::Glyph { id, paint }) => (id, paint.get()),
+            _ => panic!("expected a glyph paint"),
+        };
+        assert_eq!(id, 5);
+        assert!(matches!(
+            paint,
+            Some(Paint::Solid {
+                palette_index: 0,
+                ..
+            })
+        ));
+        let palette = font.cpal().unwrap().get(0).unwrap();
+        let blue = palette.colors.iter().map(|c| c.b).collect::<Vec<_>>();
+        assert_eq!(blue, [1, 2]);
+    }
+}
@@ -0,0 +1,188 @@
+use core::convert::TryFrom;
+use std::collections::{HashMap, VecDeque};
+use std::vec::Vec;
+
+/// Identifier of an object added to a serializer.
+pub type ObjectId = u32;
+
+/// Link from a position in an object to another object.
+#[derive(Copy, Clone, PartialEq, Eq, Hash, Debug)]
+struct Link {
+    pos: u32,
+    width: u8,
+    target: ObjectId,
+}
+
+/// Table or subtable with offsets to other objects.
+#[derive(Clone, Default, PartialEq, Eq, Hash, Debug)]
+pub struct Object {
+    data: Vec<u8>,
+    links: Vec<Link>,
+}
+
+impl Object {
+    /// Creates a new empty object.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Returns the data of the object.
+    pub fn data(&self) -> &[u8] {
+        &self.data
+    }
+
+    pub fn u8(&mut self, value: u8) {
+        self.data.push(value);
+    }
+
+    pub fn u16(&mut self, value: u16) {
+        self.data.extend_from_slice(&value.to_be_bytes());
+    }
+
+    pub fn u32(&mut self, value: u32) {
+        self.data.extend_from_slice(&value.to_be_bytes());
+    }
+
+    pub fn bytes(&mut self, bytes: &[u8]) {
+        self.data.extend_from_slice(bytes);
+    }
+
+    /// Overwrites the 16-bit value at the specified position.
+    pub fn set_u16(&mut self, pos: usize, value: u16) {
+        self.data[pos..pos + 2].copy_from_slice(&value.to_be_bytes());
+    }
+
+    /// Writes a 16-bit offset to the specified object or a null offset.
+    pub fn offset16(&mut self, target: Option<ObjectId>) {
+        self.offset(target, 2);
+    }
+
+    /// Writes a 24-bit offset to the specified object or a null offset.
+    pub fn offset24(&mut self, target: Option<ObjectId>) {
+        self.offset(target, 3);
+    }
+
+    /// Writes a 32-bit offset to the specified object or a null offset.
+    pub fn offset32(&mut self, target: Option<ObjectId>) {
+        self.offset(target, 4);
+    }
+
+    /// Replaces the placeholder at the specified position with an offset to
+    /// the target object.
+    pub fn set_offset(&mut self, pos: usize, width: u8, target: ObjectId) {
+        self.links.push(Link {
+            pos: pos as u32,
+            width,
+            target,
+        });
+    }
+
+    fn offset(&mut self, target: Option<ObjectId>, width: u8) {
+        if let Some(target) = target {
+            self.set_offset(self.data.len(), width, target);
+        }
+        self.data.resize(self.data.len() + width as usize, 0);
+    }
+}
+
+/// Collects a graph of objects and packs them into a single table,
+/// resolving the offsets between them.
+///
+/// Objects must be added after all of the objects that they link to. When
+/// enabled, byte identical objects are shared.
+pub struct Serializer {
+    objects: Vec<Object>,
+    ids: HashMap<Object, ObjectId>,
+    dedup: bool,
+}
+
+impl Serializer {
+    pub fn new(dedup: bool) -> Self {
+        Self {
+            objects: Vec::new(),
+            ids: HashMap::new(),
+            dedup,
+        }
+    }
+
+    /// Adds an object to the graph, returning its identifier.
+    pub fn add(&mut self, object: Object) -> ObjectId {
+        if self.dedup {
+            if let Some(id) = self.ids.get(&object) {
+                return *id;
+            }
+        }
+        let id = self.objects.len() as ObjectId;
+        if self.dedup {
+            self.ids.insert(object.clone(), id);
+        }
+        self.objects.push(object);
+        id
+    }
+
+    /// Adds an object containing the specified bytes.
+    pub fn add_bytes(&mut self, bytes: &[u8]) -> ObjectId {
+        let mut object = Object::new();
+        object.bytes(bytes);
+        self.add(object)
+    }
+
+    /// Packs the objects reachable from the root into a table. Objects are
+    /// placed after all of the objects that link to them, in depth first
+    /// order except that objects reached through 32-bit offsets are placed
+    /// after everything else. Returns `None` if an offset overflows.
+    pub fn pack(&self, root: ObjectId) -> Option<Vec<u8>> {
+        let count = self.objects.len();
+        let mut parents = std::vec![0u32; count];
+        let mut visited = std::vec![false; count];
+        let mut stack = std::vec![root];
+        visited[root as usize] = true;
+        while let Some(id) = stack.pop() {
+            for link in &self.objects[id as usize].links {
+                parents[link.target as usize] += 1;
+                if !visited[link.target as usize] {
+                    visited[link.target as usize] = true;
+                    stack.push(link.target);
+                }
+            }
+        }
+        let mut positions = std::vec![0usize; count];
+        let mut order = Vec::new();
+        let mut deferred = VecDeque::new();
+        let mut size = 0;
+        stack.push(root);
+        while let Some(id) = stack.pop().or_else(|| deferred.pop_front()) {
+            positions[id as usize] = size;
+            size += self.objects[id as usize].data.len();
+            order.push(id);
+            for link in self.objects[id as usize].links.iter().rev() {
+                let remaining = &mut parents[link.target as usize];
+                *remaining -= 1;
+                if *remaining == 0 {
+                    if link.width == 4 {
+                        deferred.push_back(link.target);
+                    } else {
+                        stack.push(link.target);
+                    }
+                }
+            }
+        }
+        let mut data = Vec::with_capacity(size);
+        for id in order {
+            let object = &self.objects[id as usize];
+            let start = data.len();
+            data.extend_from_slice(&object.data);
+            for link in &object.links {
+                let offset = positions[link.target as usize] - positions[id as usize];
+                let width = link.width as usize;
+                if width < 4 && offset >> (width * 8) != 0 {
+                    return None;
+                }
+                let offset = u32::try_from(offset).ok()?.to_be_bytes();
+                let pos = start + link.pos as usize;
+                data[pos..pos + width].copy_from_slice(&offset[4 - width..]);
+            }
+        }
+        Some(data)
+    }
+}
@@ -0,0 +1,397 @@
+//! Subsetting for glyph outlines, metrics and simple glyph dependent tables.
+
+use super::Plan;
+use crate::glyf::{Glyf, Loca};
+use crate::parse_prelude::*;
+use crate::var::item::DeltaSetIndexMap;
+use core::convert::TryFrom;
+use std::collections::HashMap;
+use std::vec::Vec;
+
+/// Number of glyphs with names in the standard Macintosh ordering.
+const NUM_STANDARD_NAMES: u16 = 258;
+
+pub fn set_u16(data: &mut [u8], offset: usize, value: u16) {
+    data[offset..offset + 2].copy_from_slice(&value.to_be_bytes());
+}
+
+pub fn set_u32(data: &mut [u8], offset: usize, value: u32) {
+    data[offset..offset + 4].copy_from_slice(&value.to_be_bytes());
+}
+
+/// Returns the subset glyph data and index to location tables along with a
+/// flag indicating whether the index to location table contains 32-bit
+/// offsets.
+pub fn subset_glyf(data: &[u8], loca: Loca, plan: &Plan) -> Option<(Vec<u8>, Vec<u8>, bool)> {
+    let glyf = Glyf::new(data);
+    let mut new_glyf = Vec::new();
+    let mut offsets = Vec::with_capacity(plan.num_glyphs() as usize + 1);
+    for glyph_id in 0..plan.num_glyphs() {
+        offsets.push(new_glyf.len());
+        let glyph = match plan
+            .original(glyph_id)
+            .and_then(|glyph_id| glyf.get(loca.get(glyph_id)?))
+        {
+            Some(glyph) => glyph,
+            None => continue,
+        };
+        let start = new_glyf.len();
+        new_glyf.extend_from_slice(glyph.data());
+        for component in glyph.components() {
+            let glyph_id = plan.map(component.glyph_id).unwrap_or(0);
+            set_u16(&mut new_glyf, start + component.glyph_id_offset, glyph_id);
+        }
+        new_glyf.resize((new_glyf.len() + 1) & !1, 0);
+    }
+    offsets.push(new_glyf.len());
+    let is_long = new_glyf.len() > 0x1FFFE;
+    let mut new_loca = Vec::new();
+    for offset in offsets {
+        if is_long {
+            new_loca.extend_from_slice(&(offset as u32).to_be_bytes());
+        } else {
+            new_loca.extend_from_slice(&((offset / 2) as u16).to_be_bytes());
+        }
+    }
+    Some((new_glyf, new_loca, is_long))
+}
+
+/// Returns the subset horizontal or vertical metrics table. The number of
+/// long metrics is minimized by collapsing trailing glyphs with the same
+/// advance.
+pub fn subset_metrics(data: &[u8], num_long_metrics: u16, plan: &Plan) -> Option<Vec<u8>> {
+    let b = Buffer::new(data);
+    let num_long_metrics = num_long_metrics as usize;
+    if num_long_metrics == 0 {
+        return None;
+    }
+    let mut metrics = Vec::with_capacity(plan.num_glyphs() as usize);
+    for glyph_id in 0..plan.num_glyphs() {
+        let metric = match plan.original(glyph_id) {
+            Some(glyph_id) => {
+                let index = glyph_id as usize;
+                if index < num_long_metrics {
+                    (b.read_u16(index * 4)?, b.read_i16(index * 4 + 2)?)
+                } else {
+                    let advance = b.read_u16((num_long_metrics - 1) * 4)?;
+                    let offset = num_long_metrics * 4 + (index - num_long_metrics) * 2;
+                    (advance, b.read_i16(offset).unwrap_or(0))
+                }
+            }
+            None => (0, 0),
+        };
+        metrics.push(metric);
+    }
+    let mut len = metrics.len();
+    while len > 1 && metrics[len - 2].0 == metrics[len - 1].0 {
+        len -= 1;
+    }
+    let mut new_data = Vec::with_capacity(len * 2 + metrics.len() * 2);
+    for (i, (advance, bearing)) in metrics.iter().enumerate() {
+        if i < len {
+            new_data.extend_from_slice(&advance.to_be_bytes());
+        }
+        new_data.extend_from_slice(&bearing.to_be_bytes());
+    }
+    Some(new_data)
+}
+
+/// Returns the number of long metrics in a metrics table produced by
+/// [`subset_metrics`].
+pub fn num_long_metrics(data: &[u8], plan: &Plan) -> u16 {
+    ((data.len() - plan.num_glyphs() as usize * 2) / 2) as u16
+}
+
+/// Returns the maximum profile table with an updated glyph count.
+pub fn subset_maxp(data: &[u8], plan: &Plan) -> Option<Vec<u8>> {
+    let mut data = data.to_vec();
+    if data.len() < 6 {
+        return None;
+    }
+    set_u16(&mut data, 4, plan.num_glyphs());
+    Some(data)
+}
+
+/// Returns the PostScript table with glyph names for the subset. Tables
+/// with version 1.0 are converted to version 2.0 and tables with versions
+/// other than 2.0 and 3.0 are converted to version 3.0.
+pub fn subset_post(data: &[u8], plan: &Plan) -> Option<Vec<u8>> {
+    let b = Buffer::new(data);
+    let version = b.read_u32(0)?;
+    let mut new_data = b.read_bytes(0, 32)?.to_vec();
+    if version != 0x10000 && version != 0x20000 {
+        set_u32(&mut new_data, 0, 0x30000);
+        return Some(new_data);
+    }
+    let mut names = Vec::new();
+    let mut num_glyphs = 0;
+    if version == 0x20000 {
+        num_glyphs = b.read_u16(32)? as usize;
+        let mut c = b.cursor_at(34 + num_glyphs * 2)?;
+        while let Some(len) = c.read_u8() {
+            names.push(c.read_bytes(len as usize)?);
+        }
+    }
+    set_u32(&mut new_data, 0, 0x20000);
+    new_data.extend_from_slice(&plan.num_glyphs().to_be_bytes());
+    let mut new_names = Vec::new();
+    let mut name_indices = HashMap::new();
+    for glyph_id in 0..plan.num_glyphs() {
+        let index = match plan.original(glyph_id) {
+            Some(glyph_id) if version == 0x10000 && glyph_id < NUM_STANDARD_NAMES => glyph_id,
+            _ if version == 0x10000 => 0,
+            Some(glyph_id) if (glyph_id as usize) < num_glyphs => {
+                b.read_u16(34 + glyph_id as usize * 2)?
+            }
+            _ => 0,
+        };
+        let index = if index < NUM_STANDARD_NAMES {
+            index
+        } else {
+            let name = *names.get((index - NUM_STANDARD_NAMES) as usize)?;
+            *name_indices.entry(name).or_insert_with(|| {
+                new_names.push(name);
+                NUM_STANDARD_NAMES + new_names.len() as u16 - 1
+            })
+        };
+        new_data.extend_from_slice(&index.to_be_bytes());
+    }
+    for name in new_names {
+        new_data.push(name.len() as u8);
+        new_data.extend_from_slice(name);
+    }
+    Some(new_data)
+}
+
+/// Returns the naming table with the records for the retained name
+/// identifiers.
+pub fn subset_name(data: &[u8], plan: &Plan) -> Option<Vec<u8>> {
+    let b = Buffer::new(data);
+    let version = b.read_u16(0)?;
+    let count = b.read_u16(2)? as usize;
+    let storage = b.read_u16(4)? as usize;
+    let mut strings = Vec::new();
+    let mut string_offsets = HashMap::new();
+    let mut add_string = |offset: u16, len: u16| -> Option<u16> {
+        let string = b.read_bytes(storage + offset as usize, len as usize)?;
+        let offset = *string_offsets.entry(string).or_insert_with(|| {
+            let offset = strings.len();
+            strings.extend_from_slice(string);
+            offset
+        });
+        u16::try_from(offset).ok()
+    };
+    let mut records = Vec::new();
+    for i in 0..count {
+        let mut c = b.cursor_at(6 + i * 12)?;
+        let ids = c.read_bytes(6)?;
+        let name_id = c.read_u16()?;
+        let len = c.read_u16()?;
+        let offset = c.read_u16()?;
+        if let Some(name_ids) = &plan.name_ids {
+            if !name_ids.contains(&name_id) {
+                continue;
+            }
+        }
+        records.extend_from_slice(ids);
+        records.extend_from_slice(&name_id.to_be_bytes());
+        records.extend_from_slice(&len.to_be_bytes());
+        records.extend_from_slice(&add_string(offset, len)?.to_be_bytes());
+    }
+    let mut lang_tags = Vec::new();
+    if version >= 1 {
+        let base = 6 + count * 12;
+        let lang_tag_count = b.read_u16(base)? as usize;
+        lang_tags.extend_from_slice(&(lang_tag_count as u16).to_be_bytes());
+        for i in 0..lang_tag_count {
+            let len = b.read_u16(base + 2 + i * 4)?;
+            let offset = b.read_u16(base + 4 + i * 4)?;
+            lang_tags.extend_from_slice(&len.to_be_bytes());
+            lang_tags.extend_from_slice(&add_string(offset, len)?.to_be_bytes());
+        }
+    }
+    let num_records = records.len() / 12;
+    let storage = 6 + records.len() + lang_tags.len();
+    let mut new_data = Vec::with_capacity(storage + strings.len());
+    new_data.extend_from_slice(&version.to_be_bytes());
+    new_data.extend_from_slice(&(num_records as u16).to_be_bytes());
+    new_data.extend_from_slice(&u16::try_from(storage).ok()?.to_be_bytes());
+    new_data.extend_from_slice(&records);
+    new_data.extend_from_slice(&lang_tags);
+    new_data.extend_from_slice(&strings);
+    Some(new_data)
+}
+
+/// Returns the OS/2 table with updated first and last character indices.
+pub fn subset_os2(data: &[u8], plan: &Plan) -> Vec<u8> {
+    let mut data = data.to_vec();
+    let first = plan.mapping.first().map(|m| m.0);
+    let last = plan.mapping.last().map(|m| m.0);
+    if let (Some(first), Some(last), true) = (first, last, data.len() >= 68) {
+        set_u16(&mut data, 64, first.min(0xFFFF) as u16);
+        set_u16(&mut data, 66, last.min(0xFFFF) as u16);
+    }
+    data
+}
+
+/// Returns the glyph variations table with the variation data for the
+/// glyphs in the subset.
+pub fn subset_gvar(data: &[u8], plan: &Plan) -> Option<Vec<u8>> {
+    let b = Buffer::new(data);
+    let axis_count = b.read_u16(4)? as usize;
+    let shared_tuple_count = b.read_u16(6)? as usize;
+    let shared_tuples_offset = b.read_u32(8)? as usize;
+    let glyph_count = b.read_u16(12)?;
+    let is_long = b.read_u16(14)? & 1 != 0;
+    let data_offset = b.read_u32(16)? as usize;
+    let glyph_data = |glyph_id: GlyphId| -> Option<&[u8]> {
+        if glyph_id >= glyph_count {
+            return None;
+        }
+        let index = glyph_id as usize;
+        let (start, end) = if is_long {
+            (
+                b.read_u32(20 + index * 4)? as usize,
+                b.read_u32(24 + index * 4)? as usize,
+            )
+        } else {
+            (
+                b.read_u16(20 + index * 2)? as usize * 2,
+                b.read_u16(22 + index * 2)? as usize * 2,
+            )
+        };
+        b.read_bytes(data_offset + start, end.checked_sub(start)?)
+    };
+    let shared_tuples = b.read_bytes(shared_tuples_offset, shared_tuple_count * axis_count * 2)?;
+    let num_glyphs = plan.num_glyphs() as usize;
+    let shared_tuples_offset = 20 + (num_glyphs + 1) * 4;
+    let data_offset = shared_tuples_offset + shared_tuples.len();
+    let mut new_data = Vec::new();
+    new_data.extend_from_slice(b.read_bytes(0, 8)?);
+    new_data.extend_from_slice(&(shared_tuples_offset as u32).to_be_bytes());
+    new_data.extend_from_slice(&plan.num_glyphs().to_be_bytes());
+    new_data.extend_from_slice(&1u16.to_be_bytes());
+    new_data.extend_from_slice(&(data_offset as u32).to_be_bytes());
+    let mut variations = Vec::new();
+    for glyph_id in 0..plan.num_glyphs() {
+        new_data.extend_from_slice(&(variations.len() as u32).to_be_bytes());
+        if let Some(data) = plan.original(glyph_id).and_then(glyph_data) {
+            variations.extend_from_slice(data);
+        }
+    }
+    new_data.extend_from_slice(&(variations.len() as u32).to_be_bytes());
+    new_data.extend_from_slice(shared_tuples);
+    new_data.extend_from_slice(&variations);
+    Some(new_data)
+}
+
+/// Returns a metrics variations table with delta set index mappings for
+/// the glyphs in the subset. The table header is followed by the offsets
+/// to `num_mappings` mappings, the first of which maps advances and is
+/// implicit when absent. Implicit mappings are replaced with explicit
+/// mappings to the original delta sets and the item variation store is
+/// copied unchanged.
+pub fn subset_metrics_variations(data: &[u8], num_mappings: usize, plan: &Plan) -> Option<Vec<u8>> {
+    let b = Buffer::new(data);
+    let store_offset = b.read_u32(4)? as usize;
+    let store = b.read_bytes(store_offset, item_variation_store_len(b, store_offset)?)?;
+    let header_len = 8 + num_mappings * 4;
+    let mut mappings = Vec::new();
+    for i in 0..num_mappings {
+        let offset = b.read_u32(8 + i * 4)?;
+        let map = DeltaSetIndexMap::new(b, offset);
+        if map.is_none() && i != 0 {
+            mappings.push(None);
+            continue;
+        }
+        let entries = (0..plan.num_glyphs())
+            .map(|glyph_id| {
+                let original = plan.original(glyph_id).unwrap_or(0);
+                match &map {
+                    Some(map) => map
+                        .get(original as u32)
+                        .map(|index| (index.outer, index.inner)),
+                    None => Some((0, original)),
+                }
+            })
+            .collect::<Option<Vec<_>>>()?;
+        mappings.push(Some(delta_set_index_map(&entries)));
+    }
+    let mut new_data = Vec::new();
+    new_data.extend_from_slice(b.read_bytes(0, 4)?);
+    let mut offset = header_len;
+    let mappings_len = mappings
+        .iter()
+        .flatten()
+        .map(|map| map.len())
+        .sum::<usize>();
+    new_data.extend_from_slice(&((offset + mappings_len) as u32).to_be_bytes());
+    for map in &mappings {
+        match map {
+            Some(map) => {
+                new_data.extend_from_slice(&(offset as u32).to_be_bytes());
+                offset += map.len();
+            }
+            None => new_data.extend_from_slice(&0u32.to_be_bytes()),
+        }
+    }
+    for map in mappings.iter().flatten() {
+        new_data.extend_from_slice(map);
+    }
+    new_data.extend_from_slice(store);
+    Some(new_data)
+}
+
+/// Returns a format 0 delta set index map for the specified outer and inner
+/// indices. Trailing entries that repeat the final entry are omitted.
+fn delta_set_index_map(entries: &[(u16, u16)]) -> Vec<u8> {
+    let mut len = entries.len();
+    while len > 1 && entries[len - 2] == entries[len - 1] {
+        len -= 1;
+    }
+    let entries = &entries[..len];
+    let max_inner = entries.iter().map(|(_, inner)| *inner).max().unwrap_or(0);
+    let max_outer = entries.iter().map(|(outer, _)| *outer).max().unwrap_or(0);
+    let inner_bits = (16 - max_inner.leading_zeros()).max(1);
+    let total_bits = inner_bits + (16 - max_outer.leading_zeros());
+    let entry_size = total_bits.div_ceil(8).max(1) as usize;
+    let mut data = Vec::with_capacity(4 + entries.len() * entry_size);
+    data.push(0);
+    data.push((((entry_size - 1) << 4) as u32 | (inner_bits - 1)) as u8);
+    data.extend_from_slice(&(entries.len() as u16).to_be_bytes());
+    for (outer, inner) in entries {
+        let entry = (*outer as u32) << inner_bits | *inner as u32;
+        data.extend_from_slice(&entry.to_be_bytes()[4 - entry_size..]);
+    }
+    data
+}
+
+/// Returns the size of the item variation store at the specified offset,
+/// which extends to the end of its last region list or item variation data
+/// subtable.
+fn item_variation_store_len(b: Buffer, offset: usize) -> Option<usize> {
+    let regions_offset = b.read_u32(offset + 2)? as usize;
+    let num_outer_sets = b.read_u16(offset + 6)? as usize;
+    let mut end = offset + 8 + num_outer_sets * 4;
+    if regions_offset != 0 {
+        let regions = offset + regions_offset;
+        let num_axes = b.read_u16(regions)? as usize;
+        let num_regions = b.read_u16(regions + 2)? as usize;
+        end = end.max(regions + 4 + num_regions * num_axes * 6);
+    }
+    for i in 0..num_outer_sets {
+        let set = offset + b.read_u32(offset + 8 + i * 4)? as usize;
+        let num_items = b.read_u16(set)? as usize;
+        let word_delta_count = b.read_u16(set + 2)?;
+        let num_region_indices = b.read_u16(set + 4)? as usize;
+        let is_long = word_delta_count & 0x8000 != 0;
+        let num_words = (word_delta_count & 0x7FFF) as usize;
+        let row_size = if is_long {
+            num_words * 4 + num_region_indices.saturating_sub(num_words) * 2
+        } else {
+            num_words * 2 + num_region_indices.saturating_sub(num_words)
+        };
+        end = end.max(set + 6 + num_region_indices * 2 + num_items * row_size);
+    }
+    Some(end - offset)
+}
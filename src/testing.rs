@@ -0,0 +1,548 @@
+//! Helpers for constructing font data in tests.
+
+#![allow(dead_code)]
+
+use crate::build::FontBuilder;
+use crate::parse_prelude::*;
+use std::{vec, vec::Vec};
+
+/// Writer for big endian font data.
+#[derive(Clone, Default)]
+pub struct Writer(pub Vec<u8>);
+
+impl Writer {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn len(&self) -> usize {
+        self.0.len()
+    }
+
+    pub fn u8(&mut self, value: u8) -> &mut Self {
+        self.0.push(value);
+        self
+    }
+
+    pub fn u16(&mut self, value: u16) -> &mut Self {
+        self.0.extend_from_slice(&value.to_be_bytes());
+        self
+    }
+
+    pub fn i16(&mut self, value: i16) -> &mut Self {
+        self.0.extend_from_slice(&value.to_be_bytes());
+        self
+    }
+
+    pub fn u32(&mut self, value: u32) -> &mut Self {
+        self.0.extend_from_slice(&value.to_be_bytes());
+        self
+    }
+
+    pub fn bytes(&mut self, value: &[u8]) -> &mut Self {
+        self.0.extend_from_slice(value);
+        self
+    }
+
+    pub fn set_u16(&mut self, offset: usize, value: u16) -> &mut Self {
+        self.0[offset..offset + 2].copy_from_slice(&value.to_be_bytes());
+        self
+    }
+
+    pub fn finish(&mut self) -> Vec<u8> {
+        core::mem::take(&mut self.0)
+    }
+}
+
+/// Appends the children to the writer and patches the 16-bit offsets at
+/// the specified positions, which are relative to the start of the writer.
+fn append_children(w: &mut Writer, slots: &[usize], children: &[Vec<u8>]) {
+    for (slot, child) in slots.iter().zip(children) {
+        let offset = w.len() as u16;
+        w.set_u16(*slot, offset);
+        w.bytes(child);
+    }
+}
+
+/// Returns a format 1 coverage table for the specified sorted glyphs.
+pub fn coverage(glyphs: &[GlyphId]) -> Vec<u8> {
+    let mut w = Writer::new();
+    w.u16(1).u16(glyphs.len() as u16);
+    for glyph in glyphs {
+        w.u16(*glyph);
+    }
+    w.finish()
+}
+
+/// Returns a lookup table with the specified type, flags and subtables.
+pub fn lookup(kind: u16, flag: u16, subtables: &[Vec<u8>]) -> Vec<u8> {
+    let mut w = Writer::new();
+    w.u16(kind).u16(flag).u16(subtables.len() as u16);
+    let slots = (0..subtables.len()).map(|i| 6 + i * 2).collect::<Vec<_>>();
+    for _ in subtables {
+        w.u16(0);
+    }
+    append_children(&mut w, &slots, subtables);
+    w.finish()
+}
+
+/// Returns a layout table with a single script whose default language
+/// enables all of the features. Each feature is a tag and a list of lookup
+/// indices.
+pub fn layout(script: &[u8; 4], features: &[(&[u8; 4], &[u16])], lookups: &[Vec<u8>]) -> Vec<u8> {
+    let indices = (0..features.len() as u16).collect::<Vec<_>>();
+    scripts_layout(&[(script, &indices)], features, lookups)
+}
+
+/// Returns a layout table with several scripts. Each script is a tag and the
+/// list of feature indices enabled by its default language.
+pub fn scripts_layout(
+    scripts: &[(&[u8; 4], &[u16])],
+    features: &[(&[u8; 4], &[u16])],
+    lookups: &[Vec<u8>],
+) -> Vec<u8> {
+    let mut script_list = Writer::new();
+    script_list.u16(scripts.len() as u16);
+    for (tag, _) in scripts {
+        script_list.bytes(*tag).u16(0);
+    }
+    let slots = (0..scripts.len())
+        .map(|i| 2 + i * 6 + 4)
+        .collect::<Vec<_>>();
+    let script_tables = scripts
+        .iter()
+        .map(|(_, indices)| {
+            let mut w = Writer::new();
+            w.u16(4).u16(0).u16(0).u16(0xFFFF).u16(indices.len() as u16);
+            for index in indices.iter() {
+                w.u16(*index);
+            }
+            w.finish()
+        })
+        .collect::<Vec<_>>();
+    append_children(&mut script_list, &slots, &script_tables);
+    let mut feature_list = Writer::new();
+    feature_list.u16(features.len() as u16);
+    for (tag, _) in features {
+        feature_list.bytes(*tag).u16(0);
+    }
+    let slots = (0..features.len())
+        .map(|i| 2 + i * 6 + 4)
+        .collect::<Vec<_>>();
+    let feature_tables = features
+        .iter()
+        .map(|(_, indices)| {
+            let mut w = Writer::new();
+            w.u16(0).u16(indices.len() as u16);
+            for index in indices.iter() {
+                w.u16(*index);
+            }
+            w.finish()
+        })
+        .collect::<Vec<_>>();
+    append_children(&mut feature_list, &slots, &feature_tables);
+    let mut lookup_list = Writer::new();
+    lookup_list.u16(lookups.len() as u16);
+    for _ in lookups {
+        lookup_list.u16(0);
+    }
+    let slots = (0..lookups.len()).map(|i| 2 + i * 2).collect::<Vec<_>>();
+    append_children(&mut lookup_list, &slots, lookups);
+    let mut w = Writer::new();
+    w.u32(0x00010000).u16(0).u16(0).u16(0);
+    append_children(
+        &mut w,
+        &[4, 6, 8],
+        &[script_list.0, feature_list.0, lookup_list.0],
+    );
+    w.finish()
+}
+
+/// Returns a format 2 single substitution subtable.
+pub fn single_subst(pairs: &[(GlyphId, GlyphId)]) -> Vec<u8> {
+    let mut w = Writer::new();
+    w.u16(2).u16(0).u16(pairs.len() as u16);
+    for (_, to) in pairs {
+        w.u16(*to);
+    }
+    let from = pairs.iter().map(|(from, _)| *from).collect::<Vec<_>>();
+    append_children(&mut w, &[2], &[coverage(&from)]);
+    w.finish()
+}
+
+/// Returns a multiple substitution subtable.
+pub fn multiple_subst(sequences: &[(GlyphId, &[GlyphId])]) -> Vec<u8> {
+    let mut w = Writer::new();
+    w.u16(1).u16(0).u16(sequences.len() as u16);
+    for _ in sequences {
+        w.u16(0);
+    }
+    let mut children = vec![coverage(
+        &sequences.iter().map(|(from, _)| *from).collect::<Vec<_>>(),
+    )];
+    for (_, glyphs) in sequences {
+        let mut seq = Writer::new();
+        seq.u16(glyphs.len() as u16);
+        for glyph in glyphs.iter() {
+            seq.u16(*glyph);
+        }
+        children.push(seq.finish());
+    }
+    let slots = core::iter::once(2)
+        .chain((0..sequences.len()).map(|i| 6 + i * 2))
+        .collect::<Vec<_>>();
+    append_children(&mut w, &slots, &children);
+    w.finish()
+}
+
+/// Returns a ligature substitution subtable. Ligatures must be sorted by
+/// their first component.
+pub fn ligature_subst(ligatures: &[(&[GlyphId], GlyphId)]) -> Vec<u8> {
+    let mut firsts = ligatures
+        .iter()
+        .map(|(components, _)| components[0])
+        .collect::<Vec<_>>();
+    firsts.dedup();
+    let mut w = Writer::new();
+    w.u16(1).u16(0).u16(firsts.len() as u16);
+    for _ in &firsts {
+        w.u16(0);
+    }
+    let mut children = vec![coverage(&firsts)];
+    for first in &firsts {
+        let set = ligatures
+            .iter()
+            .filter(|(components, _)| components[0] == *first)
+            .collect::<Vec<_>>();
+        let mut w = Writer::new();
+        w.u16(set.len() as u16);
+        for _ in &set {
+            w.u16(0);
+        }
+        let tables = set
+            .iter()
+            .map(|(components, ligature)| {
+                let mut w = Writer::new();
+                w.u16(*ligature).u16(components.len() as u16);
+                for component in &components[1..] {
+                    w.u16(*component);
+                }
+                w.finish()
+            })
+            .collect::<Vec<_>>();
+        let slots = (0..set.len()).map(|i| 2 + i * 2).collect::<Vec<_>>();
+        append_children(&mut w, &slots, &tables);
+        children.push(w.finish());
+    }
+    let slots = core::iter::once(2)
+        .chain((0..firsts.len()).map(|i| 6 + i * 2))
+        .collect::<Vec<_>>();
+    append_children(&mut w, &slots, &children);
+    w.finish()
+}
+
+/// Returns a format 3 sequence context subtable that matches the glyph
+/// sequence and applies the lookups at the specified sequence indices.
+pub fn context_subst(glyphs: &[GlyphId], lookups: &[(u16, u16)]) -> Vec<u8> {
+    let mut w = Writer::new();
+    w.u16(3).u16(glyphs.len() as u16).u16(lookups.len() as u16);
+    for _ in glyphs {
+        w.u16(0);
+    }
+    for (sequence_index, lookup_index) in lookups {
+        w.u16(*sequence_index).u16(*lookup_index);
+    }
+    let slots = (0..glyphs.len()).map(|i| 6 + i * 2).collect::<Vec<_>>();
+    let coverages = glyphs.iter().map(|g| coverage(&[*g])).collect::<Vec<_>>();
+    append_children(&mut w, &slots, &coverages);
+    w.finish()
+}
+
+/// Returns a format 1 anchor table.
+pub fn anchor(x: i16, y: i16) -> Vec<u8> {
+    let mut w = Writer::new();
+    w.u16(1).i16(x).i16(y);
+    w.finish()
+}
+
+/// Returns a format 2 single adjustment subtable that adjusts the
+/// horizontal advance of each glyph.
+pub fn single_pos(advances: &[(GlyphId, i16)]) -> Vec<u8> {
+    let mut w = Writer::new();
+    w.u16(2).u16(0).u16(0x0004).u16(advances.len() as u16);
+    for (_, advance) in advances {
+        w.i16(*advance);
+    }
+    let glyphs = advances.iter().map(|(glyph, _)| *glyph).collect::<Vec<_>>();
+    append_children(&mut w, &[2], &[coverage(&glyphs)]);
+    w.finish()
+}
+
+/// Returns a format 1 pair adjustment subtable that adjusts the horizontal
+/// advance of the first glyph of each pair. Pairs must be sorted.
+pub fn pair_pos(pairs: &[(GlyphId, GlyphId, i16)]) -> Vec<u8> {
+    let mut firsts = pairs.iter().map(|(first, ..)| *first).collect::<Vec<_>>();
+    firsts.dedup();
+    let mut w = Writer::new();
+    w.u16(1).u16(0).u16(0x0004).u16(0).u16(firsts.len() as u16);
+    for _ in &firsts {
+        w.u16(0);
+    }
+    let mut children = vec![coverage(&firsts)];
+    for first in &firsts {
+        let set = pairs
+            .iter()
+            .filter(|(f, ..)| f == first)
+            .collect::<Vec<_>>();
+        let mut w = Writer::new();
+        w.u16(set.len() as u16);
+        for (_, second, advance) in set {
+            w.u16(*second).i16(*advance);
+        }
+        children.push(w.finish());
+    }
+    let slots = core::iter::once(2)
+        .chain((0..firsts.len()).map(|i| 10 + i * 2))
+        .collect::<Vec<_>>();
+    append_children(&mut w, &slots, &children);
+    w.finish()
+}
+
+/// Returns a cursive attachment subtable. Each glyph has optional entry
+/// and exit anchors.
+#[allow(clippy::type_complexity)]
+pub fn cursive_pos(glyphs: &[(GlyphId, Option<(i16, i16)>, Option<(i16, i16)>)]) -> Vec<u8> {
+    let mut w = Writer::new();
+    w.u16(1).u16(0).u16(glyphs.len() as u16);
+    let mut slots = vec![2];
+    let mut children = vec![coverage(
+        &glyphs.iter().map(|(glyph, ..)| *glyph).collect::<Vec<_>>(),
+    )];
+    for (_, entry, exit) in glyphs {
+        for point in [entry, exit] {
+            if let Some((x, y)) = point {
+                slots.push(w.len());
+                children.push(anchor(*x, *y));
+            }
+            w.u16(0);
+        }
+    }
+    append_children(&mut w, &slots, &children);
+    w.finish()
+}
+
+/// Returns a mark to base attachment subtable with a single mark class.
+/// Each mark and base glyph has one anchor.
+pub fn mark_base_pos(marks: &[(GlyphId, i16, i16)], bases: &[(GlyphId, i16, i16)]) -> Vec<u8> {
+    let mut mark_array = Writer::new();
+    mark_array.u16(marks.len() as u16);
+    for _ in marks {
+        mark_array.u16(0).u16(0);
+    }
+    let slots = (0..marks.len()).map(|i| 4 + i * 4).collect::<Vec<_>>();
+    let anchors = marks
+        .iter()
+        .map(|(_, x, y)| anchor(*x, *y))
+        .collect::<Vec<_>>();
+    append_children(&mut mark_array, &slots, &anchors);
+    let mut base_array = Writer::new();
+    base_array.u16(bases.len() as u16);
+    for _ in bases {
+        base_array.u16(0);
+    }
+    let slots = (0..bases.len()).map(|i| 2 + i * 2).collect::<Vec<_>>();
+    let anchors = bases
+        .iter()
+        .map(|(_, x, y)| anchor(*x, *y))
+        .collect::<Vec<_>>();
+    append_children(&mut base_array, &slots, &anchors);
+    let mut w = Writer::new();
+    w.u16(1).u16(0).u16(0).u16(1).u16(0).u16(0);
+    let glyphs = |list: &[(GlyphId, i16, i16)]| list.iter().map(|g| g.0).collect::<Vec<_>>();
+    append_children(
+        &mut w,
+        &[2, 4, 8, 10],
+        &[
+            coverage(&glyphs(marks)),
+            coverage(&glyphs(bases)),
+            mark_array.finish(),
+            base_array.finish(),
+        ],
+    );
+    w.finish()
+}
+
+/// Returns a format 4 character map for the specified sorted mapping.
+pub fn cmap4(mapping: &[(u32, GlyphId)]) -> Vec<u8> {
+    let count = mapping.len() + 1;
+    let mut sub = Writer::new();
+    sub.u16(4).u16((16 + count * 8) as u16).u16(0);
+    sub.u16(count as u16 * 2).u16(0).u16(0).u16(0);
+    for (ch, _) in mapping {
+        sub.u16(*ch as u16);
+    }
+    sub.u16(0xFFFF).u16(0);
+    for (ch, _) in mapping {
+        sub.u16(*ch as u16);
+    }
+    sub.u16(0xFFFF);
+    for (ch, glyph_id) in mapping {
+        sub.u16(glyph_id.wrapping_sub(*ch as u16));
+    }
+    sub.u16(1);
+    for _ in 0..count {
+        sub.u16(0);
+    }
+    let mut w = Writer::new();
+    w.u16(0).u16(1).u16(3).u16(1).u32(12).bytes(&sub.0);
+    w.finish()
+}
+
+/// Builds a naming table from (platform, encoding, language, name, string)
+/// records, storing Macintosh strings as bytes and others as UTF-16.
+/// Language tags produce a version 1 table.
+pub fn name(records: &[(u16, u16, u16, u16, &str)], tags: &[&str]) -> Vec<u8> {
+    let utf16 = |s: &str| {
+        s.encode_utf16()
+            .flat_map(u16::to_be_bytes)
+            .collect::<Vec<_>>()
+    };
+    let mut storage = Vec::new();
+    let mut w = Writer::new();
+    let header = 6
+        + records.len() * 12
+        + if tags.is_empty() {
+            0
+        } else {
+            2 + tags.len() * 4
+        };
+    w.u16(!tags.is_empty() as u16)
+        .u16(records.len() as u16)
+        .u16(header as u16);
+    for &(platform_id, encoding_id, language_id, name_id, s) in records {
+        let data = if platform_id == 1 {
+            s.as_bytes().to_vec()
+        } else {
+            utf16(s)
+        };
+        w.u16(platform_id)
+            .u16(encoding_id)
+            .u16(language_id)
+            .u16(name_id)
+            .u16(data.len() as u16)
+            .u16(storage.len() as u16);
+        storage.extend_from_slice(&data);
+    }
+    if !tags.is_empty() {
+        w.u16(tags.len() as u16);
+        for tag in tags {
+            let data = utf16(tag);
+            w.u16(data.len() as u16).u16(storage.len() as u16);
+            storage.extend_from_slice(&data);
+        }
+    }
+    w.bytes(&storage).finish()
+}
+
+/// Builder for a minimal TrueType font. Every glyph except `.notdef` is a
+/// square with an advance that depends on the glyph identifier.
+#[derive(Clone, Default)]
+pub struct TestFont {
+    num_glyphs: u16,
+    mapping: Vec<(u32, GlyphId)>,
+    instructions: Vec<u8>,
+    tables: Vec<(Tag, Vec<u8>)>,
+}
+
+impl TestFont {
+    pub fn new(num_glyphs: u16) -> Self {
+        Self {
+            num_glyphs,
+            ..Default::default()
+        }
+    }
+
+    /// Maps the characters to consecutive glyphs starting at one.
+    pub fn chars(mut self, chars: &str) -> Self {
+        for (i, ch) in chars.chars().enumerate() {
+            self.mapping.push((ch as u32, i as GlyphId + 1));
+        }
+        self.mapping.sort_unstable();
+        self
+    }
+
+    /// Sets the hinting instructions for every glyph.
+    pub fn instructions(mut self, instructions: &[u8]) -> Self {
+        self.instructions = instructions.to_vec();
+        self
+    }
+
+    pub fn table(mut self, tag: &[u8; 4], data: Vec<u8>) -> Self {
+        self.tables.push((Tag::new(tag), data));
+        self
+    }
+
+    pub fn build(&self) -> Vec<u8> {
+        let n = self.num_glyphs;
+        let mut head = Writer::new();
+        head.u32(0x00010000).u32(0x00010000).u32(0).u32(0x5F0F3CF5);
+        head.u16(0).u16(1000).u32(0).u32(0).u32(0).u32(0);
+        head.i16(0).i16(0).i16(100 * n as i16).i16(100 * n as i16);
+        head.u16(0).u16(8).i16(2).i16(1).i16(0);
+        let mut hhea = Writer::new();
+        hhea.u32(0x00010000)
+            .i16(800)
+            .i16(-200)
+            .i16(0)
+            .u16(500 + 10 * n);
+        for _ in 0..11 {
+            hhea.u16(0);
+        }
+        hhea.u16(n);
+        let mut maxp = Writer::new();
+        maxp.u32(0x00010000).u16(n).u16(4).u16(1);
+        for _ in 0..10 {
+            maxp.u16(0);
+        }
+        maxp.u16(0);
+        let mut hmtx = Writer::new();
+        let mut glyf = Writer::new();
+        let mut loca = Writer::new();
+        for glyph_id in 0..n {
+            hmtx.u16(500 + 10 * glyph_id).i16(0);
+            loca.u32(glyf.len() as u32);
+            if glyph_id == 0 {
+                continue;
+            }
+            let size = 100 * glyph_id as i16;
+            glyf.i16(1).i16(0).i16(0).i16(size).i16(size);
+            glyf.u16(3).u16(self.instructions.len() as u16);
+            glyf.bytes(&self.instructions);
+            glyf.bytes(&[1, 1, 1, 1]);
+            glyf.i16(0).i16(size).i16(0).i16(-size);
+            glyf.i16(0).i16(0).i16(size).i16(0);
+            if !glyf.len().is_multiple_of(2) {
+                glyf.u8(0);
+            }
+        }
+        loca.u32(glyf.len() as u32);
+        let mut post = Writer::new();
+        post.u32(0x00030000);
+        for _ in 0..7 {
+            post.u32(0);
+        }
+        let mut builder = FontBuilder::new(0x00010000);
+        builder.insert(Tag::new(b"head"), head.finish());
+        builder.insert(Tag::new(b"hhea"), hhea.finish());
+        builder.insert(Tag::new(b"maxp"), maxp.finish());
+        builder.insert(Tag::new(b"hmtx"), hmtx.finish());
+        builder.insert(Tag::new(b"loca"), loca.finish());
+        builder.insert(Tag::new(b"glyf"), glyf.finish());
+        builder.insert(Tag::new(b"cmap"), cmap4(&self.mapping));
+        builder.insert(Tag::new(b"post"), post.finish());
+        for (tag, data) in &self.tables {
+            builder.insert(*tag, data.clone());
+        }
+        builder.build().unwrap()
+    }
+}
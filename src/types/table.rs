@@ -41,3 +41,16 @@ pub struct Table<'a> {
     /// Record for the table.
     pub record: TableRecord,
 }
+
+/// Returns the checksum for the specified table data. The data is treated
+/// as if it were padded with zeros to a multiple of four bytes.
+pub fn checksum(data: &[u8]) -> u32 {
+    let mut sum = 0u32;
+    let mut chunks = data.chunks_exact(4);
+    for chunk in &mut chunks {
+        sum = sum.wrapping_add(u32::from_be_bytes([chunk[0], chunk[1], chunk[2], chunk[3]]));
+    }
+    let mut last = [0u8; 4];
+    last[..chunks.remainder().len()].copy_from_slice(chunks.remainder());
+    sum.wrapping_add(u32::from_be_bytes(last))
+}
@@ -0,0 +1,976 @@
+//! Structural validation of font files.
+//!
+//! The validator checks the table directory, table checksums, the
+//! consistency of glyph counts between tables and the structure of the
+//! layout tables. It is intended for screening untrusted fonts before they
+//! are used and reports each problem as a [`Finding`] with a [`Severity`].
+
+use crate::checksum::{self, Mismatch};
+use crate::cmap::CMAP;
+use crate::font::{FontDataKind, FontDataRef, FontRef, TableProvider};
+use crate::glyf::{GLYF, LOCA};
+use crate::gpos::GPOS;
+use crate::gsub::GSUB;
+use crate::head::HEAD;
+use crate::hhea::HHEA;
+use crate::hmtx::HMTX;
+use crate::maxp::MAXP;
+use crate::name::NAME;
+use crate::os2::OS2;
+use crate::otl::{CoverageArray, Layout, NestedLookup, SubtableKind, MAX_NESTING_DEPTH};
+use crate::parse_prelude::*;
+use crate::post::POST;
+use crate::vhea::VHEA;
+use crate::vmtx::VMTX;
+use core::fmt;
+use std::vec;
+use std::vec::Vec;
+
+/// Value of the magic number field in the font header table.
+const HEAD_MAGIC: u32 = 0x5F0F3CF5;
+
+/// Severity of a validation finding.
+#[derive(Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash, Debug)]
+pub enum Severity {
+    /// The font is well formed but uses an uncommon or unsupported
+    /// construct.
+    Info,
+    /// The font violates the specification in a way that is unlikely to
+    /// cause incorrect results.
+    Warning,
+    /// The font is corrupt and should not be used.
+    Error,
+}
+
+impl fmt::Display for Severity {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        f.write_str(match self {
+            Self::Info => "info",
+            Self::Warning => "warning",
+            Self::Error => "error",
+        })
+    }
+}
+
+/// Kind of problem identified by the validator.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub enum FindingKind {
+    /// The data is not a recognized font format.
+    UnknownFormat,
+    /// A table could not be read.
+    Read(ReadError),
+    /// The table records are not sorted by tag.
+    UnsortedTables,
+    /// The table directory contains more than one record with the same tag.
+    DuplicateTable,
+    /// The table extends beyond the end of the font data.
+    TableOutOfBounds,
+    /// The table overlaps the table with the specified tag.
+    OverlappingTables(Tag),
+    /// The table does not begin on a four byte boundary.
+    UnalignedTable,
+    /// The checksum in the table record does not match the table data.
+    ChecksumMismatch {
+        /// Checksum in the table record.
+        expected: u32,
+        /// Checksum computed from the table data.
+        actual: u32,
+    },
+    /// The checksum adjustment in the font header table is incorrect.
+    ChecksumAdjustmentMismatch {
+        /// Checksum adjustment in the font header table.
+        expected: u32,
+        /// Checksum adjustment computed from the font data.
+        actual: u32,
+    },
+    /// The magic number in the font header table is incorrect.
+    InvalidMagicNumber(u32),
+    /// The number of glyphs in the table does not match the count in the
+    /// maximum profile table.
+    GlyphCountMismatch {
+        /// Number of glyphs in the maximum profile table.
+        expected: u16,
+        /// Number of glyphs implied by the table.
+        actual: u16,
+    },
+    /// The number of metrics in the header table exceeds the number of
+    /// glyphs.
+    TooManyMetrics {
+        /// Number of metrics in the header table.
+        num_metrics: u16,
+        /// Number of glyphs in the maximum profile table.
+        num_glyphs: u16,
+    },
+    /// The location of the glyph is out of order or beyond the end of the
+    /// glyph data table.
+    InvalidGlyphOffset(GlyphId),
+    /// The cmap subtable with the specified platform and encoding identifiers
+    /// has an unsupported format.
+    UnsupportedCmapFormat {
+        /// Platform identifier.
+        platform_id: u16,
+        /// Encoding identifier.
+        encoding_id: u16,
+        /// Subtable format.
+        format: u16,
+    },
+    /// The lookup at the specified index could not be read.
+    InvalidLookup(u16),
+    /// The subtable of the lookup could not be read.
+    InvalidSubtable {
+        /// Index of the lookup.
+        lookup: u16,
+        /// Index of the subtable in the lookup.
+        subtable: u16,
+    },
+    /// A reference to a lookup index beyond the end of the lookup list.
+    LookupIndexOutOfRange(u16),
+    /// The lookup can reach itself through nested lookups.
+    RecursiveLookup(u16),
+    /// The lookup nests other lookups beyond the supported depth.
+    NestingTooDeep {
+        /// Index of the lookup.
+        lookup: u16,
+        /// Depth of the nested lookups.
+        depth: usize,
+    },
+    /// The coverage table is malformed or its glyphs are not sorted.
+    UnsortedCoverage,
+}
+
+impl fmt::Display for FindingKind {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            Self::UnknownFormat => write!(f, "unknown font format"),
+            Self::Read(e) => write!(f, "{}", e),
+            Self::UnsortedTables => write!(f, "table records are not sorted by tag"),
+            Self::DuplicateTable => write!(f, "duplicate table record"),
+            Self::TableOutOfBounds => write!(f, "table extends beyond the end of the data"),
+            Self::OverlappingTables(tag) => write!(f, "table overlaps '{}' table", tag),
+            Self::UnalignedTable => write!(f, "table is not aligned to a four byte boundary"),
+            Self::ChecksumMismatch { expected, actual } => write!(
+                f,
+                "table checksum is {:#010x} but the data sums to {:#010x}",
+                expected, actual
+            ),
+            Self::ChecksumAdjustmentMismatch { expected, actual } => write!(
+                f,
+                "checksum adjustment is {:#010x} but should be {:#010x}",
+                expected, actual
+            ),
+            Self::InvalidMagicNumber(magic) => write!(f, "invalid magic number {:#010x}", magic),
+            Self::GlyphCountMismatch { expected, actual } => write!(
+                f,
+                "table contains {} glyphs but 'maxp' specifies {}",
+                actual, expected
+            ),
+            Self::TooManyMetrics {
+                num_metrics,
+                num_glyphs,
+            } => write!(
+                f,
+                "number of metrics ({}) exceeds number of glyphs ({})",
+                num_metrics, num_glyphs
+            ),
+            Self::InvalidGlyphOffset(glyph_id) => {
+                write!(f, "invalid location for glyph {}", glyph_id)
+            }
+            Self::UnsupportedCmapFormat {
+                platform_id,
+                encoding_id,
+                format,
+            } => write!(
+                f,
+                "unsupported format {} for subtable ({}, {})",
+                format, platform_id, encoding_id
+            ),
+            Self::InvalidLookup(index) => write!(f, "invalid lookup {}", index),
+            Self::InvalidSubtable { lookup, subtable } => {
+                write!(f, "invalid subtable {} in lookup {}", subtable, lookup)
+            }
+            Self::LookupIndexOutOfRange(index) => {
+                write!(f, "lookup index {} is out of range", index)
+            }
+            Self::RecursiveLookup(index) => write!(f, "lookup {} is recursive", index),
+            Self::NestingTooDeep { lookup, depth } => write!(
+                f,
+                "lookup {} has nesting depth {} which exceeds the limit of {}",
+                lookup, depth, MAX_NESTING_DEPTH
+            ),
+            Self::UnsortedCoverage => write!(f, "coverage table is malformed or unsorted"),
+        }
+    }
+}
+
+/// Problem identified by the validator.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Finding {
+    /// Severity of the problem.
+    pub severity: Severity,
+    /// Kind of the problem.
+    pub kind: FindingKind,
+    /// Index of the font in the file.
+    pub font_index: u32,
+    /// Tag of the table that contains the problem, if any.
+    pub tag: Option<Tag>,
+    /// Byte offset of the problem. Problems with the table directory are
+    /// relative to the beginning of the file while all others are relative
+    /// to the beginning of the table identified by the tag.
+    pub offset: usize,
+}
+
+impl fmt::Display for Finding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}: ", self.severity)?;
+        match self.tag {
+            Some(tag) => write!(f, "[{}+{}] ", tag, self.offset)?,
+            None => write!(f, "[{}] ", self.offset)?,
+        }
+        write!(f, "{}", self.kind)
+    }
+}
+
+/// Validates all fonts in the specified file data.
+pub fn validate(data: &[u8]) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let font_data = match FontDataRef::new(data) {
+        Some(font_data) => font_data,
+        None => {
+            findings.push(Finding {
+                severity: Severity::Error,
+                kind: FindingKind::UnknownFormat,
+                font_index: 0,
+                tag: None,
+                offset: 0,
+            });
+            return findings;
+        }
+    };
+    if let Some(FontDataKind::Woff) | Some(FontDataKind::Woff2) = font_data.kind() {
+        findings.push(Finding {
+            severity: Severity::Error,
+            kind: FindingKind::UnknownFormat,
+            font_index: 0,
+            tag: None,
+            offset: 0,
+        });
+        return findings;
+    }
+    for index in 0..font_data.len() {
+        match font_data.get(index) {
+            Some(font) => {
+                let mut v = Validator {
+                    font,
+                    font_index: index,
+                    findings: &mut findings,
+                };
+                v.validate();
+            }
+            None => findings.push(Finding {
+                severity: Severity::Error,
+                kind: FindingKind::Read(ReadError::out_of_bounds(12 + index as usize * 4)),
+                font_index: index,
+                tag: None,
+                offset: 12 + index as usize * 4,
+            }),
+        }
+    }
+    findings
+}
+
+/// Validates the specified font.
+pub fn validate_font(font: &FontRef) -> Vec<Finding> {
+    let mut findings = Vec::new();
+    let mut v = Validator {
+        font: *font,
+        font_index: 0,
+        findings: &mut findings,
+    };
+    v.validate();
+    findings
+}
+
+struct Validator<'a, 'b> {
+    font: FontRef<'a>,
+    font_index: u32,
+    findings: &'b mut Vec<Finding>,
+}
+
+impl<'a> Validator<'a, '_> {
+    fn validate(&mut self) {
+        if !self.directory() {
+            return;
+        }
+        self.required_tables();
+        self.checksums();
+        self.glyph_counts();
+        self.cmap();
+        if let Some(gsub) = self.font.gsub() {
+            self.layout(GSUB, &gsub.0);
+        }
+        if let Some(gpos) = self.font.gpos() {
+            self.layout(GPOS, &gpos.0);
+        }
+    }
+
+    fn push(&mut self, severity: Severity, tag: Option<Tag>, offset: usize, kind: FindingKind) {
+        self.findings.push(Finding {
+            severity,
+            kind,
+            font_index: self.font_index,
+            tag,
+            offset,
+        });
+    }
+
+    fn read_error(&mut self, severity: Severity, e: ReadError) {
+        // Tables that extend beyond the end of the data have already been
+        // reported by the directory check.
+        let font_index = self.font_index;
+        if e.kind == ReadErrorKind::OutOfBounds
+            && self.findings.iter().any(|f| {
+                f.font_index == font_index
+                    && f.tag == e.tag
+                    && f.kind == FindingKind::TableOutOfBounds
+            })
+        {
+            return;
+        }
+        self.push(severity, e.tag, e.offset, FindingKind::Read(e));
+    }
+
+    /// Checks the table directory. Returns false if the directory is not
+    /// readable and no further checks can be performed.
+    fn directory(&mut self) -> bool {
+        let b = Buffer::new(self.font.data);
+        let base = self.font.offset as usize;
+        let len = match b.try_read::<u16>(base + 4) {
+            Ok(len) => len as usize,
+            Err(e) => {
+                self.read_error(Severity::Error, e);
+                return false;
+            }
+        };
+        let records = match b.try_read_slice::<TableRecord>(base + 12, len) {
+            Ok(records) => records,
+            Err(e) => {
+                self.read_error(Severity::Error, e);
+                return false;
+            }
+        };
+        let record_offset = |index: usize| base + 12 + index * 16;
+        let mut prev: Option<Tag> = None;
+        for (i, record) in records.iter().enumerate() {
+            let tag = Some(record.tag);
+            match prev {
+                Some(prev) if prev == record.tag => {
+                    self.push(
+                        Severity::Error,
+                        tag,
+                        record_offset(i),
+                        FindingKind::DuplicateTable,
+                    );
+                }
+                Some(prev) if prev > record.tag => {
+                    self.push(
+                        Severity::Error,
+                        tag,
+                        record_offset(i),
+                        FindingKind::UnsortedTables,
+                    );
+                }
+                _ => {}
+            }
+            prev = Some(record.tag);
+            let end = record.offset as usize + record.len as usize;
+            if end > self.font.data.len() {
+                self.push(
+                    Severity::Error,
+                    tag,
+                    record_offset(i),
+                    FindingKind::TableOutOfBounds,
+                );
+            }
+            if record.offset % 4 != 0 {
+                self.push(
+                    Severity::Warning,
+                    tag,
+                    record_offset(i),
+                    FindingKind::UnalignedTable,
+                );
+            }
+        }
+        let mut ranges = records
+            .iter()
+            .enumerate()
+            .filter(|(_, record)| record.len != 0)
+            .collect::<Vec<_>>();
+        ranges.sort_unstable_by_key(|(_, record)| (record.offset, record.len));
+        for pair in ranges.windows(2) {
+            let (_, a) = pair[0];
+            let (i, b) = pair[1];
+            // Identical ranges occur in collections with shared tables.
+            if a.offset == b.offset && a.len == b.len {
+                continue;
+            }
+            if (a.offset as usize + a.len as usize) > b.offset as usize {
+                self.push(
+                    Severity::Error,
+                    Some(b.tag),
+                    record_offset(i),
+                    FindingKind::OverlappingTables(a.tag),
+                );
+            }
+        }
+        true
+    }
+
+    fn required_tables(&mut self) {
+        const REQUIRED: [(Tag, Severity); 8] = [
+            (CMAP, Severity::Error),
+            (HEAD, Severity::Error),
+            (HHEA, Severity::Error),
+            (HMTX, Severity::Error),
+            (MAXP, Severity::Error),
+            (NAME, Severity::Warning),
+            (OS2, Severity::Warning),
+            (POST, Severity::Warning),
+        ];
+        for (tag, severity) in REQUIRED.iter() {
+            if self.font.find_record(*tag).is_none() {
+                self.read_error(*severity, ReadError::missing_table(*tag));
+            }
+        }
+        let is_truetype = self.font.find_record(GLYF).is_some();
+        if is_truetype && self.font.find_record(LOCA).is_none() {
+            self.read_error(Severity::Error, ReadError::missing_table(LOCA));
+        }
+        match self.font.try_head() {
+            Ok(head) if head.magic_number() != HEAD_MAGIC => {
+                self.push(
+                    Severity::Error,
+                    Some(HEAD),
+                    12,
+                    FindingKind::InvalidMagicNumber(head.magic_number()),
+                );
+            }
+            Err(e) if e.kind != ReadErrorKind::MissingTable => self.read_error(Severity::Error, e),
+            _ => {}
+        }
+        if let Err(e) = self.font.try_name() {
+            if e.kind != ReadErrorKind::MissingTable {
+                self.read_error(Severity::Error, e);
+            }
+        }
+        if let Err(e) = self.font.try_post() {
+            if e.kind != ReadErrorKind::MissingTable {
+                self.read_error(Severity::Warning, e);
+            }
+        }
+        if let Err(e) = self.font.try_os2() {
+            if e.kind != ReadErrorKind::MissingTable {
+                self.read_error(Severity::Warning, e);
+            }
+        }
+    }
+
+    fn checksums(&mut self) {
+        for mismatch in checksum::verify(&self.font) {
+            match mismatch {
+                Mismatch::Table {
+                    tag,
+                    expected,
+                    actual,
+                } => self.push(
+                    Severity::Warning,
+                    Some(tag),
+                    0,
+                    FindingKind::ChecksumMismatch { expected, actual },
+                ),
+                Mismatch::Adjustment { expected, actual } => self.push(
+                    Severity::Warning,
+                    Some(HEAD),
+                    8,
+                    FindingKind::ChecksumAdjustmentMismatch { expected, actual },
+                ),
+            }
+        }
+    }
+
+    fn glyph_counts(&mut self) {
+        let num_glyphs = match self.font.try_maxp() {
+            Ok(maxp) => maxp.num_glyphs(),
+            Err(e) => {
+                if e.kind != ReadErrorKind::MissingTable {
+                    self.read_error(Severity::Error, e);
+                }
+                return;
+            }
+        };
+        for (header, tag) in [(HHEA, HMTX), (VHEA, VMTX)].iter() {
+            let num_metrics = if *tag == HMTX {
+                self.font.try_hhea().map(|hhea| hhea.num_long_metrics())
+            } else {
+                self.font.try_vhea().map(|vhea| vhea.num_long_metrics())
+            };
+            let num_metrics = match num_metrics {
+                Ok(num_metrics) => num_metrics,
+                Err(e) => {
+                    if e.kind != ReadErrorKind::MissingTable {
+                        self.read_error(Severity::Error, e);
+                    }
+                    continue;
+                }
+            };
+            if num_metrics > num_glyphs {
+                self.push(
+                    Severity::Warning,
+                    Some(*header),
+                    34,
+                    FindingKind::TooManyMetrics {
+                        num_metrics,
+                        num_glyphs,
+                    },
+                );
+            }
+            let result = if *tag == HMTX {
+                self.font.try_hmtx().map(|_| ())
+            } else {
+                self.font.try_vmtx().map(|_| ())
+            };
+            if let Err(e) = result {
+                // A missing horizontal metrics table is reported with the
+                // other required tables.
+                if e.kind != ReadErrorKind::MissingTable || e.tag == Some(VMTX) {
+                    self.read_error(Severity::Error, e);
+                }
+            }
+        }
+        if self.font.find_record(LOCA).is_none() {
+            return;
+        }
+        let loca = match self.font.try_loca() {
+            Ok(loca) => loca,
+            Err(e) => {
+                if e.kind == ReadErrorKind::OutOfBounds && e.tag == Some(LOCA) {
+                    let format = self
+                        .font
+                        .head()
+                        .map(|head| head.index_to_location_format())
+                        .unwrap_or(0);
+                    let actual = self
+                        .font
+                        .table_data(LOCA)
+                        .map(|data| crate::glyf::Loca::new(data, format).num_glyphs())
+                        .unwrap_or(0);
+                    self.push(
+                        Severity::Error,
+                        Some(LOCA),
+                        e.offset,
+                        FindingKind::GlyphCountMismatch {
+                            expected: num_glyphs,
+                            actual,
+                        },
+                    );
+                } else if e.kind != ReadErrorKind::MissingTable {
+                    self.read_error(Severity::Error, e);
+                }
+                return;
+            }
+        };
+        if loca.num_glyphs() > num_glyphs {
+            self.push(
+                Severity::Warning,
+                Some(LOCA),
+                0,
+                FindingKind::GlyphCountMismatch {
+                    expected: num_glyphs,
+                    actual: loca.num_glyphs(),
+                },
+            );
+        }
+        let glyf_len = match self.font.table_data(GLYF) {
+            Some(glyf) => glyf.len(),
+            None => return,
+        };
+        let entry_size = if loca.is_long() { 4 } else { 2 };
+        for glyph_id in 0..num_glyphs {
+            match loca.get(glyph_id) {
+                Some(range) if range.end <= glyf_len => {}
+                _ => {
+                    self.push(
+                        Severity::Error,
+                        Some(LOCA),
+                        glyph_id as usize * entry_size,
+                        FindingKind::InvalidGlyphOffset(glyph_id),
+                    );
+                    break;
+                }
+            }
+        }
+    }
+
+    fn cmap(&mut self) {
+        let cmap = match self.font.try_cmap() {
+            Ok(cmap) => cmap,
+            Err(e) => {
+                if e.kind != ReadErrorKind::MissingTable {
+                    self.read_error(Severity::Error, e);
+                }
+                return;
+            }
+        };
+        for subtable in cmap.subtables() {
+            let offset = subtable.encoding.offset as usize;
+            match subtable.check() {
+                Ok(_) => {}
+                // Variation sequences are not used for direct mapping.
+                Err(_) if subtable.format() == 14 => {}
+                Err(e) => match e.kind {
+                    ReadErrorKind::UnsupportedFormat(format) => self.push(
+                        Severity::Info,
+                        Some(CMAP),
+                        offset,
+                        FindingKind::UnsupportedCmapFormat {
+                            platform_id: subtable.encoding.platform_id,
+                            encoding_id: subtable.encoding.encoding_id,
+                            format: format as u16,
+                        },
+                    ),
+                    _ => self.read_error(Severity::Error, e.with_base(offset).with_tag(CMAP)),
+                },
+            }
+        }
+    }
+
+    fn layout(&mut self, tag: Tag, layout: &Layout) {
+        let b = Buffer::new(layout.data());
+        for field in [4, 6, 8].iter() {
+            let result = b
+                .try_read::<u16>(*field)
+                .and_then(|offset| b.try_read::<u16>(offset as usize));
+            if let Err(e) = result {
+                self.read_error(Severity::Error, e.with_tag(tag));
+                return;
+            }
+        }
+        let num_lookups = layout.num_lookups();
+        for feature in (0..layout.num_features()).filter_map(|index| layout.feature(index)) {
+            for index in feature.lookup_indices().iter() {
+                if index >= num_lookups {
+                    self.push(
+                        Severity::Warning,
+                        Some(tag),
+                        feature.record.offset as usize,
+                        FindingKind::LookupIndexOutOfRange(index),
+                    );
+                }
+            }
+        }
+        let mut nested: Vec<Vec<u16>> = vec![Vec::new(); num_lookups as usize];
+        for index in 0..num_lookups {
+            let lookup = match layout.lookup(index) {
+                Some(lookup) => lookup,
+                None => {
+                    self.push(
+                        Severity::Error,
+                        Some(tag),
+                        lookup_offset(&b, index),
+                        FindingKind::InvalidLookup(index),
+                    );
+                    continue;
+                }
+            };
+            for subtable_index in 0..lookup.num_subtables() {
+                let subtable = match lookup.subtable(subtable_index) {
+                    Some(subtable) => subtable,
+                    None => {
+                        self.push(
+                            Severity::Error,
+                            Some(tag),
+                            lookup.record.offset as usize,
+                            FindingKind::InvalidSubtable {
+                                lookup: index,
+                                subtable: subtable_index,
+                            },
+                        );
+                        continue;
+                    }
+                };
+                self.coverage(
+                    tag,
+                    subtable.coverage().is_sorted(),
+                    subtable.coverage().offset(),
+                );
+                let coverages = |this: &mut Self, array: &CoverageArray| {
+                    for coverage in array.iter() {
+                        this.coverage(tag, coverage.is_sorted(), coverage.offset());
+                    }
+                };
+                let lookups = &mut nested[index as usize];
+                use SubtableKind::*;
+                match subtable.kind() {
+                    SeqContext1(s) => {
+                        for rule_set in s.rule_sets().iter() {
+                            for rule in rule_set.iter() {
+                                add_nested(lookups, rule.lookups);
+                            }
+                        }
+                    }
+                    SeqContext2(s) => {
+                        for rule_set in s.rule_sets().iter() {
+                            for rule in rule_set.iter() {
+                                add_nested(lookups, rule.lookups);
+                            }
+                        }
+                    }
+                    SeqContext3(s) => {
+                        if let Some(rule) = s.rule() {
+                            add_nested(lookups, rule.lookups);
+                            coverages(self, &rule.input);
+                        }
+                    }
+                    ChainContext1(s) => {
+                        for rule_set in s.rule_sets().iter() {
+                            for rule in rule_set.iter() {
+                                add_nested(lookups, rule.lookups);
+                            }
+                        }
+                    }
+                    ChainContext2(s) => {
+                        for rule_set in s.rule_sets().iter() {
+                            for rule in rule_set.iter() {
+                                add_nested(lookups, rule.lookups);
+                            }
+                        }
+                    }
+                    ChainContext3(s) => {
+                        if let Some(rule) = s.rule() {
+                            add_nested(lookups, rule.lookups);
+                            coverages(self, &rule.backtrack);
+                            coverages(self, &rule.input);
+                            coverages(self, &rule.lookahead);
+                        }
+                    }
+                    RevChainContext1(s) => {
+                        if let Some(rule) = s.rule() {
+                            coverages(self, &rule.backtrack);
+                            coverages(self, &rule.lookahead);
+                        }
+                    }
+                    _ => {}
+                }
+            }
+        }
+        self.nesting(tag, &b, &nested);
+    }
+
+    fn coverage(&mut self, tag: Tag, is_sorted: bool, offset: u32) {
+        if !is_sorted {
+            self.push(
+                Severity::Warning,
+                Some(tag),
+                offset as usize,
+                FindingKind::UnsortedCoverage,
+            );
+        }
+    }
+
+    /// Checks the graph of nested lookups for out of range references,
+    /// cycles and excessive depth.
+    fn nesting(&mut self, tag: Tag, b: &Buffer, nested: &[Vec<u16>]) {
+        const UNVISITED: u8 = 0;
+        const ACTIVE: u8 = 1;
+        const DONE: u8 = 2;
+        let len = nested.len();
+        for (index, lookups) in nested.iter().enumerate() {
+            for nested_index in lookups {
+                if *nested_index as usize >= len {
+                    self.push(
+                        Severity::Error,
+                        Some(tag),
+                        lookup_offset(b, index as u16),
+                        FindingKind::LookupIndexOutOfRange(*nested_index),
+                    );
+                }
+            }
+        }
+        // Iterative depth first search to avoid unbounded recursion on
+        // malicious input.
+        let mut state = vec![UNVISITED; len];
+        let mut depth = vec![0usize; len];
+        let mut recursive = vec![false; len];
+        let mut stack: Vec<(usize, usize)> = Vec::new();
+        for root in 0..len {
+            if state[root] != UNVISITED {
+                continue;
+            }
+            state[root] = ACTIVE;
+            stack.push((root, 0));
+            while let Some(top) = stack.last_mut() {
+                let (node, child) = *top;
+                if let Some(next) = nested[node].get(child) {
+                    top.1 += 1;
+                    let next = *next as usize;
+                    if next >= len {
+                        continue;
+                    }
+                    match state[next] {
+                        UNVISITED => {
+                            state[next] = ACTIVE;
+                            stack.push((next, 0));
+                        }
+                        ACTIVE => recursive[next] = true,
+                        _ => {}
+                    }
+                } else {
+                    depth[node] = nested[node]
+                        .iter()
+                        .map(|next| *next as usize)
+                        .filter(|next| *next < len && state[*next] == DONE)
+                        .map(|next| depth[next] + 1)
+                        .max()
+                        .unwrap_or(0);
+                    state[node] = DONE;
+                    stack.pop();
+                }
+            }
+        }
+        for index in 0..len {
+            let offset = lookup_offset(b, index as u16);
+            if recursive[index] {
+                self.push(
+                    Severity::Error,
+                    Some(tag),
+                    offset,
+                    FindingKind::RecursiveLookup(index as u16),
+                );
+            } else if depth[index] > MAX_NESTING_DEPTH {
+                self.push(
+                    Severity::Warning,
+                    Some(tag),
+                    offset,
+                    FindingKind::NestingTooDeep {
+                        lookup: index as u16,
+                        depth: depth[index],
+                    },
+                );
+            }
+        }
+    }
+}
+
+/// Returns the offset of the lookup at the specified index from the
+/// beginning of the layout table.
+fn lookup_offset(b: &Buffer, index: u16) -> usize {
+    let list = b.read_u16(8).unwrap_or(0) as usize;
+    list + b
+        .read_u16(list + 2 + index as usize * 2)
+        .map(|offset| offset as usize)
+        .unwrap_or(0)
+}
+
+fn add_nested(lookups: &mut Vec<u16>, nested: Slice<NestedLookup>) {
+    for nested in nested.iter() {
+        if !lookups.contains(&nested.lookup_index) {
+            lookups.push(nested.lookup_index);
+        }
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::parse::ReadErrorKind;
+    use crate::testing::{context_subst, layout, lookup, TestFont, Writer};
+
+    /// Returns the severity, kind and tag of each finding, skipping the
+    /// missing naming and OS/2 tables that test fonts do not contain.
+    fn findings(data: &[u8]) -> Vec<(Severity, FindingKind, Option<Tag>)> {
+        validate(data)
+            .into_iter()
+            .filter(|f| !matches!(f.kind, FindingKind::Read(e) if e.kind == ReadErrorKind::MissingTable))
+            .map(|f| (f.severity, f.kind, f.tag))
+            .collect()
+    }
+
+    #[test]
+    fn well_formed() {
+        let data = TestFont::new(3).chars("ab").build();
+        let missing = validate(&data)
+            .iter()
+            .map(|f| (f.severity, f.tag))
+            .collect::<Vec<_>>();
+        assert_eq!(
+            missing,
+            [
+                (Severity::Warning, Some(Tag::new(b"name"))),
+                (Severity::Warning, Some(Tag::new(b"OS/2")))
+            ]
+        );
+        assert_eq!(
+            findings(b"junk data"),
+            [(Severity::Error, FindingKind::UnknownFormat, None)]
+        );
+    }
+
+    #[test]
+    fn checksums() {
+        let mut data = TestFont::new(3).build();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        let post = font.find_record(Tag::new(b"post")).unwrap().offset as usize;
+        data[post + 4] ^= 1;
+        let kinds = findings(&data)
+            .iter()
+            .map(|(severity, kind, tag)| match kind {
+                FindingKind::ChecksumMismatch { .. } => (*severity, "table", *tag),
+                FindingKind::ChecksumAdjustmentMismatch { .. } => (*severity, "font", *tag),
+                _ => (*severity, "other", *tag),
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            kinds,
+            [
+                (Severity::Warning, "table", Some(Tag::new(b"post"))),
+                (Severity::Warning, "font", Some(HEAD))
+            ]
+        );
+    }
+
+    #[test]
+    fn glyph_locations() {
+        let mut loca = Writer::new();
+        loca.u32(0).u32(60).u32(30).u32(90);
+        let data = TestFont::new(3).table(b"loca", loca.finish()).build();
+        assert_eq!(
+            findings(&data),
+            [(
+                Severity::Error,
+                FindingKind::InvalidGlyphOffset(1),
+                Some(Tag::new(b"loca"))
+            )]
+        );
+    }
+
+    #[test]
+    fn lookups() {
+        let gsub = layout(
+            b"DFLT",
+            &[(b"liga", &[0, 5])],
+            &[lookup(5, 0, &[context_subst(&[1, 2], &[(0, 0)])])],
+        );
+        let data = TestFont::new(3).table(b"GSUB", gsub).build();
+        let gsub = Some(Tag::new(b"GSUB"));
+        assert_eq!(
+            findings(&data),
+            [
+                (
+                    Severity::Warning,
+                    FindingKind::LookupIndexOutOfRange(5),
+                    gsub
+                ),
+                (Severity::Error, FindingKind::RecursiveLookup(0), gsub)
+            ]
+        );
+    }
+}
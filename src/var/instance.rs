@@ -0,0 +1,403 @@
+//! Variation axes and named instances.
+
+use crate::avar::Avar;
+use crate::font::TableProvider;
+use crate::fvar::{Axis, Fvar, Instance};
+use crate::ltag::Ltag;
+use crate::name::{Entry, Name, NameId};
+use crate::parse_prelude::*;
+#[cfg(feature = "std")]
+use std::{vec, vec::Vec};
+
+/// Maximum number of axes supported by the cross axis mapping of version 2
+/// axis variations tables.
+const MAX_AXES: usize = 64;
+
+/// Variation axes and named instances of a variable font along with the
+/// mappings between user and normalized coordinates.
+#[derive(Copy, Clone)]
+pub struct Variations<'a> {
+    /// Font variations table.
+    pub fvar: Fvar<'a>,
+    /// Axis variations table.
+    pub avar: Option<Avar<'a>>,
+    /// Naming table.
+    pub name: Option<Name<'a>>,
+    /// Language tag table.
+    pub ltag: Option<Ltag<'a>>,
+}
+
+impl<'a> Variations<'a> {
+    /// Creates variations for the specified font. Returns `None` if the font
+    /// is not variable.
+    pub fn new(font: &impl TableProvider<'a>) -> Option<Self> {
+        Some(Self {
+            fvar: font.fvar()?,
+            avar: font.avar(),
+            name: font.name(),
+            ltag: font.ltag(),
+        })
+    }
+
+    /// Returns the number of variation axes.
+    pub fn num_axes(&self) -> u16 {
+        self.fvar.num_axes()
+    }
+
+    /// Returns the variation axis at the specified index.
+    pub fn axis(&self, index: u16) -> Option<Axis> {
+        self.fvar.axis(index)
+    }
+
+    /// Returns an iterator over the variation axes.
+    pub fn axes(&self) -> impl Iterator<Item = Axis> + 'a + Clone {
+        let fvar = self.fvar;
+        (0..fvar.num_axes()).filter_map(move |index| fvar.axis(index))
+    }
+
+    /// Returns the first variation axis with the specified tag.
+    pub fn axis_by_tag(&self, tag: Tag) -> Option<Axis> {
+        self.axes().find(|axis| axis.tag == tag)
+    }
+
+    /// Returns the name of the specified axis that best matches a list of
+    /// BCP 47 language tags in order of preference.
+    pub fn axis_name(&self, axis: &Axis, languages: &[&str]) -> Option<Entry<'a>> {
+        self.find_name(axis.name_id, languages)
+    }
+
+    /// Returns the number of named instances.
+    pub fn num_instances(&self) -> u16 {
+        self.fvar.num_instances()
+    }
+
+    /// Returns the named instance at the specified index.
+    pub fn instance(&self, index: u16) -> Option<NamedInstance<'a>> {
+        Some(NamedInstance {
+            variations: *self,
+            instance: self.fvar.instance(index)?,
+        })
+    }
+
+    /// Returns an iterator over the named instances.
+    pub fn instances(&self) -> impl Iterator<Item = NamedInstance<'a>> + 'a + Clone {
+        let copy = *self;
+        (0..self.num_instances()).filter_map(move |index| copy.instance(index))
+    }
+
+    /// Returns the first named instance with a subfamily or PostScript name
+    /// that is equal to the specified name in any language.
+    pub fn instance_by_name(&self, name: &str) -> Option<NamedInstance<'a>> {
+        let table = self.name?;
+        let matches = |name_id: NameId| {
+            table.entries().any(|entry| {
+                entry.record.name_id == name_id
+                    && entry.record.is_decodable()
+                    && entry.decode().eq(name.chars())
+            })
+        };
+        self.instances().find(|instance| {
+            matches(instance.instance.subfamily_name_id)
+                || instance.instance.postscript_name_id.map(matches) == Some(true)
+        })
+    }
+
+    /// Returns the normalized coordinate for a user coordinate on the
+    /// specified axis. The segment map for the axis is applied when present.
+    pub fn normalize_axis(&self, index: u16, value: Fixed) -> Fixed {
+        let axis = match self.fvar.axis(index) {
+            Some(axis) => axis,
+            None => return Fixed::ZERO,
+        };
+        let coord = axis.normalize(value);
+        match self.avar.and_then(|avar| avar.segment_map(index)) {
+            Some(map) => map.apply(coord),
+            None => coord,
+        }
+    }
+
+    /// Returns the user coordinate for a normalized coordinate on the
+    /// specified axis. This is the inverse of
+    /// [`normalize_axis`](Self::normalize_axis).
+    pub fn denormalize_axis(&self, index: u16, coord: Fixed) -> Fixed {
+        let axis = match self.fvar.axis(index) {
+            Some(axis) => axis,
+            None => return Fixed::ZERO,
+        };
+        let coord = match self.avar.and_then(|avar| avar.segment_map(index)) {
+            Some(map) => map.apply_inverse(coord),
+            None => coord,
+        };
+        axis.denormalize(coord)
+    }
+
+    /// Computes normalized coordinates for the specified user coordinates
+    /// in axis order. Axes without a user coordinate are set to their
+    /// default values. The cross axis mapping of version 2 axis variations
+    /// tables is applied for fonts with at most 64 axes.
+    pub fn normalize(
+        &self,
+        user_coords: impl IntoIterator<Item = Fixed>,
+        coords: &mut [NormalizedCoord],
+    ) {
+        let mut user_coords = user_coords.into_iter();
+        for (index, coord) in coords.iter_mut().enumerate() {
+            *coord = match (self.fvar.axis(index as u16), user_coords.next()) {
+                (Some(_), Some(value)) => self.normalize_axis(index as u16, value).to_f2dot14(),
+                _ => 0,
+            };
+        }
+        self.apply_deltas(coords);
+    }
+
+    /// Computes normalized coordinates for the specified variation settings.
+    /// Axes that are not present in the settings are set to their default
+    /// values. The cross axis mapping of version 2 axis variations tables is
+    /// applied for fonts with at most 64 axes.
+    pub fn normalize_settings<I>(&self, settings: I, coords: &mut [NormalizedCoord])
+    where
+        I: IntoIterator,
+        I::Item: Into<(Tag, f32)>,
+    {
+        for coord in coords.iter_mut() {
+            *coord = 0;
+        }
+        for setting in settings {
+            let (tag, value) = setting.into();
+            for axis in self.axes().filter(|axis| axis.tag == tag) {
+                if let Some(coord) = coords.get_mut(axis.index as usize) {
+                    *coord = self
+                        .normalize_axis(axis.index, Fixed::from_f32(value))
+                        .to_f2dot14();
+                }
+            }
+        }
+        self.apply_deltas(coords);
+    }
+
+    /// Computes user coordinates for the specified normalized coordinates
+    /// in axis order. The cross axis mapping of version 2 axis variations
+    /// tables cannot be inverted and is ignored.
+    pub fn denormalize(&self, coords: &[NormalizedCoord], user_coords: &mut [Fixed]) {
+        for (index, value) in user_coords.iter_mut().enumerate() {
+            let coord = coords.get(index).copied().unwrap_or(0);
+            *value = self.denormalize_axis(index as u16, Fixed::from_f2dot14(coord));
+        }
+    }
+
+    fn apply_deltas(&self, coords: &mut [NormalizedCoord]) {
+        if coords.len() > MAX_AXES {
+            return;
+        }
+        if let Some(avar) = self.avar.filter(|avar| avar.major_version() >= 2) {
+            let mut mapped = [0; MAX_AXES];
+            let mapped = &mut mapped[..coords.len()];
+            mapped.copy_from_slice(coords);
+            avar.apply_deltas(mapped, coords);
+        }
+    }
+
+    fn find_name(&self, name_id: NameId, languages: &[&str]) -> Option<Entry<'a>> {
+        self.name?.find(name_id, languages, self.ltag.as_ref())
+    }
+}
+
+/// Named instance of a variable font.
+#[derive(Copy, Clone)]
+pub struct NamedInstance<'a> {
+    variations: Variations<'a>,
+    /// Instance record in the font variations table.
+    pub instance: Instance<'a>,
+}
+
+impl<'a> NamedInstance<'a> {
+    /// Returns the index of the instance.
+    pub fn index(&self) -> u16 {
+        self.instance.index
+    }
+
+    /// Returns the subfamily name that best matches a list of BCP 47
+    /// language tags in order of preference.
+    pub fn subfamily_name(&self, languages: &[&str]) -> Option<Entry<'a>> {
+        self.variations
+            .find_name(self.instance.subfamily_name_id, languages)
+    }
+
+    /// Returns the PostScript name of the instance.
+    pub fn postscript_name(&self) -> Option<Entry<'a>> {
+        self.variations
+            .find_name(self.instance.postscript_name_id?, &[])
+    }
+
+    /// Returns an iterator over the user coordinates of the instance in
+    /// axis order.
+    pub fn user_coords(&self) -> impl Iterator<Item = Fixed> + 'a + Clone {
+        self.instance.coords.iter()
+    }
+
+    /// Computes the normalized coordinates of the instance.
+    pub fn normalize(&self, coords: &mut [NormalizedCoord]) {
+        self.variations.normalize(self.user_coords(), coords);
+    }
+
+    /// Returns the normalized coordinates of the instance.
+    #[cfg(feature = "std")]
+    pub fn normalized_coords(&self) -> Vec<NormalizedCoord> {
+        let mut coords = vec![0; self.variations.num_axes() as usize];
+        self.normalize(&mut coords);
+        coords
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Font variations table with weight (100 to 900, default 400) and
+    /// width (50 to 200, default 100) axes.
+    #[rustfmt::skip]
+    const FVAR: &[u8] = &[
+        // Header: version, axes offset, reserved, axis count and size,
+        // instance count and size.
+        0, 1, 0, 0, 0, 16, 0, 2, 0, 2, 0, 20, 0, 0, 0, 12,
+        // Axes: tag, min, default and max values, flags, name identifier.
+        b'w', b'g', b'h', b't', 0, 100, 0, 0, 1, 144, 0, 0, 3, 132, 0, 0, 0, 0, 1, 0,
+        b'w', b'd', b't', b'h', 0, 50, 0, 0, 0, 100, 0, 0, 0, 200, 0, 0, 0, 0, 1, 1,
+    ];
+
+    /// Version 2 axis variations table without segment maps that moves the
+    /// width by -0.5 at the maximum weight.
+    #[rustfmt::skip]
+    const AVAR: &[u8] = &[
+        // Header: version, reserved, axis count, empty segment maps.
+        0, 2, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0,
+        // Axis index map and item variation store offsets.
+        0, 0, 0, 0, 0, 0, 0, 20,
+        // Store header: format, region list offset, data count, data offset.
+        0, 1, 0, 0, 0, 12, 0, 1, 0, 0, 0, 28,
+        // Region list: axis count, region count, (start, peak, end) per axis.
+        0, 2, 0, 1, 0x00, 0x00, 0x40, 0x00, 0x40, 0x00, 0, 0, 0, 0, 0, 0,
+        // Variation data: item count, word delta count, region indices.
+        0, 2, 0, 1, 0, 1, 0, 0,
+        // Delta sets: weight, width.
+        0, 0, 0xE0, 0x00,
+    ];
+
+    /// Font variations table with the axes above and two named instances
+    /// with PostScript names: Bold (650, 100) and Condensed Light (250, 75).
+    #[rustfmt::skip]
+    const FVAR_INSTANCES: &[u8] = &[
+        0, 1, 0, 0, 0, 16, 0, 2, 0, 2, 0, 20, 0, 2, 0, 14,
+        b'w', b'g', b'h', b't', 0, 100, 0, 0, 1, 144, 0, 0, 3, 132, 0, 0, 0, 0, 1, 0,
+        b'w', b'd', b't', b'h', 0, 50, 0, 0, 0, 100, 0, 0, 0, 200, 0, 0, 0, 0, 1, 1,
+        // Instances: subfamily name identifier, flags, coordinates and
+        // PostScript name identifier.
+        1, 2, 0, 0, 2, 138, 0, 0, 0, 100, 0, 0, 1, 3,
+        1, 4, 0, 0, 0, 250, 0, 0, 0, 75, 0, 0, 1, 5,
+    ];
+
+    /// Version 1 axis variations table that maps 0.5 to 0.25 on the weight
+    /// axis and leaves the width axis unchanged.
+    #[rustfmt::skip]
+    const AVAR_SEGMENTS: &[u8] = &[
+        0, 1, 0, 0, 0, 0, 0, 2,
+        0, 4, 0xC0, 0x00, 0xC0, 0x00, 0, 0, 0, 0, 0x20, 0x00, 0x10, 0x00, 0x40, 0x00, 0x40, 0x00,
+        0, 0,
+    ];
+
+    fn variations() -> Variations<'static> {
+        Variations {
+            fvar: Fvar::new(FVAR),
+            avar: Some(Avar::new(AVAR)),
+            name: None,
+            ltag: None,
+        }
+    }
+
+    #[test]
+    fn cross_axis_mapping() {
+        let variations = variations();
+        let mut coords = [0; 2];
+        variations.normalize([900, 100].iter().map(|&v| Fixed::from_i32(v)), &mut coords);
+        assert_eq!(coords, [0x4000, -0x2000]);
+        variations.normalize_settings([(Tag::new(b"wght"), 650.0)], &mut coords);
+        assert_eq!(coords, [0x2000, -0x1000]);
+        variations.normalize_settings([(Tag::new(b"wdth"), 200.0)], &mut coords);
+        assert_eq!(coords, [0, 0x4000]);
+    }
+
+    #[test]
+    fn too_many_axes() {
+        let variations = variations();
+        let mut coords = [0; MAX_AXES + 1];
+        variations.normalize([900, 100].iter().map(|&v| Fixed::from_i32(v)), &mut coords);
+        assert_eq!(coords[..2], [0x4000, 0]);
+    }
+
+    #[test]
+    fn segment_maps() {
+        let variations = Variations {
+            fvar: Fvar::new(FVAR),
+            avar: Some(Avar::new(AVAR_SEGMENTS)),
+            name: None,
+            ltag: None,
+        };
+        let wght = |value| variations.normalize_axis(0, Fixed::from_i32(value));
+        assert_eq!(wght(650), Fixed(0x4000));
+        assert_eq!(wght(900), Fixed::ONE);
+        assert_eq!(wght(250), Fixed(-0x8000));
+        assert_eq!(wght(1000), Fixed::ONE);
+        assert_eq!(
+            variations.normalize_axis(1, Fixed::from_i32(75)),
+            Fixed(-0x8000)
+        );
+        assert_eq!(
+            variations.normalize_axis(2, Fixed::from_i32(75)),
+            Fixed::ZERO
+        );
+        let mut user_coords = [Fixed::ZERO; 3];
+        variations.denormalize(&[0x1000, -0x2000], &mut user_coords);
+        assert_eq!(
+            user_coords,
+            [Fixed::from_i32(650), Fixed::from_i32(75), Fixed::ZERO]
+        );
+    }
+
+    #[cfg(feature = "std")]
+    #[test]
+    fn named_instances() {
+        use crate::testing::name;
+        use std::string::String;
+        let name_data = name(
+            &[
+                (3, 1, 0x0409, 258, "Bold"),
+                (3, 1, 0x0409, 259, "Test-Bold"),
+                (3, 1, 0x0409, 260, "Condensed Light"),
+                (3, 1, 0x0407, 260, "Schmal Leicht"),
+                (3, 1, 0x0409, 261, "Test-CondensedLight"),
+            ],
+            &[],
+        );
+        let variations = Variations {
+            fvar: Fvar::new(FVAR_INSTANCES),
+            avar: Some(Avar::new(AVAR_SEGMENTS)),
+            name: Some(Name::new(&name_data)),
+            ltag: None,
+        };
+        assert_eq!(variations.num_instances(), 2);
+        let instance = variations.instance_by_name("Schmal Leicht").unwrap();
+        assert_eq!(instance.index(), 1);
+        let subfamily: String = instance.subfamily_name(&["de"]).unwrap().decode().collect();
+        assert_eq!(subfamily, "Schmal Leicht");
+        let postscript: String = instance.postscript_name().unwrap().decode().collect();
+        assert_eq!(postscript, "Test-CondensedLight");
+        assert_eq!(instance.normalized_coords(), [-0x2000, -0x2000]);
+        let instance = variations.instance_by_name("Test-Bold").unwrap();
+        assert_eq!(instance.index(), 0);
+        assert!(instance
+            .user_coords()
+            .eq([Fixed::from_i32(650), Fixed::from_i32(100)]));
+        assert_eq!(instance.normalized_coords(), [0x1000, 0]);
+        assert!(variations.instance_by_name("Black").is_none());
+    }
+}
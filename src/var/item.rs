@@ -59,7 +59,7 @@ impl<'a> DeltaSetIndexMap<'a> {
         let bit_count = (format & 0xF) + 1;
         let entry_size = ((format & 0x30) >> 4) + 1;
         let base = self.offset + if self.format == 0 { 4 } else { 6 };
-        let index = index.min(self.count - 1) as usize;
+        let index = index.min(self.count.checked_sub(1)?) as usize;
         let entry = match entry_size {
             1 => d.read_u8(base + index)? as u32,
             2 => d.read_u16(base + index * 2)? as u32,
@@ -251,6 +251,7 @@ impl<'a> Region<'a> {
 #[derive(Copy, Clone)]
 struct OuterSet<'a> {
     data: Buffer<'a>,
+    /// Offset to the delta sets.
     offset: usize,
     len: u16,
     num_word_deltas: u16,
@@ -269,10 +270,11 @@ impl<'a> OuterSet<'a> {
         let region_indices = data.read_slice16(offset + 4)?;
         let num_regions = region_indices.len() as u32;
         let row_size = num_word_deltas as u32 * word_size
-            + (num_regions - num_word_deltas as u32) * small_size;
+            + num_regions.saturating_sub(num_word_deltas as u32) * small_size;
         Some(Self {
             data,
-            offset,
+            // The delta sets follow the region indices.
+            offset: offset + 6 + num_regions as usize * 2,
             len,
             num_word_deltas,
             long_words,
@@ -346,3 +348,43 @@ impl<'a> Iterator for Deltas<'a> {
         Some((region, value))
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    /// Item variation store with a single axis, a single region peaking at
+    /// 1.0 and one set of two items with 8-bit deltas of 10 and -20.
+    #[rustfmt::skip]
+    const STORE: &[u8] = &[
+        // Header: format, region list offset, data count, data offset.
+        0, 1, 0, 0, 0, 12, 0, 1, 0, 0, 0, 22,
+        // Region list: axis count, region count, (start, peak, end).
+        0, 1, 0, 1, 0x00, 0x00, 0x40, 0x00, 0x40, 0x00,
+        // Variation data: item count, word delta count, region indices.
+        0, 2, 0, 0, 0, 1, 0, 0,
+        // Delta sets.
+        10, 0xEC,
+    ];
+
+    #[test]
+    fn delta_sets_follow_region_indices() {
+        let store = ItemVariationStore::new(Buffer::new(STORE), 0).unwrap();
+        let delta = |inner, coord| store.delta(Index::new(0, inner), &[coord]);
+        // Reading rows from the start of the variation data yields 0 and 2.
+        assert_eq!(delta(0, 0x4000), Fixed::from_i32(10));
+        assert_eq!(delta(1, 0x4000), Fixed::from_i32(-20));
+        assert_eq!(delta(0, 0x2000), Fixed::from_i32(5));
+        assert_eq!(delta(1, 0), Fixed::ZERO);
+    }
+
+    #[test]
+    fn excess_word_deltas() {
+        // A word delta count larger than the region count must not overflow
+        // the row size. The only delta is read as a 16-bit value.
+        let mut data = STORE.to_vec();
+        data[25] = 2;
+        let store = ItemVariationStore::new(Buffer::new(&data), 0).unwrap();
+        assert_eq!(store.delta(Index::new(0, 0), &[0x4000]).to_i32(), 2796);
+    }
+}
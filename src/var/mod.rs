@@ -1,3 +1,4 @@
 //! OpenType variation common types.
 
+pub mod instance;
 pub mod item;
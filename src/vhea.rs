@@ -18,6 +18,14 @@ impl<'a> Vhea<'a> {
         Self(Buffer::new(data))
     }
 
+    /// Creates a new vertical header table from a byte slice containing the table
+    /// data, returning an error if the table is truncated.
+    pub fn try_new(data: &'a [u8]) -> Result<Self, ReadError> {
+        let b = Buffer::new(data);
+        b.try_ensure_range(0, 36).map_err(|e| e.with_tag(VHEA))?;
+        Ok(Self(b))
+    }
+
     /// Returns the major version.
     pub fn major_version(&self) -> u16 {
         self.0.read(0).unwrap_or(0)
@@ -45,6 +45,27 @@ impl<'a> Vmtx<'a> {
         }
     }
 
+    /// Creates a new vertical metrics table from a byte slice containing
+    /// the table data, the number of glyphs and the number of vertical
+    /// metrics, returning an error if the table is too short to contain the
+    /// metrics and side bearings for all glyphs.
+    pub fn try_new(data: &'a [u8], num_glyphs: u16, num_vmetrics: u16) -> Result<Self, ReadError> {
+        let b = Buffer::new(data);
+        if num_vmetrics == 0 && num_glyphs != 0 {
+            return Err(ReadError::invalid_data(0).with_tag(VMTX));
+        }
+        let num_metrics = num_vmetrics.min(num_glyphs) as usize;
+        let len = num_metrics * 4 + (num_glyphs as usize - num_metrics) * 2;
+        if data.len() < len {
+            return Err(ReadError::out_of_bounds(data.len()).with_tag(VMTX));
+        }
+        Ok(Self {
+            data: b,
+            num_glyphs,
+            num_vmetrics,
+        })
+    }
+
     /// Returns the number of glyphs.
     pub fn num_glyphs(&self) -> u16 {
         self.num_glyphs
@@ -0,0 +1,245 @@
+//! Web Open Font Format 1.0.
+//!
+//! <https://www.w3.org/TR/WOFF/>
+
+use crate::build::{directory_len, padded_len, FontBuilder};
+use crate::font::{FontDataKind, FontRef};
+use crate::parse_prelude::*;
+use std::borrow::Cow;
+use std::vec::Vec;
+
+/// Tag for the WOFF 1.0 signature.
+pub const WOFF: Tag = Tag::new(b"wOFF");
+
+/// Size of the WOFF header.
+const HEADER_LEN: usize = 44;
+
+/// Size of a table directory entry.
+const ENTRY_LEN: usize = 20;
+
+/// Entry in the table directory of a WOFF file.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct WoffTableRecord {
+    /// Table identifier.
+    pub tag: Tag,
+    /// Offset to the table data from the beginning of the file.
+    pub offset: u32,
+    /// Length of the compressed table data.
+    pub comp_len: u32,
+    /// Length of the uncompressed table data.
+    pub orig_len: u32,
+    /// Checksum of the uncompressed table data.
+    pub orig_checksum: u32,
+}
+
+impl ReadData for WoffTableRecord {
+    unsafe fn read_data_unchecked(buf: &[u8], offset: usize) -> Self {
+        Self {
+            tag: Tag(u32::read_data_unchecked(buf, offset)),
+            offset: u32::read_data_unchecked(buf, offset + 4),
+            comp_len: u32::read_data_unchecked(buf, offset + 8),
+            orig_len: u32::read_data_unchecked(buf, offset + 12),
+            orig_checksum: u32::read_data_unchecked(buf, offset + 16),
+        }
+    }
+}
+
+/// Font compressed with WOFF 1.0.
+#[derive(Copy, Clone)]
+pub struct Woff<'a>(Buffer<'a>);
+
+impl<'a> Woff<'a> {
+    /// Creates a new WOFF font from a byte slice containing the file data.
+    /// Returns `None` if the data does not begin with a WOFF 1.0 header.
+    pub fn new(data: &'a [u8]) -> Option<Self> {
+        let b = Buffer::new(data);
+        if b.read_tag(0)? != WOFF || data.len() < HEADER_LEN {
+            return None;
+        }
+        Some(Self(b))
+    }
+
+    /// Returns the version tag of the uncompressed font.
+    pub fn flavor(&self) -> u32 {
+        self.0.read(4).unwrap_or(0)
+    }
+
+    /// Returns the number of tables in the font.
+    pub fn len(&self) -> u16 {
+        self.0.read(12).unwrap_or(0)
+    }
+
+    /// Returns true if the font does not contain any tables.
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the size of the uncompressed font.
+    pub fn total_sfnt_size(&self) -> u32 {
+        self.0.read(16).unwrap_or(0)
+    }
+
+    /// Returns the major and minor version of the font.
+    pub fn font_version(&self) -> (u16, u16) {
+        (self.0.read(20).unwrap_or(0), self.0.read(22).unwrap_or(0))
+    }
+
+    /// Returns the table directory.
+    pub fn records(&self) -> Slice<'a, WoffTableRecord> {
+        self.0
+            .read_slice(HEADER_LEN, self.len() as usize)
+            .unwrap_or_default()
+    }
+
+    /// Returns the table record with the specified tag.
+    pub fn find_record(&self, tag: Tag) -> Option<WoffTableRecord> {
+        self.records().iter().find(|record| record.tag == tag)
+    }
+
+    /// Returns the uncompressed data for the table with the specified tag.
+    pub fn table_data(&self, tag: Tag) -> Option<Cow<'a, [u8]>> {
+        self.decompress(self.find_record(tag)?)
+    }
+
+    /// Returns the uncompressed data for the specified table record.
+    pub fn decompress(&self, record: WoffTableRecord) -> Option<Cow<'a, [u8]>> {
+        let data = self
+            .0
+            .read_bytes(record.offset as usize, record.comp_len as usize)?;
+        if record.comp_len > record.orig_len {
+            return None;
+        }
+        if record.comp_len == record.orig_len {
+            return Some(Cow::Borrowed(data));
+        }
+        let data = inflate(data, record.orig_len as usize)?;
+        if data.len() != record.orig_len as usize {
+            return None;
+        }
+        Some(Cow::Owned(data))
+    }
+
+    /// Returns the uncompressed extended metadata block.
+    pub fn metadata(&self) -> Option<Vec<u8>> {
+        let offset = self.0.read_u32(24)? as usize;
+        let len = self.0.read_u32(28)? as usize;
+        let orig_len = self.0.read_u32(32)? as usize;
+        if offset == 0 {
+            return None;
+        }
+        let data = inflate(self.0.read_bytes(offset, len)?, orig_len)?;
+        if data.len() != orig_len {
+            return None;
+        }
+        Some(data)
+    }
+
+    /// Returns the private data block.
+    pub fn private_data(&self) -> Option<&'a [u8]> {
+        let offset = self.0.read_u32(36)? as usize;
+        let len = self.0.read_u32(40)? as usize;
+        if offset == 0 {
+            return None;
+        }
+        self.0.read_bytes(offset, len)
+    }
+
+    /// Returns a builder containing the uncompressed tables of the font.
+    pub fn to_builder(&self) -> Option<FontBuilder<'a>> {
+        let mut builder = FontBuilder::new(self.flavor());
+        for record in self.records().iter() {
+            builder.insert(record.tag, self.decompress(record)?);
+        }
+        Some(builder)
+    }
+}
+
+/// Decodes a WOFF 1.0 font into an sfnt that can be read with
+/// [`FontRef`].
+pub fn decode(data: &[u8]) -> Option<Vec<u8>> {
+    Woff::new(data)?.to_builder()?.build().ok()
+}
+
+/// Encodes the specified sfnt font as WOFF 1.0. Tables are compressed with
+/// zlib unless compression does not reduce their size. Returns `None` for
+/// font collections which cannot be represented in the format.
+pub fn encode(data: &[u8]) -> Option<Vec<u8>> {
+    if !matches!(FontDataKind::parse(data, 0)?, FontDataKind::Font(_)) {
+        return None;
+    }
+    let font = FontRef::from_index(data, 0)?;
+    let flavor = Buffer::new(font.data).read_u32(font.offset as usize)?;
+    let mut tables = font.tables().collect::<Vec<_>>();
+    tables.sort_unstable_by_key(|table| table.record.tag);
+    let num_tables = tables.len();
+    let mut entries = Vec::with_capacity(num_tables * ENTRY_LEN);
+    let mut table_data = Vec::new();
+    let mut offset = HEADER_LEN + num_tables * ENTRY_LEN;
+    let mut total_sfnt_size = directory_len(num_tables);
+    for table in &tables {
+        let compressed = miniz_oxide::deflate::compress_to_vec_zlib(table.data, 9);
+        let data = if compressed.len() < table.data.len() {
+            &compressed[..]
+        } else {
+            table.data
+        };
+        entries.extend_from_slice(&table.record.tag.0.to_be_bytes());
+        entries.extend_from_slice(&((offset + table_data.len()) as u32).to_be_bytes());
+        entries.extend_from_slice(&(data.len() as u32).to_be_bytes());
+        entries.extend_from_slice(&(table.data.len() as u32).to_be_bytes());
+        entries.extend_from_slice(&table.record.checksum.to_be_bytes());
+        table_data.extend_from_slice(data);
+        table_data.resize(padded_len(table_data.len()), 0);
+        total_sfnt_size += padded_len(table.data.len());
+    }
+    offset += table_data.len();
+    let mut woff = Vec::with_capacity(offset);
+    woff.extend_from_slice(&WOFF.0.to_be_bytes());
+    woff.extend_from_slice(&flavor.to_be_bytes());
+    woff.extend_from_slice(&(offset as u32).to_be_bytes());
+    woff.extend_from_slice(&(num_tables as u16).to_be_bytes());
+    woff.extend_from_slice(&0u16.to_be_bytes());
+    woff.extend_from_slice(&(total_sfnt_size as u32).to_be_bytes());
+    // Font version, extended metadata and private data.
+    woff.extend_from_slice(&[0; 24]);
+    woff.extend_from_slice(&entries);
+    woff.extend_from_slice(&table_data);
+    Some(woff)
+}
+
+/// Decompresses a zlib stream, limiting the output to the specified size.
+fn inflate(data: &[u8], len: usize) -> Option<Vec<u8>> {
+    miniz_oxide::inflate::decompress_to_vec_zlib_with_limit(data, len).ok()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::font::TableProvider;
+    use crate::testing::TestFont;
+
+    #[test]
+    fn round_trip() {
+        let data = TestFont::new(20).chars("abc").build();
+        let woff_data = encode(&data).unwrap();
+        let woff = Woff::new(&woff_data).unwrap();
+        let font = FontRef::from_index(&data, 0).unwrap();
+        assert_eq!(woff.flavor(), 0x00010000);
+        assert_eq!(woff.len(), font.len());
+        let glyf = woff.find_record(Tag::new(b"glyf")).unwrap();
+        assert!(glyf.comp_len < glyf.orig_len);
+        let decoded = decode(&woff_data).unwrap();
+        assert_eq!(decoded, data);
+        let decoded = FontRef::from_index(&decoded, 0).unwrap();
+        for table in font.tables() {
+            assert_eq!(decoded.table_data(table.record.tag), Some(table.data));
+        }
+    }
+
+    #[test]
+    fn truncated() {
+        let data = TestFont::new(4).build();
+        let woff_data = encode(&data).unwrap();
+        assert!(decode(&woff_data[..woff_data.len() - 8]).is_none());
+    }
+}
@@ -0,0 +1,717 @@
+//! Web Open Font Format 2.0.
+//!
+//! <https://www.w3.org/TR/WOFF2/>
+
+mod transform;
+
+use crate::build::{directory_len, padded_len, CollectionBuilder, FontBuilder};
+use crate::font::{FontDataKind, FontDataRef, FontRef, TableProvider};
+use crate::glyf::{GLYF, LOCA};
+use crate::head::HEAD;
+use crate::hhea::HHEA;
+use crate::hmtx::HMTX;
+use crate::maxp::MAXP;
+use crate::parse_prelude::*;
+use brotli::enc::backward_references::BrotliEncoderMode;
+use brotli::enc::BrotliEncoderParams;
+use std::borrow::Cow;
+use std::collections::HashMap;
+use std::io::Read;
+use std::vec::Vec;
+
+/// Tag for the WOFF 2.0 signature.
+pub const WOFF2: Tag = Tag::new(b"wOF2");
+
+/// Version tag for font collections.
+const TTCF: u32 = 0x74746366;
+
+/// Size of the WOFF2 header.
+const HEADER_LEN: usize = 48;
+
+/// Maximum size of the decompressed table data or metadata. Lengths in the
+/// header and table directory are untrusted, so larger values are rejected
+/// before decompression.
+const MAX_DECOMPRESSED_LEN: usize = 1 << 30;
+
+/// Tags that are encoded as an index in the table directory.
+const KNOWN_TAGS: [&[u8; 4]; 63] = [
+    b"cmap", b"head", b"hhea", b"hmtx", b"maxp", b"name", b"OS/2", b"post", b"cvt ", b"fpgm",
+    b"glyf", b"loca", b"prep", b"CFF ", b"VORG", b"EBDT", b"EBLC", b"gasp", b"hdmx", b"kern",
+    b"LTSH", b"PCLT", b"VDMX", b"vhea", b"vmtx", b"BASE", b"GDEF", b"GPOS", b"GSUB", b"EBSC",
+    b"JSTF", b"MATH", b"CBDT", b"CBLC", b"COLR", b"CPAL", b"SVG ", b"sbix", b"acnt", b"avar",
+    b"bdat", b"bloc", b"bsln", b"cvar", b"fdsc", b"feat", b"fmtx", b"fvar", b"gvar", b"hsty",
+    b"just", b"lcar", b"mort", b"morx", b"opbd", b"prop", b"trak", b"Zapf", b"Silf", b"Glat",
+    b"Gloc", b"Feat", b"Sill",
+];
+
+/// Entry in the table directory of a WOFF2 file.
+#[derive(Copy, Clone, PartialEq, Eq, Debug)]
+pub struct Woff2TableRecord {
+    /// Table identifier.
+    pub tag: Tag,
+    /// Version of the preprocessing transformation applied to the table.
+    pub transform_version: u8,
+    /// Length of the original table data.
+    pub orig_len: u32,
+    /// Length of the transformed table data if a transformation was
+    /// applied.
+    pub transform_len: Option<u32>,
+}
+
+impl Woff2TableRecord {
+    /// Returns true if the table data was transformed.
+    pub fn is_transformed(&self) -> bool {
+        self.transform_len.is_some()
+    }
+
+    /// Returns the length of the table in the decompressed data stream.
+    pub fn stream_len(&self) -> u32 {
+        self.transform_len.unwrap_or(self.orig_len)
+    }
+}
+
+/// Font in a WOFF2 collection directory.
+#[derive(Clone, PartialEq, Eq, Debug)]
+pub struct Woff2CollectionFont {
+    /// Version tag of the font.
+    pub flavor: u32,
+    /// Indices of the tables of the font in the table directory.
+    pub tables: Vec<u16>,
+}
+
+/// Font or font collection compressed with WOFF 2.0.
+#[derive(Clone)]
+pub struct Woff2<'a> {
+    data: Buffer<'a>,
+    records: Vec<Woff2TableRecord>,
+    fonts: Vec<Woff2CollectionFont>,
+    collection_version: u32,
+    stream_offset: usize,
+}
+
+impl<'a> Woff2<'a> {
+    /// Creates a new WOFF2 font from a byte slice containing the file data.
+    /// Returns `None` if the data does not begin with a valid WOFF 2.0
+    /// header and table directory.
+    pub fn new(data: &'a [u8]) -> Option<Self> {
+        let b = Buffer::new(data);
+        if b.read_tag(0)? != WOFF2 {
+            return None;
+        }
+        let flavor = b.read_u32(4)?;
+        let num_tables = b.read_u16(12)? as usize;
+        let mut c = b.cursor_at(HEADER_LEN)?;
+        let mut records = Vec::with_capacity(num_tables);
+        for _ in 0..num_tables {
+            let flags = c.read_u8()?;
+            let tag = match flags & 0x3F {
+                63 => c.read_tag()?,
+                index => Tag::new(KNOWN_TAGS[index as usize]),
+            };
+            let transform_version = flags >> 6;
+            let orig_len = read_base128(&mut c)?;
+            let is_transformed = if tag == GLYF || tag == LOCA {
+                transform_version != 3
+            } else {
+                transform_version != 0
+            };
+            let transform_len = if is_transformed {
+                Some(read_base128(&mut c)?)
+            } else {
+                None
+            };
+            records.push(Woff2TableRecord {
+                tag,
+                transform_version,
+                orig_len,
+                transform_len,
+            });
+        }
+        let mut fonts = Vec::new();
+        let mut collection_version = 0;
+        if flavor == TTCF {
+            collection_version = c.read_u32()?;
+            let num_fonts = read_u255(&mut c)?;
+            for _ in 0..num_fonts {
+                let num_tables = read_u255(&mut c)?;
+                let flavor = c.read_u32()?;
+                let mut tables = Vec::with_capacity(num_tables as usize);
+                for _ in 0..num_tables {
+                    let index = read_u255(&mut c)?;
+                    if index as usize >= records.len() {
+                        return None;
+                    }
+                    tables.push(index);
+                }
+                fonts.push(Woff2CollectionFont { flavor, tables });
+            }
+        } else {
+            fonts.push(Woff2CollectionFont {
+                flavor,
+                tables: (0..num_tables as u16).collect(),
+            });
+        }
+        let stream_offset = HEADER_LEN + c.offset();
+        Some(Self {
+            data: b,
+            records,
+            fonts,
+            collection_version,
+            stream_offset,
+        })
+    }
+
+    /// Returns the version tag of the uncompressed font or collection.
+    pub fn flavor(&self) -> u32 {
+        self.data.read_u32(4).unwrap_or(0)
+    }
+
+    /// Returns true if the data contains a font collection.
+    pub fn is_collection(&self) -> bool {
+        self.flavor() == TTCF
+    }
+
+    /// Returns the size of the uncompressed font or collection.
+    pub fn total_sfnt_size(&self) -> u32 {
+        self.data.read_u32(16).unwrap_or(0)
+    }
+
+    /// Returns the major and minor version of the font.
+    pub fn font_version(&self) -> (u16, u16) {
+        (
+            self.data.read_u16(24).unwrap_or(0),
+            self.data.read_u16(26).unwrap_or(0),
+        )
+    }
+
+    /// Returns the table directory.
+    pub fn records(&self) -> &[Woff2TableRecord] {
+        &self.records
+    }
+
+    /// Returns the fonts in the file. For a single font, this contains one
+    /// entry that refers to every table in the directory.
+    pub fn fonts(&self) -> &[Woff2CollectionFont] {
+        &self.fonts
+    }
+
+    /// Returns the uncompressed extended metadata block.
+    pub fn metadata(&self) -> Option<Vec<u8>> {
+        let offset = self.data.read_u32(28)? as usize;
+        let len = self.data.read_u32(32)? as usize;
+        let orig_len = self.data.read_u32(36)? as usize;
+        if offset == 0 {
+            return None;
+        }
+        decompress(self.data.read_bytes(offset, len)?, orig_len)
+    }
+
+    /// Returns the private data block.
+    pub fn private_data(&self) -> Option<&'a [u8]> {
+        let offset = self.data.read_u32(40)? as usize;
+        let len = self.data.read_u32(44)? as usize;
+        if offset == 0 {
+            return None;
+        }
+        self.data.read_bytes(offset, len)
+    }
+
+    /// Decodes the font or collection into an sfnt that can be read with
+    /// [`FontRef`].
+    pub fn decode(&self) -> Option<Vec<u8>> {
+        let compressed_len = self.data.read_u32(20)? as usize;
+        let stream_len = self.records.iter().try_fold(0usize, |len, record| {
+            len.checked_add(record.stream_len() as usize)
+        })?;
+        let compressed = self.data.read_bytes(self.stream_offset, compressed_len)?;
+        let stream = decompress(compressed, stream_len)?;
+        let mut offset = 0;
+        let mut tables = Vec::with_capacity(self.records.len());
+        for record in &self.records {
+            let len = record.stream_len() as usize;
+            tables.push(&stream[offset..offset + len]);
+            offset += len;
+        }
+        // Reconstruct the transformed tables first so that the builders
+        // below can borrow them.
+        let mut reconstructed: HashMap<u16, Vec<u8>> = HashMap::new();
+        for font in &self.fonts {
+            let find = |tag: Tag| {
+                font.tables
+                    .iter()
+                    .copied()
+                    .find(|index| self.records[*index as usize].tag == tag)
+            };
+            let glyf = find(GLYF);
+            let loca = find(LOCA);
+            if let (Some(glyf), Some(loca)) = (glyf, loca) {
+                if self.records[glyf as usize].is_transformed()
+                    && !reconstructed.contains_key(&glyf)
+                {
+                    let (new_glyf, new_loca) = transform::reconstruct_glyf(tables[glyf as usize])?;
+                    let expected_len = self.records[loca as usize].orig_len as usize;
+                    if new_loca.len() != expected_len {
+                        return None;
+                    }
+                    reconstructed.insert(glyf, new_glyf);
+                    reconstructed.insert(loca, new_loca);
+                }
+            }
+            let hmtx = match find(HMTX) {
+                Some(hmtx) if self.records[hmtx as usize].is_transformed() => hmtx,
+                _ => continue,
+            };
+            if reconstructed.contains_key(&hmtx) {
+                continue;
+            }
+            let table = |index: Option<u16>| -> Option<&[u8]> {
+                let index = index?;
+                match reconstructed.get(&index) {
+                    Some(data) => Some(data),
+                    None => Some(tables[index as usize]),
+                }
+            };
+            let num_glyphs = Buffer::new(table(find(MAXP))?).read_u16(4)?;
+            let num_long_metrics = Buffer::new(table(find(HHEA))?).read_u16(34)?;
+            let loca_format = Buffer::new(table(find(HEAD))?).read_i16(50)?;
+            let new_hmtx = transform::reconstruct_hmtx(
+                tables[hmtx as usize],
+                num_glyphs,
+                num_long_metrics,
+                table(glyf)?,
+                table(loca)?,
+                loca_format,
+            )?;
+            reconstructed.insert(hmtx, new_hmtx);
+        }
+        let mut builders = Vec::with_capacity(self.fonts.len());
+        for font in &self.fonts {
+            let mut builder = FontBuilder::new(font.flavor);
+            for &index in &font.tables {
+                let record = &self.records[index as usize];
+                let data = match reconstructed.get(&index) {
+                    Some(data) => data.as_slice(),
+                    None if record.is_transformed() => return None,
+                    None => tables[index as usize],
+                };
+                builder.insert(record.tag, data);
+            }
+            builders.push(builder);
+        }
+        if self.is_collection() {
+            let mut collection = CollectionBuilder::new();
+            if self.collection_version >= 0x20000 {
+                collection.set_major_version(2);
+            }
+            for builder in builders {
+                collection.push(builder);
+            }
+            collection.build().ok()
+        } else {
+            builders.first()?.build().ok()
+        }
+    }
+}
+
+/// Decodes a WOFF 2.0 font or collection into an sfnt that can be read with
+/// [`FontRef`].
+pub fn decode(data: &[u8]) -> Option<Vec<u8>> {
+    Woff2::new(data)?.decode()
+}
+
+/// Encodes the specified sfnt font or collection as WOFF 2.0. The glyph
+/// data, index to location and horizontal metrics tables are transformed
+/// when possible.
+pub fn encode(data: &[u8]) -> Option<Vec<u8>> {
+    let file = FontDataRef::new(data)?;
+    let is_collection = match file.kind()? {
+        FontDataKind::Collection => true,
+        FontDataKind::Font(_) => false,
+        _ => return None,
+    };
+    let mut entries: Vec<Entry> = Vec::new();
+    let mut fonts = Vec::new();
+    for font in file.fonts() {
+        let flavor = Buffer::new(font.data).read_u32(font.offset as usize)?;
+        let mut indices = Vec::with_capacity(font.len() as usize);
+        let glyf = transform_glyf(&font);
+        for table in font.tables() {
+            let tag = table.record.tag;
+            let entry = match tag {
+                GLYF => match &glyf {
+                    Some((glyf, _)) => {
+                        let loca = font.table_data(LOCA)?;
+                        let entry = Entry::transformed(GLYF, table.data, 0, glyf.clone());
+                        add_entry(&mut entries, &mut indices, entry);
+                        Entry::transformed(LOCA, loca, 0, Vec::new())
+                    }
+                    None => {
+                        if let Some(loca) = font.table_data(LOCA) {
+                            add_entry(&mut entries, &mut indices, Entry::new(GLYF, table.data, 3));
+                            Entry::new(LOCA, loca, 3)
+                        } else {
+                            Entry::new(GLYF, table.data, 3)
+                        }
+                    }
+                },
+                LOCA if font.table_data(GLYF).is_some() => continue,
+                LOCA => Entry::new(LOCA, table.data, 3),
+                HMTX => match glyf.as_ref().and_then(|(_, hmtx)| hmtx.clone()) {
+                    Some(hmtx) => Entry::transformed(HMTX, table.data, 1, hmtx),
+                    None => Entry::new(HMTX, table.data, 0),
+                },
+                HEAD if glyf.is_some() && table.data.len() >= 18 => {
+                    // Signal that the font has undergone a lossless
+                    // modifying transform.
+                    let mut head = table.data.to_vec();
+                    head[17] |= 0x08;
+                    let mut entry = Entry::new(HEAD, table.data, 0);
+                    entry.stream = Cow::Owned(head);
+                    entry
+                }
+                _ => Entry::new(tag, table.data, 0),
+            };
+            add_entry(&mut entries, &mut indices, entry);
+        }
+        indices.sort_unstable_by_key(|index| entries[*index as usize].tag);
+        fonts.push((flavor, indices));
+    }
+    let mut directory = Vec::new();
+    let mut stream = Vec::new();
+    let mut total_sfnt_size = 0;
+    for entry in &entries {
+        let known = KNOWN_TAGS
+            .iter()
+            .position(|tag| Tag::new(tag) == entry.tag)
+            .unwrap_or(63);
+        directory.push(known as u8 | entry.version << 6);
+        if known == 63 {
+            directory.extend_from_slice(&entry.tag.0.to_be_bytes());
+        }
+        write_base128(&mut directory, entry.data.len() as u32);
+        if entry.is_transformed {
+            write_base128(&mut directory, entry.stream.len() as u32);
+        }
+        stream.extend_from_slice(&entry.stream);
+        total_sfnt_size += padded_len(entry.data.len());
+    }
+    if is_collection {
+        let version = Buffer::new(data).read_u32(4)?;
+        directory.extend_from_slice(&version.to_be_bytes());
+        transform::write_u255(&mut directory, fonts.len() as u16);
+        total_sfnt_size += 12 + fonts.len() * 4 + if version >= 0x20000 { 12 } else { 0 };
+        for (flavor, indices) in &fonts {
+            transform::write_u255(&mut directory, indices.len() as u16);
+            directory.extend_from_slice(&flavor.to_be_bytes());
+            for index in indices {
+                transform::write_u255(&mut directory, *index);
+            }
+            total_sfnt_size += directory_len(indices.len());
+        }
+    } else {
+        total_sfnt_size += directory_len(entries.len());
+    }
+    let compressed = compress(&stream)?;
+    let len = padded_len(HEADER_LEN + directory.len() + compressed.len());
+    let mut woff2 = Vec::with_capacity(len);
+    woff2.extend_from_slice(&WOFF2.0.to_be_bytes());
+    let flavor = if is_collection {
+        TTCF
+    } else {
+        fonts.first()?.0
+    };
+    woff2.extend_from_slice(&flavor.to_be_bytes());
+    woff2.extend_from_slice(&(len as u32).to_be_bytes());
+    woff2.extend_from_slice(&(entries.len() as u16).to_be_bytes());
+    woff2.extend_from_slice(&0u16.to_be_bytes());
+    woff2.extend_from_slice(&(total_sfnt_size as u32).to_be_bytes());
+    woff2.extend_from_slice(&(compressed.len() as u32).to_be_bytes());
+    // Font version, extended metadata and private data.
+    woff2.extend_from_slice(&[0; 24]);
+    woff2.extend_from_slice(&directory);
+    woff2.extend_from_slice(&compressed);
+    woff2.resize(len, 0);
+    Some(woff2)
+}
+
+/// Table to be encoded in a WOFF2 file.
+struct Entry<'a> {
+    tag: Tag,
+    data: &'a [u8],
+    version: u8,
+    stream: Cow<'a, [u8]>,
+    is_transformed: bool,
+}
+
+impl<'a> Entry<'a> {
+    fn new(tag: Tag, data: &'a [u8], version: u8) -> Self {
+        Self {
+            tag,
+            data,
+            version,
+            stream: Cow::Borrowed(data),
+            is_transformed: false,
+        }
+    }
+
+    fn transformed(tag: Tag, data: &'a [u8], version: u8, transformed: Vec<u8>) -> Self {
+        Self {
+            tag,
+            data,
+            version,
+            stream: Cow::Owned(transformed),
+            is_transformed: true,
+        }
+    }
+}
+
+/// Adds a table to the directory unless the same table was added by a
+/// previous font in a collection and records its index.
+fn add_entry<'a>(entries: &mut Vec<Entry<'a>>, indices: &mut Vec<u16>, entry: Entry<'a>) {
+    let index = entries
+        .iter()
+        .position(|other| other.tag == entry.tag && core::ptr::eq(other.data, entry.data))
+        .unwrap_or_else(|| {
+            entries.push(entry);
+            entries.len() - 1
+        });
+    indices.push(index as u16);
+}
+
+/// Returns the transformed glyph data table and, if applicable, the
+/// transformed horizontal metrics table for the specified font.
+fn transform_glyf(font: &FontRef) -> Option<(Vec<u8>, Option<Vec<u8>>)> {
+    let num_glyphs = font.maxp()?.num_glyphs();
+    let index_format = font.head()?.index_to_location_format();
+    let glyf = font.table_data(GLYF)?;
+    let loca_data = font.table_data(LOCA)?;
+    let loca = font.loca()?;
+    let entry_size = if loca.is_long() { 4 } else { 2 };
+    if loca_data.len() != (num_glyphs as usize + 1) * entry_size {
+        return None;
+    }
+    let transformed = transform::transform_glyf(glyf, loca, num_glyphs, index_format)?;
+    let hmtx = match (font.table_data(HMTX), font.hhea()) {
+        (Some(hmtx), Some(hhea)) => {
+            transform::transform_hmtx(hmtx, num_glyphs, hhea.num_long_metrics(), glyf, loca)
+        }
+        _ => None,
+    };
+    Some((transformed, hmtx))
+}
+
+/// Compresses the specified data with Brotli using the font mode.
+fn compress(data: &[u8]) -> Option<Vec<u8>> {
+    let params = BrotliEncoderParams {
+        mode: BrotliEncoderMode::BROTLI_MODE_FONT,
+        quality: 11,
+        lgwin: 22,
+        size_hint: data.len(),
+        ..Default::default()
+    };
+    let mut out = Vec::new();
+    brotli::BrotliCompress(&mut &data[..], &mut out, &params).ok()?;
+    Some(out)
+}
+
+/// Decompresses a Brotli stream that is expected to produce exactly the
+/// specified number of bytes. The output buffer grows with the data that is
+/// actually produced rather than being sized by the expected length.
+fn decompress(data: &[u8], len: usize) -> Option<Vec<u8>> {
+    if len > MAX_DECOMPRESSED_LEN {
+        return None;
+    }
+    let mut out = Vec::new();
+    brotli::Decompressor::new(data, 4096)
+        .take(len as u64 + 1)
+        .read_to_end(&mut out)
+        .ok()?;
+    if out.len() != len {
+        return None;
+    }
+    Some(out)
+}
+
+/// Reads a variable length unsigned integer with up to 32 bits.
+fn read_base128(c: &mut Cursor) -> Option<u32> {
+    let mut value = 0u32;
+    for i in 0..5 {
+        let byte = c.read_u8()?;
+        if i == 0 && byte == 0x80 {
+            return None;
+        }
+        if value & 0xFE00_0000 != 0 {
+            return None;
+        }
+        value = (value << 7) | (byte & 0x7F) as u32;
+        if byte & 0x80 == 0 {
+            return Some(value);
+        }
+    }
+    None
+}
+
+/// Writes a variable length unsigned integer with up to 32 bits.
+fn write_base128(buf: &mut Vec<u8>, mut value: u32) {
+    let mut bytes = [0u8; 5];
+    let mut len = 0;
+    loop {
+        bytes[len] = (value & 0x7F) as u8;
+        value >>= 7;
+        len += 1;
+        if value == 0 {
+            break;
+        }
+    }
+    for i in (0..len).rev() {
+        buf.push(bytes[i] | if i != 0 { 0x80 } else { 0 });
+    }
+}
+
+/// Reads a variable length unsigned 16-bit integer.
+fn read_u255(c: &mut Cursor) -> Option<u16> {
+    Some(match c.read_u8()? {
+        253 => c.read_u16()?,
+        254 => c.read_u8()? as u16 + 506,
+        255 => c.read_u8()? as u16 + 253,
+        code => code as u16,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::glyf::Glyph;
+    use crate::testing::TestFont;
+
+    fn round_trip(data: &[u8]) {
+        let woff2_data = encode(data).unwrap();
+        let woff2 = Woff2::new(&woff2_data).unwrap();
+        assert!(woff2
+            .records()
+            .iter()
+            .any(|record| record.tag == Tag::new(b"glyf") && record.is_transformed()));
+        let decoded = decode(&woff2_data).unwrap();
+        let font = FontRef::from_index(data, 0).unwrap();
+        let decoded = FontRef::from_index(&decoded, 0).unwrap();
+        for table in font.tables() {
+            if ![GLYF, LOCA, HEAD].contains(&table.record.tag) {
+                assert_eq!(decoded.table_data(table.record.tag), Some(table.data));
+            }
+        }
+        // Reconstructed glyphs have the same contours, bounding boxes and
+        // instructions, although the point data may be encoded differently.
+        fn glyph<'a>(font: &FontRef<'a>, glyph_id: GlyphId) -> Option<Glyph<'a>> {
+            let range = font.loca().unwrap().get(glyph_id).unwrap();
+            font.glyf().unwrap().get(range)
+        }
+        for glyph_id in 0..font.maxp().unwrap().num_glyphs() {
+            let (glyph, decoded) = match (glyph(&font, glyph_id), glyph(&decoded, glyph_id)) {
+                (Some(glyph), Some(decoded)) => (glyph, decoded),
+                (glyph, decoded) => {
+                    assert!(glyph.is_none() && decoded.is_none());
+                    continue;
+                }
+            };
+            assert_eq!(glyph.num_contours(), decoded.num_contours());
+            assert_eq!(
+                (glyph.x_min(), glyph.y_min(), glyph.x_max(), glyph.y_max()),
+                (
+                    decoded.x_min(),
+                    decoded.y_min(),
+                    decoded.x_max(),
+                    decoded.y_max()
+                )
+            );
+            let instructions = |glyph: &Glyph| {
+                let b = Buffer::new(glyph.data());
+                let offset = 10 + glyph.num_contours() as usize * 2;
+                let len = b.read_u16(offset).unwrap() as usize;
+                b.read_bytes(offset + 2, len).unwrap().to_vec()
+            };
+            assert_eq!(instructions(&glyph), instructions(&decoded));
+        }
+    }
+
+    #[test]
+    fn round_trip_without_instructions() {
+        round_trip(&TestFont::new(6).chars("abc").build());
+    }
+
+    #[test]
+    fn round_trip_with_instructions() {
+        round_trip(
+            &TestFont::new(6)
+                .chars("abc")
+                .instructions(&[0xB0, 1])
+                .build(),
+        );
+    }
+
+    #[test]
+    fn collection() {
+        let fonts = [4, 6].map(|num_glyphs| TestFont::new(num_glyphs).chars("ab").build());
+        let mut collection = CollectionBuilder::new();
+        for data in &fonts {
+            let font = FontRef::from_index(data, 0).unwrap();
+            collection.push(FontBuilder::from_font(&font).unwrap());
+        }
+        let data = collection.build().unwrap();
+        let woff2_data = encode(&data).unwrap();
+        let woff2 = Woff2::new(&woff2_data).unwrap();
+        assert!(woff2.is_collection());
+        assert_eq!(woff2.fonts().len(), 2);
+        // The shared character map is stored once.
+        let cmaps = woff2
+            .records()
+            .iter()
+            .filter(|record| record.tag == Tag::new(b"cmap"));
+        assert_eq!(cmaps.count(), 1);
+        let decoded = decode(&woff2_data).unwrap();
+        let fonts = FontDataRef::new(&decoded).unwrap();
+        assert_eq!(fonts.len(), 2);
+        for (index, num_glyphs) in [(0, 4), (1, 6)] {
+            let font = fonts.get(index).unwrap();
+            assert_eq!(font.maxp().unwrap().num_glyphs(), num_glyphs);
+            assert_eq!(font.charmap().unwrap().map('b' as u32), Some(2));
+        }
+    }
+
+    #[test]
+    fn invalid_font() {
+        assert!(encode(b"not a font").is_none());
+    }
+
+    #[test]
+    fn oversized_lengths() {
+        let compressed = compress(b"table data").unwrap();
+        let mut data = Vec::new();
+        data.extend_from_slice(&WOFF2.0.to_be_bytes());
+        data.extend_from_slice(&0x00010000u32.to_be_bytes());
+        data.extend_from_slice(&[0; 4]);
+        data.extend_from_slice(&2000u16.to_be_bytes());
+        data.extend_from_slice(&[0; 6]);
+        data.extend_from_slice(&(compressed.len() as u32).to_be_bytes());
+        data.extend_from_slice(&[0; 4]);
+        // Extended metadata that claims to decompress to 4 GiB.
+        let stream_offset = HEADER_LEN + 2000 * 6;
+        data.extend_from_slice(&(stream_offset as u32).to_be_bytes());
+        data.extend_from_slice(&(compressed.len() as u32).to_be_bytes());
+        data.extend_from_slice(&u32::MAX.to_be_bytes());
+        data.extend_from_slice(&[0; 8]);
+        for _ in 0..2000 {
+            data.push(5);
+            write_base128(&mut data, u32::MAX);
+        }
+        data.extend_from_slice(&compressed);
+        let woff2 = Woff2::new(&data).unwrap();
+        assert_eq!(woff2.records().len(), 2000);
+        assert!(woff2.metadata().is_none());
+        assert!(woff2.decode().is_none());
+        assert!(decode(&data).is_none());
+        // The output must match the expected length exactly.
+        assert_eq!(decompress(&compressed, 10).unwrap(), b"table data");
+        assert!(decompress(&compressed, 4).is_none());
+        assert!(decompress(&compressed, 11).is_none());
+    }
+}
@@ -0,0 +1,623 @@
+//! Reconstruction of transformed glyph data, index to location and
+//! horizontal metrics tables.
+
+use super::read_u255;
+use crate::glyf::{Component, Glyf, Loca};
+use crate::parse_prelude::*;
+use core::convert::TryFrom;
+use std::vec::Vec;
+
+/// Flag indicating that a point is on the curve.
+const ON_CURVE_POINT: u8 = 0x01;
+/// Flag indicating that the x coordinate is a single byte.
+const X_SHORT_VECTOR: u8 = 0x02;
+/// Flag indicating that the y coordinate is a single byte.
+const Y_SHORT_VECTOR: u8 = 0x04;
+/// Flag indicating that the flag is repeated.
+const REPEAT_FLAG: u8 = 0x08;
+/// Flag indicating that the x coordinate is repeated or positive.
+const X_IS_SAME_OR_POSITIVE: u8 = 0x10;
+/// Flag indicating that the y coordinate is repeated or positive.
+const Y_IS_SAME_OR_POSITIVE: u8 = 0x20;
+/// Flag indicating that contours in a simple glyph may overlap.
+const OVERLAP_SIMPLE: u8 = 0x40;
+
+/// Returns the glyph data and index to location tables reconstructed from
+/// a transformed glyph data table.
+pub fn reconstruct_glyf(data: &[u8]) -> Option<(Vec<u8>, Vec<u8>)> {
+    let b = Buffer::new(data);
+    let option_flags = b.read_u16(2)?;
+    let num_glyphs = b.read_u16(4)? as usize;
+    let index_format = b.read_u16(6)?;
+    let mut next = 36;
+    let mut stream = |index: usize| -> Option<Cursor> {
+        let len = b.read_u32(8 + index * 4)? as usize;
+        let data = b.read_bytes(next, len)?;
+        next += len;
+        Some(Cursor::new(data))
+    };
+    let mut contour_counts = stream(0)?;
+    let mut point_counts = stream(1)?;
+    let mut flags = stream(2)?;
+    let mut glyphs = stream(3)?;
+    let mut composites = stream(4)?;
+    let mut bboxes = stream(5)?;
+    let mut instructions = stream(6)?;
+    let bbox_bitmap = bboxes.read_bytes(((num_glyphs + 31) >> 5) * 4)?;
+    let overlap_bitmap = if option_flags & 1 != 0 {
+        Some(b.read_bytes(next, (num_glyphs + 7) >> 3)?)
+    } else {
+        None
+    };
+    let is_set = |bitmap: &[u8], index: usize| bitmap[index >> 3] & (0x80 >> (index & 7)) != 0;
+    let mut glyf = Vec::new();
+    let mut offsets = Vec::with_capacity(num_glyphs + 1);
+    let mut points = Vec::new();
+    for glyph_id in 0..num_glyphs {
+        offsets.push(glyf.len());
+        let has_bbox = is_set(bbox_bitmap, glyph_id);
+        let num_contours = contour_counts.read_i16()?;
+        if num_contours == 0 {
+            if has_bbox {
+                return None;
+            }
+            continue;
+        }
+        glyf.extend_from_slice(&num_contours.to_be_bytes());
+        if num_contours > 0 {
+            let mut end_points = Vec::with_capacity(num_contours as usize);
+            let mut num_points = 0usize;
+            for _ in 0..num_contours {
+                num_points += read_u255(&mut point_counts)? as usize;
+                end_points.push(u16::try_from(num_points.checked_sub(1)?).ok()?);
+            }
+            points.clear();
+            let (mut x, mut y) = (0i32, 0i32);
+            for _ in 0..num_points {
+                let flag = flags.read_u8()?;
+                let (dx, dy) = read_triplet(flag & 0x7F, &mut glyphs)?;
+                x += dx;
+                y += dy;
+                points.push((x, y, flag & 0x80 == 0));
+            }
+            if has_bbox {
+                glyf.extend_from_slice(bboxes.read_bytes(8)?);
+            } else {
+                let (mut x_min, mut y_min) = (i32::MAX, i32::MAX);
+                let (mut x_max, mut y_max) = (i32::MIN, i32::MIN);
+                for &(x, y, _) in &points {
+                    x_min = x_min.min(x);
+                    y_min = y_min.min(y);
+                    x_max = x_max.max(x);
+                    y_max = y_max.max(y);
+                }
+                if points.is_empty() {
+                    x_min = 0;
+                    y_min = 0;
+                    x_max = 0;
+                    y_max = 0;
+                }
+                for value in [x_min, y_min, x_max, y_max] {
+                    glyf.extend_from_slice(&(value as i16).to_be_bytes());
+                }
+            }
+            for end_point in end_points {
+                glyf.extend_from_slice(&end_point.to_be_bytes());
+            }
+            let instruction_len = read_u255(&mut glyphs)?;
+            glyf.extend_from_slice(&instruction_len.to_be_bytes());
+            glyf.extend_from_slice(instructions.read_bytes(instruction_len as usize)?);
+            let overlap = overlap_bitmap.map(|bitmap| is_set(bitmap, glyph_id));
+            write_points(&mut glyf, &points, overlap.unwrap_or(false));
+        } else if num_contours == -1 {
+            if !has_bbox {
+                return None;
+            }
+            glyf.extend_from_slice(bboxes.read_bytes(8)?);
+            let start = composites.offset();
+            let mut have_instructions = false;
+            loop {
+                let flags = composites.read_u16()?;
+                let mut len = 2;
+                len += if flags & Component::ARG_1_AND_2_ARE_WORDS != 0 {
+                    4
+                } else {
+                    2
+                };
+                if flags & Component::WE_HAVE_A_SCALE != 0 {
+                    len += 2;
+                } else if flags & Component::WE_HAVE_AN_X_AND_Y_SCALE != 0 {
+                    len += 4;
+                } else if flags & Component::WE_HAVE_A_TWO_BY_TWO != 0 {
+                    len += 8;
+                }
+                composites.skip(len)?;
+                have_instructions |= flags & Component::WE_HAVE_INSTRUCTIONS != 0;
+                if flags & Component::MORE_COMPONENTS == 0 {
+                    break;
+                }
+            }
+            glyf.extend_from_slice(composites.data().get(start..composites.offset())?);
+            if have_instructions {
+                let instruction_len = read_u255(&mut glyphs)?;
+                glyf.extend_from_slice(&instruction_len.to_be_bytes());
+                glyf.extend_from_slice(instructions.read_bytes(instruction_len as usize)?);
+            }
+        } else {
+            return None;
+        }
+        glyf.resize((glyf.len() + 3) & !3, 0);
+    }
+    offsets.push(glyf.len());
+    let mut loca = Vec::with_capacity(offsets.len() * 4);
+    for offset in offsets {
+        if index_format == 0 {
+            loca.extend_from_slice(&u16::try_from(offset / 2).ok()?.to_be_bytes());
+        } else {
+            loca.extend_from_slice(&(offset as u32).to_be_bytes());
+        }
+    }
+    Some((glyf, loca))
+}
+
+/// Returns the horizontal metrics table reconstructed from a transformed
+/// horizontal metrics table. Omitted side bearings are taken from the
+/// minimum x coordinates of the glyphs.
+pub fn reconstruct_hmtx(
+    data: &[u8],
+    num_glyphs: u16,
+    num_long_metrics: u16,
+    glyf: &[u8],
+    loca: &[u8],
+    loca_format: i16,
+) -> Option<Vec<u8>> {
+    let num_glyphs = num_glyphs as usize;
+    let num_long_metrics = num_long_metrics as usize;
+    if num_long_metrics == 0 || num_long_metrics > num_glyphs {
+        return None;
+    }
+    let glyf = Glyf::new(glyf);
+    let loca = Loca::new(loca, loca_format);
+    let x_min = |glyph_id: usize| -> Option<i16> {
+        let range = loca.get(glyph_id as u16)?;
+        Some(glyf.get(range).map(|glyph| glyph.x_min()).unwrap_or(0))
+    };
+    let mut c = Cursor::new(data);
+    let flags = c.read_u8()?;
+    let mut advances = Vec::with_capacity(num_long_metrics);
+    for _ in 0..num_long_metrics {
+        advances.push(c.read_u16()?);
+    }
+    let mut bearings = Vec::with_capacity(num_glyphs);
+    for glyph_id in 0..num_glyphs {
+        let is_explicit = if glyph_id < num_long_metrics {
+            flags & 1 == 0
+        } else {
+            flags & 2 == 0
+        };
+        if is_explicit {
+            bearings.push(c.read_i16()?);
+        } else {
+            bearings.push(x_min(glyph_id)?);
+        }
+    }
+    let mut hmtx = Vec::with_capacity(num_long_metrics * 2 + num_glyphs * 2);
+    for (glyph_id, bearing) in bearings.iter().enumerate() {
+        if let Some(advance) = advances.get(glyph_id) {
+            hmtx.extend_from_slice(&advance.to_be_bytes());
+        }
+        hmtx.extend_from_slice(&bearing.to_be_bytes());
+    }
+    Some(hmtx)
+}
+
+/// Reads a pair of coordinate deltas encoded as a triplet with the
+/// specified flag.
+fn read_triplet(flag: u8, c: &mut Cursor) -> Option<(i32, i32)> {
+    let with_sign = |flag: u8, value: i32| if flag & 1 != 0 { value } else { -value };
+    let flag32 = flag as i32;
+    Some(if flag < 10 {
+        let b0 = c.read_u8()? as i32;
+        (0, with_sign(flag, ((flag32 & 14) << 7) + b0))
+    } else if flag < 20 {
+        let b0 = c.read_u8()? as i32;
+        (with_sign(flag, (((flag32 - 10) & 14) << 7) + b0), 0)
+    } else if flag < 84 {
+        let b0 = flag32 - 20;
+        let b1 = c.read_u8()? as i32;
+        (
+            with_sign(flag, 1 + (b0 & 0x30) + (b1 >> 4)),
+            with_sign(flag >> 1, 1 + ((b0 & 0x0C) << 2) + (b1 & 0x0F)),
+        )
+    } else if flag < 120 {
+        let b0 = flag32 - 84;
+        let b1 = c.read_u8()? as i32;
+        let b2 = c.read_u8()? as i32;
+        (
+            with_sign(flag, 1 + ((b0 / 12) << 8) + b1),
+            with_sign(flag >> 1, 1 + (((b0 % 12) >> 2) << 8) + b2),
+        )
+    } else if flag < 124 {
+        let b1 = c.read_u8()? as i32;
+        let b2 = c.read_u8()? as i32;
+        let b3 = c.read_u8()? as i32;
+        (
+            with_sign(flag, (b1 << 4) + (b2 >> 4)),
+            with_sign(flag >> 1, ((b2 & 0x0F) << 8) + b3),
+        )
+    } else {
+        let x = c.read_u16()? as i32;
+        let y = c.read_u16()? as i32;
+        (with_sign(flag, x), with_sign(flag >> 1, y))
+    })
+}
+
+/// Writes the flags and coordinates of a simple glyph.
+fn write_points(glyf: &mut Vec<u8>, points: &[(i32, i32, bool)], overlap: bool) {
+    let mut flags = Vec::with_capacity(points.len());
+    let mut xs = Vec::with_capacity(points.len() * 2);
+    let mut ys = Vec::with_capacity(points.len() * 2);
+    let (mut last_x, mut last_y) = (0, 0);
+    let mut last_flag = None;
+    let mut repeat = 0;
+    for (i, &(x, y, on_curve)) in points.iter().enumerate() {
+        let mut flag = if on_curve { ON_CURVE_POINT } else { 0 };
+        if overlap && i == 0 {
+            flag |= OVERLAP_SIMPLE;
+        }
+        let (dx, dy) = (x - last_x, y - last_y);
+        last_x = x;
+        last_y = y;
+        flag |= write_delta(&mut xs, dx, X_SHORT_VECTOR, X_IS_SAME_OR_POSITIVE);
+        flag |= write_delta(&mut ys, dy, Y_SHORT_VECTOR, Y_IS_SAME_OR_POSITIVE);
+        if last_flag == Some(flag) && repeat < 255 {
+            if repeat == 0 {
+                let len = flags.len();
+                flags[len - 1] |= REPEAT_FLAG;
+                flags.push(1);
+            } else {
+                let len = flags.len();
+                flags[len - 1] += 1;
+            }
+            repeat += 1;
+        } else {
+            flags.push(flag);
+            last_flag = Some(flag);
+            repeat = 0;
+        }
+    }
+    glyf.extend_from_slice(&flags);
+    glyf.extend_from_slice(&xs);
+    glyf.extend_from_slice(&ys);
+}
+
+/// Writes a coordinate delta in its most compact form, returning the
+/// flags that describe the encoding.
+fn write_delta(buf: &mut Vec<u8>, delta: i32, short: u8, same_or_positive: u8) -> u8 {
+    if delta == 0 {
+        same_or_positive
+    } else if delta > -256 && delta < 256 {
+        buf.push(delta.unsigned_abs() as u8);
+        if delta > 0 {
+            short | same_or_positive
+        } else {
+            short
+        }
+    } else {
+        buf.extend_from_slice(&(delta as i16).to_be_bytes());
+        0
+    }
+}
+
+/// Returns the transformed glyph data table for the specified glyph data and
+/// index to location tables. Returns `None` if the glyph data cannot be
+/// represented in the transformed format.
+pub fn transform_glyf(
+    glyf: &[u8],
+    loca: Loca,
+    num_glyphs: u16,
+    index_format: i16,
+) -> Option<Vec<u8>> {
+    let glyf = Glyf::new(glyf);
+    let num_glyphs = num_glyphs as usize;
+    let mut contour_counts = Vec::new();
+    let mut point_counts = Vec::new();
+    let mut flags = Vec::new();
+    let mut glyphs = Vec::new();
+    let mut composites = Vec::new();
+    let mut bbox_bitmap = std::vec![0u8; ((num_glyphs + 31) >> 5) * 4];
+    let mut bboxes = Vec::new();
+    let mut instructions = Vec::new();
+    let mut overlap_bitmap = std::vec![0u8; (num_glyphs + 7) >> 3];
+    let mut has_overlap = false;
+    for glyph_id in 0..num_glyphs {
+        let glyph = match glyf.get(loca.get(glyph_id as u16)?) {
+            Some(glyph) if glyph.num_contours() != 0 => glyph,
+            _ => {
+                contour_counts.extend_from_slice(&0i16.to_be_bytes());
+                continue;
+            }
+        };
+        let num_contours = glyph.num_contours();
+        let data = glyph.data();
+        let bbox = data.get(2..10)?;
+        contour_counts.extend_from_slice(&num_contours.to_be_bytes());
+        if num_contours > 0 {
+            let simple = SimpleGlyph::parse(data, num_contours as usize)?;
+            let mut last_end = -1i32;
+            for &end_point in &simple.end_points {
+                let count = end_point as i32 - last_end;
+                write_u255(&mut point_counts, u16::try_from(count).ok()?);
+                last_end = end_point as i32;
+            }
+            let (mut last_x, mut last_y) = (0, 0);
+            for &(x, y, on_curve) in &simple.points {
+                write_triplet(&mut flags, &mut glyphs, on_curve, x - last_x, y - last_y);
+                last_x = x;
+                last_y = y;
+            }
+            write_u255(&mut glyphs, simple.instructions.len() as u16);
+            instructions.extend_from_slice(simple.instructions);
+            if simple.bbox() != bbox {
+                bbox_bitmap[glyph_id >> 3] |= 0x80 >> (glyph_id & 7);
+                bboxes.extend_from_slice(bbox);
+            }
+            if simple.overlap {
+                overlap_bitmap[glyph_id >> 3] |= 0x80 >> (glyph_id & 7);
+                has_overlap = true;
+            }
+        } else if num_contours == -1 {
+            bbox_bitmap[glyph_id >> 3] |= 0x80 >> (glyph_id & 7);
+            bboxes.extend_from_slice(bbox);
+            let mut end = 10;
+            let mut have_instructions = false;
+            for component in glyph.components() {
+                have_instructions |= component.flags & Component::WE_HAVE_INSTRUCTIONS != 0;
+                end = component_end(data, component.glyph_id_offset - 2)?;
+            }
+            composites.extend_from_slice(data.get(10..end)?);
+            if have_instructions {
+                let b = Buffer::new(data);
+                let len = b.read_u16(end)?;
+                write_u255(&mut glyphs, len);
+                instructions.extend_from_slice(b.read_bytes(end + 2, len as usize)?);
+            }
+        } else {
+            return None;
+        }
+    }
+    bbox_bitmap.extend_from_slice(&bboxes);
+    let streams = [
+        &contour_counts,
+        &point_counts,
+        &flags,
+        &glyphs,
+        &composites,
+        &bbox_bitmap,
+        &instructions,
+    ];
+    let mut data = Vec::new();
+    data.extend_from_slice(&0u16.to_be_bytes());
+    data.extend_from_slice(&(has_overlap as u16).to_be_bytes());
+    data.extend_from_slice(&(num_glyphs as u16).to_be_bytes());
+    data.extend_from_slice(&(index_format as u16).to_be_bytes());
+    for stream in &streams {
+        data.extend_from_slice(&(stream.len() as u32).to_be_bytes());
+    }
+    for stream in &streams {
+        data.extend_from_slice(stream);
+    }
+    if has_overlap {
+        data.extend_from_slice(&overlap_bitmap);
+    }
+    Some(data)
+}
+
+/// Returns the transformed horizontal metrics table or `None` if no side
+/// bearings can be omitted.
+pub fn transform_hmtx(
+    data: &[u8],
+    num_glyphs: u16,
+    num_long_metrics: u16,
+    glyf: &[u8],
+    loca: Loca,
+) -> Option<Vec<u8>> {
+    let b = Buffer::new(data);
+    let glyf = Glyf::new(glyf);
+    let num_glyphs = num_glyphs as usize;
+    let num_long_metrics = num_long_metrics as usize;
+    if num_long_metrics == 0 || num_long_metrics > num_glyphs {
+        return None;
+    }
+    let mut bearings = Vec::with_capacity(num_glyphs);
+    let mut matches = [true, true];
+    for glyph_id in 0..num_glyphs {
+        let bearing = if glyph_id < num_long_metrics {
+            b.read_i16(glyph_id * 4 + 2)?
+        } else {
+            b.read_i16(num_long_metrics * 4 + (glyph_id - num_long_metrics) * 2)?
+        };
+        let x_min = glyf
+            .get(loca.get(glyph_id as u16)?)
+            .map(|glyph| glyph.x_min())
+            .unwrap_or(0);
+        if bearing != x_min {
+            matches[(glyph_id >= num_long_metrics) as usize] = false;
+        }
+        bearings.push(bearing);
+    }
+    let flags = matches[0] as u8 | (matches[1] as u8) << 1;
+    if flags == 0 {
+        return None;
+    }
+    let mut hmtx = Vec::with_capacity(1 + num_long_metrics * 2 + num_glyphs * 2);
+    hmtx.push(flags);
+    for glyph_id in 0..num_long_metrics {
+        hmtx.extend_from_slice(b.read_bytes(glyph_id * 4, 2)?);
+    }
+    for (glyph_id, bearing) in bearings.iter().enumerate() {
+        if !matches[(glyph_id >= num_long_metrics) as usize] {
+            hmtx.extend_from_slice(&bearing.to_be_bytes());
+        }
+    }
+    Some(hmtx)
+}
+
+/// Decoded outline of a simple glyph.
+struct SimpleGlyph<'a> {
+    end_points: Vec<u16>,
+    instructions: &'a [u8],
+    points: Vec<(i32, i32, bool)>,
+    overlap: bool,
+}
+
+impl<'a> SimpleGlyph<'a> {
+    fn parse(data: &'a [u8], num_contours: usize) -> Option<Self> {
+        let mut c = Cursor::new(data);
+        c.skip(10)?;
+        let mut end_points = Vec::with_capacity(num_contours);
+        for _ in 0..num_contours {
+            let end_point = c.read_u16()?;
+            if end_points.last().map(|last| end_point < *last) == Some(true) {
+                return None;
+            }
+            end_points.push(end_point);
+        }
+        let num_points = *end_points.last()? as usize + 1;
+        let instruction_len = c.read_u16()? as usize;
+        let instructions = c.read_bytes(instruction_len)?;
+        let mut flags = Vec::with_capacity(num_points);
+        while flags.len() < num_points {
+            let flag = c.read_u8()?;
+            flags.push(flag);
+            if flag & REPEAT_FLAG != 0 {
+                for _ in 0..c.read_u8()? {
+                    flags.push(flag);
+                }
+            }
+        }
+        flags.truncate(num_points);
+        let mut points = Vec::with_capacity(num_points);
+        let mut x = 0i32;
+        for &flag in &flags {
+            x += read_delta(&mut c, flag, X_SHORT_VECTOR, X_IS_SAME_OR_POSITIVE)?;
+            points.push((x, 0, flag & ON_CURVE_POINT != 0));
+        }
+        let mut y = 0i32;
+        for (point, &flag) in points.iter_mut().zip(&flags) {
+            y += read_delta(&mut c, flag, Y_SHORT_VECTOR, Y_IS_SAME_OR_POSITIVE)?;
+            point.1 = y;
+        }
+        Some(Self {
+            end_points,
+            instructions,
+            points,
+            overlap: flags[0] & OVERLAP_SIMPLE != 0,
+        })
+    }
+
+    /// Returns the bounding box computed from the points in the same format
+    /// as the glyph header.
+    fn bbox(&self) -> [u8; 8] {
+        let mut bbox = [i32::MAX, i32::MAX, i32::MIN, i32::MIN];
+        for &(x, y, _) in &self.points {
+            bbox[0] = bbox[0].min(x);
+            bbox[1] = bbox[1].min(y);
+            bbox[2] = bbox[2].max(x);
+            bbox[3] = bbox[3].max(y);
+        }
+        let mut data = [0u8; 8];
+        for (i, value) in bbox.iter().enumerate() {
+            data[i * 2..i * 2 + 2].copy_from_slice(&(*value as i16).to_be_bytes());
+        }
+        data
+    }
+}
+
+/// Reads a coordinate delta with the encoding described by the flag.
+fn read_delta(c: &mut Cursor, flag: u8, short: u8, same_or_positive: u8) -> Option<i32> {
+    Some(if flag & short != 0 {
+        let value = c.read_u8()? as i32;
+        if flag & same_or_positive != 0 {
+            value
+        } else {
+            -value
+        }
+    } else if flag & same_or_positive != 0 {
+        0
+    } else {
+        c.read_i16()? as i32
+    })
+}
+
+/// Returns the offset of the end of the component at the specified offset.
+fn component_end(data: &[u8], offset: usize) -> Option<usize> {
+    let flags = Buffer::new(data).read_u16(offset)?;
+    let mut len = 4;
+    len += if flags & Component::ARG_1_AND_2_ARE_WORDS != 0 {
+        4
+    } else {
+        2
+    };
+    if flags & Component::WE_HAVE_A_SCALE != 0 {
+        len += 2;
+    } else if flags & Component::WE_HAVE_AN_X_AND_Y_SCALE != 0 {
+        len += 4;
+    } else if flags & Component::WE_HAVE_A_TWO_BY_TWO != 0 {
+        len += 8;
+    }
+    let end = offset + len;
+    if end > data.len() {
+        return None;
+    }
+    Some(end)
+}
+
+/// Writes a pair of coordinate deltas as a triplet.
+fn write_triplet(flags: &mut Vec<u8>, glyphs: &mut Vec<u8>, on_curve: bool, dx: i32, dy: i32) {
+    let (x, y) = (dx.unsigned_abs(), dy.unsigned_abs());
+    let on_curve_bit = if on_curve { 0 } else { 128 };
+    let x_sign = (dx >= 0) as u32;
+    let y_sign = (dy >= 0) as u32;
+    let xy_signs = x_sign | y_sign << 1;
+    let flag = if dx == 0 && y < 1280 {
+        glyphs.push(y as u8);
+        ((y & 0xF00) >> 7) + y_sign
+    } else if dy == 0 && x < 1280 {
+        glyphs.push(x as u8);
+        10 + ((x & 0xF00) >> 7) + x_sign
+    } else if x < 65 && y < 65 && x > 0 && y > 0 {
+        glyphs.push((((x - 1) & 0xF) << 4 | ((y - 1) & 0xF)) as u8);
+        20 + ((x - 1) & 0x30) + (((y - 1) & 0x30) >> 2) + xy_signs
+    } else if x < 769 && y < 769 && x > 0 && y > 0 {
+        glyphs.push((x - 1) as u8);
+        glyphs.push((y - 1) as u8);
+        84 + 12 * (((x - 1) & 0x300) >> 8) + (((y - 1) & 0x300) >> 6) + xy_signs
+    } else if x < 4096 && y < 4096 {
+        glyphs.push((x >> 4) as u8);
+        glyphs.push(((x & 0xF) << 4 | y >> 8) as u8);
+        glyphs.push(y as u8);
+        120 + xy_signs
+    } else {
+        glyphs.extend_from_slice(&(x as u16).to_be_bytes());
+        glyphs.extend_from_slice(&(y as u16).to_be_bytes());
+        124 + xy_signs
+    };
+    flags.push(flag as u8 | on_curve_bit);
+}
+
+/// Writes a variable length unsigned 16-bit integer.
+pub fn write_u255(buf: &mut Vec<u8>, value: u16) {
+    if value < 253 {
+        buf.push(value as u8);
+    } else if value < 506 {
+        buf.push(255);
+        buf.push((value - 253) as u8);
+    } else if value < 762 {
+        buf.push(254);
+        buf.push((value - 506) as u8);
+    } else {
+        buf.push(253);
+        buf.extend_from_slice(&value.to_be_bytes());
+    }
+}